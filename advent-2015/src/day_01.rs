@@ -0,0 +1,163 @@
+use std::{collections::BTreeMap, io::BufRead};
+
+use advent_core::{
+    answer::Answer, context::Context, day::Day, describe::DayInfo, examples::examples,
+};
+use anyhow::Context as _;
+
+/// Everything about Santa's trip through the building. Positions count instructions from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FloorTraversalResult {
+    pub end_floor: i32,
+    /// Every position where Santa stepped from the ground floor down into the basement
+    pub basement_entries: Vec<usize>,
+    pub min_floor: i32,
+    pub max_floor: i32,
+    /// How many times Santa was on each floor, counting the ground floor he starts on
+    pub floor_visits: BTreeMap<i32, usize>,
+}
+
+impl FloorTraversalResult {
+    /// Position of the instruction that first takes Santa into the basement
+    pub fn first_basement_entry(&self) -> Option<usize> {
+        self.basement_entries.first().copied()
+    }
+}
+
+/// Follows `instructions` from the ground floor, going up a floor for `(` and down for `)`.
+/// Any other character leaves Santa where he is.
+pub fn walk_floors(instructions: &str) -> FloorTraversalResult {
+    let mut result = FloorTraversalResult {
+        end_floor: 0,
+        basement_entries: Vec::new(),
+        min_floor: 0,
+        max_floor: 0,
+        floor_visits: BTreeMap::from([(0, 1)]),
+    };
+
+    for (idx, c) in instructions.chars().enumerate() {
+        let prev_floor = result.end_floor;
+
+        result.end_floor += match c {
+            '(' => 1,
+            ')' => -1,
+            _ => 0,
+        };
+
+        if prev_floor == 0 && result.end_floor == -1 {
+            result.basement_entries.push(idx + 1);
+        }
+
+        result.min_floor = result.min_floor.min(result.end_floor);
+        result.max_floor = result.max_floor.max(result.end_floor);
+        *result.floor_visits.entry(result.end_floor).or_default() += 1;
+    }
+
+    result
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Not Quite Lisp",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = FloorTraversalResult;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut instructions = String::new();
+
+        reader.read_to_string(&mut instructions)?;
+
+        Ok(walk_floors(instructions.trim_end()))
+    }
+
+    /// Floor Santa ends up on
+    fn part_01(input: &Self::Input) -> Answer {
+        input.end_floor.into()
+    }
+
+    /// Step at which Santa first enters the basement, or 0 when he never does
+    fn part_02(input: &Self::Input) -> Answer {
+        input.first_basement_entry().unwrap_or_default().into()
+    }
+
+    /// Fails rather than answering 0 when Santa never enters the basement
+    fn part_02_with(input: &Self::Input, _ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(input
+            .first_basement_entry()
+            .context("Santa never enters the basement")?
+            .into())
+    }
+}
+
+examples! {
+    part1: "(())" => "0",
+    part1: "))(((((" => "3",
+    part1: ")())())" => "-3",
+    part2: ")" => "1",
+    part2: "()())" => "5",
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn end_floor() {
+        let floor_tests = vec![
+            ("(())", 0),
+            ("()()", 0),
+            ("(((", 3),
+            ("(()(()(", 3),
+            ("))(((((", 3),
+            ("())", -1),
+            ("))(", -1),
+            (")))", -3),
+            (")())())", -3),
+        ];
+
+        for (directions, expected) in floor_tests {
+            let FloorTraversalResult { end_floor, .. } = walk_floors(directions);
+            assert_eq!(end_floor, expected);
+        }
+    }
+
+    #[test]
+    fn first_basement_position() {
+        let basement_tests = vec![(")", 1), ("()())", 5)];
+
+        for (directions, expected) in basement_tests {
+            assert_eq!(
+                walk_floors(directions).first_basement_entry(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn traversal_statistics() {
+        let result = walk_floors("(()))()))((((");
+
+        assert_eq!(result.end_floor, 1);
+        assert_eq!(result.basement_entries, [5, 7]);
+        assert_eq!((result.min_floor, result.max_floor), (-3, 2));
+        assert_eq!(
+            result.floor_visits,
+            BTreeMap::from([(-3, 1), (-2, 2), (-1, 3), (0, 4), (1, 3), (2, 1)])
+        );
+        assert_eq!(result.floor_visits.values().sum::<usize>(), 14);
+
+        assert_eq!(walk_floors("(((").first_basement_entry(), None);
+    }
+
+    #[test]
+    fn never_in_basement() {
+        let input = Puzzle::parse("(((".as_bytes()).unwrap();
+        let error = Puzzle::part_02_with(&input, &mut Context::new(None)).unwrap_err();
+
+        assert_eq!(error.to_string(), "Santa never enters the basement");
+    }
+}
@@ -0,0 +1,221 @@
+use std::{fmt, io::prelude::*, str::FromStr};
+
+use anyhow::Context;
+use thiserror::Error;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    lint::{self, Malformed},
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PresentParseError {
+    #[error("presents should have exactly three dimensions but {0} were given")]
+    DimensionCount(usize),
+    #[error("'{0}' is not a valid dimension")]
+    InvalidDimension(String),
+}
+
+/// A box shaped present written as `LxWxH`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Present {
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Present {
+    /// Dimensions from smallest to largest
+    fn sorted_dims(&self) -> [u32; 3] {
+        let mut dims = [self.length, self.width, self.height];
+
+        dims.sort_unstable();
+
+        dims
+    }
+
+    /// Paper to cover every side plus slack equal to the area of the smallest side
+    pub fn paper(&self) -> u32 {
+        let [a, b, c] = self.sorted_dims();
+
+        2 * (a * b + b * c + a * c) + a * b
+    }
+
+    /// Ribbon to go around the smallest perimeter plus a bow as long as the volume
+    pub fn ribbon(&self) -> u32 {
+        let [a, b, _] = self.sorted_dims();
+
+        2 * (a + b) + self.volume()
+    }
+
+    pub fn volume(&self) -> u32 {
+        self.length * self.width * self.height
+    }
+}
+
+impl FromStr for Present {
+    type Err = PresentParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dims = s
+            .trim()
+            .split('x')
+            .map(|d| {
+                d.parse()
+                    .map_err(|_| PresentParseError::InvalidDimension(d.to_owned()))
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+
+        match dims[..] {
+            [length, width, height] => Ok(Self {
+                length,
+                width,
+                height,
+            }),
+            _ => Err(PresentParseError::DimensionCount(dims.len())),
+        }
+    }
+}
+
+/// Totals for wrapping a whole list of presents
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WrapReport {
+    pub count: usize,
+    /// Totals are wider than a single present's so long lists do not overflow them
+    pub paper: u64,
+    pub ribbon: u64,
+    /// The present with the most volume, the earliest one if several are tied
+    pub largest: Option<Present>,
+}
+
+impl<'a> FromIterator<&'a Present> for WrapReport {
+    fn from_iter<T: IntoIterator<Item = &'a Present>>(iter: T) -> Self {
+        let mut report = Self::default();
+
+        for p in iter {
+            report.count += 1;
+            report.paper += u64::from(p.paper());
+            report.ribbon += u64::from(p.ribbon());
+
+            if report.largest.is_none_or(|l| p.volume() > l.volume()) {
+                report.largest = Some(*p);
+            }
+        }
+
+        report
+    }
+}
+
+impl fmt::Display for WrapReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} presents need {} square feet of paper and {} feet of ribbon",
+            self.count, self.paper, self.ribbon
+        )?;
+
+        if let Some(p) = self.largest {
+            write!(
+                f,
+                ", the largest being {}x{}x{}",
+                p.length, p.width, p.height
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "I Was Told There Would Be No Math",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Present>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid present", i + 1))
+            })
+            .collect()
+    }
+
+    const VALIDATES: bool = true;
+
+    fn validate(input: &str) -> Result<(), Malformed> {
+        lint::each_line(input, |line| {
+            line.parse::<Present>()
+                .map(drop)
+                .map_err(|e| format!("is not a present like 2x3x4 ({e})"))
+        })
+    }
+
+    /// Total wrapping paper needed
+    fn part_01(input: &Self::Input) -> Answer {
+        input.iter().collect::<WrapReport>().paper.into()
+    }
+
+    /// Total feet of ribbon
+    fn part_02(input: &Self::Input) -> Answer {
+        input.iter().collect::<WrapReport>().ribbon.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paper_ribbons() {
+        let present = "2x3x4".parse::<Present>().unwrap();
+
+        assert_eq!(present.paper(), 58);
+        assert_eq!(present.ribbon(), 34);
+
+        let present = "1x1x10".parse::<Present>().unwrap();
+
+        assert_eq!(present.paper(), 43);
+        assert_eq!(present.ribbon(), 14);
+    }
+
+    #[test]
+    fn malformed_presents() {
+        assert_eq!(
+            "2x3".parse::<Present>(),
+            Err(PresentParseError::DimensionCount(2))
+        );
+        assert_eq!(
+            "2x3x4x5".parse::<Present>(),
+            Err(PresentParseError::DimensionCount(4))
+        );
+        assert_eq!(
+            "2x-3x4".parse::<Present>(),
+            Err(PresentParseError::InvalidDimension("-3".into()))
+        );
+        assert!(Puzzle::parse("2x3x4\n\n1x1x1".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn report() {
+        let presents = Puzzle::parse("2x3x4\n1x1x10\n5x1x5".as_bytes()).unwrap();
+        let report = presents.iter().collect::<WrapReport>();
+
+        assert_eq!(report.count, 3);
+        assert_eq!(report.paper, 58 + 43 + 75);
+        assert_eq!(report.ribbon, 34 + 14 + 37);
+        assert_eq!(report.largest, Some(presents[2]));
+        assert_eq!(
+            report.to_string(),
+            "3 presents need 176 square feet of paper and 85 feet of ribbon, the largest being 5x1x5"
+        );
+    }
+}
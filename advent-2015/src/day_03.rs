@@ -0,0 +1,321 @@
+use std::{fmt, io::BufRead};
+
+use anyhow::Context;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::{
+        collections::FastHashMap,
+        geom::{Bounds, Dir4, Point2},
+        quadrants::QuadrantGrid,
+    },
+};
+
+/// Directions from which deliveries are tracked in a dense grid rather than a hash map
+pub const DENSE_FROM: usize = 131_072;
+
+/// Cells a dense grid may grow to per direction before its houses move to a hash map, which
+/// bounds the memory of walks that head off in a straight line instead of wandering
+const CELLS_PER_DIRECTION: usize = 4;
+
+#[derive(Clone, Debug)]
+enum Houses {
+    /// Only the houses visited, hashed by position
+    Sparse(FastHashMap<Point2<i64>, u32>),
+    /// Every house in reach of the walk so far, up to `budget` of them
+    Dense {
+        grid: QuadrantGrid<u32>,
+        visited: usize,
+        budget: usize,
+    },
+}
+
+/// How many presents each house received, keyed by position where the first house is the origin
+/// and `^` moves towards negative `y`. Houses are hashed by position, or for long walks counted in
+/// a dense grid that grows outwards from the origin as needed.
+#[derive(Clone, Debug)]
+pub struct HouseGrid {
+    houses: Houses,
+}
+
+impl Default for HouseGrid {
+    fn default() -> Self {
+        Self::sparse()
+    }
+}
+
+impl HouseGrid {
+    /// Tracks houses in a hash map, which suits short walks
+    pub fn sparse() -> Self {
+        Self {
+            houses: Houses::Sparse(FastHashMap::default()),
+        }
+    }
+
+    /// Tracks houses in a dense grid until it holds more than `budget` cells, after which the
+    /// houses visited move to a hash map
+    pub fn dense(budget: usize) -> Self {
+        Self {
+            houses: Houses::Dense {
+                grid: QuadrantGrid::new(),
+                visited: 0,
+                budget,
+            },
+        }
+    }
+
+    /// Whichever of [`HouseGrid::sparse`] and [`HouseGrid::dense`] is faster for a walk of
+    /// `directions`
+    pub fn for_directions(directions: usize) -> Self {
+        if directions < DENSE_FROM {
+            Self::sparse()
+        } else {
+            Self::dense(directions.saturating_mul(CELLS_PER_DIRECTION))
+        }
+    }
+
+    /// Whether houses are still counted in a dense grid
+    pub fn is_dense(&self) -> bool {
+        matches!(self.houses, Houses::Dense { .. })
+    }
+
+    /// Delivers `presents` to the house at `pos`
+    pub fn deliver(&mut self, pos: Point2<i64>, presents: u32) {
+        match &mut self.houses {
+            Houses::Sparse(houses) => *houses.entry(pos).or_default() += presents,
+            Houses::Dense {
+                grid,
+                visited,
+                budget,
+            } => {
+                let count = grid.get_mut(pos);
+
+                if *count == 0 && presents > 0 {
+                    *visited += 1;
+                }
+
+                *count += presents;
+
+                if grid.allocated() > *budget {
+                    let houses = grid.iter().filter(|&(_, count)| count > 0).collect();
+
+                    self.houses = Houses::Sparse(houses);
+                }
+            }
+        }
+    }
+
+    /// Houses that received at least one present
+    pub fn houses_visited(&self) -> usize {
+        match &self.houses {
+            Houses::Sparse(houses) => houses.len(),
+            Houses::Dense { visited, .. } => *visited,
+        }
+    }
+
+    /// Presents delivered to the house at `pos`
+    pub fn visits(&self, pos: Point2<i64>) -> u32 {
+        match &self.houses {
+            Houses::Sparse(houses) => houses.get(&pos).copied().unwrap_or(0),
+            Houses::Dense { grid, .. } => grid.get(pos),
+        }
+    }
+
+    /// Every visited house and how many presents it received, ordered by `x` and then `y`
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, u32)> {
+        let mut houses = match &self.houses {
+            Houses::Sparse(houses) => houses.iter().map(|(&pos, &count)| (pos, count)).collect(),
+            Houses::Dense { grid, .. } => grid
+                .iter()
+                .filter(|&(_, count)| count > 0)
+                .collect::<Vec<_>>(),
+        };
+
+        houses.sort_unstable_by_key(|&(pos, _)| pos);
+        houses.into_iter()
+    }
+
+    /// Smallest rectangle holding every visited house
+    pub fn bounds(&self) -> Option<Bounds> {
+        Bounds::of(self.iter().map(|(pos, _)| pos))
+    }
+
+    /// Delivers presents with `n_agents` all starting at the origin and taking turns following
+    /// `directions`. Panics if there are no agents.
+    pub fn visit(mut self, directions: &[Dir4], n_agents: usize) -> Self {
+        assert!(n_agents > 0, "at least one agent should deliver presents");
+
+        let mut agents = vec![Point2::ORIGIN; n_agents];
+
+        self.deliver(Point2::ORIGIN, n_agents as u32);
+
+        for (turn, &dir) in directions.iter().enumerate() {
+            let agent = &mut agents[turn % n_agents];
+
+            *agent = agent.step(dir);
+            self.deliver(*agent, 1);
+        }
+
+        self
+    }
+}
+
+/// Grids are equal when the same houses received the same presents, however they are tracked
+impl PartialEq for HouseGrid {
+    fn eq(&self, other: &Self) -> bool {
+        self.houses_visited() == other.houses_visited() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for HouseGrid {}
+
+/// Draws the bounding box with the northernmost row first, marking visited houses with `#`
+impl fmt::Display for HouseGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(bounds) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in bounds.min.y..=bounds.max.y {
+            let row = (bounds.min.x..=bounds.max.x)
+                .map(|x| {
+                    if self.visits(Point2::new(x, y)) > 0 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+
+            writeln!(f, "{row}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Delivers presents with `n_agents` all starting at the same house and taking turns following
+/// `directions`, tracking houses in whichever grid suits the length of the walk. Panics if there
+/// are no agents.
+pub fn visit_houses_n(directions: &[Dir4], n_agents: usize) -> HouseGrid {
+    HouseGrid::for_directions(directions.len()).visit(directions, n_agents)
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Perfectly Spherical Houses in a Vacuum",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Dir4>;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut directions = String::new();
+
+        reader.read_to_string(&mut directions)?;
+
+        directions
+            .trim_end()
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                Dir4::try_from(c).with_context(|| format!("character {} is not an arrow", i + 1))
+            })
+            .collect()
+    }
+
+    /// Houses visited at least once by Santa alone
+    fn part_01(input: &Self::Input) -> Answer {
+        visit_houses_n(input, 1).houses_visited().into()
+    }
+
+    /// Houses visited at least once by Santa and Robo-Santa taking turns
+    fn part_02(input: &Self::Input) -> Answer {
+        visit_houses_n(input, 2).houses_visited().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn visit(directions: &str, n_agents: usize) -> HouseGrid {
+        visit_houses_n(&Puzzle::parse(directions.as_bytes()).unwrap(), n_agents)
+    }
+
+    #[test]
+    fn straight_delivery() {
+        let tests = vec![(">", 2), ("^>v<", 4), ("^v^v^v^v^v", 2)];
+
+        for (directions, expected) in tests {
+            let res = visit(directions, 1).houses_visited();
+
+            assert_eq!(expected, res);
+        }
+    }
+
+    #[test]
+    fn split_delivery() {
+        let tests = vec![("^>v<", 3), ("^v^v^v^v^v", 11)];
+
+        for (directions, expected) in tests {
+            let res = visit(directions, 2).houses_visited();
+
+            assert_eq!(expected, res);
+        }
+    }
+
+    #[test]
+    fn many_agents() {
+        let grid = visit("^>v<^^", 3);
+
+        assert_eq!(grid.houses_visited(), 6);
+        assert_eq!(grid.visits(Point2::ORIGIN), 4);
+        assert_eq!(grid.visits(Point2::new(0, 1)), 1);
+        assert_eq!(grid.visits(Point2::new(-1, 1)), 0);
+        assert_eq!(
+            grid.bounds(),
+            Some(Bounds {
+                min: Point2::new(-1, -1),
+                max: Point2::new(1, 1)
+            })
+        );
+        assert_eq!(grid.iter().map(|(_, c)| c).sum::<u32>(), 9);
+        assert!(grid.iter().map(|(pos, _)| pos).is_sorted());
+        assert_eq!(grid.to_string(), "###\n.##\n.#.\n");
+
+        // With more agents than directions, each agent moves at most once
+        assert_eq!(visit("^^^", 5).houses_visited(), 2);
+        assert!(Puzzle::parse("^>x".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn dense_matches_sparse() {
+        let directions = Puzzle::parse("^>v<<^^>>>vvv<<<<^".repeat(50).as_bytes()).unwrap();
+
+        for n_agents in 1..=3 {
+            let sparse = HouseGrid::sparse().visit(&directions, n_agents);
+            let dense = HouseGrid::dense(usize::MAX).visit(&directions, n_agents);
+
+            assert!(dense.is_dense() && !sparse.is_dense());
+            assert_eq!(dense.houses_visited(), sparse.houses_visited());
+            assert_eq!(
+                dense.visits(Point2::new(-1, -1)),
+                sparse.visits(Point2::new(-1, -1))
+            );
+            assert_eq!(dense, sparse);
+        }
+
+        // A staircase needs far more cells than houses, so it outgrows its budget
+        let staircase = Puzzle::parse("^>".repeat(500).as_bytes()).unwrap();
+        let grid = HouseGrid::dense(4 * staircase.len()).visit(&staircase, 1);
+
+        assert!(!grid.is_dense());
+        assert_eq!(grid.houses_visited(), 1001);
+        assert_eq!(grid, HouseGrid::sparse().visit(&staircase, 1));
+    }
+}
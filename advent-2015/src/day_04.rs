@@ -0,0 +1,63 @@
+use std::io::BufRead;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    progress::Progress,
+    util::md5_mine::{find_zeroes_from, ZeroesCheckpoint},
+};
+
+/// The secret key along with how far searches of its hashes have got, so that solving both parts
+/// from one parse lets part 2 pick up where part 1 stopped
+#[derive(Debug)]
+pub struct Miner {
+    key: &'static str,
+    checkpoint: ZeroesCheckpoint,
+}
+
+impl Miner {
+    pub fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            checkpoint: ZeroesCheckpoint::new(),
+        }
+    }
+
+    /// Lowest number giving a hash of the key with `nibbles` leading zeroes, counting hashes as it
+    /// goes
+    fn mine_coin(&self, nibbles: usize) -> u64 {
+        let progress = Progress::counter("hashes checked");
+
+        // Only a cancelled search stops without a match, and its answer is never shown
+        find_zeroes_from(self.key, nibbles, &self.checkpoint, &progress).map_or(0, |(i, _)| i)
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "The Ideal Stocking Stuffer",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Miner;
+
+    const NEEDS_INPUT: bool = false;
+
+    fn parse(_reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Ok(Miner::new("ckczppom"))
+    }
+
+    /// Lowest number giving a hash with five leading zeroes
+    fn part_01(input: &Self::Input) -> Answer {
+        input.mine_coin(5).into()
+    }
+
+    /// Lowest number giving a hash with six leading zeroes, which cannot be below the lowest with
+    /// five
+    fn part_02(input: &Self::Input) -> Answer {
+        input.mine_coin(6).into()
+    }
+}
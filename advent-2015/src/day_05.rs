@@ -0,0 +1,351 @@
+use std::{collections::HashMap, io::BufRead, str::FromStr};
+
+use thiserror::Error;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::par_lines::par_lines_str};
+
+/// A check that a string either passes or fails. Rules can be combined into larger rules with
+/// [`StringRule::and`] and [`StringRule::not`].
+pub trait StringRule: Send + Sync {
+    fn matches(&self, s: &str) -> bool;
+
+    /// Passes when both `self` and `other` pass
+    fn and<R: StringRule>(self, other: R) -> And<Self, R>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Passes when `self` fails
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+impl StringRule for Box<dyn StringRule> {
+    fn matches(&self, s: &str) -> bool {
+        self.as_ref().matches(s)
+    }
+}
+
+/// See [`StringRule::and`]
+#[derive(Clone, Debug)]
+pub struct And<A, B>(A, B);
+
+impl<A: StringRule, B: StringRule> StringRule for And<A, B> {
+    fn matches(&self, s: &str) -> bool {
+        self.0.matches(s) && self.1.matches(s)
+    }
+}
+
+/// See [`StringRule::not`]
+#[derive(Clone, Debug)]
+pub struct Not<A>(A);
+
+impl<A: StringRule> StringRule for Not<A> {
+    fn matches(&self, s: &str) -> bool {
+        !self.0.matches(s)
+    }
+}
+
+/// At least `min` characters are accepted by `predicate`
+#[derive(Clone, Debug)]
+pub struct AtLeast<P> {
+    pub min: usize,
+    pub predicate: P,
+}
+
+impl AtLeast<fn(char) -> bool> {
+    /// At least `min` of the characters are vowels (`aeiou`)
+    pub fn vowels(min: usize) -> Self {
+        Self {
+            min,
+            predicate: |c| "aeiou".contains(c),
+        }
+    }
+}
+
+impl<P: Fn(char) -> bool + Send + Sync> StringRule for AtLeast<P> {
+    fn matches(&self, s: &str) -> bool {
+        s.chars()
+            .filter(|&c| (self.predicate)(c))
+            .take(self.min)
+            .count()
+            == self.min
+    }
+}
+
+/// Some letter appears twice in a row, like `xx`
+#[derive(Copy, Clone, Debug)]
+pub struct DoubleLetter;
+
+impl StringRule for DoubleLetter {
+    fn matches(&self, s: &str) -> bool {
+        s.as_bytes().windows(2).any(|w| w[0] == w[1])
+    }
+}
+
+/// None of the pairs appear anywhere in the string
+#[derive(Clone, Debug)]
+pub struct ForbiddenPairs(pub Vec<String>);
+
+impl Default for ForbiddenPairs {
+    /// The pairs from the original rules: `ab`, `cd`, `pq`, and `xy`
+    fn default() -> Self {
+        Self(["ab", "cd", "pq", "xy"].map(String::from).to_vec())
+    }
+}
+
+impl StringRule for ForbiddenPairs {
+    fn matches(&self, s: &str) -> bool {
+        !self.0.iter().any(|p| s.contains(p.as_str()))
+    }
+}
+
+/// Some pair of letters appears twice without overlapping, like `xyxy` but not `aaa`
+#[derive(Copy, Clone, Debug)]
+pub struct PairTwice;
+
+impl StringRule for PairTwice {
+    fn matches(&self, s: &str) -> bool {
+        let mut first_seen = HashMap::new();
+
+        s.as_bytes()
+            .windows(2)
+            .enumerate()
+            .any(|(i, pair)| i >= *first_seen.entry(pair).or_insert(i) + 2)
+    }
+}
+
+/// Some letter repeats with exactly one letter between, like `xyx` or `aaa`
+#[derive(Copy, Clone, Debug)]
+pub struct Sandwich;
+
+impl StringRule for Sandwich {
+    fn matches(&self, s: &str) -> bool {
+        s.as_bytes().windows(3).any(|w| w[0] == w[2])
+    }
+}
+
+/// Rules from part 1: three vowels, a double letter, and no forbidden pairs
+pub fn nice_rules() -> impl StringRule {
+    AtLeast::vowels(3)
+        .and(DoubleLetter)
+        .and(ForbiddenPairs::default())
+}
+
+/// Rules from part 2: a repeated pair and a sandwiched letter
+pub fn nicer_rules() -> impl StringRule {
+    PairTwice.and(Sandwich)
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RuleParseError {
+    #[error("unknown rule '{0}'")]
+    UnknownRule(String),
+    #[error("'{0}' should be followed by a number")]
+    InvalidCount(String),
+}
+
+/// Rules that must all pass, written as a comma separated list such as `vowels>=3,double`.
+/// Available rules are `vowels>=N`, `double`, `forbid` (the original pairs) or `forbid=ab|cd`,
+/// `pair-twice`, `sandwich`, and the complete sets `nice` and `nicer`. Any rule can be negated
+/// with a leading `!`.
+pub struct RuleSet(Vec<Box<dyn StringRule>>);
+
+impl StringRule for RuleSet {
+    fn matches(&self, s: &str) -> bool {
+        self.0.iter().all(|r| r.matches(s))
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .map(|term| {
+                let (negated, name) = match term.strip_prefix('!') {
+                    Some(name) => (true, name),
+                    None => (false, term),
+                };
+
+                let rule: Box<dyn StringRule> = match name {
+                    "double" => Box::new(DoubleLetter),
+                    "forbid" => Box::new(ForbiddenPairs::default()),
+                    "pair-twice" => Box::new(PairTwice),
+                    "sandwich" => Box::new(Sandwich),
+                    "nice" => Box::new(nice_rules()),
+                    "nicer" => Box::new(nicer_rules()),
+                    _ => {
+                        if let Some(min) = name.strip_prefix("vowels>=") {
+                            let min = min
+                                .parse()
+                                .map_err(|_| RuleParseError::InvalidCount("vowels>=".into()))?;
+
+                            Box::new(AtLeast::vowels(min))
+                        } else if let Some(pairs) = name.strip_prefix("forbid=") {
+                            Box::new(ForbiddenPairs(pairs.split('|').map(String::from).collect()))
+                        } else {
+                            return Err(RuleParseError::UnknownRule(term.to_owned()));
+                        }
+                    }
+                };
+
+                Ok(if negated { Box::new(rule.not()) } else { rule })
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+fn string_is_nice(input: &str) -> bool {
+    nice_rules().matches(input)
+}
+
+fn string_is_nicer(input: &str) -> bool {
+    nicer_rules().matches(input)
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Doesn't He Have Intern-Elves For This?",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Every string is judged on its own, so the lines are kept together and checked in parallel
+    type Input = String;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        Ok(text)
+    }
+
+    /// Total nice strings under the original rules
+    fn part_01(input: &Self::Input) -> Answer {
+        par_lines_str(
+            input,
+            || 0,
+            |s| usize::from(string_is_nice(s)),
+            |a, b| a + b,
+        )
+        .into()
+    }
+
+    /// Total nice strings under the revised rules
+    fn part_02(input: &Self::Input) -> Answer {
+        par_lines_str(
+            input,
+            || 0,
+            |s| usize::from(string_is_nicer(s)),
+            |a, b| a + b,
+        )
+        .into()
+    }
+
+    const STREAMING: bool = true;
+
+    fn part_01_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut nice = 0;
+
+        for l in reader.lines() {
+            nice += usize::from(string_is_nice(&l?));
+        }
+
+        Ok(nice.into())
+    }
+
+    fn part_02_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut nicer = 0;
+
+        for l in reader.lines() {
+            nicer += usize::from(string_is_nicer(&l?));
+        }
+
+        Ok(nicer.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nice_strings() {
+        assert!(string_is_nice("ugknbfddgicrmopn"));
+        assert!(string_is_nice("aaa"));
+    }
+
+    #[test]
+    fn not_nice_strings() {
+        assert!(!string_is_nice("jchzalrnumimnmhp"));
+        assert!(!string_is_nice("haegwjzuvuyypxyu"));
+        assert!(!string_is_nice("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn nicer_strings() {
+        assert!(string_is_nicer("qjhvhtzxzqqjkmpb"));
+        assert!(string_is_nicer("xxyxx"));
+    }
+
+    #[test]
+    fn not_nicer_strings() {
+        assert!(!string_is_nicer("uurcxstgmygtbstg"));
+        assert!(!string_is_nicer("ieodomkazucvgmuy"));
+    }
+
+    #[test]
+    fn individual_rules() {
+        assert!(AtLeast::vowels(3).matches("xazegov"));
+        assert!(!AtLeast::vowels(3).matches("dvszwmarrgswjxmb"));
+        assert!(DoubleLetter.matches("abcdde"));
+        assert!(!DoubleLetter.matches("abcde"));
+        assert!(!ForbiddenPairs::default().matches("haegwjzuvuyypxyu"));
+        assert!(ForbiddenPairs(vec!["zz".into()]).matches("xyab"));
+        assert!(PairTwice.matches("aabcdefgaa"));
+        assert!(!PairTwice.matches("aaa"));
+        assert!(PairTwice.matches("aaaa"));
+        assert!(Sandwich.matches("abcdefeghi"));
+        assert!(!Sandwich.matches("abcd"));
+    }
+
+    #[test]
+    fn combine_rules() {
+        let rule = DoubleLetter.and(Sandwich.not());
+
+        assert!(rule.matches("abbc"));
+        assert!(!rule.matches("abbb"));
+        assert!(!rule.matches("abc"));
+
+        let parsed = "vowels>=2, !forbid=zz|yy".parse::<RuleSet>().unwrap();
+
+        assert!(parsed.matches("aezz"));
+        assert!(!parsed.matches("aexx"));
+        assert!(!parsed.matches("axzz"));
+
+        let nice = "nice".parse::<RuleSet>().unwrap();
+
+        assert!(nice.matches("ugknbfddgicrmopn"));
+        assert!(!nice.matches("jchzalrnumimnmhp"));
+
+        assert_eq!(
+            "double,triple".parse::<RuleSet>().err(),
+            Some(RuleParseError::UnknownRule("triple".into()))
+        );
+        assert_eq!(
+            "vowels>=x".parse::<RuleSet>().err(),
+            Some(RuleParseError::InvalidCount("vowels>=".into()))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::io::BufRead;
+
+use advent_core::{
+    answer::Answer,
+    context::Context,
+    day::Day,
+    describe::DayInfo,
+    util::grid::{LightChanger, Region, SimpleLights, VariableLights},
+    visualize::{shade_grid, Visualizer},
+};
+
+/// Width and height of the square light grid
+const GRID_SIZE: usize = 1000;
+
+/// Parses a corner written as "x,y"
+fn parse_corner(part: &str) -> (usize, usize) {
+    let coords: Vec<_> = part.split(',').map(|p| p.parse().unwrap()).collect();
+
+    (coords[0], coords[1])
+}
+
+pub enum RequestedAction {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+impl RequestedAction {
+    fn from_parts<'a, I>(parts: &mut I) -> Self
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        match parts.next().unwrap() {
+            "turn" => match parts.next().unwrap() {
+                "on" => RequestedAction::TurnOn,
+                "off" => RequestedAction::TurnOff,
+                _ => unreachable!("Turn can only be 'on' or 'off'."),
+            },
+            "toggle" => RequestedAction::Toggle,
+            _ => unreachable!("First word must be 'turn' or 'toggle'."),
+        }
+    }
+}
+
+/// Parses a line such as "toggle 0,0 through 999,0" into its action and the area it affects
+pub fn parse_instruction(line: &str) -> (RequestedAction, Region) {
+    let mut parts = line.split_ascii_whitespace();
+    let action = RequestedAction::from_parts(&mut parts);
+    let parts = parts.collect::<Vec<_>>();
+    let area = Region::new(parse_corner(parts[0]), parse_corner(parts[2]));
+
+    (action, area)
+}
+
+/// Applies every instruction to `lights` in order
+pub fn process_instructions(
+    instructions: &[(RequestedAction, Region)],
+    lights: &mut impl LightChanger,
+) {
+    for (action, area) in instructions {
+        match action {
+            RequestedAction::TurnOn => lights.turn_on(area),
+            RequestedAction::TurnOff => lights.turn_off(area),
+            RequestedAction::Toggle => lights.toggle(area),
+        }
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Probably a Fire Hazard",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<(RequestedAction, Region)>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader.lines().map(|l| Ok(parse_instruction(&l?))).collect()
+    }
+
+    /// Lights on when each light is either on or off
+    fn part_01(input: &Self::Input) -> Answer {
+        let mut simple_lights = SimpleLights::<GRID_SIZE, GRID_SIZE>::default();
+
+        process_instructions(input, &mut simple_lights);
+
+        simple_lights.count_on().into()
+    }
+
+    /// Total brightness when each light has a variable brightness
+    fn part_02(input: &Self::Input) -> Answer {
+        let mut var_lights = VariableLights::<GRID_SIZE, GRID_SIZE>::default();
+
+        process_instructions(input, &mut var_lights);
+
+        var_lights.count_on().into()
+    }
+}
+
+/// Applies each instruction in turn, rendering the grid shrunk to 100x50 characters after each
+fn visualize_instructions(
+    instructions: &[(RequestedAction, Region)],
+    lights: &mut impl LightChanger,
+    frame: &mut dyn FnMut(&str),
+) {
+    for (i, instruction) in instructions.iter().enumerate() {
+        process_instructions(std::slice::from_ref(instruction), lights);
+
+        let max = lights.lights().iter().copied().max().unwrap_or(0);
+
+        frame(&format!(
+            "{}Instruction {}/{}, lights on: {}\n",
+            shade_grid(lights.lights(), GRID_SIZE, (10, 20), max),
+            i + 1,
+            instructions.len(),
+            lights.count_on()
+        ));
+    }
+}
+
+impl Visualizer for Puzzle {
+    fn visualize(
+        input: &Self::Input,
+        part: i32,
+        _ctx: &Context<'_>,
+        frame: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<()> {
+        if part == 1 {
+            visualize_instructions(
+                input,
+                &mut SimpleLights::<GRID_SIZE, GRID_SIZE>::default(),
+                frame,
+            );
+        } else {
+            visualize_instructions(
+                input,
+                &mut VariableLights::<GRID_SIZE, GRID_SIZE>::default(),
+                frame,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn row_operations() {
+        let instructions =
+            ["turn on 0,0 through 999,999", "toggle 0,0 through 999,0"].map(parse_instruction);
+
+        let mut simple = SimpleLights::<GRID_SIZE, GRID_SIZE>::default();
+
+        process_instructions(&instructions, &mut simple);
+
+        assert_eq!(simple.count_on(), 1000 * 1000 - 1000);
+
+        let mut variable = VariableLights::<GRID_SIZE, GRID_SIZE>::default();
+
+        process_instructions(
+            &[
+                parse_instruction("turn on 0,0 through 0,0"),
+                parse_instruction("toggle 0,0 through 999,999"),
+                parse_instruction("turn off 499,499 through 500,500"),
+                parse_instruction("turn off 499,499 through 500,500"),
+                parse_instruction("turn off 499,499 through 500,500"),
+            ],
+            &mut variable,
+        );
+
+        assert_eq!(variable.count_on(), 1 + 2_000_000 - 4 * 2);
+    }
+}
@@ -0,0 +1,150 @@
+use std::io::BufRead;
+
+use advent_core::{
+    answer::Answer,
+    context::Context,
+    day::{parse_input, Day},
+    describe::DayInfo,
+    explore::{self, Explorable, ExploreCommand, ExploreError},
+    util::circuit::{CircuitError, LogicWires},
+};
+
+const EXPLORE_COMMANDS: &[ExploreCommand] = &[
+    ExploreCommand {
+        name: "wire",
+        args: "NAME",
+        about: "Signal on a wire",
+    },
+    ExploreCommand {
+        name: "order",
+        args: "NAME",
+        about: "Wires still to be evaluated before the signal on a wire is known",
+    },
+    ExploreCommand {
+        name: "set",
+        args: "NAME SIGNAL",
+        about: "Overrides a wire with a signal, as part 2 does",
+    },
+];
+
+/// The circuit as the `explore` command sees it, since [`LogicWires`] lives in `advent_core`
+pub struct CircuitExplorer(pub LogicWires);
+
+/// Parses the circuit in `ctx` to be explored
+pub fn explore(ctx: &mut Context<'_>) -> anyhow::Result<Box<dyn Explorable>> {
+    Ok(Box::new(CircuitExplorer(parse_input::<Puzzle>(
+        ctx.take_input(),
+    )?)))
+}
+
+impl Explorable for CircuitExplorer {
+    fn commands(&self) -> &'static [ExploreCommand] {
+        EXPLORE_COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, ExploreError> {
+        let failed = |e: CircuitError| ExploreError::Failed(e.to_string());
+
+        match (command, args) {
+            ("wire", [wire]) => self.0.val(wire).map(|v| v.to_string()).map_err(failed),
+            ("order", [wire]) => match self.0.evaluation_order(wire).map_err(failed)? {
+                order if order.is_empty() => Ok(format!("{wire} is already evaluated")),
+                order => Ok(order.join(" -> ")),
+            },
+            ("set", [wire, signal]) => {
+                let signal = signal.parse().map_err(|_| EXPLORE_COMMANDS[2].misused())?;
+                let stale = self.0.invalidate(wire);
+
+                self.0.override_wire(wire, signal);
+
+                Ok(format!(
+                    "{wire} set to {signal}, {stale} signals to evaluate again"
+                ))
+            }
+            _ => Err(explore::unrecognized(EXPLORE_COMMANDS, command)),
+        }
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Some Assembly Required",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// The circuit, already settled so both parts can read wire `a` without failing
+    type Input = LogicWires;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut wires = LogicWires::default();
+
+        for line in reader.lines() {
+            wires.add_connection(&line?)?;
+        }
+
+        wires.settle()?;
+        wires.val("a")?;
+
+        Ok(wires)
+    }
+
+    /// Signal on wire `a`
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .clone()
+            .val("a")
+            .expect("circuit was settled when parsing the input")
+            .into()
+    }
+
+    /// Signal on wire `a` after overriding wire `b` with the original signal on `a`
+    fn part_02(input: &Self::Input) -> Answer {
+        // The settled signals are kept, so only the wires downstream of `b` are evaluated again
+        let mut wires = input.clone();
+        let a = wires
+            .val("a")
+            .expect("circuit was settled when parsing the input");
+
+        wires.override_wire("b", a);
+        wires
+            .val("a")
+            .expect("overriding a wire with a signal cannot break a settled circuit")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reject_cycles() {
+        assert!(Puzzle::parse(b"b -> a\na -> b".as_slice()).is_err());
+        assert!(Puzzle::parse(b"1 -> b".as_slice()).is_err());
+        assert!(Puzzle::parse(b"1 -> b\nb -> a".as_slice()).is_ok());
+    }
+
+    #[test]
+    fn explore_wires() {
+        let mut wires = explore(&mut Context::new(Some(
+            &mut b"123 -> x\nx AND 15 -> y\ny -> a".as_slice(),
+        )))
+        .unwrap();
+
+        assert_eq!(wires.run("wire", &["a"]).unwrap(), "11");
+        assert_eq!(
+            wires.run("order", &["a"]).unwrap(),
+            "a is already evaluated"
+        );
+        assert_eq!(
+            wires.run("set", &["x", "7"]).unwrap(),
+            "x set to 7, 3 signals to evaluate again"
+        );
+        assert_eq!(wires.run("order", &["a"]).unwrap(), "x -> y -> a");
+        assert_eq!(wires.run("wire", &["a"]).unwrap(), "7");
+        assert!(wires.run("wire", &["q"]).is_err());
+        assert!(wires.run("set", &["x", "-1"]).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::strings::{escape, unescape},
+};
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Matchsticks",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// String literals exactly as they appear in the code
+    type Input = Vec<String>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let l = l?;
+
+                unescape(&l).with_context(|| format!("line {} is not a valid literal", i + 1))?;
+
+                Ok(l)
+            })
+            .collect()
+    }
+
+    /// Characters of code minus characters in memory across all strings
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|l| {
+                let unescaped = unescape(l).expect("literals are validated when parsing the input");
+
+                l.len() - unescaped.chars().count()
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    /// Characters once encoded minus characters of code across all strings
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|l| escape(l).len() - l.len())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r#"""
+"abc"
+"aaa\"aaa"
+"\x27""#;
+
+    #[test]
+    fn mem_lengths() {
+        let input = Puzzle::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_01(&input), Answer::Int(12));
+    }
+
+    #[test]
+    fn encode_lengths() {
+        let input = Puzzle::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_02(&input), Answer::Int(19));
+    }
+
+    #[test]
+    fn reject_invalid_literals() {
+        let err = Puzzle::parse(
+            r#""ok"
+"\xg1""#
+                .as_bytes(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "line 2 is not a valid literal");
+    }
+}
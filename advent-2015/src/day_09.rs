@@ -1,20 +1,27 @@
-use itertools::Itertools;
 use std::{collections::HashMap, io::BufRead};
 
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::{intern::Interner, perm::Permutations},
+};
+
+/// Named locations and the distances between every pair of them, keyed by the index of each
+/// location's interned name
 #[derive(Debug, Default)]
-struct LocationGraph {
-    names: Vec<String>,
+pub struct LocationGraph {
+    names: Interner,
     distances: HashMap<(usize, usize), usize>,
 }
 
 impl LocationGraph {
-    fn from_lines<S, I>(lines: I) -> LocationGraph
+    pub fn from_lines<S, I>(lines: I) -> LocationGraph
     where
         S: AsRef<str>,
         I: Iterator<Item = S>,
     {
-        let mut name_indices = HashMap::new();
-        let mut names = Vec::new();
+        let mut names = Interner::new();
         let mut distances = HashMap::new();
 
         for l in lines {
@@ -23,14 +30,9 @@ impl LocationGraph {
             let from_to = main_parts.next().unwrap().split(" to ");
             let dist = main_parts.next().unwrap().parse::<usize>().unwrap();
 
-            for loc in from_to.clone() {
-                if !name_indices.contains_key(loc) {
-                    name_indices.insert(loc.to_string(), names.len());
-                    names.push(loc.to_string());
-                }
-            }
-
-            let from_to_indices = from_to.map(|loc| name_indices[loc]).collect::<Vec<_>>();
+            let from_to_indices = from_to
+                .map(|loc| names.intern(loc).index())
+                .collect::<Vec<_>>();
 
             distances.insert((from_to_indices[0], from_to_indices[1]), dist);
             distances.insert((from_to_indices[1], from_to_indices[0]), dist);
@@ -43,44 +45,61 @@ impl LocationGraph {
     where
         F: Fn(usize, usize) -> bool,
     {
-        let num_entries = self.names.len();
+        let mut routes = Permutations::of_indices(self.names.len());
         let mut route = Vec::new();
         let mut chosen_dist = initial;
 
-        for p in (0..num_entries).permutations(num_entries) {
+        while let Some(p) = routes.next_permutation() {
             let dist: usize = p.windows(2).map(|w| self.distances[&(w[0], w[1])]).sum();
 
             if cmp(dist, chosen_dist) {
-                route = p;
+                route = p.to_vec();
                 chosen_dist = dist;
             }
         }
 
+        let names = self.names.iter().map(|(_, name)| name).collect::<Vec<_>>();
+
         (
-            route.into_iter().map(|i| self.names[i].clone()).collect(),
+            route.into_iter().map(|i| names[i].to_owned()).collect(),
             chosen_dist,
         )
     }
 
-    fn bad_traveling_salesman(&self) -> (Vec<String>, usize) {
+    pub fn bad_traveling_salesman(&self) -> (Vec<String>, usize) {
         self.traveling_salesman(0, |curr, sel| curr > sel)
     }
 
-    fn good_traveling_salesman(&self) -> (Vec<String>, usize) {
+    pub fn good_traveling_salesman(&self) -> (Vec<String>, usize) {
         self.traveling_salesman(usize::MAX, |curr, sel| curr < sel)
     }
 }
 
-pub fn part_01(reader: Option<impl BufRead>) {
-    let graph = LocationGraph::from_lines(reader.unwrap().lines().map_while(Result::ok));
+pub const INFO: DayInfo = DayInfo {
+    title: "All in a Single Night",
+    links: &[],
+};
 
-    println!("{:?}", graph.good_traveling_salesman());
-}
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = LocationGraph;
 
-pub fn part_02(reader: Option<impl BufRead>) {
-    let graph = LocationGraph::from_lines(reader.unwrap().lines().map_while(Result::ok));
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
 
-    println!("{:?}", graph.bad_traveling_salesman());
+        Ok(LocationGraph::from_lines(lines.iter()))
+    }
+
+    /// Distance of the shortest route visiting every location
+    fn part_01(input: &Self::Input) -> Answer {
+        input.good_traveling_salesman().1.into()
+    }
+
+    /// Distance of the longest route visiting every location
+    fn part_02(input: &Self::Input) -> Answer {
+        input.bad_traveling_salesman().1.into()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,83 @@
+use std::io::BufRead;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::rle};
+
+/// Reads `seq` aloud, replacing each run of a digit with how many times it repeats followed by the
+/// digit itself
+pub fn look_and_say(seq: &[u8]) -> Vec<u8> {
+    let mut res = Vec::with_capacity(seq.len() * 2);
+
+    for (count, digit) in rle::runs(seq) {
+        // Past the seed, runs never grow longer than three, so a single digit almost always does
+        if count < 10 {
+            res.push(b'0' + count as u8);
+        } else {
+            res.extend_from_slice(count.to_string().as_bytes());
+        }
+
+        res.push(digit);
+    }
+
+    res
+}
+
+/// The sequence after reading `seq` aloud `times` times
+pub fn repeat_look_and_say(seq: &str, times: usize) -> Vec<u8> {
+    let mut data = seq.as_bytes().to_vec();
+
+    for _ in 0..times {
+        data = look_and_say(&data);
+    }
+
+    data
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Elves Look, Elves Say",
+    links: &["https://en.wikipedia.org/wiki/Look-and-say_sequence"],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = &'static str;
+
+    const NEEDS_INPUT: bool = false;
+
+    fn parse(_reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Ok("3113322113")
+    }
+
+    /// Length of the sequence after 40 rounds
+    fn part_01(input: &Self::Input) -> Answer {
+        repeat_look_and_say(input, 40).len().into()
+    }
+
+    /// Length of the sequence after 50 rounds
+    fn part_02(input: &Self::Input) -> Answer {
+        repeat_look_and_say(input, 50).len().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn look_say() {
+        let tests = vec![
+            ("1", "11"),
+            ("11", "21"),
+            ("21", "1211"),
+            ("1211", "111221"),
+            ("111221", "312211"),
+        ];
+
+        for (src, exp) in tests {
+            assert_eq!(exp.as_bytes(), look_and_say(src.as_bytes()));
+        }
+
+        assert_eq!(look_and_say(b"1111111111"), b"101");
+        assert_eq!(repeat_look_and_say("1", 5), b"312211");
+    }
+}
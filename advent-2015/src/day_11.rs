@@ -0,0 +1,224 @@
+use std::{collections::HashSet, io::BufRead};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Letters that may never appear in a password
+const FORBIDDEN: &[u8] = b"ilo";
+
+fn allowed(c: u8) -> bool {
+    c.is_ascii_lowercase() && !FORBIDDEN.contains(&c)
+}
+
+fn has_straight(pass: &[u8]) -> bool {
+    for w in pass.windows(3) {
+        let (p, c, n) = (w[0], w[1], w[2]);
+
+        if (c == (p + 1)) && (n == (c + 1)) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Letters that appear doubled, counting each letter once no matter how often it is doubled
+fn pair_letters(pass: &[u8]) -> HashSet<u8> {
+    pass.windows(2)
+        .filter(|w| w[0] == w[1])
+        .map(|w| w[0])
+        .collect()
+}
+
+fn has_two_pairs(pass: &[u8]) -> bool {
+    pair_letters(pass).len() > 1
+}
+
+fn is_valid(pass: &[u8]) -> bool {
+    pass.iter().all(|&c| allowed(c)) && has_straight(pass) && has_two_pairs(pass)
+}
+
+/// Lower bound on how many letters must follow `prefix` for the password to become valid. Each
+/// rule is counted exactly on its own, taking the larger of the two keeps the bound admissible.
+fn letters_needed(prefix: &[u8]) -> usize {
+    let straight = if has_straight(prefix) {
+        0
+    } else {
+        match prefix {
+            [.., a, b] if *b == a + 1 && allowed(b + 1) => 1,
+            [.., b] if allowed(b + 1) && allowed(b + 2) => 2,
+            _ => 3,
+        }
+    };
+
+    let pairs = pair_letters(prefix);
+    let pairs = match 2_usize.saturating_sub(pairs.len()) {
+        0 => 0,
+        missing => {
+            // The last letter can be doubled by the very next one unless it is already a pair
+            let last_pairs = prefix.last().is_some_and(|c| !pairs.contains(c));
+
+            missing * 2 - usize::from(last_pairs)
+        }
+    };
+
+    straight.max(pairs)
+}
+
+/// Fills `pass[pos..]` with the smallest letters that make the whole password valid, returning
+/// whether that is possible. Branches that cannot fit the remaining rules are skipped entirely.
+fn complete(pass: &mut [u8], pos: usize) -> bool {
+    if pos == pass.len() {
+        return is_valid(pass);
+    }
+
+    for c in (b'a'..=b'z').filter(|&c| allowed(c)) {
+        pass[pos] = c;
+
+        if letters_needed(&pass[..=pos]) < pass.len() - pos && complete(pass, pos + 1) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Finds the smallest valid password of the same length that comes after `pass`, or `None` if
+/// there is no such password or `pass` contains anything other than lowercase letters.
+///
+/// Rather than counting up one password at a time, each position from the right is bumped to its
+/// next allowed letter and the rest is filled in by [`complete`]. Positions to the right of a
+/// forbidden letter are never bumped since every password keeping that letter is invalid.
+pub fn next_valid_password(pass: &str) -> Option<String> {
+    if !pass.bytes().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+
+    let mut pass = pass.as_bytes().to_vec();
+    let bumpable = pass
+        .iter()
+        .position(|c| FORBIDDEN.contains(c))
+        .map_or(pass.len(), |forbidden| forbidden + 1);
+
+    for i in (0..bumpable).rev() {
+        for c in (pass[i] + 1..=b'z').filter(|&c| allowed(c)) {
+            pass[i] = c;
+
+            if letters_needed(&pass[..=i]) < pass.len() - i && complete(&mut pass, i + 1) {
+                return Some(String::from_utf8(pass).expect("passwords are ASCII"));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every valid password after `pass` in order
+pub fn valid_passwords(pass: &str) -> impl Iterator<Item = String> {
+    std::iter::successors(next_valid_password(pass), |p| next_valid_password(p))
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Corporate Policy",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = &'static str;
+
+    const NEEDS_INPUT: bool = false;
+
+    fn parse(_reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Ok("hepxcrrq")
+    }
+
+    /// Santa's next password
+    fn part_01(input: &Self::Input) -> Answer {
+        valid_passwords(input)
+            .next()
+            .expect("the puzzle password has a successor")
+            .into()
+    }
+
+    /// The password after that, once the first one expires
+    fn part_02(input: &Self::Input) -> Answer {
+        valid_passwords(input)
+            .nth(1)
+            .expect("the puzzle password has two successors")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn detect_straight() {
+        assert!(has_straight(b"hijklmmn"));
+    }
+
+    #[test]
+    fn detect_two_pairs() {
+        assert!(has_two_pairs(b"abbceffg"));
+    }
+
+    #[test]
+    fn successful_next() {
+        assert_eq!(next_valid_password("abcdefgh").unwrap(), "abcdffaa");
+        assert_eq!(next_valid_password("ghijklmn").unwrap(), "ghjaabcc");
+    }
+
+    #[test]
+    fn any_length() {
+        assert_eq!(next_valid_password("aaaaa").unwrap(), "aabcc");
+        assert_eq!(next_valid_password("aaaa"), None);
+        assert_eq!(next_valid_password("zzzzzzzz"), None);
+        assert_eq!(next_valid_password("abc1"), None);
+        assert_eq!(next_valid_password(""), None);
+
+        assert_eq!(
+            valid_passwords("abcdefgh").take(3).collect::<Vec<_>>(),
+            ["abcdffaa", "abcdffbb", "abcdffcc"]
+        );
+    }
+
+    /// Counts up one password at a time, which is always correct but slow
+    fn brute_force_next(pass: &str) -> Option<String> {
+        let mut pass = pass.as_bytes().to_vec();
+
+        loop {
+            let carried = pass.iter_mut().rev().all(|c| {
+                if *c == b'z' {
+                    *c = b'a';
+                    true
+                } else {
+                    *c += 1;
+                    false
+                }
+            });
+
+            if carried {
+                return None;
+            }
+
+            if is_valid(&pass) {
+                return Some(String::from_utf8(pass).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for start in ["aabccyzz", "abcdffzz", "ghjaabcc", "xxyzzzzy", "zzzzzxyz"] {
+            assert_eq!(
+                valid_passwords(start).take(3).collect::<Vec<_>>(),
+                std::iter::successors(brute_force_next(start), |p| brute_force_next(p))
+                    .take(3)
+                    .collect::<Vec<_>>(),
+                "successors of {start}"
+            );
+        }
+    }
+}
@@ -0,0 +1,629 @@
+use std::{borrow::Cow, io::BufRead};
+
+use thiserror::Error;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Reasons a document can fail to parse, along with the byte offset the problem was found at
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum JsonError {
+    #[error("unexpected end of document")]
+    UnexpectedEnd,
+    #[error("unexpected character '{0}' at byte {1}")]
+    UnexpectedChar(char, usize),
+    #[error("invalid escape sequence at byte {0}")]
+    InvalidEscape(usize),
+    #[error("number at byte {0} is not an integer that fits in an i64")]
+    UnsupportedNumber(usize),
+}
+
+/// A JSON value borrowing its strings from the source document wherever they contain no escapes.
+/// Numbers are limited to integers, which is all the puzzle uses. Objects keep their members in
+/// document order, including any repeated keys, so every number in the document is counted.
+#[derive(Debug, PartialEq)]
+pub enum BorrowedJSON<'s> {
+    Null,
+    Number(i64),
+    String(Cow<'s, str>),
+    Boolean(bool),
+    Array(Vec<BorrowedJSON<'s>>),
+    Object(Vec<(Cow<'s, str>, BorrowedJSON<'s>)>),
+}
+
+impl<'s> BorrowedJSON<'s> {
+    /// Parses a complete document, allowing whitespace between tokens and around the document
+    pub fn parse(source: &'s str) -> Result<BorrowedJSON<'s>, JsonError> {
+        let mut parser = Parser { source, pos: 0 };
+        let value = parser.value()?;
+
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            None => Ok(value),
+            Some(c) => Err(JsonError::UnexpectedChar(c, parser.pos)),
+        }
+    }
+
+    /// Sum of every number in the document
+    pub fn sum(&self) -> i64 {
+        match self {
+            BorrowedJSON::Number(v) => *v,
+            BorrowedJSON::Array(a) => a.iter().map(Self::sum).sum(),
+            BorrowedJSON::Object(o) => o.iter().map(|(_, v)| v.sum()).sum(),
+            _ => 0,
+        }
+    }
+
+    /// Sum of every number not inside an object with a "red" value. Returns `None` for the string
+    /// "red" so the object containing it can discard its whole sum, while a key of "red" counts
+    /// for nothing.
+    fn non_red_sum(&self) -> Option<i64> {
+        match self {
+            BorrowedJSON::Number(v) => Some(*v),
+            BorrowedJSON::String(v) => {
+                if *v == "red" {
+                    None
+                } else {
+                    Some(0)
+                }
+            }
+            BorrowedJSON::Array(a) => Some(a.iter().filter_map(Self::non_red_sum).sum()),
+            BorrowedJSON::Object(o) => {
+                let mut s = 0;
+
+                for (_, v) in o {
+                    match v.non_red_sum() {
+                        Some(si) => s += si,
+                        None => {
+                            return Some(0);
+                        }
+                    }
+                }
+
+                Some(s)
+            }
+            _ => Some(0),
+        }
+    }
+}
+
+/// Recursive descent parser over the bytes of a document. All structural characters are ASCII,
+/// so byte offsets into the source always land on character boundaries between tokens.
+struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+impl<'s> Parser<'s> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn unexpected(&self) -> JsonError {
+        match self.peek() {
+            Some(c) => JsonError::UnexpectedChar(c, self.pos),
+            None => JsonError::UnexpectedEnd,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.source[self.pos..];
+
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    /// Consumes `expected` if it is the next character, otherwise fails without consuming anything
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        if self.peek() == Some(expected) {
+            self.pos += expected.len_utf8();
+
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn value(&mut self) -> Result<BorrowedJSON<'s>, JsonError> {
+        self.skip_whitespace();
+
+        match self.peek().ok_or(JsonError::UnexpectedEnd)? {
+            '[' => self.array(),
+            '{' => self.object(),
+            '"' => Ok(BorrowedJSON::String(self.string()?)),
+            '-' | '0'..='9' => self.number(),
+            't' => self.literal("true", BorrowedJSON::Boolean(true)),
+            'f' => self.literal("false", BorrowedJSON::Boolean(false)),
+            'n' => self.literal("null", BorrowedJSON::Null),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn literal(
+        &mut self,
+        word: &str,
+        value: BorrowedJSON<'s>,
+    ) -> Result<BorrowedJSON<'s>, JsonError> {
+        for c in word.chars() {
+            self.expect(c)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Parses a comma separated sequence of items between `open` and `close`
+    fn sequence<T>(
+        &mut self,
+        open: char,
+        close: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, JsonError>,
+    ) -> Result<Vec<T>, JsonError> {
+        let mut items = Vec::new();
+
+        self.expect(open)?;
+        self.skip_whitespace();
+
+        if self.expect(close).is_ok() {
+            return Ok(items);
+        }
+
+        loop {
+            items.push(item(self)?);
+
+            self.skip_whitespace();
+
+            if self.expect(close).is_ok() {
+                return Ok(items);
+            }
+
+            self.expect(',')?;
+        }
+    }
+
+    fn array(&mut self) -> Result<BorrowedJSON<'s>, JsonError> {
+        Ok(BorrowedJSON::Array(self.sequence('[', ']', Self::value)?))
+    }
+
+    fn object(&mut self) -> Result<BorrowedJSON<'s>, JsonError> {
+        let entries = self.sequence('{', '}', |p| {
+            p.skip_whitespace();
+
+            let key = p.string()?;
+
+            p.skip_whitespace();
+            p.expect(':')?;
+
+            Ok((key, p.value()?))
+        })?;
+
+        Ok(BorrowedJSON::Object(entries))
+    }
+
+    fn number(&mut self) -> Result<BorrowedJSON<'s>, JsonError> {
+        let start = self.pos;
+        let bytes = self.source.as_bytes();
+
+        if bytes[self.pos] == b'-' {
+            self.pos += 1;
+        }
+
+        let digits = bytes[self.pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+
+        // JSON forbids leading zeros and a lone minus sign
+        if digits == 0 || (digits > 1 && bytes[self.pos] == b'0') {
+            return Err(self.unexpected());
+        }
+
+        self.pos += digits;
+
+        if matches!(bytes.get(self.pos), Some(b'.' | b'e' | b'E')) {
+            return Err(JsonError::UnsupportedNumber(start));
+        }
+
+        self.source[start..self.pos]
+            .parse()
+            .map(BorrowedJSON::Number)
+            .map_err(|_| JsonError::UnsupportedNumber(start))
+    }
+
+    /// Parses a string, only allocating when it contains escape sequences
+    fn string(&mut self) -> Result<Cow<'s, str>, JsonError> {
+        self.expect('"')?;
+
+        let start = self.pos;
+        let mut owned: Option<String> = None;
+
+        loop {
+            let c = self.peek().ok_or(JsonError::UnexpectedEnd)?;
+
+            match c {
+                '"' => {
+                    let text = match owned {
+                        Some(s) => Cow::Owned(s),
+                        None => Cow::Borrowed(&self.source[start..self.pos]),
+                    };
+
+                    self.pos += 1;
+
+                    return Ok(text);
+                }
+                '\\' => {
+                    let text = owned.get_or_insert_with(|| self.source[start..self.pos].to_owned());
+
+                    text.push(self.escape()?);
+                }
+                '\u{0}'..='\u{1f}' => return Err(self.unexpected()),
+                _ => {
+                    if let Some(s) = &mut owned {
+                        s.push(c);
+                    }
+                }
+            }
+
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Decodes the escape sequence at the current position, leaving the position on its last
+    /// character. Surrogate pairs written as two `\u` escapes are combined into one character.
+    fn escape(&mut self) -> Result<char, JsonError> {
+        let start = self.pos;
+
+        self.pos += 1;
+
+        let c = match self.peek().ok_or(JsonError::UnexpectedEnd)? {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let high = self.hex_escape(start)?;
+
+                if !(0xd800..0xdc00).contains(&high) {
+                    char::from_u32(high).ok_or(JsonError::InvalidEscape(start))?
+                } else {
+                    self.pos += 1;
+
+                    if !self.source[self.pos..].starts_with('\\') {
+                        return Err(JsonError::InvalidEscape(start));
+                    }
+
+                    self.pos += 1;
+
+                    if self.peek() != Some('u') {
+                        return Err(JsonError::InvalidEscape(start));
+                    }
+
+                    let low = self.hex_escape(start)?;
+
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return Err(JsonError::InvalidEscape(start));
+                    }
+
+                    char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+                        .ok_or(JsonError::InvalidEscape(start))?
+                }
+            }
+            _ => return Err(JsonError::InvalidEscape(start)),
+        };
+
+        Ok(c)
+    }
+
+    /// Reads the four hex digits following the `u` at the current position, leaving the position
+    /// on the last digit
+    fn hex_escape(&mut self, escape_start: usize) -> Result<u32, JsonError> {
+        let digits = self
+            .source
+            .get(self.pos + 1..self.pos + 5)
+            .filter(|d| d.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or(JsonError::InvalidEscape(escape_start))?;
+
+        self.pos += 4;
+
+        u32::from_str_radix(digits, 16).map_err(|_| JsonError::InvalidEscape(escape_start))
+    }
+}
+
+fn sum_nums(json: &str) -> Result<i64, JsonError> {
+    Ok(BorrowedJSON::parse(json)?.sum())
+}
+
+fn non_red_sum(json: &str) -> Result<i64, JsonError> {
+    Ok(BorrowedJSON::parse(json)?.non_red_sum().unwrap_or(0))
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "JSAbacusFramework.io",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// The whole document, already checked to be valid
+    type Input = String;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut document = String::new();
+
+        reader.read_to_string(&mut document)?;
+
+        BorrowedJSON::parse(&document)?;
+
+        Ok(document)
+    }
+
+    /// Sum of every number in the document
+    fn part_01(input: &Self::Input) -> Answer {
+        sum_nums(input)
+            .expect("document was validated when parsing the input")
+            .into()
+    }
+
+    /// Sum of every number not inside an object with a "red" value
+    fn part_02(input: &Self::Input) -> Answer {
+        non_red_sum(input)
+            .expect("document was validated when parsing the input")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use serde_json::Value;
+
+    use super::*;
+
+    fn parse(source: &str) -> BorrowedJSON<'_> {
+        BorrowedJSON::parse(source).unwrap()
+    }
+
+    #[test]
+    fn parse_array() {
+        assert_eq!(parse("[]"), BorrowedJSON::Array(Vec::new()));
+
+        assert_eq!(
+            parse("[1]"),
+            BorrowedJSON::Array(vec![BorrowedJSON::Number(1)])
+        );
+
+        assert_eq!(
+            parse(r#"["a",4,false,null]"#),
+            BorrowedJSON::Array(vec![
+                BorrowedJSON::String("a".into()),
+                BorrowedJSON::Number(4),
+                BorrowedJSON::Boolean(false),
+                BorrowedJSON::Null
+            ])
+        );
+
+        assert_eq!(
+            parse(" [ 1 ,\n\ttrue\r\n, \"val\" ] "),
+            BorrowedJSON::Array(vec![
+                BorrowedJSON::Number(1),
+                BorrowedJSON::Boolean(true),
+                BorrowedJSON::String("val".into())
+            ])
+        );
+
+        assert_eq!(
+            BorrowedJSON::parse(r#"[1,,true,,"val"]"#),
+            Err(JsonError::UnexpectedChar(',', 3))
+        );
+
+        assert_eq!(
+            BorrowedJSON::parse("[1,]"),
+            Err(JsonError::UnexpectedChar(']', 3))
+        );
+        assert_eq!(BorrowedJSON::parse("[1"), Err(JsonError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn parse_number() {
+        assert_eq!(parse("64"), BorrowedJSON::Number(64));
+        assert_eq!(parse("-12"), BorrowedJSON::Number(-12));
+        assert_eq!(parse("0"), BorrowedJSON::Number(0));
+        assert!(BorrowedJSON::parse("-").is_err());
+        assert!(BorrowedJSON::parse("012").is_err());
+        assert_eq!(
+            BorrowedJSON::parse("1.5"),
+            Err(JsonError::UnsupportedNumber(0))
+        );
+        assert_eq!(BorrowedJSON::parse("-0"), Ok(BorrowedJSON::Number(0)));
+    }
+
+    #[test]
+    fn parse_object() {
+        assert_eq!(parse("{}"), BorrowedJSON::Object(Vec::new()));
+
+        assert_eq!(
+            parse(r#"{"key":"value"}"#),
+            BorrowedJSON::Object(vec![("key".into(), BorrowedJSON::String("value".into()))])
+        );
+
+        assert_eq!(
+            parse(r#"{"a":4,"b":true}"#),
+            BorrowedJSON::Object(vec![
+                ("a".into(), BorrowedJSON::Number(4)),
+                ("b".into(), BorrowedJSON::Boolean(true))
+            ])
+        );
+
+        assert_eq!(
+            parse("{ \"a\" : \"b\" ,\n \"c\":4,\"d\" :false, \"e\": [ ] }"),
+            BorrowedJSON::Object(vec![
+                ("a".into(), BorrowedJSON::String("b".into())),
+                ("c".into(), BorrowedJSON::Number(4)),
+                ("d".into(), BorrowedJSON::Boolean(false)),
+                ("e".into(), BorrowedJSON::Array(Vec::new()))
+            ])
+        );
+
+        assert!(BorrowedJSON::parse(r#"{"a" 1}"#).is_err());
+        assert!(BorrowedJSON::parse(r#"{a:1}"#).is_err());
+    }
+
+    #[test]
+    fn parse_string() {
+        assert_eq!(parse(r#""test""#), BorrowedJSON::String("test".into()));
+        assert_eq!(parse(r#""""#), BorrowedJSON::String("".into()));
+
+        assert_eq!(
+            parse(r#""say \"hi\"\\\/\né\ud83d\ude00""#),
+            BorrowedJSON::String("say \"hi\"\\/\n\u{e9}\u{1f600}".into())
+        );
+
+        assert!(matches!(
+            parse(r#""plain""#),
+            BorrowedJSON::String(Cow::Borrowed("plain"))
+        ));
+
+        assert_eq!(
+            BorrowedJSON::parse(r#""\x""#),
+            Err(JsonError::InvalidEscape(1))
+        );
+        assert_eq!(
+            BorrowedJSON::parse(r#""\ud83d""#),
+            Err(JsonError::InvalidEscape(1))
+        );
+        assert!(BorrowedJSON::parse("\"a\nb\"").is_err());
+        assert_eq!(
+            BorrowedJSON::parse(r#""open"#),
+            Err(JsonError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn basic_counts() {
+        let tests = vec![
+            ("[1,2,3]", 6),
+            (r#"{"a":2,"b":4}"#, 6),
+            ("[[[3]]]", 3),
+            (r#"{"a":{"b":4},"c":-1}"#, 3),
+            (r#"{"a":[-1,1]}"#, 0),
+            (r#"[-1,{"a":1}]"#, 0),
+            ("[]", 0),
+            ("{}", 0),
+            (r#"["12-4", 3]"#, 3),
+            (r#"{"a":1,"a":2}"#, 3),
+        ];
+
+        for (s, e) in tests {
+            assert_eq!(sum_nums(s), Ok(e));
+        }
+    }
+
+    #[test]
+    fn structured_counts() {
+        let tests = vec![
+            ("[1,2,3]", 6),
+            (r#"[1,{"c":"red","b":2},3]"#, 4),
+            (r#"{"d":"red","e":[1,2,3,4],"f":5}"#, 0),
+            (r#"[1,"red",5]"#, 6),
+            (r#"{"red":1,"b":2}"#, 3),
+            (r#"{"a":1,"a":2}"#, 3),
+            (r#"{"a":1,"a":"red"}"#, 0),
+        ];
+
+        for (s, e) in tests {
+            assert_eq!(non_red_sum(s), Ok(e));
+        }
+    }
+
+    /// Converts a parsed document into serde_json's representation for comparison
+    fn to_value(json: &BorrowedJSON) -> Value {
+        match json {
+            BorrowedJSON::Null => Value::Null,
+            BorrowedJSON::Number(n) => Value::from(*n),
+            BorrowedJSON::String(s) => Value::from(s.as_ref()),
+            BorrowedJSON::Boolean(b) => Value::from(*b),
+            BorrowedJSON::Array(a) => Value::Array(a.iter().map(to_value).collect()),
+            BorrowedJSON::Object(o) => Value::Object(
+                o.iter()
+                    .map(|(k, v)| (k.to_string(), to_value(v)))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Arbitrary documents made of the value types the parser supports
+    fn document() -> impl Strategy<Value = Value> {
+        let leaf = prop_oneof![
+            Just(Value::Null),
+            any::<bool>().prop_map(Value::from),
+            any::<i64>().prop_map(Value::from),
+            any::<String>().prop_map(Value::from),
+        ];
+
+        leaf.prop_recursive(4, 64, 8, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..8).prop_map(Value::Array),
+                prop::collection::hash_map(any::<String>(), inner, 0..8)
+                    .prop_map(|m| Value::Object(m.into_iter().collect())),
+            ]
+        })
+    }
+
+    /// Rewrites every `\uXXXX`-eligible character as an escape so escapes are exercised too
+    fn escape_all(source: &str) -> String {
+        source
+            .chars()
+            .map(|c| match c {
+                c if c.is_ascii() => c.to_string(),
+                c => c
+                    .encode_utf16(&mut [0; 2])
+                    .iter()
+                    .map(|u| format!("\\u{u:04x}"))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn matches_serde_json(value in document(), pretty: bool, escaped: bool) {
+            let mut source = if pretty {
+                serde_json::to_string_pretty(&value).unwrap()
+            } else {
+                serde_json::to_string(&value).unwrap()
+            };
+
+            if escaped {
+                source = escape_all(&source);
+            }
+
+            let parsed = BorrowedJSON::parse(&source).unwrap();
+
+            prop_assert_eq!(to_value(&parsed), value);
+        }
+
+        #[test]
+        fn rejects_what_serde_json_rejects(source in "[\\[\\]{}\",:0-9a-z\\\\ -]{0,16}") {
+            // serde_json reads a negative zero as a float, while it is simply 0 here
+            prop_assume!(!source.contains("-0"));
+
+            let ours = BorrowedJSON::parse(&source).is_ok();
+            let theirs = serde_json::from_str::<Value>(&source).is_ok_and(|v| !has_float(&v));
+
+            prop_assert_eq!(ours, theirs);
+        }
+    }
+
+    /// Whether a value contains a number the parser deliberately does not support
+    fn has_float(value: &Value) -> bool {
+        match value {
+            Value::Number(n) => !n.is_i64(),
+            Value::Array(a) => a.iter().any(has_float),
+            Value::Object(o) => o.values().any(has_float),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::{collections::HashMap, io::BufRead};
+
+use itertools::Itertools;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::intern::{Interner, Symbol},
+};
+
+/// Happiness change for each guest when seated next to each other guest. Guests with no opinion
+/// of each other are indifferent.
+#[derive(Clone, Debug, Default)]
+pub struct HappinessMap {
+    guests: Interner,
+    changes: HashMap<(Symbol, Symbol), i64>,
+}
+
+impl HappinessMap {
+    /// Records that `guest` gains `amount` happiness next to `neighbor`, or loses it if negative
+    pub fn insert(&mut self, guest: &str, neighbor: &str, amount: i64) {
+        let pair = (self.guests.intern(guest), self.guests.intern(neighbor));
+
+        self.changes.insert(pair, amount);
+    }
+
+    /// Adds a guest who is indifferent to everyone, and everyone to them
+    pub fn add_guest(&mut self, guest: &str) {
+        self.guests.intern(guest);
+    }
+
+    /// Happiness `guest` gains next to `neighbor`
+    pub fn change(&self, guest: &str, neighbor: &str) -> i64 {
+        self.guests
+            .get(guest)
+            .zip(self.guests.get(neighbor))
+            .map_or(0, |pair| self.pair_change(pair))
+    }
+
+    fn pair_change(&self, pair: (Symbol, Symbol)) -> i64 {
+        self.changes.get(&pair).copied().unwrap_or_default()
+    }
+
+    /// Every guest in the order they were first mentioned
+    pub fn guests(&self) -> impl Iterator<Item = &str> {
+        self.guests.iter().map(|(_, name)| name)
+    }
+}
+
+fn parse_happiness_map<S, I>(lines: I) -> HappinessMap
+where
+    S: AsRef<str>,
+    I: Iterator<Item = S>,
+{
+    let mut m = HappinessMap::default();
+
+    for l in lines {
+        let mut parts = l.as_ref().split_ascii_whitespace();
+        let name = parts.next().unwrap();
+        let lg = parts.nth(1).unwrap();
+        let amount = parts.next().unwrap().parse::<i64>().unwrap();
+        let other = parts.last().unwrap().trim_matches('.');
+
+        let amount = if lg == "gain" { amount } else { -amount };
+
+        m.insert(name, other, amount);
+    }
+
+    m
+}
+
+/// A seating order around the table and the total change in happiness it causes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Arrangement {
+    pub happiness: i64,
+    /// Guests in order around the table, where the last sits next to the first
+    pub guests: Vec<String>,
+}
+
+/// Finds the happiest arrangement with the Held-Karp algorithm for cycles. Rotating a table changes
+/// nothing, so the first guest (by name) is always seated first, then the best way to seat every
+/// subset of the others ending with each guest is built up from smaller subsets. This takes
+/// `O(n² 2ⁿ)` time rather than `O(n!)`.
+pub fn best_seating(hap_map: &HappinessMap) -> Arrangement {
+    let guests = hap_map
+        .guests
+        .iter()
+        .sorted_by_key(|&(_, name)| name)
+        .collect_vec();
+    let n = guests.len();
+    let name = |g: usize| guests[g].1.to_owned();
+
+    if n < 2 {
+        return Arrangement {
+            happiness: 0,
+            guests: (0..n).map(name).collect(),
+        };
+    }
+
+    let one_way = |a: usize, b: usize| hap_map.pair_change((guests[a].0, guests[b].0));
+
+    let pairs = (0..n)
+        .map(|a| (0..n).map(|b| one_way(a, b) + one_way(b, a)).collect_vec())
+        .collect_vec();
+
+    // Bit `g - 1` of a subset stands for guest `g` since guest 0 is always seated first.
+    // best[subset][g] is the happiest way to seat `subset` after guest 0 ending with `g`.
+    let others = n - 1;
+    let mut best = vec![vec![None::<(i64, usize)>; n]; 1 << others];
+
+    for g in 1..n {
+        best[1 << (g - 1)][g] = Some((pairs[0][g], 0));
+    }
+
+    for subset in 1usize..(1 << others) {
+        for last in 1..n {
+            let Some((happiness, _)) = best[subset][last] else {
+                continue;
+            };
+
+            for next in (1..n).filter(|g| subset & (1 << (g - 1)) == 0) {
+                let candidate = happiness + pairs[last][next];
+                let entry = &mut best[subset | (1 << (next - 1))][next];
+
+                if entry.is_none_or(|(h, _)| candidate > h) {
+                    *entry = Some((candidate, last));
+                }
+            }
+        }
+    }
+
+    let everyone = (1 << others) - 1;
+
+    let (happiness, mut last) = (1..n)
+        .map(|g| {
+            let (h, _) = best[everyone][g].expect("every guest can be seated last");
+
+            (h + pairs[g][0], g)
+        })
+        .max()
+        .expect("there are at least two guests");
+
+    let mut seated = Vec::with_capacity(n);
+    let mut subset = everyone;
+
+    while last != 0 {
+        seated.push(name(last));
+
+        let (_, prev) = best[subset][last].expect("seated guests were reached");
+
+        subset &= !(1 << (last - 1));
+        last = prev;
+    }
+
+    seated.push(name(0));
+    seated.reverse();
+
+    Arrangement {
+        happiness,
+        guests: seated,
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Knights of the Dinner Table",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = HappinessMap;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+
+        Ok(parse_happiness_map(lines.iter()))
+    }
+
+    /// Total change in happiness for the best seating arrangement
+    fn part_01(input: &Self::Input) -> Answer {
+        best_seating(input).happiness.into()
+    }
+
+    /// Total change in happiness for the best arrangement once you are seated too
+    fn part_02(input: &Self::Input) -> Answer {
+        let mut hap_map = input.clone();
+
+        hap_map.add_guest("Me");
+
+        best_seating(&hap_map).happiness.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_core::util::perm::Permutations;
+
+    #[test]
+    fn happiness() {
+        let data = r"Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+        let hap_map = parse_happiness_map(data.lines());
+        let best = best_seating(&hap_map);
+
+        assert_eq!(best.happiness, 330);
+        assert_eq!(best.guests, ["Alice", "Bob", "Carol", "David"]);
+        assert_eq!(seating_happiness(&hap_map, &best.guests), 330);
+    }
+
+    /// Total happiness of seating `guests` in order around the table
+    fn seating_happiness(hap_map: &HappinessMap, guests: &[String]) -> i64 {
+        guests
+            .iter()
+            .zip(guests.iter().cycle().skip(1))
+            .map(|(p, n)| hap_map.change(p, n) + hap_map.change(n, p))
+            .sum()
+    }
+
+    #[test]
+    fn match_every_permutation() {
+        // Scrambled happiness between seven guests, checked against trying every order
+        let names = ["A", "B", "C", "D", "E", "F", "G"];
+        let mut hap_map = HappinessMap::default();
+
+        for (i, a) in names.iter().enumerate() {
+            for (j, b) in names.iter().enumerate().filter(|&(j, _)| j != i) {
+                let amount = ((i * 37 + j * 91) % 101) as i64 - 50;
+
+                hap_map.insert(a, b, amount);
+            }
+        }
+
+        let mut orders = Permutations::new(hap_map.guests().map(str::to_owned).collect());
+        let mut brute_force = i64::MIN;
+
+        while let Some(order) = orders.next_permutation() {
+            brute_force = brute_force.max(seating_happiness(&hap_map, order));
+        }
+
+        let best = best_seating(&hap_map);
+
+        assert_eq!(best.happiness, brute_force);
+        assert_eq!(seating_happiness(&hap_map, &best.guests), brute_force);
+        assert_eq!(best.guests.len(), names.len());
+    }
+}
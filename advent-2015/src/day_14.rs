@@ -0,0 +1,247 @@
+use std::io::BufRead;
+
+use advent_core::{
+    answer::Answer,
+    context::{Context, Param},
+    day::Day,
+    describe::DayInfo,
+    progress::Progress,
+};
+
+/// Length of the race in seconds, unless given as the `seconds` parameter
+const RACE_DURATION: u32 = 2503;
+
+/// A reindeer alternating between flying at a fixed speed and resting
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reindeer {
+    pub name: String,
+    speed: u32,
+    fly_time: u32,
+    rest_time: u32,
+}
+
+impl Reindeer {
+    pub fn from_line(l: &str) -> Self {
+        let mut parts = l.split_ascii_whitespace();
+
+        let name = parts.next().unwrap().to_owned();
+        let speed = parts.nth(2).unwrap().parse().unwrap();
+        let fly_time = parts.nth(2).unwrap().parse().unwrap();
+        let rest_time = parts.nth(6).unwrap().parse().unwrap();
+
+        Self {
+            name,
+            speed,
+            fly_time,
+            rest_time,
+        }
+    }
+
+    pub fn traveled(&self, time: u32) -> u32 {
+        let cycle_time = self.fly_time + self.rest_time;
+        let full_cycles = time / cycle_time;
+        let partial_time = time % cycle_time;
+
+        (full_cycles * self.fly_time + partial_time.min(self.fly_time)) * self.speed
+    }
+
+    /// Whether the reindeer is flying during `second`, where the race starts with second 1
+    pub fn flying_during(&self, second: u32) -> bool {
+        (second - 1) % (self.fly_time + self.rest_time) < self.fly_time
+    }
+}
+
+/// Where every reindeer stands after some second of the race, in the order they were given
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Standings {
+    pub second: u32,
+    pub distances: Vec<u32>,
+    /// One point is awarded each second to every reindeer tied for the lead
+    pub points: Vec<u32>,
+}
+
+/// Runs a race one second at a time, yielding the standings after each second
+#[derive(Clone, Debug)]
+pub struct RaceSimulator<'a> {
+    reindeer: &'a [Reindeer],
+    duration: u32,
+    standings: Standings,
+}
+
+impl<'a> RaceSimulator<'a> {
+    pub fn new(reindeer: &'a [Reindeer], duration: u32) -> Self {
+        Self {
+            reindeer,
+            duration,
+            standings: Standings {
+                second: 0,
+                distances: vec![0; reindeer.len()],
+                points: vec![0; reindeer.len()],
+            },
+        }
+    }
+}
+
+impl Iterator for RaceSimulator<'_> {
+    type Item = Standings;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = &mut self.standings;
+
+        if s.second == self.duration {
+            return None;
+        }
+
+        s.second += 1;
+
+        for (d, r) in s.distances.iter_mut().zip(self.reindeer) {
+            if r.flying_during(s.second) {
+                *d += r.speed;
+            }
+        }
+
+        let lead = s.distances.iter().copied().max().unwrap_or(0);
+
+        for (p, &d) in s.points.iter_mut().zip(&s.distances) {
+            if d == lead {
+                *p += 1;
+            }
+        }
+
+        Some(s.clone())
+    }
+}
+
+/// Points of the winning reindeer after a race of `duration` seconds
+fn per_second_scoring(reindeer: &[Reindeer], duration: u32, progress: &Progress) -> u32 {
+    RaceSimulator::new(reindeer, duration)
+        .inspect(|_| progress.tick())
+        .last()
+        .and_then(|s| s.points.into_iter().max())
+        .unwrap_or(0)
+}
+
+/// Distance of the reindeer furthest along after `duration` seconds
+fn furthest_traveled(reindeer: &[Reindeer], duration: u32) -> u32 {
+    reindeer
+        .iter()
+        .map(|r| r.traveled(duration))
+        .max()
+        .unwrap_or(0)
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Reindeer Olympics",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Reindeer>;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "seconds",
+        about: "length of the race, 2503 in the puzzle",
+    }];
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .map(|l| Ok(Reindeer::from_line(&l?)))
+            .collect()
+    }
+
+    /// Distance traveled by the winning reindeer
+    fn part_01(input: &Self::Input) -> Answer {
+        furthest_traveled(input, RACE_DURATION).into()
+    }
+
+    /// Points of the winning reindeer when scoring the leader every second
+    fn part_02(input: &Self::Input) -> Answer {
+        per_second_scoring(input, RACE_DURATION, &Progress::hidden()).into()
+    }
+
+    fn part_01_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(furthest_traveled(input, ctx.param("seconds", RACE_DURATION)?).into())
+    }
+
+    fn part_02_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        let duration = ctx.param("seconds", RACE_DURATION)?;
+        let progress = ctx.progress("race seconds", duration.into());
+
+        Ok(per_second_scoring(input, duration, &progress).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reindeer() {
+        let r1 = Reindeer::from_line(
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.",
+        );
+
+        let r2 = Reindeer::from_line(
+            "Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.",
+        );
+
+        assert_eq!(r1.traveled(1000), 1120);
+        assert_eq!(r2.traveled(1000), 1056);
+
+        let reindeer = [r1, r2];
+
+        assert_eq!(furthest_traveled(&reindeer, 1000), 1120);
+        assert_eq!(
+            per_second_scoring(&reindeer, 1000, &Progress::hidden()),
+            689
+        );
+
+        let mut ctx = Context::new(None).with_param("seconds", 1000);
+
+        assert_eq!(
+            Puzzle::part_01_with(&reindeer.to_vec(), &mut ctx).unwrap(),
+            Answer::UInt(1120)
+        );
+        assert_eq!(
+            Puzzle::part_02_with(&reindeer.to_vec(), &mut ctx).unwrap(),
+            Answer::UInt(689)
+        );
+    }
+
+    #[test]
+    fn race_timeline() {
+        let reindeer = [
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.",
+            "Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.",
+        ]
+        .map(Reindeer::from_line);
+
+        let timeline = RaceSimulator::new(&reindeer, 1000).collect::<Vec<_>>();
+
+        assert_eq!(timeline.len(), 1000);
+
+        assert_eq!(timeline[0].distances, [14, 16]);
+        assert_eq!(timeline[0].points, [0, 1]);
+        assert_eq!(timeline[10].distances, [140, 176]);
+        assert_eq!(timeline[138].points, [0, 139]);
+        assert_eq!(timeline[139].points, [1, 139]);
+
+        let last = timeline.last().unwrap();
+
+        assert_eq!(last.second, 1000);
+        assert_eq!(last.distances, [1120, 1056]);
+        assert_eq!(last.points, [312, 689]);
+
+        // Distances agree with the closed form every second
+        assert!(timeline.iter().all(|s| {
+            s.distances
+                == reindeer
+                    .iter()
+                    .map(|r| r.traveled(s.second))
+                    .collect::<Vec<_>>()
+        }));
+    }
+}
@@ -0,0 +1,649 @@
+use std::io::BufRead;
+
+use rayon::prelude::*;
+
+use advent_core::{
+    answer::Answer,
+    context::{Context, Param},
+    day::Day,
+    describe::DayInfo,
+    trace::trace_event,
+    util::collections::ShardedMap,
+};
+
+/// Teaspoons of ingredients in a cookie, unless given as the `teaspoons` parameter
+const TEASPOONS: i64 = 100;
+
+/// Calories of the cookies in part 2, unless given as the `calories` parameter
+const CALORIES: i64 = 500;
+
+/// Recipes the gradient ascent remembers testing before forgetting those that cannot lead to a
+/// better one, unless given as the `max_states` parameter
+const MAX_STATES: usize = 1 << 20;
+
+/// Per-teaspoon properties of a cookie ingredient
+#[derive(Debug, Default)]
+pub struct Ingredient {
+    _name: String,
+    capacity: i64,
+    durability: i64,
+    flavor: i64,
+    texture: i64,
+    calories: i64,
+}
+
+impl Ingredient {
+    pub fn parse<S>(data: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let mut halves = data.as_ref().split(':');
+        let mut c = Ingredient {
+            _name: halves.next().unwrap().to_owned(),
+            ..Default::default()
+        };
+
+        for attr in halves.next().unwrap().split(',') {
+            let mut parts = attr.split_ascii_whitespace();
+            let field = parts.next().unwrap();
+            let val = parts.next().unwrap().parse().unwrap();
+
+            match field {
+                "capacity" => c.capacity = val,
+                "durability" => c.durability = val,
+                "flavor" => c.flavor = val,
+                "texture" => c.texture = val,
+                "calories" => c.calories = val,
+                _ => unreachable!(),
+            }
+        }
+
+        c
+    }
+
+    fn properties(&self) -> [i64; 4] {
+        [self.capacity, self.durability, self.flavor, self.texture]
+    }
+}
+
+/// Score of a cookie with the given property totals. Negative totals count as zero. `None` if the
+/// score does not fit in an `i64`.
+fn score(totals: [i64; 4]) -> Option<i64> {
+    totals
+        .into_iter()
+        .try_fold(1_i64, |product, t| product.checked_mul(t.max(0)))
+}
+
+/// Upper bound on [`score`], clamped to `i64::MAX` instead of overflowing. Used to prune searches,
+/// where a clamped bound still never undercounts.
+fn score_bound(totals: [i64; 4]) -> i64 {
+    totals
+        .into_iter()
+        .fold(1_i64, |product, t| product.saturating_mul(t.max(0)))
+}
+
+/// Property totals of a recipe with `c` teaspoons of each ingredient, or `None` on overflow
+fn recipe_totals(c: &[i64], ingredients: &[Ingredient]) -> Option<[i64; 4]> {
+    c.iter()
+        .zip(ingredients)
+        .try_fold([0; 4], |totals, (&n, i)| {
+            let props = i.properties();
+            let mut next = totals;
+
+            for p in 0..4 {
+                next[p] = n.checked_mul(props[p])?.checked_add(totals[p])?;
+            }
+
+            Some(next)
+        })
+}
+
+/// Calories in a recipe with `c` teaspoons of each ingredient, or `None` on overflow
+fn recipe_calories(c: &[i64], ingredients: &[Ingredient]) -> Option<i64> {
+    c.iter().zip(ingredients).try_fold(0_i64, |total, (&n, i)| {
+        total.checked_add(n.checked_mul(i.calories)?)
+    })
+}
+
+/// Pushes every recipe one teaspoon away from `base`, moving it from one ingredient to another
+fn add_valid_cases(base: &[i64], n: usize, q: &mut Vec<Vec<i64>>) {
+    for i in 0..n {
+        for j in 0..n {
+            if i == j || base[j] == 0 {
+                continue;
+            }
+
+            let mut nc = base.to_owned();
+
+            nc[i] += 1;
+            nc[j] -= 1;
+
+            q.push(nc);
+        }
+    }
+}
+
+fn eval_candidate(c: &[i64], ingredients: &[Ingredient]) -> Option<i64> {
+    score(recipe_totals(c, ingredients)?)
+}
+
+/// What a gradient ascent looked at, reported under `--verbose`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Explored {
+    /// Recipes scored, including any scored again after being forgotten
+    evaluated: usize,
+    /// Batches of neighbouring recipes scored in parallel
+    waves: usize,
+    /// Tested recipes forgotten to keep under the cap
+    pruned: usize,
+}
+
+/// Climbs from `start` by moving a teaspoon at a time between ingredients, scoring each wave of
+/// neighbouring recipes in parallel and climbing on from those that beat the best recipe before
+/// the wave. With `calories`, only recipes of exactly that many calories count as the best,
+/// though any recipe beating it is still climbed from.
+///
+/// Since the best only ever improves, a tested recipe scoring no better than it will never be
+/// climbed from again, so once more than `max_states` recipes have been tested those are
+/// forgotten. Meeting one again costs a score but changes nothing. `None` if a recipe's score or
+/// calories do not fit in an i64.
+fn ascend(
+    start: Vec<i64>,
+    ingredients: &[Ingredient],
+    calories: Option<i64>,
+    max_states: usize,
+) -> Option<(Vec<i64>, i64, Explored)> {
+    let mut tested = ShardedMap::new(4 * rayon::current_num_threads());
+    let mut explored = Explored::default();
+    let mut best_total = 0;
+    let mut best_candidate = vec![];
+    let mut wave = vec![start];
+
+    while !wave.is_empty() {
+        // Recipes reached more than once in a wave are only kept by whichever insert comes first
+        let scored = wave
+            .into_par_iter()
+            .filter(|c| !tested.contains_key(c))
+            .map(|c| {
+                let total = eval_candidate(&c, ingredients)?;
+
+                Some(tested.insert(c.clone(), total).then_some((c, total)))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let floor = best_total;
+
+        wave = Vec::new();
+        explored.waves += 1;
+
+        for (c, total) in scored.into_iter().flatten() {
+            explored.evaluated += 1;
+
+            if total <= floor {
+                continue;
+            }
+
+            let counts = match calories {
+                Some(calories) => recipe_calories(&c, ingredients)? == calories,
+                None => true,
+            };
+
+            add_valid_cases(&c, ingredients.len(), &mut wave);
+
+            if counts && total > best_total {
+                best_total = total;
+                best_candidate = c;
+            }
+        }
+
+        if tested.len() > max_states {
+            let before = tested.len();
+
+            tested.retain(|_, &mut total| total > best_total);
+            explored.pruned += before - tested.len();
+        }
+    }
+
+    trace_event!(
+        evaluated = explored.evaluated,
+        waves = explored.waves,
+        pruned = explored.pruned,
+        remembered = tested.len(),
+        best_total,
+        ?calories,
+        "gradient ascent finished"
+    );
+
+    Some((best_candidate, best_total, explored))
+}
+
+// Assumes the multi-dimensional evaluation space forms a convex hull and performs gradient-ascent.
+// `None` if a recipe's score does not fit in an i64.
+fn highest_score(
+    teaspoons: i64,
+    ingredients: &[Ingredient],
+    max_states: usize,
+) -> Option<(Vec<i64>, i64)> {
+    let len = ingredients.len();
+    let num_ingredients = i64::try_from(len).expect("number of ingredients should fit in an i64");
+    let initial_guess = teaspoons / num_ingredients;
+    let mut guesses = vec![initial_guess; len];
+
+    guesses[0] = teaspoons - ((num_ingredients - 1) * initial_guess);
+
+    let (best, total, _) = ascend(guesses, ingredients, None, max_states)?;
+
+    Some((best, total))
+}
+
+/// Climbs from the best recipe regardless of calories to the best with exactly `calories`
+fn highest_calorie_score(
+    teaspoons: i64,
+    calories: i64,
+    ingredients: &[Ingredient],
+    max_states: usize,
+) -> Option<(Vec<i64>, i64)> {
+    let (initial, _) = highest_score(teaspoons, ingredients, max_states)?;
+    let (best, total, _) = ascend(initial, ingredients, Some(calories), max_states)?;
+
+    Some((best, total))
+}
+
+/// Exhaustive search over every way of splitting the teaspoons between the ingredients in order,
+/// pruning partial recipes that cannot beat the best recipe found so far
+struct ExactSearch<'a> {
+    ingredients: &'a [Ingredient],
+    calories: Option<i64>,
+    /// Best value of each property among the ingredients from each index onwards
+    best_after: Vec<[i64; 4]>,
+    /// Lowest and highest calories per teaspoon among the ingredients from each index onwards
+    calories_after: Vec<(i64, i64)>,
+    amounts: Vec<i64>,
+    best: Option<(Vec<i64>, i64)>,
+    /// Partial and complete recipes looked at so far
+    explored: u64,
+}
+
+impl ExactSearch<'_> {
+    fn best_score(&self) -> i64 {
+        self.best.as_ref().map_or(-1, |b| b.1)
+    }
+
+    /// Chooses the amount of ingredient `idx` given the `totals` and `calories` of the ingredients
+    /// before it and the `remaining` teaspoons. The last ingredient always takes all that remain.
+    /// `None` if the totals of a recipe looked at do not fit in an `i64`.
+    fn search(
+        &mut self,
+        idx: usize,
+        remaining: i64,
+        totals: [i64; 4],
+        calories: i64,
+    ) -> Option<()> {
+        let ingredient = &self.ingredients[idx];
+        let last = idx == self.ingredients.len() - 1;
+        let amounts = if last {
+            remaining..=remaining
+        } else {
+            0..=remaining
+        };
+        let props = ingredient.properties();
+
+        for amount in amounts {
+            self.explored += 1;
+
+            let mut next = totals;
+
+            for p in 0..4 {
+                next[p] = amount.checked_mul(props[p])?.checked_add(totals[p])?;
+            }
+
+            let totals = next;
+            let calories = amount
+                .checked_mul(ingredient.calories)?
+                .checked_add(calories)?;
+            let left = remaining - amount;
+
+            if last {
+                let score = score(totals)?;
+
+                if self.calories.is_none_or(|c| c == calories) && score > self.best_score() {
+                    self.amounts[idx] = amount;
+                    self.best = Some((self.amounts.clone(), score));
+                }
+
+                continue;
+            }
+
+            // Spending the rest on the best ingredient for each property separately is optimistic,
+            // and clamping only makes it more so
+            let best = self.best_after[idx + 1];
+            let bound = score_bound(
+                [0, 1, 2, 3].map(|p| totals[p].saturating_add(left.saturating_mul(best[p]))),
+            );
+            let (low, high) = self.calories_after[idx + 1];
+            let reach = |per: i64| calories.saturating_add(left.saturating_mul(per));
+
+            let calories_reachable = self
+                .calories
+                .is_none_or(|c| (reach(low)..=reach(high)).contains(&c));
+
+            if bound > self.best_score() && calories_reachable {
+                self.amounts[idx] = amount;
+                self.search(idx + 1, left, totals, calories)?;
+            }
+        }
+
+        Some(())
+    }
+}
+
+/// Finds the best recipe exactly, optionally limited to recipes with exactly `calories` calories.
+/// Unlike [`highest_score`] this makes no assumptions about the shape of the score space. `None`
+/// if the totals of a recipe do not fit in an `i64`.
+fn exact_highest_score(
+    teaspoons: i64,
+    ingredients: &[Ingredient],
+    calories: Option<i64>,
+) -> Option<(Vec<i64>, i64)> {
+    if ingredients.is_empty() {
+        return Some((vec![], 0));
+    }
+
+    let mut best_after = vec![[i64::MIN; 4]; ingredients.len() + 1];
+    let mut calories_after = vec![(i64::MAX, i64::MIN); ingredients.len() + 1];
+
+    for (i, ingredient) in ingredients.iter().enumerate().rev() {
+        let props = ingredient.properties();
+        let (low, high) = calories_after[i + 1];
+
+        best_after[i] = [0, 1, 2, 3].map(|p| best_after[i + 1][p].max(props[p]));
+        calories_after[i] = (low.min(ingredient.calories), high.max(ingredient.calories));
+    }
+
+    let mut search = ExactSearch {
+        ingredients,
+        calories,
+        best_after,
+        calories_after,
+        amounts: vec![0; ingredients.len()],
+        best: None,
+        explored: 0,
+    };
+
+    search.search(0, teaspoons, [0; 4], 0)?;
+
+    trace_event!(
+        explored = search.explored,
+        best = search.best_score(),
+        ?calories,
+        "exact search finished"
+    );
+
+    Some(search.best.unwrap_or((vec![], 0)))
+}
+
+/// Score of the best cookie from `teaspoons` of the ingredients, with exactly `calories` if given.
+/// `max_states` caps the recipes remembered by the gradient ascent.
+fn best_cookie(
+    teaspoons: i64,
+    ingredients: &[Ingredient],
+    calories: Option<i64>,
+    max_states: usize,
+) -> i64 {
+    let best = match (cfg!(feature = "gradient-ascent"), calories) {
+        (true, None) => highest_score(teaspoons, ingredients, max_states),
+        (true, Some(calories)) => {
+            highest_calorie_score(teaspoons, calories, ingredients, max_states)
+        }
+        (false, _) => exact_highest_score(teaspoons, ingredients, calories),
+    };
+
+    best.expect("cookie scores should fit in an i64").1
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Science for Hungry People",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Ingredient>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "teaspoons",
+            about: "teaspoons of ingredients in each cookie, 100 in the puzzle",
+        },
+        Param {
+            name: "calories",
+            about: "calories of the cookies in part 2, 500 in the puzzle",
+        },
+        Param {
+            name: "max_states",
+            about: "recipes the gradient-ascent feature remembers testing before pruning",
+        },
+    ];
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader.lines().map(|l| Ok(Ingredient::parse(l?))).collect()
+    }
+
+    /// Score of the best cookie
+    fn part_01(input: &Self::Input) -> Answer {
+        best_cookie(TEASPOONS, input, None, MAX_STATES).into()
+    }
+
+    /// Score of the best cookie with exactly 500 calories
+    fn part_02(input: &Self::Input) -> Answer {
+        best_cookie(TEASPOONS, input, Some(CALORIES), MAX_STATES).into()
+    }
+
+    fn part_01_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        let teaspoons = ctx.param("teaspoons", TEASPOONS)?;
+        let max_states = ctx.param("max_states", MAX_STATES)?;
+
+        Ok(best_cookie(teaspoons, input, None, max_states).into())
+    }
+
+    fn part_02_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        let teaspoons = ctx.param("teaspoons", TEASPOONS)?;
+        let calories = ctx.param("calories", CALORIES)?;
+        let max_states = ctx.param("max_states", MAX_STATES)?;
+
+        Ok(best_cookie(teaspoons, input, Some(calories), max_states).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn highest_total_score() {
+        let ingredients_data = r"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+        let ingredients = ingredients_data
+            .lines()
+            .map(Ingredient::parse)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            highest_score(100, &ingredients, MAX_STATES).unwrap().1,
+            62_842_880
+        );
+    }
+
+    #[test]
+    fn highest_cal_score() {
+        let ingredients_data = r"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+        let ingredients = ingredients_data
+            .lines()
+            .map(Ingredient::parse)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            highest_calorie_score(100, 500, &ingredients, MAX_STATES)
+                .unwrap()
+                .1,
+            57_600_000
+        );
+    }
+
+    #[test]
+    fn pruned_ascent() {
+        let ingredients_data = r"Sprinkles: capacity 2, durability 0, flavor -2, texture 0, calories 3
+Butterscotch: capacity 0, durability 5, flavor -3, texture 0, calories 3
+Chocolate: capacity 0, durability 0, flavor 5, texture -1, calories 8
+Candy: capacity 0, durability -1, flavor 0, texture 5, calories 8";
+
+        let ingredients = ingredients_data
+            .lines()
+            .map(Ingredient::parse)
+            .collect::<Vec<_>>();
+
+        for calories in [None, Some(200)] {
+            let start = vec![8, 8, 16, 8];
+            let (best, total, full) =
+                ascend(start.clone(), &ingredients, calories, MAX_STATES).unwrap();
+            let (pruned_best, pruned_total, pruned) =
+                ascend(start, &ingredients, calories, 16).unwrap();
+
+            assert_eq!((pruned_best, pruned_total), (best, total));
+            assert_eq!(full.pruned, 0);
+            assert!(pruned.pruned > 0);
+            assert!(pruned.evaluated >= full.evaluated);
+        }
+    }
+
+    #[test]
+    fn exact_scores() {
+        let ingredients_data = r"Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+        let ingredients = ingredients_data
+            .lines()
+            .map(Ingredient::parse)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            exact_highest_score(100, &ingredients, None),
+            Some((vec![44, 56], 62_842_880))
+        );
+
+        assert_eq!(
+            exact_highest_score(100, &ingredients, Some(500)),
+            Some((vec![40, 60], 57_600_000))
+        );
+
+        assert_eq!(
+            exact_highest_score(100, &ingredients, Some(1)).unwrap().1,
+            0
+        );
+
+        let mut ctx = Context::new(None)
+            .with_param("teaspoons", 10)
+            .with_param("calories", 50);
+
+        // Four of butterscotch and six of cinnamon is the only 50 calorie cookie
+        assert_eq!(
+            Puzzle::part_02_with(&ingredients, &mut ctx).unwrap(),
+            Answer::Int(8 * 10 * 12 * 6)
+        );
+    }
+
+    #[test]
+    fn exact_matches_enumeration() {
+        let ingredients_data = r"A: capacity 4, durability -2, flavor 0, texture 0, calories 5
+B: capacity 0, durability 5, flavor -1, texture 0, calories 8
+C: capacity -1, durability 0, flavor 5, texture 0, calories 6
+D: capacity 0, durability 0, flavor -2, texture 2, calories 1";
+
+        let ingredients = ingredients_data
+            .lines()
+            .map(Ingredient::parse)
+            .collect::<Vec<_>>();
+
+        let teaspoons = 30;
+        let mut best = 0;
+        let mut best_calories = 0;
+
+        for a in 0..=teaspoons {
+            for b in 0..=teaspoons - a {
+                for c in 0..=teaspoons - a - b {
+                    let amounts = [a, b, c, teaspoons - a - b - c];
+                    let score = eval_candidate(&amounts, &ingredients).unwrap();
+                    let calories = amounts
+                        .iter()
+                        .zip(&ingredients)
+                        .map(|(n, i)| n * i.calories)
+                        .sum::<i64>();
+
+                    best = best.max(score);
+
+                    if calories == 150 {
+                        best_calories = best_calories.max(score);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(
+            exact_highest_score(teaspoons, &ingredients, None)
+                .unwrap()
+                .1,
+            best
+        );
+        assert_eq!(
+            exact_highest_score(teaspoons, &ingredients, Some(150))
+                .unwrap()
+                .1,
+            best_calories
+        );
+    }
+
+    #[test]
+    fn scores_near_overflow() {
+        let single = |amount: i64| {
+            vec![Ingredient::parse(format!(
+                "A: capacity {amount}, durability {amount}, flavor {amount}, texture {amount}, \
+                 calories 0"
+            ))]
+        };
+
+        // 55108 is the largest value whose fourth power fits in an i64
+        let fits = single(55_108);
+        let too_large = single(55_109);
+
+        assert_eq!(
+            exact_highest_score(1, &fits, None),
+            Some((vec![1], 55_108_i64.pow(4)))
+        );
+        assert_eq!(
+            highest_score(1, &fits, MAX_STATES).unwrap().1,
+            55_108_i64.pow(4)
+        );
+        assert_eq!(exact_highest_score(1, &too_large, None), None);
+        assert_eq!(highest_score(1, &too_large, MAX_STATES), None);
+
+        // Totals that overflow before the score is taken, including negative ones that would only
+        // have counted as zero
+        let extreme = [
+            "Max: capacity 9223372036854775807, durability 1, flavor 1, texture 1, calories 1",
+            "Min: capacity -9223372036854775808, durability 1, flavor 1, texture 1, calories 1",
+        ]
+        .map(Ingredient::parse);
+
+        assert_eq!(eval_candidate(&[1, 0], &extreme), Some(i64::MAX));
+        assert_eq!(eval_candidate(&[0, 1], &extreme), Some(0));
+        assert_eq!(eval_candidate(&[2, 0], &extreme), None);
+        assert_eq!(eval_candidate(&[0, 2], &extreme), None);
+        assert_eq!(recipe_calories(&[1, 1], &extreme), Some(2));
+        assert_eq!(exact_highest_score(2, &extreme, None), None);
+        assert_eq!(exact_highest_score(2, &extreme, Some(500)), None);
+    }
+}
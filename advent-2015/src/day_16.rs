@@ -0,0 +1,359 @@
+use std::{collections::BTreeMap, fmt, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use thiserror::Error;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::intern::{Interner, Symbol},
+};
+
+/// What the MFCSAM detected on the gift
+const MFCSAM_READINGS: &str = r"children: 3
+cats: 7
+samoyeds: 2
+pomeranians: 3
+akitas: 0
+vizslas: 0
+goldfish: 5
+trees: 3
+cars: 2
+perfumes: 1";
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SueParseError {
+    #[error("'{0}' should be of the format 'Sue #: thing: #, thing: #'")]
+    InvalidAunt(String),
+    #[error("'{0}' should be of the format 'thing: #'")]
+    InvalidCount(String),
+}
+
+/// Parses `thing: count`
+fn thing_count(s: &str) -> Result<(&str, usize), SueParseError> {
+    s.split_once(": ")
+        .and_then(|(k, v)| Some((k, v.parse().ok()?)))
+        .ok_or_else(|| SueParseError::InvalidCount(s.to_owned()))
+}
+
+/// One of the many Aunt Sues along with the things remembered about her, named by the symbols
+/// of the [`Aunts`] she belongs to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Aunt {
+    pub number: usize,
+    pub things: Vec<(Symbol, usize)>,
+}
+
+/// Every Aunt Sue, with the names of the things remembered about them interned since the same
+/// few come up for every aunt
+#[derive(Clone, Debug, Default)]
+pub struct Aunts {
+    things: Interner,
+    pub aunts: Vec<Aunt>,
+}
+
+impl Aunts {
+    /// Adds an aunt written as `Sue #: thing: #, thing: #`
+    pub fn add(&mut self, s: &str) -> Result<(), SueParseError> {
+        let (name, things) = s
+            .split_once(": ")
+            .ok_or_else(|| SueParseError::InvalidAunt(s.to_owned()))?;
+
+        let number = name
+            .strip_prefix("Sue ")
+            .and_then(|n| n.parse().ok())
+            .ok_or_else(|| SueParseError::InvalidAunt(s.to_owned()))?;
+
+        let things = things
+            .split(", ")
+            .map(|t| thing_count(t).map(|(thing, count)| (self.things.intern(thing), count)))
+            .collect::<Result<_, _>>()?;
+
+        self.aunts.push(Aunt { number, things });
+
+        Ok(())
+    }
+
+    /// Name of a thing remembered about one of the aunts
+    pub fn thing(&self, thing: Symbol) -> &str {
+        self.things.resolve(thing)
+    }
+}
+
+/// One aunt per line
+impl FromStr for Aunts {
+    type Err = SueParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut aunts = Self::default();
+
+        for line in s.lines() {
+            aunts.add(line)?;
+        }
+
+        Ok(aunts)
+    }
+}
+
+/// How a count the MFCSAM detected relates to the real count of a thing
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Reading {
+    Exactly(usize),
+    GreaterThan(usize),
+    LessThan(usize),
+}
+
+impl Reading {
+    pub fn matches(&self, val: usize) -> bool {
+        match *self {
+            Reading::Exactly(v) => val == v,
+            Reading::GreaterThan(v) => val > v,
+            Reading::LessThan(v) => val < v,
+        }
+    }
+}
+
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reading::Exactly(v) => write!(f, "= {v}"),
+            Reading::GreaterThan(v) => write!(f, "> {v}"),
+            Reading::LessThan(v) => write!(f, "< {v}"),
+        }
+    }
+}
+
+/// Every reading the MFCSAM produced for a gift
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Signature(BTreeMap<String, Reading>);
+
+impl Signature {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the reading for `thing`
+    pub fn reading(mut self, thing: impl Into<String>, reading: Reading) -> Self {
+        self.0.insert(thing.into(), reading);
+
+        self
+    }
+
+    /// Accounts for the outdated retroencabulator: cats and trees read low, while pomeranians and
+    /// goldfish read high
+    pub fn with_ranges(mut self) -> Self {
+        for (thing, reading) in &mut self.0 {
+            if let Reading::Exactly(v) = *reading {
+                match thing.as_str() {
+                    "cats" | "trees" => *reading = Reading::GreaterThan(v),
+                    "pomeranians" | "goldfish" => *reading = Reading::LessThan(v),
+                    _ => {}
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Every aunt consistent with the readings in the order given. Things the MFCSAM has no
+    /// reading for can not be confirmed, so they count against the aunts remembered for them.
+    pub fn matching<'a>(&self, aunts: &'a Aunts) -> impl Iterator<Item = AuntMatch<'a>> {
+        // Each thing is looked up once rather than once for every aunt it is remembered for
+        let readings = aunts
+            .things
+            .iter()
+            .map(|(_, thing)| self.0.get(thing).copied())
+            .collect::<Vec<_>>();
+
+        aunts.aunts.iter().filter_map(move |aunt| {
+            let mut matched = aunt
+                .things
+                .iter()
+                .map(|&(thing, count)| {
+                    let reading = readings[thing.index()].filter(|r| r.matches(count))?;
+
+                    Some((aunts.thing(thing), count, reading))
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            matched.sort_unstable_by_key(|&(thing, ..)| thing);
+
+            Some(AuntMatch {
+                aunt,
+                readings: matched,
+            })
+        })
+    }
+}
+
+/// Readings are `thing: count` lines that must be matched exactly
+impl FromStr for Signature {
+    type Err = SueParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| thing_count(l.trim()).map(|(k, v)| (k.to_owned(), Reading::Exactly(v))))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+/// An aunt consistent with a [`Signature`] along with each of her things and the reading it
+/// matched, ordered by thing
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuntMatch<'a> {
+    pub aunt: &'a Aunt,
+    pub readings: Vec<(&'a str, usize, Reading)>,
+}
+
+impl fmt::Display for AuntMatch<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sue {}:", self.aunt.number)?;
+
+        for (i, (thing, count, reading)) in self.readings.iter().enumerate() {
+            let sep = if i == 0 { "" } else { "," };
+
+            write!(f, "{sep} {thing} {count} {reading}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The number of the only aunt that matches `signature`
+fn only_match(aunts: &Aunts, signature: &Signature) -> usize {
+    let matches = signature.matching(aunts).collect::<Vec<_>>();
+
+    match matches.as_slice() {
+        [m] => m.aunt.number,
+        _ => panic!(
+            "exactly one aunt should match the readings but {} did",
+            matches.len()
+        ),
+    }
+}
+
+fn mfcsam() -> Signature {
+    MFCSAM_READINGS
+        .parse()
+        .expect("MFCSAM readings should be valid")
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Aunt Sue",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Aunts;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut aunts = Aunts::default();
+
+        for (i, l) in reader.lines().enumerate() {
+            aunts
+                .add(&l?)
+                .with_context(|| format!("line {} is not a valid aunt", i + 1))?;
+        }
+
+        Ok(aunts)
+    }
+
+    /// Number of the Sue whose things exactly match the MFCSAM readings
+    fn part_01(input: &Self::Input) -> Answer {
+        only_match(input, &mfcsam()).into()
+    }
+
+    /// Number of the Sue matching the readings once ranges are accounted for
+    fn part_02(input: &Self::Input) -> Answer {
+        only_match(input, &mfcsam().with_ranges()).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn aunts() -> Aunts {
+        let input = r"Sue 1: cats: 2, dogs: 1
+Sue 2: cats: 4, trees: 0
+Sue 3: trees: 1, dogs: 1
+Sue 4: cats: 3";
+
+        Puzzle::parse(input.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn parse_signature() {
+        let expected = Signature::new()
+            .reading("cats", Reading::Exactly(3))
+            .reading("dogs", Reading::Exactly(1));
+
+        assert_eq!("cats: 3\ndogs: 1\n".parse(), Ok(expected));
+        assert_eq!(
+            "cats 3".parse::<Signature>(),
+            Err(SueParseError::InvalidCount("cats 3".into()))
+        );
+        assert_eq!(
+            "Sue: cats: 3".parse::<Aunts>().map(|a| a.aunts),
+            Err(SueParseError::InvalidAunt("Sue: cats: 3".into()))
+        );
+    }
+
+    #[test]
+    fn all_matches() {
+        let aunts = aunts();
+
+        let signature = Signature::new()
+            .reading("cats", Reading::GreaterThan(1))
+            .reading("dogs", Reading::Exactly(1))
+            .reading("trees", Reading::LessThan(2));
+
+        let explained = signature
+            .matching(&aunts)
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            explained,
+            [
+                "Sue 1: cats 2 > 1, dogs 1 = 1",
+                "Sue 2: cats 4 > 1, trees 0 < 2",
+                "Sue 3: dogs 1 = 1, trees 1 < 2",
+                "Sue 4: cats 3 > 1",
+            ]
+        );
+
+        // Without a reading for dogs, the aunts remembered for them can not be confirmed
+        let signature = Signature::new().reading("cats", Reading::Exactly(3));
+
+        assert_eq!(
+            signature
+                .matching(&aunts)
+                .map(|m| m.aunt.number)
+                .collect::<Vec<_>>(),
+            [4]
+        );
+    }
+
+    #[test]
+    fn ranged_readings() {
+        let signature = "cats: 3\ntrees: 1\ngoldfish: 5\ncars: 2"
+            .parse::<Signature>()
+            .unwrap()
+            .with_ranges();
+
+        assert_eq!(
+            signature,
+            Signature::new()
+                .reading("cats", Reading::GreaterThan(3))
+                .reading("trees", Reading::GreaterThan(1))
+                .reading("goldfish", Reading::LessThan(5))
+                .reading("cars", Reading::Exactly(2))
+        );
+    }
+}
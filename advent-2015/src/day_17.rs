@@ -0,0 +1,90 @@
+use std::io::BufRead;
+
+use advent_core::{
+    answer::Answer,
+    context::{Context, Param},
+    day::Day,
+    describe::DayInfo,
+    util::subset_sum::SubsetCounts,
+};
+
+/// Eggnog to store in liters, unless given as the `liters` parameter
+const LITERS: usize = 150;
+
+pub const INFO: DayInfo = DayInfo {
+    title: "No Such Thing as Too Much",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+/// Combinations of containers that hold exactly `liters`
+fn combinations(containers: &[usize], liters: usize) -> u64 {
+    SubsetCounts::new(liters, containers).total()
+}
+
+/// Combinations using the fewest containers that hold exactly `liters`
+fn fewest_combinations(containers: &[usize], liters: usize) -> u64 {
+    SubsetCounts::new(liters, containers)
+        .fewest()
+        .map_or(0, |(_, ways)| ways)
+}
+
+impl Day for Puzzle {
+    type Input = Vec<usize>;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "liters",
+        about: "eggnog the containers must hold, 150 in the puzzle",
+    }];
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader.lines().map(|l| Ok(l?.trim().parse()?)).collect()
+    }
+
+    /// Combinations of containers that hold exactly 150 liters
+    fn part_01(input: &Self::Input) -> Answer {
+        combinations(input, LITERS).into()
+    }
+
+    /// Combinations using the fewest containers that hold exactly 150 liters
+    fn part_02(input: &Self::Input) -> Answer {
+        fewest_combinations(input, LITERS).into()
+    }
+
+    fn part_01_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(combinations(input, ctx.param("liters", LITERS)?).into())
+    }
+
+    fn part_02_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(fewest_combinations(input, ctx.param("liters", LITERS)?).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn case_count() {
+        let containers = "20, 15, 10, 5, 5"
+            .split(", ")
+            .filter_map(|l| l.parse::<usize>().ok())
+            .collect::<Vec<_>>();
+
+        let counts = SubsetCounts::new(25, &containers);
+
+        assert_eq!((counts.total(), counts.fewest()), (4, Some((2, 3))));
+
+        let mut ctx = Context::new(None).with_param("liters", 25);
+
+        assert_eq!(
+            Puzzle::part_01_with(&containers, &mut ctx).unwrap(),
+            Answer::UInt(4)
+        );
+        assert_eq!(
+            Puzzle::part_02_with(&containers, &mut ctx).unwrap(),
+            Answer::UInt(3)
+        );
+    }
+}
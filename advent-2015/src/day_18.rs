@@ -0,0 +1,494 @@
+use std::{
+    fmt::{Display, Write},
+    io::BufRead,
+};
+
+use advent_core::{
+    answer::Answer,
+    context::{Context, Param},
+    day::Day,
+    describe::DayInfo,
+    progress::Progress,
+    visualize::Visualizer,
+};
+use anyhow::bail;
+
+/// Number of steps both parts animate the lights for, unless given as the `steps` parameter
+const STEPS: usize = 100;
+
+/// Adds three bit-sliced values, returning the sum and carry bits
+fn full_add(a: u64, b: u64, c: u64) -> (u64, u64) {
+    (a ^ b ^ c, (a & b) | (c & (a ^ b)))
+}
+
+/// A double-buffered Game of Life grid of lights of any size. Each row is packed into `u64` words,
+/// one bit per light, and surrounded by a padding row above and below so every light has the same
+/// neighbor layout. A whole word of lights is stepped at once by adding up their neighbors with
+/// bitwise adders.
+#[derive(Clone, Debug)]
+pub struct World {
+    cells: Vec<u64>,
+    next: Vec<u64>,
+    rows: usize,
+    columns: usize,
+    words_per_row: usize,
+    stuck_corners: bool,
+}
+
+impl World {
+    /// Builds a world from lines of `#` (on) and `.` (off), failing on a line that is not the same
+    /// length as the first or has anything else on it
+    pub fn from_data<S, I>(lines: I) -> anyhow::Result<Self>
+    where
+        S: AsRef<str>,
+        I: Iterator<Item = S>,
+    {
+        let lines = lines.collect::<Vec<_>>();
+        let rows = lines.len();
+        let columns = lines.first().map_or(0, |l| l.as_ref().len());
+        let mut world = World::new(rows, columns);
+
+        for (r, line) in lines.iter().enumerate() {
+            let line = line.as_ref();
+
+            if line.len() != columns {
+                bail!(
+                    "line {} has {} lights rather than the {columns} of the first",
+                    r + 1,
+                    line.len()
+                );
+            }
+
+            for (c, ch) in line.chars().enumerate() {
+                match ch {
+                    '#' => world.set(r, c, true),
+                    '.' => {}
+                    _ => bail!("line {} has '{ch}' rather than a light", r + 1),
+                }
+            }
+        }
+
+        Ok(world)
+    }
+
+    /// Creates a world of the given size with every light off
+    pub fn new(rows: usize, columns: usize) -> Self {
+        let words_per_row = columns.div_ceil(64);
+        let cells = vec![0; (rows + 2) * words_per_row];
+
+        World {
+            next: cells.clone(),
+            cells,
+            rows,
+            columns,
+            words_per_row,
+            stuck_corners: false,
+        }
+    }
+
+    /// Number of `(rows, columns)` in the grid
+    pub fn dims(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    /// Index of the word holding `column` of `row`, accounting for the padding row at the top
+    fn word_idx(&self, row: usize, column: usize) -> usize {
+        (row + 1) * self.words_per_row + column / 64
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> bool {
+        assert!(row < self.rows && column < self.columns);
+
+        (self.cells[self.word_idx(row, column)] >> (column % 64)) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, on: bool) {
+        assert!(row < self.rows && column < self.columns);
+
+        let idx = self.word_idx(row, column);
+        let bit = 1 << (column % 64);
+
+        if on {
+            self.cells[idx] |= bit;
+        } else {
+            self.cells[idx] &= !bit;
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|w| usize::try_from(w.count_ones()).unwrap())
+            .sum()
+    }
+
+    /// Pins the four corner lights on, both now and after every following step
+    pub fn enable_corners(&mut self) {
+        self.stuck_corners = true;
+
+        if self.rows == 0 || self.columns == 0 {
+            return;
+        }
+
+        let last = (self.rows - 1, self.columns - 1);
+
+        for (r, c) in [(0, 0), (0, last.1), (last.0, 0), (last.0, last.1)] {
+            self.set(r, c, true);
+        }
+    }
+
+    /// Advances the lights by one generation
+    pub fn step(&mut self) {
+        let wpr = self.words_per_row;
+
+        // Bits past the last column of each row must stay off so they are never counted
+        let tail_mask = match self.columns % 64 {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        };
+
+        for r in 1..=self.rows {
+            let above = &self.cells[(r - 1) * wpr..r * wpr];
+            let row = &self.cells[r * wpr..(r + 1) * wpr];
+            let below = &self.cells[(r + 1) * wpr..(r + 2) * wpr];
+
+            // Each light's neighbors to the west and east, lined up with the light's own bit
+            let west = |line: &[u64], k: usize| {
+                (line[k] << 1) | k.checked_sub(1).map_or(0, |p| line[p] >> 63)
+            };
+            let east =
+                |line: &[u64], k: usize| (line[k] >> 1) | line.get(k + 1).map_or(0, |n| n << 63);
+
+            for k in 0..wpr {
+                let (s_a, c_a) = full_add(west(above, k), above[k], east(above, k));
+                let (s_b, c_b) = full_add(west(below, k), below[k], east(below, k));
+                let (s_c, c_c) = (west(row, k) ^ east(row, k), west(row, k) & east(row, k));
+
+                let (ones, c_d) = full_add(s_a, s_b, s_c);
+                let (t, c_e) = full_add(c_a, c_b, c_c);
+                let (twos, c_f) = (t ^ c_d, t & c_d);
+                let fours = c_e ^ c_f;
+
+                let mut next = twos & !fours & (ones | row[k]);
+
+                if k == wpr - 1 {
+                    next &= tail_mask;
+                }
+
+                self.next[r * wpr + k] = next;
+            }
+        }
+
+        std::mem::swap(&mut self.cells, &mut self.next);
+
+        if self.stuck_corners {
+            self.enable_corners();
+        }
+    }
+
+    /// Advances the lights by `count` generations
+    pub fn steps(&mut self, count: usize) {
+        self.steps_reporting(count, &Progress::hidden());
+    }
+
+    /// [`World::steps`], ticking `progress` after each generation
+    pub fn steps_reporting(&mut self, count: usize, progress: &Progress) {
+        for _ in 0..count {
+            self.step();
+            progress.tick();
+        }
+    }
+}
+
+impl PartialEq for World {
+    fn eq(&self, other: &Self) -> bool {
+        self.dims() == other.dims() && self.cells == other.cells
+    }
+}
+
+impl Eq for World {}
+
+impl Display for World {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for r in 0..self.rows {
+            for c in 0..self.columns {
+                f.write_char(if self.get(r, c) { '#' } else { '.' })?;
+            }
+
+            f.write_char('\n')?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Lights on after `steps` steps, with the corners stuck on when `corners` is set
+fn lights_after(input: &World, corners: bool, steps: usize, progress: &Progress) -> usize {
+    let mut w = input.clone();
+
+    if corners {
+        w.enable_corners();
+    }
+
+    w.steps_reporting(steps, progress);
+    w.count()
+}
+
+/// [`lights_after`] for the number of steps and progress of the run
+fn lights_after_in(input: &World, corners: bool, ctx: &Context<'_>) -> anyhow::Result<usize> {
+    let steps = ctx.param("steps", STEPS)?;
+
+    Ok(lights_after(
+        input,
+        corners,
+        steps,
+        &ctx.progress("steps", steps as u64),
+    ))
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Like a GIF For Your Yard",
+    links: &["https://en.wikipedia.org/wiki/Conway%27s_Game_of_Life"],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = World;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        about: "steps to animate the lights for, 100 in the puzzle",
+    }];
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+
+        World::from_data(lines.iter())
+    }
+
+    /// Lights on after 100 steps
+    fn part_01(input: &Self::Input) -> Answer {
+        lights_after(input, false, STEPS, &Progress::hidden()).into()
+    }
+
+    /// Lights on after 100 steps with the corners stuck on
+    fn part_02(input: &Self::Input) -> Answer {
+        lights_after(input, true, STEPS, &Progress::hidden()).into()
+    }
+
+    fn part_01_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(lights_after_in(input, false, ctx)?.into())
+    }
+
+    fn part_02_with(input: &Self::Input, ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(lights_after_in(input, true, ctx)?.into())
+    }
+}
+
+impl Visualizer for Puzzle {
+    fn visualize(
+        input: &Self::Input,
+        part: i32,
+        ctx: &Context<'_>,
+        frame: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<()> {
+        let mut w = input.clone();
+
+        if part == 2 {
+            w.enable_corners();
+        }
+
+        frame(&w.to_string());
+
+        for _ in 0..ctx.param("steps", STEPS)? {
+            w.step();
+
+            frame(&w.to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_core::util::cycle::{fast_forward, find_cycle, Cycle};
+
+    /// The world one generation after `world`, for iterating without changing it
+    fn next(world: &World) -> World {
+        let mut next = world.clone();
+
+        next.step();
+        next
+    }
+
+    #[test]
+    fn game_of_life_step() {
+        let initial = r".#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+        let expected = r"......
+......
+..##..
+..##..
+......
+......";
+
+        let mut initial = World::from_data(initial.lines()).unwrap();
+        let expected = World::from_data(expected.lines()).unwrap();
+
+        for _ in 0..4 {
+            println!("{initial}");
+            initial.step();
+        }
+
+        println!("{initial}");
+
+        assert_eq!(initial, expected);
+    }
+
+    #[test]
+    fn step_broken_corners() {
+        let initial = r"##.#.#
+...##.
+#....#
+..#...
+#.#..#
+####.#";
+
+        let expected = r"##.###
+.##..#
+.##...
+.##...
+#.#...
+##...#";
+
+        let mut initial = World::from_data(initial.lines()).unwrap();
+        let expected = World::from_data(expected.lines()).unwrap();
+
+        initial.enable_corners();
+
+        for _ in 0..5 {
+            println!("{initial}");
+            initial.step();
+        }
+
+        println!("{initial}");
+
+        assert_eq!(initial, expected);
+    }
+
+    #[test]
+    fn steps_parameter() {
+        let example = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..";
+
+        let solve = |steps: &str, part| {
+            let mut reader = example.as_bytes();
+            let mut ctx = Context::new(Some(&mut reader)).with_param("steps", steps);
+
+            advent_core::day::solve::<Puzzle>(&mut ctx, part)
+        };
+
+        assert_eq!(solve("4", 1).unwrap(), Answer::UInt(4));
+        assert_eq!(solve("5", 2).unwrap(), Answer::UInt(17));
+        assert!(solve("many", 1).is_err());
+    }
+
+    #[test]
+    fn malformed_lights() {
+        let error = |data: &str| World::from_data(data.lines()).unwrap_err().to_string();
+
+        assert_eq!(
+            error("#.#\n##\n..."),
+            "line 2 has 2 lights rather than the 3 of the first"
+        );
+        assert_eq!(error("#.#\n#x#"), "line 2 has 'x' rather than a light");
+        assert!(Puzzle::parse("#.\n.o".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn step_across_words() {
+        // A blinker straddling the boundary between the first two words of each row
+        let mut world = World::new(3, 130);
+
+        for c in 63..=65 {
+            world.set(1, c, true);
+        }
+
+        world.step();
+
+        assert!((0..3).all(|r| world.get(r, 64)));
+        assert!(!world.get(1, 63) && !world.get(1, 65));
+        assert_eq!(world.count(), 3);
+
+        // Lights on the last column must not leak into the unused bits of the final word
+        let mut edge = World::new(3, 70);
+
+        for r in 0..3 {
+            edge.set(r, 69, true);
+        }
+
+        edge.step();
+
+        // Only the middle of the line survives, along with the light born beside it
+        assert_eq!(edge.count(), 2);
+        assert!(edge.get(1, 68) && edge.get(1, 69));
+    }
+
+    #[test]
+    fn cycles() {
+        let mut blinker = World::new(5, 5);
+
+        for c in 1..=3 {
+            blinker.set(2, c, true);
+        }
+
+        assert_eq!(
+            find_cycle(&blinker, next, World::clone),
+            Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+
+        // The puzzle's example settles into a block after four steps
+        let example = World::from_data(
+            [".#.#.#", "...##.", "#....#", "..#...", "#.#..#", "####.."].into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_cycle(&example, next, World::clone),
+            Cycle {
+                start: 4,
+                length: 1
+            }
+        );
+
+        // Stuck corners keep the example changing for longer, which skipping ahead has to match
+        let mut stuck = example.clone();
+
+        stuck.enable_corners();
+
+        let cycle = find_cycle(&stuck, next, World::clone);
+
+        for steps in [0, 1, cycle.start, cycle.start + cycle.length + 1, 1_000] {
+            let mut stepped = stuck.clone();
+
+            stepped.steps(steps);
+
+            assert_eq!(fast_forward(&stuck, steps, next, World::clone), stepped);
+        }
+
+        assert_eq!(
+            fast_forward(&stuck, 1_000_000_000, next, World::clone),
+            fast_forward(&stuck, cycle.equivalent(1_000_000_000), next, World::clone)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::{collections::HashSet, io::BufRead};
+
+use anyhow::{bail, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Number of times [`fewest_steps`] reshuffles the replacements before giving up
+const ATTEMPTS: usize = 1000;
+
+/// Replacements the machine can make along with the medicine molecule to build
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Machine {
+    pub replacements: Vec<(String, String)>,
+    pub molecule: String,
+}
+
+/// Every molecule that a single replacement can turn `molecule` into
+fn single_replacements(machine: &Machine) -> HashSet<String> {
+    let molecule = &machine.molecule;
+
+    machine
+        .replacements
+        .iter()
+        .flat_map(|(from, to)| {
+            molecule
+                .match_indices(from.as_str())
+                .map(move |(i, _)| format!("{}{to}{}", &molecule[..i], &molecule[i + from.len()..]))
+        })
+        .collect()
+}
+
+/// Fewest replacements to make the molecule starting from a single `e`. Works backwards by
+/// replacing the last occurrence of any replacement's result with its source until only `e` is
+/// left. Replacements only ever grow a molecule, so this always finishes, but an unlucky order can
+/// leave a molecule that can not be reduced any further. When that happens the replacements are
+/// reordered and the search starts over. Puzzle grammars leave no choice in how many steps a
+/// molecule takes, so the first complete reduction is the answer.
+fn fewest_steps(machine: &Machine) -> Option<usize> {
+    let (from_e, mut others) = machine
+        .replacements
+        .iter()
+        .partition::<Vec<_>, _>(|(from, _)| from == "e");
+
+    // xorshift is plenty to reorder a few dozen rules
+    let mut seed = 0x2015_1219_u64;
+
+    for _ in 0..ATTEMPTS {
+        let mut molecule = machine.molecule.clone();
+        let mut steps = 0;
+
+        loop {
+            if from_e.iter().any(|(_, to)| *to == molecule) {
+                return Some(steps + 1);
+            }
+
+            let reduced = others.iter().find_map(|(from, to)| {
+                let i = molecule.rfind(to.as_str())?;
+
+                Some(format!(
+                    "{}{from}{}",
+                    &molecule[..i],
+                    &molecule[i + to.len()..]
+                ))
+            });
+
+            match reduced {
+                Some(r) => {
+                    molecule = r;
+                    steps += 1;
+                }
+                None => break,
+            }
+        }
+
+        for i in (1..others.len()).rev() {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+
+            others.swap(i, (seed % (i as u64 + 1)) as usize);
+        }
+    }
+
+    None
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Medicine for Rudolph",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Machine;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut replacements = Vec::new();
+        let mut lines = reader.lines();
+
+        for l in &mut lines {
+            let l = l?;
+
+            if l.is_empty() {
+                break;
+            }
+
+            let (from, to) = l
+                .split_once(" => ")
+                .with_context(|| format!("'{l}' should be a replacement like 'H => HO'"))?;
+
+            replacements.push((from.to_owned(), to.to_owned()));
+        }
+
+        let Some(molecule) = lines.next() else {
+            bail!("the replacements should be followed by a blank line and the molecule");
+        };
+
+        Ok(Machine {
+            replacements,
+            molecule: molecule?.trim().to_owned(),
+        })
+    }
+
+    /// Distinct molecules made by one replacement on the medicine molecule
+    fn part_01(input: &Self::Input) -> Answer {
+        single_replacements(input).len().into()
+    }
+
+    /// Fewest steps to make the medicine molecule from `e`
+    fn part_02(input: &Self::Input) -> Answer {
+        fewest_steps(input)
+            .expect("the medicine molecule should be reachable from e")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn machine(molecule: &str) -> Machine {
+        let input = format!("e => H\ne => O\nH => HO\nH => OH\nO => HH\n\n{molecule}");
+
+        Puzzle::parse(input.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn calibration() {
+        assert_eq!(single_replacements(&machine("HOH")).len(), 4);
+        assert_eq!(single_replacements(&machine("HOHOHO")).len(), 7);
+    }
+
+    #[test]
+    fn fabrication() {
+        assert_eq!(fewest_steps(&machine("HOH")), Some(3));
+        assert_eq!(fewest_steps(&machine("HOHOHO")), Some(6));
+        assert_eq!(fewest_steps(&machine("X")), None);
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert!(Puzzle::parse("H -> HO\n\nHOH".as_bytes()).is_err());
+        assert!(Puzzle::parse("H => HO\n".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::BufRead;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Lowest numbered house to receive at least `target` presents when elf `e` leaves `per_elf * e`
+/// presents at every `e`th house, stopping after `house_limit` houses if given. Every house from
+/// `target / per_elf` onward gets at least that many from its own elf, so only houses up to there
+/// need to be counted. They are all filled in at once, elf by elf.
+fn lowest_house(target: usize, per_elf: usize, house_limit: Option<usize>) -> usize {
+    let last = target.div_ceil(per_elf).max(1);
+    let mut presents = vec![0; last + 1];
+
+    for elf in 1..=last {
+        let houses = (elf..=last).step_by(elf);
+
+        for house in houses.take(house_limit.unwrap_or(usize::MAX)) {
+            presents[house] += per_elf * elf;
+        }
+    }
+
+    presents
+        .iter()
+        .position(|&p| p >= target)
+        .expect("the last house always receives enough presents")
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Infinite Elves and Infinite Houses",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Number of presents a house should receive
+    type Input = usize;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        Ok(text.trim().parse()?)
+    }
+
+    /// Lowest house to get the target number of presents
+    fn part_01(input: &Self::Input) -> Answer {
+        lowest_house(*input, 10, None).into()
+    }
+
+    /// Lowest house to get the target number of presents once elves stop after 50 houses
+    fn part_02(input: &Self::Input) -> Answer {
+        lowest_house(*input, 11, Some(50)).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn infinite_elves() {
+        let presents = [10, 30, 40, 70, 60, 120, 80, 150, 130];
+
+        for (house, &p) in presents.iter().enumerate() {
+            assert!(lowest_house(p, 10, None) <= house + 1);
+        }
+
+        assert_eq!(lowest_house(70, 10, None), 4);
+        assert_eq!(lowest_house(121, 10, None), 8);
+        assert_eq!(lowest_house(1, 10, None), 1);
+    }
+
+    #[test]
+    fn lazy_elves() {
+        // Elf 1 only visits house 1, so house 2 only gets presents from elf 2
+        assert_eq!(lowest_house(23, 11, Some(1)), 3);
+        assert_eq!(lowest_house(23, 11, None), 2);
+    }
+}
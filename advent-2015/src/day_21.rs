@@ -0,0 +1,169 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Hit points the player starts with
+const PLAYER_HIT_POINTS: u32 = 100;
+
+/// Something for sale in the shop as `(cost, damage, armor)`
+type Item = (u32, u32, u32);
+
+const WEAPONS: [Item; 5] = [(8, 4, 0), (10, 5, 0), (25, 6, 0), (40, 7, 0), (74, 8, 0)];
+
+const ARMOR: [Item; 5] = [(13, 0, 1), (31, 0, 2), (53, 0, 3), (75, 0, 4), (102, 0, 5)];
+
+const RINGS: [Item; 6] = [
+    (25, 1, 0),
+    (50, 2, 0),
+    (100, 3, 0),
+    (20, 0, 1),
+    (40, 0, 2),
+    (80, 0, 3),
+];
+
+/// Statistics of either side of a fight
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fighter {
+    pub hit_points: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+impl Fighter {
+    /// Turns `self` needs to bring `other` down to zero hit points
+    fn turns_to_defeat(&self, other: &Self) -> u32 {
+        let per_hit = self.damage.saturating_sub(other.armor).max(1);
+
+        other.hit_points.div_ceil(per_hit)
+    }
+
+    /// Whether `self` wins when attacking first
+    pub fn defeats(&self, other: &Self) -> bool {
+        self.turns_to_defeat(other) <= other.turns_to_defeat(self)
+    }
+}
+
+/// Every way to equip exactly one weapon, up to one armor, and up to two different rings, as the
+/// total cost and the player it makes
+fn loadouts() -> impl Iterator<Item = (u32, Fighter)> {
+    let armor = std::iter::once(None).chain(ARMOR.iter().map(Some));
+    let rings = (0..=2).flat_map(|n| RINGS.iter().combinations(n));
+
+    WEAPONS
+        .iter()
+        .cartesian_product(armor)
+        .cartesian_product(rings.collect_vec())
+        .map(|((weapon, armor), rings)| {
+            let (cost, damage, armor) = std::iter::once(weapon)
+                .chain(armor)
+                .chain(rings)
+                .fold((0, 0, 0), |(c, d, a), &(ic, id, ia)| {
+                    (c + ic, d + id, a + ia)
+                });
+
+            (
+                cost,
+                Fighter {
+                    hit_points: PLAYER_HIT_POINTS,
+                    damage,
+                    armor,
+                },
+            )
+        })
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "RPG Simulator 20XX",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// The boss to fight
+    type Input = Fighter;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut stats = [None; 3];
+
+        for l in reader.lines() {
+            let l = l?;
+            let (name, value) = l
+                .split_once(": ")
+                .with_context(|| format!("'{l}' should be a statistic like 'Armor: 2'"))?;
+
+            let slot = match name {
+                "Hit Points" => 0,
+                "Damage" => 1,
+                "Armor" => 2,
+                _ => anyhow::bail!("unknown statistic '{name}'"),
+            };
+
+            stats[slot] = Some(value.trim().parse()?);
+        }
+
+        let [Some(hit_points), Some(damage), Some(armor)] = stats else {
+            anyhow::bail!("the boss should have hit points, damage, and armor");
+        };
+
+        Ok(Fighter {
+            hit_points,
+            damage,
+            armor,
+        })
+    }
+
+    /// Least gold spent to win the fight
+    fn part_01(input: &Self::Input) -> Answer {
+        loadouts()
+            .filter(|(_, player)| player.defeats(input))
+            .map(|(cost, _)| cost)
+            .min()
+            .expect("some loadout should win")
+            .into()
+    }
+
+    /// Most gold spent and still lose the fight
+    fn part_02(input: &Self::Input) -> Answer {
+        loadouts()
+            .filter(|(_, player)| !player.defeats(input))
+            .map(|(cost, _)| cost)
+            .max()
+            .expect("some loadout should lose")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fight() {
+        let player = Fighter {
+            hit_points: 8,
+            damage: 5,
+            armor: 5,
+        };
+
+        let boss = Puzzle::parse("Hit Points: 12\nDamage: 7\nArmor: 2".as_bytes()).unwrap();
+
+        assert!(player.defeats(&boss));
+        assert!(!Fighter {
+            hit_points: 6,
+            ..player
+        }
+        .defeats(&boss));
+        assert!(Puzzle::parse("Hit Points: 12\nDamage: 7".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn shop() {
+        // 5 weapons, 6 armor choices, and 1 + 6 + 15 ring choices
+        assert_eq!(loadouts().count(), 5 * 6 * 22);
+        assert_eq!(loadouts().map(|(c, _)| c).min(), Some(8));
+    }
+}
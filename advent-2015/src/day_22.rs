@@ -0,0 +1,234 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::search};
+
+/// Hit points and mana the player starts with
+const PLAYER_START: (i32, i32) = (50, 500);
+
+/// Statistics of the boss, who has no armor
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Boss {
+    pub hit_points: i32,
+    pub damage: i32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Spell {
+    const ALL: [Self; 5] = [
+        Self::MagicMissile,
+        Self::Drain,
+        Self::Shield,
+        Self::Poison,
+        Self::Recharge,
+    ];
+
+    fn cost(self) -> i32 {
+        match self {
+            Self::MagicMissile => 53,
+            Self::Drain => 73,
+            Self::Shield => 113,
+            Self::Poison => 173,
+            Self::Recharge => 229,
+        }
+    }
+}
+
+/// Everything that can change over the course of a fight
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct Battle {
+    player_hp: i32,
+    mana: i32,
+    boss_hp: i32,
+    shield: u8,
+    poison: u8,
+    recharge: u8,
+}
+
+impl Battle {
+    /// Applies active effects at the start of a turn, returning the player's armor for the turn
+    fn tick(&mut self) -> i32 {
+        let mut armor = 0;
+
+        if self.shield > 0 {
+            self.shield -= 1;
+            armor = 7;
+        }
+
+        if self.poison > 0 {
+            self.poison -= 1;
+            self.boss_hp -= 3;
+        }
+
+        if self.recharge > 0 {
+            self.recharge -= 1;
+            self.mana += 101;
+        }
+
+        armor
+    }
+
+    /// Casts `spell` if the player can afford it and its effect is not already active
+    fn cast(mut self, spell: Spell) -> Option<Self> {
+        self.mana -= spell.cost();
+
+        if self.mana < 0 {
+            return None;
+        }
+
+        match spell {
+            Spell::MagicMissile => self.boss_hp -= 4,
+            Spell::Drain => {
+                self.boss_hp -= 2;
+                self.player_hp += 2;
+            }
+            Spell::Shield if self.shield == 0 => self.shield = 6,
+            Spell::Poison if self.poison == 0 => self.poison = 6,
+            Spell::Recharge if self.recharge == 0 => self.recharge = 5,
+            _ => return None,
+        }
+
+        Some(self)
+    }
+}
+
+/// Least mana the player can spend and still win, losing a hit point at the start of each of
+/// their turns on `hard` difficulty. Each move is one round: the player's turn and then the
+/// boss's, costing the mana of the spell cast.
+fn least_mana(boss: &Boss, (player_hp, mana): (i32, i32), hard: bool) -> Option<i32> {
+    let start = Battle {
+        player_hp,
+        mana,
+        boss_hp: boss.hit_points,
+        shield: 0,
+        poison: 0,
+        recharge: 0,
+    };
+
+    let rounds = |&battle: &Battle| {
+        let mut battle = battle;
+
+        if hard {
+            battle.player_hp -= 1;
+
+            if battle.player_hp <= 0 {
+                return vec![];
+            }
+        }
+
+        battle.tick();
+
+        // Effects alone can finish the boss, which wins without casting anything
+        if battle.boss_hp <= 0 {
+            return vec![(battle, 0)];
+        }
+
+        Spell::ALL
+            .into_iter()
+            .filter_map(|spell| {
+                let mut next = battle.cast(spell)?;
+                let armor = next.tick();
+
+                if next.boss_hp > 0 {
+                    next.player_hp -= (boss.damage - armor).max(1);
+                }
+
+                (next.player_hp > 0).then_some((next, spell.cost()))
+            })
+            .collect()
+    };
+
+    search::dijkstra([start], rounds, |b| b.boss_hp <= 0).map(|path| path.cost)
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Wizard Simulator 20XX",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Boss;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut stats = [None; 2];
+
+        for l in reader.lines() {
+            let l = l?;
+            let (name, value) = l
+                .split_once(": ")
+                .with_context(|| format!("'{l}' should be a statistic like 'Damage: 8'"))?;
+
+            let slot = match name {
+                "Hit Points" => 0,
+                "Damage" => 1,
+                _ => anyhow::bail!("unknown statistic '{name}'"),
+            };
+
+            stats[slot] = Some(value.trim().parse()?);
+        }
+
+        let [Some(hit_points), Some(damage)] = stats else {
+            anyhow::bail!("the boss should have hit points and damage");
+        };
+
+        Ok(Boss { hit_points, damage })
+    }
+
+    /// Least mana spent to win the fight
+    fn part_01(input: &Self::Input) -> Answer {
+        least_mana(input, PLAYER_START, false)
+            .expect("the fight should be winnable")
+            .into()
+    }
+
+    /// Least mana spent to win the fight on hard difficulty
+    fn part_02(input: &Self::Input) -> Answer {
+        least_mana(input, PLAYER_START, true)
+            .expect("the fight should be winnable")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        let boss = Puzzle::parse("Hit Points: 13\nDamage: 8".as_bytes()).unwrap();
+
+        // Poison then Magic Missile
+        assert_eq!(least_mana(&boss, (10, 250), false), Some(173 + 53));
+
+        let boss = Boss {
+            hit_points: 14,
+            ..boss
+        };
+
+        // Recharge, Shield, Drain, Poison, then Magic Missile
+        assert_eq!(least_mana(&boss, (10, 250), false), Some(641));
+    }
+
+    #[test]
+    fn hard_mode() {
+        let boss = Boss {
+            hit_points: 13,
+            damage: 8,
+        };
+
+        // Losing a hit point every turn leaves too little to survive a second hit
+        assert_eq!(least_mana(&boss, (10, 250), true), None);
+        assert!(least_mana(&boss, (20, 500), true).is_some());
+    }
+}
@@ -0,0 +1,174 @@
+use std::{io::BufRead, str::FromStr};
+
+use thiserror::Error;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::vm::{self, Control, Cpu, Machine},
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum InstructionParseError {
+    #[error("'{0}' is not a known instruction")]
+    UnknownInstruction(String),
+    #[error("'{0}' is not a register")]
+    InvalidRegister(String),
+    #[error("'{0}' is not a valid jump offset")]
+    InvalidOffset(String),
+}
+
+/// One of the computer's two registers
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl FromStr for Register {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Self::A),
+            "b" => Ok(Self::B),
+            _ => Err(InstructionParseError::InvalidRegister(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(isize),
+    /// Jump if the register is even
+    JumpIfEven(Register, isize),
+    /// Jump if the register is exactly one
+    JumpIfOne(Register, isize),
+}
+
+impl FromStr for Instruction {
+    type Err = InstructionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let offset = |o: &str| {
+            o.trim()
+                .parse()
+                .map_err(|_| InstructionParseError::InvalidOffset(o.trim().to_owned()))
+        };
+
+        let (op, args) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| InstructionParseError::UnknownInstruction(s.trim().to_owned()))?;
+
+        let conditional = || {
+            let (r, o) = args
+                .split_once(',')
+                .ok_or_else(|| InstructionParseError::InvalidOffset(args.to_owned()))?;
+
+            Ok((r.parse()?, offset(o)?))
+        };
+
+        Ok(match op {
+            "hlf" => Self::Half(args.parse()?),
+            "tpl" => Self::Triple(args.parse()?),
+            "inc" => Self::Increment(args.parse()?),
+            "jmp" => Self::Jump(offset(args)?),
+            "jie" => conditional().map(|(r, o)| Self::JumpIfEven(r, o))?,
+            "jio" => conditional().map(|(r, o)| Self::JumpIfOne(r, o))?,
+            _ => return Err(InstructionParseError::UnknownInstruction(op.to_owned())),
+        })
+    }
+}
+
+impl vm::Instruction for Instruction {
+    type Value = u64;
+
+    const REGISTERS: usize = 2;
+
+    fn execute(&self, cpu: &mut Cpu<u64>) -> Control {
+        let regs = &mut cpu.registers;
+        let reg = |r: Register| r as usize;
+
+        match *self {
+            Self::Half(r) => regs[reg(r)] /= 2,
+            Self::Triple(r) => regs[reg(r)] *= 3,
+            Self::Increment(r) => regs[reg(r)] += 1,
+            Self::Jump(o) => return Control::Jump(o),
+            Self::JumpIfEven(r, o) if regs[reg(r)].is_multiple_of(2) => return Control::Jump(o),
+            Self::JumpIfOne(r, o) if regs[reg(r)] == 1 => return Control::Jump(o),
+            Self::JumpIfEven(..) | Self::JumpIfOne(..) => {}
+        }
+
+        Control::Next
+    }
+}
+
+/// Runs `program` until it jumps outside of itself, starting with register `a` set to `a`.
+/// Returns the final values of `a` and `b`.
+pub fn run(program: &[Instruction], a: u64) -> (u64, u64) {
+    let mut machine = Machine::new(program.to_vec());
+
+    machine.set_register(Register::A as usize, a);
+    machine.run();
+
+    (
+        machine.register(Register::A as usize),
+        machine.register(Register::B as usize),
+    )
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Opening the Turing Lock",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Instruction>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Ok(vm::load(reader)?)
+    }
+
+    /// Value of register `b` once the program finishes
+    fn part_01(input: &Self::Input) -> Answer {
+        run(input, 0).1.into()
+    }
+
+    /// Value of register `b` once the program finishes when `a` starts at one
+    fn part_02(input: &Self::Input) -> Answer {
+        run(input, 1).1.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let program = Puzzle::parse("inc a\njio a, +2\ntpl a\ninc a".as_bytes()).unwrap();
+
+        assert_eq!(program[1], Instruction::JumpIfOne(Register::A, 2));
+        assert_eq!(run(&program, 0), (2, 0));
+        assert_eq!(run(&program, 1), (7, 0));
+    }
+
+    #[test]
+    fn jumps() {
+        let program =
+            Puzzle::parse("jie b, +3\ninc a\njmp -3\ninc b\nhlf b\njmp -9".as_bytes()).unwrap();
+
+        // b is even so skips straight to `inc b`, `hlf b` halves 1 to 0, then jumps off the start
+        assert_eq!(run(&program, 5), (5, 0));
+        assert!(Puzzle::parse("jio c, +2".as_bytes()).is_err());
+        assert!(Puzzle::parse("jie a +2".as_bytes()).is_err());
+        assert!(Puzzle::parse("nop".as_bytes()).is_err());
+    }
+}
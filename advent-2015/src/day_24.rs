@@ -0,0 +1,133 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::memo::Memo};
+
+/// Groups already known to split or not, keyed by the remaining weights and number of groups
+type SplitMemo = Memo<(Vec<u64>, usize), bool>;
+
+/// Whether `weights` can be split into `groups` groups that each weigh `target`. Different
+/// passenger compartments often leave the same packages behind, so earlier answers for the same
+/// target are reused from `memo`.
+fn can_split(weights: &[u64], groups: usize, target: u64, memo: &mut SplitMemo) -> bool {
+    if groups <= 1 {
+        return weights.iter().sum::<u64>() == target * groups as u64;
+    }
+
+    memo.get_or_compute((weights.to_vec(), groups), |memo| {
+        (1..=weights.len()).any(|k| {
+            weights
+                .iter()
+                .enumerate()
+                .combinations(k)
+                .filter(|c| c.iter().map(|(_, &w)| w).sum::<u64>() == target)
+                .any(|c| {
+                    let rest = weights
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| c.iter().all(|(j, _)| i != j))
+                        .map(|(_, &w)| w)
+                        .collect_vec();
+
+                    can_split(&rest, groups - 1, target, memo)
+                })
+        })
+    })
+}
+
+/// Lowest quantum entanglement of the passenger compartment when packages are split into `groups`
+/// equal weight groups. The passenger compartment holds the fewest packages possible, with the
+/// product of their weights breaking ties.
+fn best_entanglement(weights: &[u64], groups: usize) -> Option<u64> {
+    let total = weights.iter().sum::<u64>();
+
+    if groups == 0 || total % groups as u64 != 0 {
+        return None;
+    }
+
+    let target = total / groups as u64;
+    let mut memo = Memo::new("2015/24 splits");
+
+    (1..=weights.len()).find_map(|k| {
+        let mut candidates = (0..weights.len())
+            .combinations(k)
+            .filter(|c| c.iter().map(|&i| weights[i]).sum::<u64>() == target)
+            .map(|c| (c.iter().map(|&i| weights[i]).product::<u64>(), c))
+            .collect_vec();
+
+        candidates.sort_unstable();
+
+        candidates.into_iter().find_map(|(entanglement, c)| {
+            let rest = (0..weights.len())
+                .filter(|i| !c.contains(i))
+                .map(|i| weights[i])
+                .collect_vec();
+
+            can_split(&rest, groups - 1, target, &mut memo).then_some(entanglement)
+        })
+    })
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "It Hangs in the Balance",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Weights of every package
+    type Input = Vec<u64>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.trim()
+                    .parse()
+                    .with_context(|| format!("line {} is not a valid weight", i + 1))
+            })
+            .collect()
+    }
+
+    /// Quantum entanglement of the passenger compartment with three groups
+    fn part_01(input: &Self::Input) -> Answer {
+        best_entanglement(input, 3)
+            .expect("packages should split into three groups")
+            .into()
+    }
+
+    /// Quantum entanglement of the passenger compartment with four groups
+    fn part_02(input: &Self::Input) -> Answer {
+        best_entanglement(input, 4)
+            .expect("packages should split into four groups")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn balance() {
+        let weights = Puzzle::parse("1\n2\n3\n4\n5\n7\n8\n9\n10\n11".as_bytes()).unwrap();
+
+        assert_eq!(best_entanglement(&weights, 3), Some(99));
+        assert_eq!(best_entanglement(&weights, 4), Some(44));
+    }
+
+    #[test]
+    fn unbalanced() {
+        // Only the 7 weighs a third, and the rest can not make two more groups of 7
+        assert_eq!(best_entanglement(&[7, 5, 3, 3, 3], 3), None);
+        assert_eq!(best_entanglement(&[14, 10, 4, 9, 5], 3), Some(14));
+        let mut memo = Memo::new("2015/24 test splits");
+
+        assert!(can_split(&[1, 2, 3], 2, 3, &mut memo));
+        assert!(!can_split(&[1, 1, 4], 2, 3, &mut memo));
+    }
+}
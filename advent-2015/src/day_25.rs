@@ -0,0 +1,111 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+const FIRST_CODE: u64 = 20151125;
+const MULTIPLIER: u64 = 252533;
+const MODULUS: u64 = 33554393;
+
+/// `base` raised to `exp` modulo `modulus` by repeated squaring
+fn mod_pow(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+
+    base %= modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Code at the one based `(row, column)` of the manual's grid. Codes are filled in along
+/// diagonals from bottom left to top right, each one the previous times [`MULTIPLIER`], so the
+/// code only depends on how far along the diagonals it is.
+fn code_at(row: u64, column: u64) -> u64 {
+    let diagonal = row + column - 1;
+    let index = diagonal * (diagonal - 1) / 2 + column;
+
+    FIRST_CODE * mod_pow(MULTIPLIER, index - 1, MODULUS) % MODULUS
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Let It Snow",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Row and column of the code to enter
+    type Input = (u64, u64);
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        let number_after = |label: &str| -> anyhow::Result<u64> {
+            let (_, rest) = text
+                .split_once(label)
+                .with_context(|| format!("the message should mention a {}", label.trim()))?;
+            let digits = rest
+                .trim_start()
+                .split(|c: char| !c.is_ascii_digit())
+                .next()
+                .unwrap_or_default();
+
+            digits
+                .parse()
+                .with_context(|| format!("'{digits}' is not a valid {}", label.trim()))
+        };
+
+        let (row, column) = (number_after("row ")?, number_after("column ")?);
+
+        anyhow::ensure!(row > 0 && column > 0, "rows and columns start at one");
+
+        Ok((row, column))
+    }
+
+    /// Code to give the weather machine
+    fn part_01(input: &Self::Input) -> Answer {
+        code_at(input.0, input.1).into()
+    }
+
+    /// Day 25 only has one puzzle
+    fn part_02(_input: &Self::Input) -> Answer {
+        "Merry Christmas!".into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grid() {
+        assert_eq!(code_at(1, 1), 20151125);
+        assert_eq!(code_at(2, 1), 31916031);
+        assert_eq!(code_at(1, 2), 18749137);
+        assert_eq!(code_at(3, 4), 7981243);
+        assert_eq!(code_at(1, 6), 33511524);
+        assert_eq!(code_at(6, 6), 27995004);
+    }
+
+    #[test]
+    fn message() {
+        let text = "To continue, please consult the code grid in the manual.  \
+                    Enter the code at row 2981, column 3075.";
+
+        assert_eq!(Puzzle::parse(text.as_bytes()).unwrap(), (2981, 3075));
+        assert!(Puzzle::parse("Enter the code at row 0, column 3.".as_bytes()).is_err());
+        assert!(Puzzle::parse("Enter the code at row 4.".as_bytes()).is_err());
+    }
+}
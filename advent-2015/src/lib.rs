@@ -0,0 +1,34 @@
+//! Solutions to the 2015 puzzles, along with the structures built for them that other crates
+//! reuse, such as the benchmarks comparing ways of lighting a grid or wiring a circuit.
+
+use advent_macros::{generate_missing_test_stubs, generate_year};
+
+use advent_core::explore::ExplorerFn;
+use advent_core::visualize::{visualize, VisualizeFn};
+
+generate_year!(2015 25; explorable = EXPLORABLE, visualizers = VISUALIZERS);
+generate_missing_test_stubs!(2015);
+
+/// Days of this year whose parsed input can be explored from a command loop
+pub const EXPLORABLE: &[(i32, ExplorerFn)] = &[(7, day_07::explore)];
+
+/// Days of this year that can render their progress
+pub const VISUALIZERS: &[(i32, VisualizeFn)] = &[
+    (6, visualize::<day_06::Puzzle>),
+    (18, visualize::<day_18::Puzzle>),
+];
+
+pub use advent_core::util::circuit::LogicWires;
+pub use day_01::{walk_floors, FloorTraversalResult};
+pub use day_02::{Present, WrapReport};
+pub use day_03::{visit_houses_n, HouseGrid};
+pub use day_05::{nice_rules, nicer_rules, RuleSet, StringRule};
+pub use day_06::{parse_instruction, process_instructions, RequestedAction};
+pub use day_09::LocationGraph;
+pub use day_10::{look_and_say, repeat_look_and_say};
+pub use day_13::{best_seating, Arrangement};
+pub use day_14::{RaceSimulator, Reindeer, Standings};
+pub use day_15::Ingredient;
+pub use day_16::{Aunt, AuntMatch, Aunts, Reading, Signature};
+pub use day_18::World;
+pub use day_23::{run, Instruction, Register};
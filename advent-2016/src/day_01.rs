@@ -0,0 +1,133 @@
+use std::{collections::HashSet, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use thiserror::Error;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TurnParseError {
+    #[error("'{0}' should start with L or R")]
+    InvalidDirection(String),
+    #[error("'{0}' should end with a number of blocks")]
+    InvalidDistance(String),
+}
+
+/// A turn left or right followed by walking some number of blocks
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Turn {
+    pub right: bool,
+    pub blocks: i32,
+}
+
+impl FromStr for Turn {
+    type Err = TurnParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let right = match s.chars().next() {
+            Some('R') => true,
+            Some('L') => false,
+            _ => return Err(TurnParseError::InvalidDirection(s.to_string())),
+        };
+
+        let blocks = s[1..]
+            .parse()
+            .map_err(|_| TurnParseError::InvalidDistance(s.to_string()))?;
+
+        Ok(Self { right, blocks })
+    }
+}
+
+/// Every block stepped on while following `turns` from `(0, 0)` facing north, starting with the
+/// first block after the origin
+pub fn walk(turns: &[Turn]) -> impl Iterator<Item = (i32, i32)> + '_ {
+    let mut facing = (0, 1);
+    let mut position = (0, 0);
+
+    turns.iter().flat_map(move |turn| {
+        facing = if turn.right {
+            (facing.1, -facing.0)
+        } else {
+            (-facing.1, facing.0)
+        };
+
+        let (dx, dy) = facing;
+        let (x, y) = position;
+
+        position = (x + dx * turn.blocks, y + dy * turn.blocks);
+
+        (1..=turn.blocks).map(move |i| (x + dx * i, y + dy * i))
+    })
+}
+
+fn distance((x, y): (i32, i32)) -> i32 {
+    x.abs() + y.abs()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "No Time for a Taxicab",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Turn>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let line = reader
+            .lines()
+            .next()
+            .context("the document should have a line of instructions")??;
+
+        Ok(line.split(',').map(str::parse).collect::<Result<_, _>>()?)
+    }
+
+    /// Blocks away from the start once every instruction is followed
+    fn part_01(input: &Self::Input) -> Answer {
+        distance(walk(input).last().unwrap_or((0, 0))).into()
+    }
+
+    /// Blocks away from the start of the first location visited twice
+    fn part_02(input: &Self::Input) -> Answer {
+        let mut visited = HashSet::from([(0, 0)]);
+
+        distance(
+            walk(input)
+                .find(|&p| !visited.insert(p))
+                .expect("some location should be visited twice"),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn final_distance() {
+        for (text, blocks) in [("R2, L3", 5), ("R2, R2, R2", 2), ("R5, L5, R5, R3", 12)] {
+            let turns = Puzzle::parse(text.as_bytes()).unwrap();
+
+            assert_eq!(Puzzle::part_01(&turns), Answer::from(blocks));
+        }
+
+        assert_eq!(
+            "X2".parse::<Turn>(),
+            Err(TurnParseError::InvalidDirection("X2".to_string()))
+        );
+        assert_eq!(
+            "R".parse::<Turn>(),
+            Err(TurnParseError::InvalidDistance("R".to_string()))
+        );
+    }
+
+    #[test]
+    fn first_revisit() {
+        let turns = Puzzle::parse("R8, R4, R4, R8".as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_02(&turns), Answer::from(4));
+    }
+}
@@ -0,0 +1,113 @@
+use std::io::BufRead;
+
+use anyhow::bail;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Buttons laid out in rows, with spaces where there is no button
+pub struct Keypad(&'static [&'static [u8]]);
+
+/// The square keypad the bathroom was expected to have
+pub const SQUARE: Keypad = Keypad(&[b"123", b"456", b"789"]);
+
+/// The diamond keypad the bathroom actually has
+pub const DIAMOND: Keypad = Keypad(&[b"  1  ", b" 234 ", b"56789", b" ABC ", b"  D  "]);
+
+impl Keypad {
+    fn button(&self, (row, column): (usize, usize)) -> Option<u8> {
+        self.0.get(row)?.get(column).copied().filter(|&b| b != b' ')
+    }
+
+    /// Code found by following each line of moves from the button the previous line ended on,
+    /// starting at 5. Moves that would leave the keypad are ignored.
+    pub fn code(&self, lines: &[Vec<(isize, isize)>]) -> String {
+        let row = self
+            .0
+            .iter()
+            .position(|r| r.contains(&b'5'))
+            .expect("every keypad has a 5");
+        let column = self.0[row]
+            .iter()
+            .position(|&b| b == b'5')
+            .expect("the row has a 5");
+        let mut position = (row, column);
+
+        lines
+            .iter()
+            .map(|moves| {
+                for &(dr, dc) in moves {
+                    let next = (
+                        position.0.wrapping_add_signed(dr),
+                        position.1.wrapping_add_signed(dc),
+                    );
+
+                    if self.button(next).is_some() {
+                        position = next;
+                    }
+                }
+
+                self.button(position).expect("only buttons are visited") as char
+            })
+            .collect()
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Bathroom Security",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Row and column offsets of each move on each line
+    type Input = Vec<Vec<(isize, isize)>>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.chars()
+                    .map(|c| match c {
+                        'U' => Ok((-1, 0)),
+                        'D' => Ok((1, 0)),
+                        'L' => Ok((0, -1)),
+                        'R' => Ok((0, 1)),
+                        _ => bail!("'{c}' on line {} is not a direction", i + 1),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Bathroom code on the keypad as imagined
+    fn part_01(input: &Self::Input) -> Answer {
+        SQUARE.code(input).into()
+    }
+
+    /// Bathroom code on the real keypad
+    fn part_02(input: &Self::Input) -> Answer {
+        DIAMOND.code(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "ULL
+RRDDD
+LURDL
+UUUUD";
+
+    #[test]
+    fn codes() {
+        let moves = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(SQUARE.code(&moves), "1985");
+        assert_eq!(DIAMOND.code(&moves), "5DB3");
+        assert_eq!(SQUARE.code(&[]), "");
+        assert!(Puzzle::parse("UX".as_bytes()).is_err());
+    }
+}
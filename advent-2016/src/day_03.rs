@@ -0,0 +1,85 @@
+use std::io::BufRead;
+
+use anyhow::{bail, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Whether the sides can form a triangle, meaning any two are longer than the third
+pub fn is_triangle([a, b, c]: [u32; 3]) -> bool {
+    a + b > c && a + c > b && b + c > a
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Squares With Three Sides",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Three numbers on each line of the design document
+    type Input = Vec<[u32; 3]>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let sides = l?
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<Vec<_>, _>>()
+                    .with_context(|| format!("line {} should only have numbers", i + 1))?;
+
+                match sides[..] {
+                    [a, b, c] => Ok([a, b, c]),
+                    _ => bail!("line {} should have three sides", i + 1),
+                }
+            })
+            .collect()
+    }
+
+    /// Possible triangles when each row is a triangle
+    fn part_01(input: &Self::Input) -> Answer {
+        input.iter().filter(|&&t| is_triangle(t)).count().into()
+    }
+
+    /// Possible triangles when each group of three rows holds a triangle in every column
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .chunks_exact(3)
+            .flat_map(|rows| (0..3).map(move |c| [rows[0][c], rows[1][c], rows[2][c]]))
+            .filter(|&t| is_triangle(t))
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "101 301 501
+102 302 502
+103 303 503
+201 401 601
+202 402 602
+203 403 603";
+
+    #[test]
+    fn rows() {
+        let triangles = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert!(!is_triangle([5, 10, 25]));
+        assert!(!is_triangle([1, 2, 3]));
+        assert_eq!(Puzzle::part_01(&triangles), Answer::from(3));
+        assert!(Puzzle::parse("1 2".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn columns() {
+        let triangles = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_02(&triangles), Answer::from(6));
+    }
+}
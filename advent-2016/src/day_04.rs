@@ -0,0 +1,145 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// An encrypted room name with its sector ID and checksum
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Room {
+    pub name: String,
+    pub sector: u32,
+    pub checksum: String,
+}
+
+impl Room {
+    /// Whether the checksum is the five most common letters of the name, ties broken
+    /// alphabetically
+    pub fn is_real(&self) -> bool {
+        let mut counts = [0; 26];
+
+        for b in self.name.bytes().filter(u8::is_ascii_lowercase) {
+            counts[(b - b'a') as usize] += 1;
+        }
+
+        let mut letters = (b'a'..=b'z').collect::<Vec<_>>();
+
+        // Sorting is stable, so letters with the same count stay in alphabetical order
+        letters.sort_by_key(|&l| std::cmp::Reverse(counts[(l - b'a') as usize]));
+
+        self.checksum.as_bytes() == &letters[..5]
+    }
+
+    /// The name with every letter rotated forward by the sector ID and dashes turned into spaces
+    pub fn decrypt(&self) -> String {
+        self.name
+            .bytes()
+            .map(|b| match b {
+                b'-' => ' ',
+                _ => (b'a' + ((b - b'a') as u32 + self.sector).rem_euclid(26) as u8) as char,
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Room {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (rest, checksum) = s
+            .strip_suffix(']')
+            .and_then(|s| s.split_once('['))
+            .context("rooms should end with a checksum in brackets")?;
+        let (name, sector) = rest
+            .rsplit_once('-')
+            .context("the sector ID should follow the name")?;
+
+        ensure!(
+            name.bytes().all(|b| b == b'-' || b.is_ascii_lowercase()),
+            "names should only have lowercase letters and dashes"
+        );
+        ensure!(
+            checksum.len() == 5 && checksum.bytes().all(|b| b.is_ascii_lowercase()),
+            "checksums should be five lowercase letters"
+        );
+
+        Ok(Self {
+            name: name.to_string(),
+            sector: sector.parse()?,
+            checksum: checksum.to_string(),
+        })
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Security Through Obscurity",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Room>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid room", i + 1))
+            })
+            .collect()
+    }
+
+    /// Sum of the sector IDs of the real rooms
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|r| r.is_real())
+            .map(|r| r.sector)
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Sector ID of the room where North Pole objects are stored
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .filter(|r| r.is_real())
+            .find(|r| r.decrypt().contains("northpole object"))
+            .expect("the North Pole objects should be stored somewhere")
+            .sector
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "aaaaa-bbb-z-y-x-123[abxyz]
+a-b-c-d-e-f-g-h-987[abcde]
+not-a-real-room-404[oarel]
+totally-real-room-200[decoy]";
+
+    #[test]
+    fn checksums() {
+        let rooms = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(
+            rooms.iter().map(Room::is_real).collect::<Vec<_>>(),
+            vec![true, true, true, false]
+        );
+        assert_eq!(Puzzle::part_01(&rooms), Answer::from(1514));
+        assert!(Puzzle::parse("abc-12".as_bytes()).is_err());
+        assert!(Puzzle::parse("Abc-12[abcde]".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn decryption() {
+        let room = "qzmt-zixmtkozy-ivhz-343[zimth]".parse::<Room>().unwrap();
+
+        assert_eq!(room.decrypt(), "very encrypted name");
+    }
+}
@@ -0,0 +1,124 @@
+use std::io::BufRead;
+
+use anyhow::{ensure, Context};
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::md5_mine::{mine, Md5Digest},
+};
+
+/// Hashes of the door ID followed by an increasing index that start with five zeroes in hex, in
+/// the order they are found
+fn interesting_hashes(door_id: &str) -> impl Iterator<Item = Md5Digest> + '_ {
+    mine(door_id, 5, 0).map(|(_, hash)| hash)
+}
+
+fn hex(nibble: u8) -> char {
+    char::from_digit(nibble.into(), 16).expect("nibbles are below 16")
+}
+
+/// Password made from the sixth hex digit of the first eight hashes
+pub fn password(hashes: impl Iterator<Item = Md5Digest>) -> String {
+    hashes.take(8).map(|h| hex(h[2] & 0x0F)).collect()
+}
+
+/// Password where the sixth hex digit of each hash is a position and the seventh is the character
+/// to put there. Positions past the end or already filled are skipped.
+pub fn positioned_password(hashes: impl Iterator<Item = Md5Digest>) -> String {
+    let mut password = [None; 8];
+
+    for h in hashes {
+        let position = (h[2] & 0x0F) as usize;
+
+        if let Some(slot @ None) = password.get_mut(position) {
+            *slot = Some(hex(h[3] >> 4));
+
+            if password.iter().all(Option::is_some) {
+                break;
+            }
+        }
+    }
+
+    password.iter().flatten().collect()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "How About a Nice Game of Chess?",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// The door ID
+    type Input = String;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let door_id = reader
+            .lines()
+            .next()
+            .context("the input should have the door ID")??;
+
+        ensure!(
+            !door_id.trim().is_empty(),
+            "the door ID should not be empty"
+        );
+
+        Ok(door_id.trim().to_string())
+    }
+
+    /// Password for the first door
+    fn part_01(input: &Self::Input) -> Answer {
+        password(interesting_hashes(input)).into()
+    }
+
+    /// Password for the second door
+    fn part_02(input: &Self::Input) -> Answer {
+        positioned_password(interesting_hashes(input)).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A hash with the given sixth and seventh hex digits, since finding real ones takes millions
+    /// of attempts
+    fn hash(sixth: u8, seventh: u8) -> Md5Digest {
+        let mut h = [0; 16];
+
+        h[2] = sixth;
+        h[3] = seventh << 4;
+        h
+    }
+
+    #[test]
+    fn first_door() {
+        let hashes = [1, 8, 0xF, 4, 7, 0xA, 3, 0, 9].map(|d| hash(d, 0));
+
+        assert_eq!(password(hashes.into_iter()), "18f47a30");
+        assert!(Puzzle::parse("".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn second_door() {
+        // The third hash is ignored since its position is taken, and the fourth is out of range
+        let hashes = [
+            (1, 5),
+            (4, 0xE),
+            (1, 0),
+            (9, 1),
+            (0, 0),
+            (2, 0xA),
+            (3, 0xC),
+            (5, 8),
+            (6, 0xE),
+            (7, 3),
+        ]
+        .map(|(p, c)| hash(p, c));
+
+        assert_eq!(positioned_password(hashes.into_iter()), "05ace8e3");
+    }
+}
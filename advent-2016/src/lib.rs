@@ -0,0 +1,12 @@
+//! Solutions to the 2016 puzzles.
+
+use advent_macros::{generate_missing_test_stubs, generate_year};
+
+generate_year!(2016 5);
+generate_missing_test_stubs!(2016);
+
+pub use day_01::{walk, Turn, TurnParseError};
+pub use day_02::{Keypad, DIAMOND, SQUARE};
+pub use day_03::is_triangle;
+pub use day_04::Room;
+pub use day_05::{password, positioned_password};
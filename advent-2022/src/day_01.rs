@@ -0,0 +1,301 @@
+use std::io::BufRead;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, examples::examples};
+
+/// Helper struct to make generating an unknown number of Elves more idiomatic by leveraging the
+/// fact that [`Elf::parse_one`] modifies the iterator and returns an `Option<Elf>`.
+struct ElfGenerator<S, I>(I)
+where
+    S: AsRef<str>,
+    I: Iterator<Item = S>;
+
+impl<S, I> Iterator for ElfGenerator<S, I>
+where
+    S: AsRef<str>,
+    I: Iterator<Item = S>,
+{
+    type Item = Elf;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Elf::parse_one(&mut self.0)
+    }
+}
+
+/// Elves carry a number of items, each with a known caloric value
+#[derive(Clone, Debug)]
+pub struct Elf {
+    items: Vec<u32>,
+}
+
+impl Elf {
+    /// An Elf is defined as a sequence of newline separated numbers (their items) a blank line or
+    /// EOF indicates the end of an Elf's list
+    pub fn parse_one<S, I>(lines: &mut I) -> Option<Self>
+    where
+        S: AsRef<str>,
+        I: Iterator<Item = S>,
+    {
+        let items: Vec<_> = lines
+            .map_while(|l| {
+                if l.as_ref().is_empty() {
+                    None
+                } else {
+                    Some(
+                        l.as_ref()
+                            .parse::<u32>()
+                            .expect("Input lines should only be empty or numbers"),
+                    )
+                }
+            })
+            .collect();
+
+        if items.is_empty() {
+            None
+        } else {
+            Some(Self { items })
+        }
+    }
+
+    /// Leverages [`ElfGenerator`] to trivially collect all available Elves in the input into a
+    /// `Vec<Elf>`
+    pub fn parse_all<S, I>(lines: I) -> Vec<Self>
+    where
+        S: AsRef<str>,
+        I: Iterator<Item = S>,
+    {
+        ElfGenerator(lines).collect()
+    }
+
+    /// Total calories of every item. Summing `u32` items into a `u64` would take billions of them
+    /// to overflow.
+    pub fn calories_carried(&self) -> u64 {
+        self.items.iter().copied().map(u64::from).sum()
+    }
+}
+
+/// Parses the problem input and returns the Elf objects parsed as well as their cummulative
+/// carried calories.
+fn parse_input(reader: impl BufRead) -> anyhow::Result<(Vec<Elf>, Vec<u64>)> {
+    let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+    let elves = Elf::parse_all(lines.iter());
+
+    let sum_calories = elves.iter().map(Elf::calories_carried).collect();
+
+    Ok((elves, sum_calories))
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Calorie Counting",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Cummulative calories carried by each Elf
+    type Input = Vec<u64>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(reader)?.1)
+    }
+
+    /// Most calories carried by an Elf, or 0 when there are none, as streaming answers
+    fn part_01(input: &Self::Input) -> Answer {
+        input.iter().max().copied().unwrap_or(0).into()
+    }
+
+    /// Total calories carried by the three Elves carrying the most calories
+    fn part_02(input: &Self::Input) -> Answer {
+        let mut sum_calories = input.clone();
+
+        sum_calories.sort_by(|a, b| b.cmp(a));
+
+        sum_calories.iter().take(3).sum::<u64>().into()
+    }
+
+    const STREAMING: bool = true;
+
+    /// Keeps only the running total of the current Elf and the highest total so far
+    fn part_01_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut highest = 0;
+        let mut current = 0;
+
+        for l in reader.lines() {
+            let l = l?;
+
+            if l.is_empty() {
+                highest = highest.max(current);
+                current = 0;
+            } else {
+                current += u64::from(l.parse::<u32>()?);
+            }
+        }
+
+        Ok(highest.max(current).into())
+    }
+
+    /// Keeps only the running total of the current Elf and the three highest totals so far
+    fn part_02_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut top_three = [0; 3];
+
+        let mut try_insert = |v| {
+            if v > top_three[0] {
+                top_three[0] = v;
+                top_three.sort_unstable();
+            }
+        };
+
+        let mut current = 0;
+
+        for l in reader.lines() {
+            let l = l?;
+
+            if l.is_empty() {
+                try_insert(current);
+
+                current = 0;
+            } else {
+                current += u64::from(l.parse::<u32>()?);
+            }
+        }
+
+        try_insert(current);
+
+        Ok(top_three.iter().sum::<u64>().into())
+    }
+}
+
+/// Picks out the three highest totals with a partial selection rather than sorting every Elf
+pub struct Fast;
+
+impl Day for Fast {
+    type Input = Vec<u64>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Puzzle::parse(reader)
+    }
+
+    fn part_01(input: &Self::Input) -> Answer {
+        Puzzle::part_01(input)
+    }
+
+    fn part_02(input: &Self::Input) -> Answer {
+        let mut sum_calories = input.clone();
+
+        // With three Elves or fewer every one of them is among the top three
+        if sum_calories.len() > 3 {
+            sum_calories.select_nth_unstable_by(2, |a, b| b.cmp(a));
+        }
+
+        sum_calories.iter().take(3).sum::<u64>().into()
+    }
+}
+
+examples! {
+    part1: "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000" => "24000",
+    part2: "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000" => "45000",
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufReader;
+
+    use super::*;
+
+    /// Verify that parsing all elves and getting their total carried calories works as intended
+    #[test]
+    fn parse_sum() {
+        let input = r"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+        let (_, sum_calories) = parse_input(BufReader::new(input.as_bytes())).unwrap();
+
+        assert_eq!(vec![6000, 4000, 11000, 24000, 10000], sum_calories);
+    }
+
+    /// Totals of items near the largest `u32` go past it without wrapping
+    #[test]
+    fn large_totals() {
+        let max = u32::MAX;
+        let input = format!("{max}\n{max}\n\n{max}\n\n{max}\n{max}\n{max}");
+        let collected = Puzzle::parse(input.as_bytes()).unwrap();
+        let max = u64::from(max);
+
+        assert_eq!(collected, vec![2 * max, max, 3 * max]);
+        assert_eq!(Puzzle::part_01(&collected), Answer::UInt(3 * max));
+        assert_eq!(Puzzle::part_02(&collected), Answer::UInt(6 * max));
+        assert_eq!(Fast::part_02(&collected), Answer::UInt(6 * max));
+        assert_eq!(
+            Puzzle::part_01_streaming(input.as_bytes()).unwrap(),
+            Answer::UInt(3 * max)
+        );
+        assert_eq!(
+            Puzzle::part_02_streaming(input.as_bytes()).unwrap(),
+            Answer::UInt(6 * max)
+        );
+
+        let too_large = format!("{}", u64::from(u32::MAX) + 1);
+
+        assert!(Puzzle::part_01_streaming(too_large.as_bytes()).is_err());
+    }
+
+    /// Verify that streaming the input gives the same answers as collecting it
+    #[test]
+    fn streaming_matches() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+        let collected = Puzzle::parse(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            Puzzle::part_01_streaming(input.as_bytes()).unwrap(),
+            Puzzle::part_01(&collected)
+        );
+        assert_eq!(
+            Puzzle::part_02_streaming(input.as_bytes()).unwrap(),
+            Puzzle::part_02(&collected)
+        );
+        assert_eq!(
+            Puzzle::part_02_streaming(input.as_bytes()).unwrap(),
+            Answer::Int(45000)
+        );
+        assert!(Puzzle::part_01_streaming("1\nx".as_bytes()).is_err());
+    }
+
+    /// Fewer than three Elves are all counted in part 2
+    #[test]
+    fn two_elves() {
+        let input = "1000\n2000\n\n4000";
+        let collected = Puzzle::parse(input.as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_02(&collected), Answer::UInt(7000));
+        assert_eq!(Fast::part_02(&collected), Answer::UInt(7000));
+        assert_eq!(
+            Puzzle::part_02_streaming(input.as_bytes()).unwrap(),
+            Answer::UInt(7000)
+        );
+    }
+
+    /// An input without Elves answers 0 rather than panicking
+    #[test]
+    fn no_elves() {
+        let collected = Puzzle::parse("".as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_01(&collected), Answer::UInt(0));
+        assert_eq!(
+            Puzzle::part_01_streaming("".as_bytes()).unwrap(),
+            Answer::UInt(0)
+        );
+    }
+}
@@ -1,12 +1,17 @@
 use std::{fmt, io::BufRead};
 
-trait Score {
+use anyhow::Context;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Points awarded towards the total score of a strategy guide
+pub trait Score {
     fn score(&self) -> u32;
 }
 
 /// Result of a rock, paper, scissors competition
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum RoundOutcome {
+pub enum RoundOutcome {
     Lose,
     Draw,
     Win,
@@ -14,7 +19,7 @@ enum RoundOutcome {
 
 /// Error that can occur from trying to parse a `RoundOutcome` from a `u8` ASCII character
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct ParseRoundOutcomeByteError();
+pub struct ParseRoundOutcomeByteError();
 
 impl fmt::Display for ParseRoundOutcomeByteError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -48,7 +53,7 @@ impl Score for RoundOutcome {
 
 /// Possible hand shapes for rock, paper, scissors
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum HandShape {
+pub enum HandShape {
     Rock,
     Paper,
     Scissors,
@@ -56,7 +61,7 @@ enum HandShape {
 
 impl HandShape {
     /// Gets the `HandShape` that is needed to achieve the desired `RoundOutcome` against `other`
-    fn for_outcome(outcome: RoundOutcome, other: Self) -> Self {
+    pub fn for_outcome(outcome: RoundOutcome, other: Self) -> Self {
         match outcome {
             RoundOutcome::Lose => other.defeats(),
             RoundOutcome::Draw => other,
@@ -65,7 +70,7 @@ impl HandShape {
     }
 
     /// Resolves a competition of `self` against another into a `RoundOutcome`
-    fn compete(self, against: Self) -> RoundOutcome {
+    pub fn compete(self, against: Self) -> RoundOutcome {
         match (self, against) {
             // Alternatively (a, b) if (b as u32 + 1) % 3 == a as u32
             (HandShape::Rock, HandShape::Scissors)
@@ -77,7 +82,7 @@ impl HandShape {
     }
 
     /// Gets the `HandShape` that can defeat `self`
-    fn defeated_by(self) -> Self {
+    pub fn defeated_by(self) -> Self {
         match self {
             HandShape::Rock => HandShape::Paper,
             HandShape::Paper => HandShape::Scissors,
@@ -86,7 +91,7 @@ impl HandShape {
     }
 
     /// Gets the `HandShape` that `self` can defeat
-    fn defeats(self) -> Self {
+    pub fn defeats(self) -> Self {
         match self {
             HandShape::Rock => HandShape::Scissors,
             HandShape::Paper => HandShape::Rock,
@@ -107,7 +112,7 @@ impl Score for HandShape {
 
 /// Error that can occur from trying to parse a `HandShape` from a `u8` ASCII character
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct ParseHandShapeByteError();
+pub struct ParseHandShapeByteError();
 
 impl fmt::Display for ParseHandShapeByteError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -129,49 +134,67 @@ impl TryFrom<u8> for HandShape {
     }
 }
 
-pub fn part_01(reader: Option<impl BufRead>) {
-    let total_score = reader
-        .expect("This problem requires data input")
-        .lines()
-        .flatten()
-        .map(|l| {
-            let mut hands = l.bytes().filter_map(|b| b.try_into().ok());
+pub const INFO: DayInfo = DayInfo {
+    title: "Rock Paper Scissors",
+    links: &[],
+};
 
-            let theirs: HandShape = hands.next().expect("Each round must have two hands");
-            let ours = hands.next().expect("Each round must have two hands");
+pub struct Puzzle;
 
-            ours.score() + ours.compete(theirs).score()
-        })
-        .sum::<u32>();
+impl Day for Puzzle {
+    /// Opponent's hand and the second column of the strategy guide, whose meaning depends on the
+    /// part being solved
+    type Input = Vec<(HandShape, u8)>;
 
-    println!("Strategy guide total score: {total_score}");
-}
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .map(|l| {
+                let l = l?;
+                let mut parts = l.split(' ').filter_map(|c| c.as_bytes().first()).copied();
+
+                let (Some(other), Some(second)) = (parts.next(), parts.next()) else {
+                    anyhow::bail!(
+                        "Every line should consist of two characters separated by a single space"
+                    );
+                };
+
+                let other = HandShape::try_from(other)
+                    .ok()
+                    .context("The first character should be one of ABC")?;
+
+                Ok((other, second))
+            })
+            .collect()
+    }
+
+    /// Total score when the second column is the hand to play
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|&(theirs, second)| {
+                let ours =
+                    HandShape::try_from(second).expect("The second character should be one of XYZ");
 
-pub fn part_02(reader: Option<impl BufRead>) {
-    let total_score = reader
-        .expect("This problem requires data input")
-        .lines()
-        .flatten()
-        .map(|l| {
-            let mut parts = l.split(' ').filter_map(|c| c.as_bytes().first()).copied();
-
-            let other = parts
-                .next()
-                .map(HandShape::try_from)
-                .expect("Every line should consist of two characters separated by a single space")
-                .expect("The first character should be one of ABC");
-
-            let outcome = parts
-                .next()
-                .map(RoundOutcome::try_from)
-                .expect("Every line should consist of two characters separated by a single space")
-                .expect("The second character should be one of XYZ");
-
-            HandShape::for_outcome(outcome, other).score() + outcome.score()
-        })
-        .sum::<u32>();
-
-    println!("Strategy guide total score: {total_score}");
+                ours.score() + ours.compete(theirs).score()
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Total score when the second column is the desired outcome
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|&(other, second)| {
+                let outcome = RoundOutcome::try_from(second)
+                    .expect("The second character should be one of XYZ");
+
+                HandShape::for_outcome(outcome, other).score() + outcome.score()
+            })
+            .sum::<u32>()
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -205,12 +228,14 @@ C Z";
 
     #[test]
     fn compete_hands() {
-        let hands = [HandShape::Rock,
+        let hands = [
+            HandShape::Rock,
             HandShape::Paper,
             HandShape::Paper,
             HandShape::Rock,
             HandShape::Scissors,
-            HandShape::Scissors];
+            HandShape::Scissors,
+        ];
 
         let outcomes = hands
             .chunks_exact(2)
@@ -225,12 +250,14 @@ C Z";
 
     #[test]
     fn score_hands() {
-        let hands = [HandShape::Rock,
+        let hands = [
+            HandShape::Rock,
             HandShape::Paper,
             HandShape::Paper,
             HandShape::Rock,
             HandShape::Scissors,
-            HandShape::Scissors];
+            HandShape::Scissors,
+        ];
 
         let outcomes = [RoundOutcome::Win, RoundOutcome::Lose, RoundOutcome::Draw];
 
@@ -275,9 +302,11 @@ C Z";
 
     #[test]
     fn score_mixed() {
-        let rounds = [(HandShape::Rock, RoundOutcome::Draw),
+        let rounds = [
+            (HandShape::Rock, RoundOutcome::Draw),
             (HandShape::Paper, RoundOutcome::Lose),
-            (HandShape::Scissors, RoundOutcome::Win)];
+            (HandShape::Scissors, RoundOutcome::Win),
+        ];
 
         let scores = rounds
             .iter()
@@ -1,20 +1,27 @@
 use itertools::Itertools;
-use std::{io::BufRead, ops::BitOr};
+use std::io::BufRead;
 use thiserror::Error;
 
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::bitset::{NotSingleError, SmallSet},
+};
+
 /// An item in an Elf's rucksack. Guaranteed to be in the range `0..52`
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct Item(u32);
+pub struct Item(u32);
 
 impl Item {
-    fn priority(self) -> u32 {
+    pub fn priority(self) -> u32 {
         self.0 + 1
     }
 }
 
 #[derive(Error, Copy, Clone, Debug, PartialEq, Eq)]
 #[error("ASCII character was not one of a-z or A-Z")]
-struct NotItemError();
+pub struct NotItemError();
 
 /// Attempts to convert ASCII input `[a-zA-Z]` into an `Item`
 impl TryFrom<u8> for Item {
@@ -29,61 +36,28 @@ impl TryFrom<u8> for Item {
     }
 }
 
-#[derive(Error, Copy, Clone, Debug, PartialEq, Eq)]
-#[error("Number passed not in the range 0..52")]
-struct ItemRangeError();
-
-/// Essentially a compact hash set of an item as the range of possible item values allows perfect
-/// hashing in the bits of a `u64`
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-struct ItemSet(u64);
-
-impl ItemSet {
-    fn intersection(self, other: Self) -> Self {
-        ItemSet(self.0 & other.0)
-    }
-
-    fn _union(self, other: Self) -> Self {
-        ItemSet(self.0 | other.0)
-    }
-}
-
-#[derive(Error, Copy, Clone, Debug, PartialEq, Eq)]
-#[error("Number passed has value greater than 1 << 51")]
-struct SetRangeError();
+/// Every possible `Item` fits in a `SmallSet` so sets of them are a single integer
+pub type ItemSet = SmallSet<52>;
 
 /// Essentially hash an `Item` into an `ItemSet`
 impl From<Item> for ItemSet {
     fn from(value: Item) -> Self {
-        ItemSet(1u64 << value.0)
+        std::iter::once(value).collect()
     }
 }
 
 impl FromIterator<Item> for ItemSet {
     fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
-        ItemSet(
-            iter.into_iter()
-                .map(|i| 1u64 << i.0)
-                .reduce(BitOr::bitor)
-                .unwrap_or(0),
-        )
+        iter.into_iter().map(|i| i.0 as usize).collect()
     }
 }
 
-#[derive(Error, Copy, Clone, Debug, PartialEq, Eq)]
-#[error("ItemSet does not have exactly one bit set.")]
-struct SetNotSingleItemError();
-
 /// Converts an `ItemSet` representing a single `Item` into that `Item`
 impl TryFrom<ItemSet> for Item {
-    type Error = anyhow::Error;
+    type Error = NotSingleError;
 
     fn try_from(value: ItemSet) -> Result<Self, Self::Error> {
-        if value.0.count_ones() == 1 {
-            Ok(Item((value.0 - 1).count_ones()))
-        } else {
-            Err(SetNotSingleItemError().into())
-        }
+        usize::try_from(value).map(|i| Item(i as u32))
     }
 }
 
@@ -99,61 +73,59 @@ where
         .and_then(|ii| Item::try_from(ii).ok())
 }
 
-pub fn part_01(reader: Option<impl BufRead>) {
-    let priority_sum = reader
-        .expect("This problem requires data input")
-        .lines()
-        .flatten()
-        .filter_map(|l| {
-            let bytes = l.bytes();
-            let len = bytes.len();
-
-            if len == 0 {
-                return None;
-            }
+pub const INFO: DayInfo = DayInfo {
+    title: "Rucksack Reorganization",
+    links: &[],
+};
 
-            let items = bytes.map(|b| Item::try_from(b).expect("Invalid value for rucksack item"));
+pub struct Puzzle;
 
-            Some(
-                common_rucksack_item(items, len / 2)
-                    .expect("No common item found between the two halves")
-                    .priority(),
-            )
-        })
-        .sum::<u32>();
+impl Day for Puzzle {
+    /// Items in each non-empty rucksack
+    type Input = Vec<Vec<Item>>;
 
-    println!("Sum of priorities present in both compartments of each rucksack: {priority_sum}");
-}
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut rucksacks = Vec::new();
+
+        for l in reader.lines() {
+            let l = l?;
 
-pub fn part_02(reader: Option<impl BufRead>) {
-    let priority_sum = reader
-        .expect("This problem requires data input")
-        .lines()
-        .flatten()
-        .filter_map(|l| {
-            let bytes = l.bytes();
-            let len = bytes.len();
-
-            if len == 0 {
-                return None;
+            if !l.is_empty() {
+                rucksacks.push(l.bytes().map(Item::try_from).collect::<Result<_, _>>()?);
             }
+        }
 
-            Some(
-                bytes
-                    .map(|b| Item::try_from(b).expect("Invalid value for rucksack item"))
-                    .collect::<ItemSet>(),
-            )
-        })
-        .chunks(3)
-        .into_iter()
-        .map(|i| {
-            Item::try_from(i.reduce(ItemSet::intersection).unwrap())
-                .unwrap()
-                .priority()
-        })
-        .sum::<u32>();
-
-    println!("Sum of priorities common within groups: {priority_sum}");
+        Ok(rucksacks)
+    }
+
+    /// Sum of priorities present in both compartments of each rucksack
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|items| {
+                common_rucksack_item(items.iter().copied(), items.len() / 2)
+                    .expect("No common item found between the two halves")
+                    .priority()
+            })
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Sum of priorities common within groups of three
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|items| items.iter().copied().collect::<ItemSet>())
+            .chunks(3)
+            .into_iter()
+            .map(|i| {
+                Item::try_from(i.reduce(ItemSet::intersection).unwrap())
+                    .unwrap()
+                    .priority()
+            })
+            .sum::<u32>()
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -167,7 +139,7 @@ mod test {
             .flat_map(Item::try_from)
             .collect();
 
-        assert_eq!(full_set.0, (1u64 << 52) - 1);
+        assert_eq!(full_set, ItemSet::full());
     }
 
     #[test]
@@ -0,0 +1,207 @@
+use std::io::BufRead;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::interval::SectionRange};
+
+/// A convenient iterator adapter to create pairs of `SectionRange`s from lines containing a
+/// comma-separated string equivalent
+struct RangePairs<S, I>(I)
+where
+    S: AsRef<str>,
+    I: Iterator<Item = S>;
+
+impl<S, I> Iterator for RangePairs<S, I>
+where
+    S: AsRef<str>,
+    I: Iterator<Item = S>,
+{
+    type Item = (SectionRange<u32>, SectionRange<u32>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|l| range_pair(l.as_ref()))
+    }
+}
+
+/// Parses a single line of two comma separated ranges
+fn range_pair(line: &str) -> (SectionRange<u32>, SectionRange<u32>) {
+    line.split_once(',')
+        .map(|(l, r)| {
+            (
+                l.parse()
+                    .expect("first part of line should represent a range"),
+                r.parse()
+                    .expect("second part of line should represent a range"),
+            )
+        })
+        .expect("line should be two ranges separated by a comma")
+}
+
+/// Helper trait to add the method `as_range_pairs` to any `Iterator` that it applies to. This
+/// method converts the `Iterator` into one that outputs pairs of `SectionRange`s instead.
+trait IntoRangePairs<S: AsRef<str>>: Iterator<Item = S> {
+    fn range_pairs(self) -> RangePairs<S, Self>
+    where
+        Self: Sized,
+    {
+        RangePairs(self)
+    }
+}
+
+impl<S: AsRef<str>, T: Sized> IntoRangePairs<S> for T where T: Iterator<Item = S> {}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Camp Cleanup",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<(SectionRange<u32>, SectionRange<u32>)>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+
+        Ok(lines.iter().range_pairs().collect())
+    }
+
+    /// Number of assignment pairs where one fully contains the other
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|(l, r)| u32::from(l.contains_range(r) || r.contains_range(l)))
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Number of assignment pairs where one overlaps the other
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|(l, r)| u32::from(l.overlaps(r)))
+            .sum::<u32>()
+            .into()
+    }
+
+    const STREAMING: bool = true;
+
+    fn part_01_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut contained = 0;
+
+        for line in reader.lines() {
+            let (l, r) = range_pair(&line?);
+
+            contained += u32::from(l.contains_range(&r) || r.contains_range(&l));
+        }
+
+        Ok(contained.into())
+    }
+
+    fn part_02_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut overlapping = 0;
+
+        for line in reader.lines() {
+            let (l, r) = range_pair(&line?);
+
+            overlapping += u32::from(l.overlaps(&r));
+        }
+
+        Ok(overlapping.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+    use advent_core::util::testgen::section_range;
+
+    #[test]
+    fn parse_ranges() {
+        let input = r"2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+        let ranges = input.lines().range_pairs().collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (SectionRange(2, 4), SectionRange(6, 8)),
+                (SectionRange(2, 3), SectionRange(4, 5)),
+                (SectionRange(5, 7), SectionRange(7, 9)),
+                (SectionRange(2, 8), SectionRange(3, 7)),
+                (SectionRange(6, 6), SectionRange(4, 6)),
+                (SectionRange(2, 6), SectionRange(4, 8))
+            ],
+            ranges
+        );
+    }
+
+    #[test]
+    fn range_contains() {
+        let ranges = [
+            (SectionRange(2, 4), SectionRange(6, 8)),
+            (SectionRange(2, 3), SectionRange(4, 5)),
+            (SectionRange(5, 7), SectionRange(7, 9)),
+            (SectionRange(2, 8), SectionRange(3, 7)),
+            (SectionRange(6, 6), SectionRange(4, 6)),
+            (SectionRange(2, 6), SectionRange(4, 8)),
+        ];
+
+        let full_overlaps = ranges
+            .iter()
+            .map(|(l, r)| l.contains_range(r) || r.contains_range(l))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![false, false, false, true, true, false], full_overlaps);
+    }
+
+    #[test]
+    fn range_overlaps() {
+        let ranges = [
+            (SectionRange(2, 4), SectionRange(6, 8)),
+            (SectionRange(2, 3), SectionRange(4, 5)),
+            (SectionRange(5, 7), SectionRange(7, 9)),
+            (SectionRange(2, 8), SectionRange(3, 7)),
+            (SectionRange(6, 6), SectionRange(4, 6)),
+            (SectionRange(2, 6), SectionRange(4, 8)),
+        ];
+
+        let partial_overlaps = ranges
+            .iter()
+            .map(|(l, r)| l.overlaps(r))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![false, false, true, true, true, true], partial_overlaps);
+    }
+
+    proptest! {
+        /// Which Elf of a pair is listed first changes neither count, and a range containing the
+        /// other always overlaps it
+        #[test]
+        fn pairs_are_symmetric(
+            pairs in prop::collection::vec((section_range(100), section_range(100)), 0..20),
+        ) {
+            let line = |(l, r): &(SectionRange<u32>, SectionRange<u32>)| {
+                format!("{}-{},{}-{}", l.0, l.1, r.0, r.1)
+            };
+
+            let swapped = pairs.iter().map(|(l, r)| (r.clone(), l.clone())).collect::<Vec<_>>();
+            let input = pairs.iter().map(line).collect::<Vec<_>>().join("\n");
+            let parsed = Puzzle::parse(input.as_bytes()).unwrap();
+
+            prop_assert_eq!(&parsed, &pairs);
+            prop_assert_eq!(Puzzle::part_01(&parsed), Puzzle::part_01(&swapped));
+            prop_assert_eq!(Puzzle::part_02(&parsed), Puzzle::part_02(&swapped));
+
+            for (l, r) in &pairs {
+                prop_assert_eq!(l.overlaps(r), r.overlaps(l));
+                prop_assert_eq!(l.overlaps(r), l.intersection(r).is_some());
+                prop_assert!(!l.contains_range(r) || l.overlaps(r));
+            }
+        }
+    }
+}
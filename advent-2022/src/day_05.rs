@@ -0,0 +1,734 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use thiserror::Error;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    lint::{self, Malformed},
+};
+
+/// Reasons a [`StackMove`] cannot be applied to some [`Stacks`]. Stacks are numbered from 1 as in
+/// the puzzle input.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum StackError {
+    #[error("stack {} does not exist", .0 + 1)]
+    OutOfRange(usize),
+    #[error("cannot move {count} crates from stack {} which only holds {available}", .stack + 1)]
+    InsufficientCrates {
+        stack: usize,
+        count: usize,
+        available: usize,
+    },
+}
+
+/// Representation for moving at least one crate between two separate stacks
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StackMove {
+    pub count: usize,
+    pub from_stack: usize,
+    pub to_stack: usize,
+}
+
+impl FromStr for StackMove {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(' ').skip(1).step_by(2);
+
+        let count = parts
+            .next()
+            .context("input should be \"move [count] from [stack] to [stack]\"")?
+            .parse()?;
+
+        let from_stack = parts
+            .next()
+            .context("input should be \"move [count] from [stack] to [stack]\"")?
+            .parse::<usize>()?
+            .checked_sub(1)
+            .context("stacks are numbered from 1")?;
+
+        let to_stack = parts
+            .next()
+            .context("input should be \"move [count] from [stack] to [stack]\"")?
+            .parse::<usize>()?
+            .checked_sub(1)
+            .context("stacks are numbered from 1")?;
+
+        if count == 0 {
+            Err(anyhow::Error::msg(
+                "A move operation should move at least one crate",
+            ))
+        } else if from_stack == to_stack {
+            Err(anyhow::Error::msg(
+                "A move operation should move between different stacks",
+            ))
+        } else {
+            Ok(StackMove {
+                count,
+                from_stack,
+                to_stack,
+            })
+        }
+    }
+}
+
+/// Represents stacks of crates that can be manipulated via `StackMove`s. The moves can be
+/// performed one at a time by calling `apply_move_9000`, or a whole move at a time by calling
+/// `apply_move_9001`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stacks(Vec<Vec<char>>);
+
+impl Stacks {
+    /// Converts the first part of a line-based input into stacks by rotating from a visually
+    /// intuitive vertical form into a memory friendly orientation.
+    pub fn from_lines<S, L>(lines: &mut L) -> Self
+    where
+        S: AsRef<str>,
+        L: Iterator<Item = S>,
+    {
+        let mut stacks_transposed: Vec<Vec<_>> = lines
+            .take_while(|l| !l.as_ref().is_empty())
+            .map(|l| l.as_ref().chars().skip(1).step_by(4).collect())
+            .collect();
+
+        let n_cols = stacks_transposed.iter().map(Vec::len).max().unwrap_or(0);
+
+        // The numbers under the stacks only label them, they are not crates
+        if stacks_transposed
+            .last()
+            .is_some_and(|row| row.iter().all(|c| c.is_ascii_digit() || *c == ' '))
+        {
+            stacks_transposed.pop();
+        }
+
+        // All the information is there, but transposed
+        let n_rows = stacks_transposed.len();
+
+        let mut stacks = vec![vec![' '; n_rows]; n_cols];
+
+        for r in 0..n_rows {
+            for (c, item) in stacks_transposed[n_rows - r - 1].iter().enumerate() {
+                stacks[c][r] = *item;
+            }
+        }
+
+        for s in &mut stacks {
+            let first_blank = s
+                .iter()
+                .enumerate()
+                .find(|&(_, &c)| c.is_ascii_whitespace())
+                .map(|(idx, _)| idx);
+
+            if let Some(idx) = first_blank {
+                s.truncate(idx);
+            }
+        }
+
+        Self(stacks)
+    }
+
+    /// Checks that `m` can be applied before borrowing the `from` and `to` stacks at the same time
+    fn two_stacks(
+        &mut self,
+        m: &StackMove,
+    ) -> Result<(&mut Vec<char>, &mut Vec<char>), StackError> {
+        let (from, to) = (m.from_stack, m.to_stack);
+
+        for stack in [from, to] {
+            if stack >= self.0.len() {
+                return Err(StackError::OutOfRange(stack));
+            }
+        }
+
+        let available = self.0[from].len();
+
+        if m.count > available {
+            return Err(StackError::InsufficientCrates {
+                stack: from,
+                count: m.count,
+                available,
+            });
+        }
+
+        assert_ne!(from, to, "Cannot borrow the same stack twice");
+
+        Ok(if from < to {
+            let (lower, upper) = self.0.split_at_mut(to);
+
+            (&mut lower[from], &mut upper[0])
+        } else {
+            let (lower, upper) = self.0.split_at_mut(from);
+
+            (&mut upper[0], &mut lower[to])
+        })
+    }
+
+    /// Applies the movements in a 9000 series compliant manner by moving one crate at a time. The
+    /// stacks are left untouched if the move cannot be made.
+    pub fn apply_move_9000(&mut self, m: &StackMove) -> Result<(), StackError> {
+        if m.from_stack == m.to_stack {
+            return self.check_in_place(m);
+        }
+
+        let (from, to) = self.two_stacks(m)?;
+
+        for _ in 0..m.count {
+            to.push(from.pop().expect("crates were counted before moving"));
+        }
+
+        Ok(())
+    }
+
+    /// Applies the movements in a 9001 series compliant manner by moving whole groups at a time.
+    /// The group is copied to the destination in a single block. The stacks are left untouched if
+    /// the move cannot be made.
+    pub fn apply_move_9001(&mut self, m: &StackMove) -> Result<(), StackError> {
+        if m.from_stack == m.to_stack {
+            return self.check_in_place(m);
+        }
+
+        let (from, to) = self.two_stacks(m)?;
+        let split_point = from.len() - m.count;
+
+        to.extend_from_slice(&from[split_point..]);
+        from.truncate(split_point);
+
+        Ok(())
+    }
+
+    /// Moving crates onto the stack they came from changes nothing, but must still be possible
+    fn check_in_place(&self, m: &StackMove) -> Result<(), StackError> {
+        let available = self
+            .0
+            .get(m.from_stack)
+            .ok_or(StackError::OutOfRange(m.from_stack))?
+            .len();
+
+        if m.count > available {
+            Err(StackError::InsufficientCrates {
+                stack: m.from_stack,
+                count: m.count,
+                available,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Iterates the crates residing at the top of all stacks in the same order as the stacks.
+    pub fn top_crates(&self) -> impl Iterator<Item = char> + '_ {
+        self.0.iter().filter_map(|s| s.last().copied())
+    }
+}
+
+/// Why a line before the first blank one is rejected when it does not draw crates or labels
+const NOT_DRAWN: &str = "is not a row of crates like '[A] [B]' or stack labels";
+
+/// Whether `line` draws crates or labels the stacks, four columns to a stack
+fn drawn_row(line: &str) -> bool {
+    line.as_bytes().chunks(4).all(|cell| {
+        // Trailing spaces may have been trimmed from the last stack
+        let mut padded = [b' '; 4];
+
+        padded[..cell.len()].copy_from_slice(cell);
+
+        matches!(
+            padded,
+            [b'[', b'A'..=b'Z', b']', b' '] | [b' ', b' ' | b'0'..=b'9', b' ', b' ']
+        )
+    })
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Supply Stacks",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// The starting arrangement of the stacks and the moves to apply to them
+    type Input = (Stacks, Vec<StackMove>);
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+        let total_lines = lines.len();
+
+        // Anything else in the drawing, such as a saved HTML page, would be read as crates
+        if let Some(i) = lines
+            .iter()
+            .take_while(|l| !l.is_empty())
+            .position(|l| !drawn_row(l))
+        {
+            anyhow::bail!("line {} {NOT_DRAWN}", i + 1);
+        }
+
+        let mut lines = lines.into_iter();
+
+        let stacks = Stacks::from_lines(&mut lines);
+        let first_move_line = total_lines - lines.len() + 1;
+
+        let moves = lines
+            .map(|s| {
+                s.parse::<StackMove>()
+                    .context("remaining lines should all be moves")
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        // Both cranes move the same number of crates between the same stacks, so trying the moves
+        // once catches every move that would fail in either part
+        let mut trial = stacks.clone();
+
+        for (i, m) in moves.iter().enumerate() {
+            trial
+                .apply_move_9000(m)
+                .with_context(|| format!("move on line {} is impossible", first_move_line + i))?;
+        }
+
+        Ok((stacks, moves))
+    }
+
+    const VALIDATES: bool = true;
+
+    /// Every line before the first blank one draws crates or labels the stacks, four columns to a
+    /// stack, and every line after it is a move
+    fn validate(input: &str) -> Result<(), Malformed> {
+        let mut in_moves = false;
+
+        lint::each_line(input, |line| {
+            if in_moves {
+                // Parsing a move only reads its numbers, so the words around them are checked here
+                let worded = matches!(
+                    line.split(' ').collect::<Vec<_>>()[..],
+                    ["move", _, "from", _, "to", _]
+                );
+
+                return match line.parse::<StackMove>() {
+                    Ok(_) if worded => Ok(()),
+                    _ => Err("is not a move like 'move 1 from 2 to 3'".to_owned()),
+                };
+            }
+
+            if line.is_empty() {
+                in_moves = true;
+
+                return Ok(());
+            }
+
+            if drawn_row(line) {
+                Ok(())
+            } else {
+                Err(NOT_DRAWN.to_owned())
+            }
+        })
+    }
+
+    /// Crates on the tops of the stacks when moved one at a time
+    fn part_01(input: &Self::Input) -> Answer {
+        let (stacks, moves) = input;
+        let mut stacks = stacks.clone();
+
+        for m in moves {
+            stacks
+                .apply_move_9000(m)
+                .expect("moves are checked when parsing the input");
+        }
+
+        stacks.top_crates().collect::<String>().into()
+    }
+
+    /// Crates on the tops of the stacks when moved as whole groups
+    fn part_02(input: &Self::Input) -> Answer {
+        let (stacks, moves) = input;
+        let mut stacks = stacks.clone();
+
+        for m in moves {
+            stacks
+                .apply_move_9001(m)
+                .expect("moves are checked when parsing the input");
+        }
+
+        stacks.top_crates().collect::<String>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    use super::*;
+    use advent_core::util::testgen::{crate_stacks, move_picks, pick_move};
+
+    #[test]
+    fn validate_input() {
+        let input = "    [D]\n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+
+        assert_eq!(Puzzle::validate(input), Ok(()));
+        assert_eq!(
+            Puzzle::validate(&input.replace("[M]", "(M)")).map_err(|m| m.line),
+            Err(3)
+        );
+        assert_eq!(
+            Puzzle::validate(&format!("{input}move 1 from 2\n")).map_err(|m| m.line),
+            Err(7)
+        );
+        assert_eq!(
+            Puzzle::validate(&format!("{input}move 1 frm 2 to 1\n")).map_err(|m| m.line),
+            Err(7)
+        );
+        // Without the blank line the moves are read as drawing
+        assert_eq!(
+            Puzzle::validate(&input.replace("\n\n", "\n")).map_err(|m| m.line),
+            Err(5)
+        );
+
+        // Solving rejects the same drawings rather than reading them as crates
+        let page = Puzzle::parse(b"<!DOCTYPE html>\n<html lang=\"en-us\">".as_slice());
+
+        assert_eq!(
+            page.map_err(|e| e.to_string()).err().as_deref(),
+            Some("line 1 is not a row of crates like '[A] [B]' or stack labels")
+        );
+        assert!(Puzzle::parse(input.replace("[M]", "(M)").as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parse_input() {
+        let input = r"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+        let mut lines = input.lines();
+
+        let stacks = Stacks::from_lines(&mut lines);
+
+        assert_eq!(
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']],
+            stacks.0
+        );
+
+        let moves = lines.flat_map(StackMove::from_str).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                StackMove {
+                    count: 1,
+                    from_stack: 1,
+                    to_stack: 0
+                },
+                StackMove {
+                    count: 3,
+                    from_stack: 0,
+                    to_stack: 2
+                },
+                StackMove {
+                    count: 2,
+                    from_stack: 1,
+                    to_stack: 0
+                },
+                StackMove {
+                    count: 1,
+                    from_stack: 0,
+                    to_stack: 1
+                },
+            ],
+            moves
+        );
+    }
+
+    #[test]
+    fn apply_moves_9000() {
+        let mut stacks = Stacks(vec![
+            vec!['1', 'Z', 'N'],
+            vec!['2', 'M', 'C', 'D'],
+            vec!['3', 'P'],
+        ]);
+
+        let moves = vec![
+            StackMove {
+                count: 1,
+                from_stack: 1,
+                to_stack: 0,
+            },
+            StackMove {
+                count: 3,
+                from_stack: 0,
+                to_stack: 2,
+            },
+            StackMove {
+                count: 2,
+                from_stack: 1,
+                to_stack: 0,
+            },
+            StackMove {
+                count: 1,
+                from_stack: 0,
+                to_stack: 1,
+            },
+        ];
+
+        let outcomes = vec![
+            vec![
+                vec!['1', 'Z', 'N', 'D'],
+                vec!['2', 'M', 'C'],
+                vec!['3', 'P'],
+            ],
+            vec![
+                vec!['1'],
+                vec!['2', 'M', 'C'],
+                vec!['3', 'P', 'D', 'N', 'Z'],
+            ],
+            vec![
+                vec!['1', 'C', 'M'],
+                vec!['2'],
+                vec!['3', 'P', 'D', 'N', 'Z'],
+            ],
+            vec![
+                vec!['1', 'C'],
+                vec!['2', 'M'],
+                vec!['3', 'P', 'D', 'N', 'Z'],
+            ],
+        ];
+
+        for (m, o) in moves.into_iter().zip(outcomes) {
+            stacks.apply_move_9000(&m).unwrap();
+
+            assert_eq!(o, stacks.0);
+        }
+
+        assert_eq!("CMZ", stacks.top_crates().collect::<String>());
+    }
+
+    #[test]
+    fn apply_moves_9001() {
+        let mut stacks = Stacks(vec![
+            vec!['1', 'Z', 'N'],
+            vec!['2', 'M', 'C', 'D'],
+            vec!['3', 'P'],
+        ]);
+
+        let moves = vec![
+            StackMove {
+                count: 1,
+                from_stack: 1,
+                to_stack: 0,
+            },
+            StackMove {
+                count: 3,
+                from_stack: 0,
+                to_stack: 2,
+            },
+            StackMove {
+                count: 2,
+                from_stack: 1,
+                to_stack: 0,
+            },
+            StackMove {
+                count: 1,
+                from_stack: 0,
+                to_stack: 1,
+            },
+        ];
+
+        let outcomes = vec![
+            vec![
+                vec!['1', 'Z', 'N', 'D'],
+                vec!['2', 'M', 'C'],
+                vec!['3', 'P'],
+            ],
+            vec![
+                vec!['1'],
+                vec!['2', 'M', 'C'],
+                vec!['3', 'P', 'Z', 'N', 'D'],
+            ],
+            vec![
+                vec!['1', 'M', 'C'],
+                vec!['2'],
+                vec!['3', 'P', 'Z', 'N', 'D'],
+            ],
+            vec![
+                vec!['1', 'M'],
+                vec!['2', 'C'],
+                vec!['3', 'P', 'Z', 'N', 'D'],
+            ],
+        ];
+
+        for (m, o) in moves.into_iter().zip(outcomes) {
+            stacks.apply_move_9001(&m).unwrap();
+
+            assert_eq!(o, stacks.0);
+        }
+
+        assert_eq!("MCD", stacks.top_crates().collect::<String>());
+    }
+
+    #[test]
+    fn move_between_any_stacks() {
+        let mut stacks = Stacks(vec![vec!['A', 'B'], vec![], vec!['C']]);
+
+        // Moving to a stack before the source splits the borrow the other way around
+        stacks
+            .apply_move_9001(&StackMove {
+                count: 2,
+                from_stack: 0,
+                to_stack: 2,
+            })
+            .unwrap();
+        stacks
+            .apply_move_9001(&StackMove {
+                count: 3,
+                from_stack: 2,
+                to_stack: 1,
+            })
+            .unwrap();
+
+        assert_eq!(stacks.0, vec![vec![], vec!['C', 'A', 'B'], vec![]]);
+    }
+
+    #[test]
+    fn reject_impossible_moves() {
+        let mut stacks = Stacks(vec![vec!['A', 'B'], vec!['C']]);
+        let original = stacks.clone();
+
+        let moves = [
+            (
+                StackMove {
+                    count: 1,
+                    from_stack: 2,
+                    to_stack: 0,
+                },
+                StackError::OutOfRange(2),
+            ),
+            (
+                StackMove {
+                    count: 1,
+                    from_stack: 0,
+                    to_stack: 5,
+                },
+                StackError::OutOfRange(5),
+            ),
+            (
+                StackMove {
+                    count: 2,
+                    from_stack: 1,
+                    to_stack: 0,
+                },
+                StackError::InsufficientCrates {
+                    stack: 1,
+                    count: 2,
+                    available: 1,
+                },
+            ),
+        ];
+
+        for (m, err) in moves {
+            assert_eq!(stacks.apply_move_9000(&m), Err(err.clone()));
+            assert_eq!(stacks.apply_move_9001(&m), Err(err));
+            assert_eq!(stacks, original);
+        }
+
+        let input = "[A]    \n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1";
+        let err = Puzzle::parse(input.as_bytes()).unwrap_err();
+
+        assert_eq!(
+            format!("{err:#}"),
+            "move on line 5 is impossible: cannot move 2 crates from stack 2 which only holds 1"
+        );
+    }
+
+    /// Moves a group by taking one crate at a time and restoring their order, which is obviously
+    /// correct but slow
+    fn reference_9001(stacks: &mut [Vec<char>], m: &StackMove) {
+        let mut group = (0..m.count)
+            .map(|_| stacks[m.from_stack].pop().unwrap())
+            .collect::<Vec<_>>();
+
+        group.reverse();
+        stacks[m.to_stack].extend(group);
+    }
+
+    proptest! {
+        // Miri is far too slow for hundreds of cases and isolates the file system proptest uses to
+        // persist failures, while the tests above cover the same code
+        #[cfg_attr(miri, ignore)]
+        #[test]
+        fn random_moves(
+            stacks in crate_stacks(2..6, 0..8),
+            picks in move_picks(0..64),
+        ) {
+            let mut fast = Stacks(stacks.clone());
+            let mut one_by_one = Stacks(stacks.clone());
+            let mut reference = stacks;
+            let total = reference.iter().map(Vec::len).sum::<usize>();
+
+            for pick in picks {
+                // Skip the rest of the picks once every stack is empty
+                let Some((from_stack, to_stack, count)) = pick_move(&reference, pick) else {
+                    break;
+                };
+
+                let m = StackMove { count, from_stack, to_stack };
+
+                fast.apply_move_9001(&m).unwrap();
+                one_by_one.apply_move_9000(&m).unwrap();
+                reference_9001(&mut reference, &m);
+
+                prop_assert_eq!(&fast.0, &reference);
+                prop_assert_eq!(one_by_one.0.iter().map(Vec::len).sum::<usize>(), total);
+            }
+        }
+
+        /// Whichever crane does the moving, every crate ends up somewhere and no crate appears
+        /// from nowhere, while impossible moves leave the stacks untouched
+        #[cfg_attr(miri, ignore)]
+        #[test]
+        fn crates_are_conserved(
+            stacks in crate_stacks(1..6, 0..8),
+            picks in move_picks(0..32),
+            count in 0usize..10,
+            to_stack in 0usize..8,
+        ) {
+            let sorted = |stacks: &Stacks| stacks.0.concat().into_iter().sorted().collect::<Vec<_>>();
+            let crates = sorted(&Stacks(stacks.clone()));
+
+            for apply in [Stacks::apply_move_9000, Stacks::apply_move_9001] {
+                let mut stacks = Stacks(stacks.clone());
+
+                for pick in &picks {
+                    let Some((from_stack, to_stack, count)) = pick_move(&stacks.0, *pick) else {
+                        break;
+                    };
+
+                    apply(&mut stacks, &StackMove { count, from_stack, to_stack }).unwrap();
+                }
+
+                prop_assert_eq!(sorted(&stacks), crates.clone());
+
+                // Any move at all from the first stack, which is only possible when the target is
+                // in range and the stack holds enough crates
+                let before = stacks.clone();
+                let m = StackMove { count, from_stack: 0, to_stack };
+                let possible = to_stack < stacks.0.len() && count <= stacks.0[0].len();
+
+                prop_assert_eq!(apply(&mut stacks, &m).is_ok(), possible);
+
+                if !possible {
+                    prop_assert_eq!(&stacks, &before);
+                }
+
+                prop_assert_eq!(sorted(&stacks), crates.clone());
+            }
+        }
+    }
+}
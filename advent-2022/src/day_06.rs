@@ -0,0 +1,101 @@
+use std::io::BufRead;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::windows::{first_distinct_byte_run, first_distinct_window},
+};
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Tuning Trouble",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// The datastream buffer without any surrounding whitespace
+    type Input = Vec<u8>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut data = reader.bytes().collect::<Result<Vec<_>, _>>()?;
+
+        data.truncate(data.trim_ascii_end().len());
+
+        Ok(data)
+    }
+
+    /// Characters processed before the first start-of-packet marker
+    fn part_01(input: &Self::Input) -> Answer {
+        first_distinct_byte_run(input, 4)
+            .expect("data should contain start-of-packet marker")
+            .into()
+    }
+
+    /// Characters processed before the first start-of-message marker
+    fn part_02(input: &Self::Input) -> Answer {
+        first_distinct_byte_run(input, 14)
+            .expect("data should contain start-of-message marker")
+            .into()
+    }
+}
+
+/// Finds the markers by checking every window of the datastream on its own, which is easier to
+/// follow than [`Puzzle`] but much slower for the longer start-of-message marker
+pub struct Readable;
+
+impl Day for Readable {
+    type Input = Vec<u8>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Puzzle::parse(reader)
+    }
+
+    fn part_01(input: &Self::Input) -> Answer {
+        first_distinct_window(input, 4)
+            .expect("data should contain start-of-packet marker")
+            .into()
+    }
+
+    fn part_02(input: &Self::Input) -> Answer {
+        first_distinct_window(input, 14)
+            .expect("data should contain start-of-message marker")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn start_of_packet() {
+        let cases = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", Some(7)),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", Some(5)),
+            ("nppdvjthqldpwncqszvftbrmjlhg", Some(6)),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", Some(10)),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", Some(11)),
+        ];
+
+        for (s, e) in cases {
+            assert_eq!(first_distinct_byte_run(s.as_bytes(), 4), e);
+        }
+    }
+
+    #[test]
+    fn start_of_message() {
+        let cases = vec![
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", Some(19)),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", Some(23)),
+            ("nppdvjthqldpwncqszvftbrmjlhg", Some(23)),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", Some(29)),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", Some(26)),
+        ];
+
+        for (s, e) in cases {
+            assert_eq!(first_distinct_byte_run(s.as_bytes(), 14), e);
+        }
+    }
+}
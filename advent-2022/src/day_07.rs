@@ -1,31 +1,36 @@
-use std::{
-    collections::{BTreeMap, HashMap},
-    fmt,
-    io::BufRead,
-    str::FromStr,
+use std::{collections::BTreeMap, fmt, io::BufRead, str::FromStr, sync::OnceLock};
+
+use anyhow::anyhow;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    examples::examples,
+    explore::{self, Explorable, ExploreCommand, ExploreError},
 };
 
 /// Explicit typing for the lines of a terminal session. Lines are either a `Command` or an
 /// `Output` from one.
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum TerminalLine {
+pub enum TerminalLine {
     Command(String),
     Output(String),
 }
 
 impl TerminalLine {
     /// Returns `true` if the `TerminalLine` is a `Command` value
-    fn _is_command(&self) -> bool {
+    pub fn is_command(&self) -> bool {
         matches!(self, Self::Command(_))
     }
 
     /// Returns `true` if the `TerminalLine` is an `Output` value
-    fn is_output(&self) -> bool {
+    pub fn is_output(&self) -> bool {
         matches!(self, Self::Output(_))
     }
 
     /// Quick access to the trimmed source line this type was created with
-    fn raw_line(&self) -> &str {
+    pub fn raw_line(&self) -> &str {
         match self {
             Self::Command(s) | Self::Output(s) => s,
         }
@@ -49,47 +54,49 @@ impl FromStr for TerminalLine {
 /// Type representation for the elements of a path as expressed in a terminal borrowing text where
 /// necessary.
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum PathSegment<'a> {
+pub enum PathSegment<'a> {
     Root,
     Up,
     Down(&'a str),
 }
 
+/// A path as given to `cd`, either absolute (starting with `/`) or relative to the current directory
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct Path(String);
+pub struct Path(String);
 
 impl Path {
     /// Turns the raw text representation of a path into individual segments for easier tree
     /// traversal. A `Path` starting with `/` will first output a `PathSegment::Root` to indicate
     /// navigation to the root of the file system. The remaining parts are parsed as either moving
     /// up a directory or down into another directory.
-    fn segments(&self) -> impl Iterator<Item = Result<PathSegment, &'static str>> {
-        let source = if self.0.ends_with('/') {
-            &self.0[..(self.0.len() - 1)]
-        } else {
-            &self.0
-        };
-
-        source.split('/').enumerate().map(|es| match es {
-            (0, "") => Ok(PathSegment::Root),
-            (_, "") => Err("path should consist of valid segments"),
-            (_, "..") => Ok(PathSegment::Up),
-            (_, s) => Ok(PathSegment::Down(s)),
-        })
+    pub fn segments(&self) -> impl Iterator<Item = Result<PathSegment<'_>, &'static str>> {
+        path_segments(&self.0)
     }
 }
 
+/// Splits the text of a path into segments as described by [`Path::segments`]
+fn path_segments(path: &str) -> impl Iterator<Item = Result<PathSegment<'_>, &'static str>> {
+    let source = path.strip_suffix('/').unwrap_or(path);
+
+    source.split('/').enumerate().map(|es| match es {
+        (0, "") => Ok(PathSegment::Root),
+        (_, "") => Err("path should consist of valid segments"),
+        (_, "..") => Ok(PathSegment::Up),
+        (_, s) => Ok(PathSegment::Down(s)),
+    })
+}
+
 /// Information gathered when calling `ls` in a directory. Items listed may be directory or a
 /// filename with its size in bytes.
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum StatEntry {
+pub enum StatEntry {
     Directory(String),
     File(String, usize),
 }
 
 impl StatEntry {
     /// Get the file system name of either the `Directory` or `File` this `StatEntry` represents.
-    fn name(&self) -> &str {
+    pub fn name(&self) -> &str {
         match self {
             StatEntry::Directory(name) | StatEntry::File(name, _) => name,
         }
@@ -118,19 +125,19 @@ impl FromStr for StatEntry {
 /// - `Jump`ing to another path
 /// - `List` of items in the current directory
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum Command {
+pub enum Command {
     Jump(Path),
     List(Vec<StatEntry>),
 }
 
 impl Command {
     /// Returns `true` if the `Command` is a `Jump` value
-    fn _is_jump(&self) -> bool {
+    pub fn is_jump(&self) -> bool {
         matches!(self, Self::Jump(_))
     }
 
     /// Returns `true` if the `Command` is a `List` value
-    fn _is_list(&self) -> bool {
+    pub fn is_list(&self) -> bool {
         matches!(self, Self::List(_))
     }
 }
@@ -208,7 +215,7 @@ impl<T> TakeWhileLosselssExt for T where T: Iterator {}
 /// Seamlessly associate commands with their output and bundle them into a `Command`. The builder
 /// will consume the iterator until it can create a `Command` and return it.
 #[derive(Clone, Debug)]
-struct CommandBuilder<I>
+pub struct CommandBuilder<I>
 where
     I: Iterator<Item = TerminalLine>,
 {
@@ -254,7 +261,7 @@ where
     }
 }
 
-trait Commands: Iterator<Item = TerminalLine> {
+pub trait Commands: Iterator<Item = TerminalLine> {
     /// Enables recovering structured `Command`s with their outputs from a sequence of raw
     /// `TerminalLine`s.
     fn commands(self) -> CommandBuilder<Self>
@@ -271,42 +278,43 @@ trait Commands: Iterator<Item = TerminalLine> {
 impl<T> Commands for T where T: Iterator<Item = TerminalLine> {}
 
 /// Details about a directory in a `FileSystem` including its `name` and a name to index mapping
-/// for the `children` of this directory.
+/// for the `children` of this directory, kept in name order.
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct DirectoryEntry {
-    name: String,
-    children: HashMap<String, usize>,
+pub struct DirectoryEntry {
+    pub name: String,
+    pub children: BTreeMap<String, usize>,
 }
 
 /// Details about a file in a `FileSystem` including its full `name` and the size of the file in
 /// bytes via `size_bytes`.
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct FileEntry {
-    name: String,
-    size_bytes: usize,
+pub struct FileEntry {
+    pub name: String,
+    pub size_bytes: usize,
 }
 
 /// This problem's `FileSystem` consists of only two kinds of items:
 /// - `Directory`: which has a name and can indirectly contain other `FileSystemEntry` items
 /// - `Flie`: which has a name and a size in bytes
 #[derive(Clone, Debug, PartialEq, Eq)]
-enum FileSystemEntry {
+pub enum FileSystemEntry {
     Directory(DirectoryEntry),
     File(FileEntry),
 }
 
 impl FileSystemEntry {
     /// Returns `true` if the `FileSystemEntry` is a `Directory` value
-    fn is_directory(&self) -> bool {
+    pub fn is_directory(&self) -> bool {
         matches!(self, FileSystemEntry::Directory(_))
     }
 
     /// Returns `true` if the `FileSystemEntry` is a `File` value
-    fn _is_file(&self) -> bool {
+    pub fn is_file(&self) -> bool {
         matches!(self, FileSystemEntry::File(_))
     }
 
-    fn name(&self) -> &str {
+    /// Get the name of the `Directory` or `File` this entry represents.
+    pub fn name(&self) -> &str {
         match self {
             FileSystemEntry::Directory(d) => &d.name,
             FileSystemEntry::File(f) => &f.name,
@@ -319,7 +327,7 @@ impl From<&StatEntry> for FileSystemEntry {
         match value {
             StatEntry::Directory(name) => Self::Directory(DirectoryEntry {
                 name: name.clone(),
-                children: HashMap::default(),
+                children: BTreeMap::default(),
             }),
             StatEntry::File(name, size_bytes) => Self::File(FileEntry {
                 name: name.clone(),
@@ -331,42 +339,140 @@ impl From<&StatEntry> for FileSystemEntry {
 
 /// Trait for a type to visit all the entries in a `FileSystem` treating files and directories
 /// distinctly.
-trait FileSystemVisitor<'a> {
+pub trait FileSystemVisitor<'a> {
     fn visit_directory(&mut self, idx: usize, entry: &'a DirectoryEntry);
 
     fn visit_file(&mut self, idx: usize, entry: &'a FileEntry);
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-struct FileSystem(Vec<FileSystemEntry>);
+/// A directory tree rebuilt from a terminal session where entries are stored in a flat list and
+/// directories refer to their children by index. The root directory is always the first entry.
+#[derive(Clone, Debug)]
+pub struct FileSystem {
+    entries: Vec<FileSystemEntry>,
+    /// Total size of every entry by index, calculated the first time a size is needed
+    sizes: OnceLock<Vec<usize>>,
+}
+
+impl PartialEq for FileSystem {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl Eq for FileSystem {}
 
 impl FileSystem {
+    fn new(entries: Vec<FileSystemEntry>) -> Self {
+        Self {
+            entries,
+            sizes: OnceLock::new(),
+        }
+    }
+
     /// Get the total number of `FileSystemEntry` objects in this `FileSystem`.
-    fn len(&self) -> usize {
-        self.0.len()
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the `FileSystem` has no entries. A `FileSystem` built from commands
+    /// always contains at least the root directory.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Index of the entry at `path`, which is resolved from the root the same way `cd` would
+    /// resolve it from there
+    fn index_of(&self, path: &str) -> Option<usize> {
+        let mut parents = Vec::new();
+        let mut current = 0;
+
+        for seg in path_segments(path) {
+            match seg.ok()? {
+                PathSegment::Root => {
+                    parents.clear();
+                    current = 0;
+                }
+                PathSegment::Up => current = parents.pop().unwrap_or(0),
+                PathSegment::Down(name) => match &self.entries[current] {
+                    FileSystemEntry::Directory(d) => {
+                        parents.push(current);
+                        current = *d.children.get(name)?;
+                    }
+                    FileSystemEntry::File(_) => return None,
+                },
+            }
+        }
+
+        Some(current)
+    }
+
+    /// Looks up the file or directory at `path`, such as `/a/e` or `a/e/../f`
+    pub fn entry_at(&self, path: &str) -> Option<&FileSystemEntry> {
+        self.index_of(path).map(|idx| &self.entries[idx])
+    }
+
+    /// Size in bytes of the file at `path`, or of everything within the directory at `path`
+    pub fn size_of(&self, path: &str) -> Option<usize> {
+        self.index_of(path).map(|idx| self.sizes()[idx])
+    }
+
+    fn sizes(&self) -> &[usize] {
+        self.sizes.get_or_init(|| {
+            DirectorySizer::for_file_system(self)
+                .0
+                .iter()
+                .map(|e| e.size_bytes)
+                .collect()
+        })
+    }
+
+    /// Iterates every entry as its full path and size in bytes, visiting each directory before its
+    /// contents and the contents of a directory in name order. The root directory's path is `/`
+    /// and paths of directories do not end in `/`.
+    pub fn iter_sizes(&self) -> impl Iterator<Item = (String, usize)> + '_ {
+        let sizes = self.sizes();
+        let mut stack = vec![(String::from("/"), 0)];
+
+        std::iter::from_fn(move || {
+            let (path, idx) = stack.pop()?;
+
+            if let FileSystemEntry::Directory(d) = &self.entries[idx] {
+                let prefix = path.strip_suffix('/').unwrap_or(&path);
+
+                stack.extend(
+                    d.children
+                        .iter()
+                        .rev()
+                        .map(|(name, &child)| (format!("{prefix}/{name}"), child)),
+                );
+            }
+
+            Some((path, sizes[idx]))
+        })
     }
 
     /// Visit every `FileSystemEntry` in this `FileSystem` in a depth-first order exactly once.
     /// Files are guaranteed to be visited before the directories that contain them. Calls the
-    /// appropriate `FileSystemVisitor` method for the `FileSystemEntry` being observed. The visit
-    /// order of siblings within a directory is not guaranteed.
-    fn visit_depth_first<'a, V: FileSystemVisitor<'a>>(&'a self, visitor: &mut V)
+    /// appropriate `FileSystemVisitor` method for the `FileSystemEntry` being observed. Siblings
+    /// within a directory are visited in name order.
+    pub fn visit_depth_first<'a, V: FileSystemVisitor<'a>>(&'a self, visitor: &mut V)
     where
         Self: 'a,
     {
-        let mut stack = Vec::with_capacity(self.0.len());
+        let mut stack = Vec::with_capacity(self.entries.len());
 
         stack.push((0usize, false));
 
         while let Some((idx, visited_children)) = stack.pop() {
-            match &self.0[idx] {
+            match &self.entries[idx] {
                 FileSystemEntry::Directory(details) => {
                     if visited_children {
                         visitor.visit_directory(idx, details);
                     } else {
                         stack.push((idx, true));
 
-                        stack.extend(details.children.values().map(|ci| (*ci, false)));
+                        stack.extend(details.children.values().rev().map(|ci| (*ci, false)));
                     }
                 }
                 FileSystemEntry::File(details) => visitor.visit_file(idx, details),
@@ -381,7 +487,7 @@ impl FromIterator<Command> for FileSystem {
         let mut current = 0usize;
         let mut fs = vec![FileSystemEntry::Directory(DirectoryEntry {
             name: "/".into(),
-            children: HashMap::default(),
+            children: BTreeMap::default(),
         })];
 
         for cmd in iter {
@@ -397,45 +503,62 @@ impl FromIterator<Command> for FileSystem {
                                 current = nodes.pop().unwrap_or(0);
                             }
                             PathSegment::Down(d) => {
-                                if let FileSystemEntry::Directory(entry) = &fs[current] {
+                                let placeholder = fs.len();
+
+                                if let FileSystemEntry::Directory(entry) = &mut fs[current] {
                                     nodes.push(current);
-                                    current = entry.children[d];
+                                    current =
+                                        *entry.children.entry(d.to_owned()).or_insert(placeholder);
                                 } else {
                                     panic!("Trying to change directories when not in a directory");
                                 }
+
+                                // Directories entered before being listed start out empty
+                                if current == placeholder {
+                                    fs.push(FileSystemEntry::Directory(DirectoryEntry {
+                                        name: d.to_owned(),
+                                        children: BTreeMap::default(),
+                                    }));
+                                }
                             }
                         }
                     }
                 }
                 Command::List(entries) => {
+                    let FileSystemEntry::Directory(d) = &fs[current] else {
+                        panic!("Trying to add files when not in a directory");
+                    };
+
+                    // Entries already known from an earlier `ls` or `cd` keep what was found in them
+                    let new_entries = entries
+                        .iter()
+                        .filter(|e| !d.children.contains_key(e.name()))
+                        .collect::<Vec<_>>();
+
                     let base_idx = fs.len();
 
-                    if fs[current].is_directory() {
-                        fs.extend(entries.iter().map(FileSystemEntry::from));
+                    fs.extend(new_entries.iter().map(|&e| FileSystemEntry::from(e)));
 
-                        if let FileSystemEntry::Directory(d) = &mut fs[current] {
-                            d.children.extend(
-                                entries
-                                    .iter()
-                                    .enumerate()
-                                    .map(|(i, e)| (e.name().to_owned(), base_idx + i)),
-                            );
-                        }
-                    } else {
-                        panic!("Trying to add files when not in a directory");
+                    if let FileSystemEntry::Directory(d) = &mut fs[current] {
+                        d.children.extend(
+                            new_entries
+                                .iter()
+                                .enumerate()
+                                .map(|(i, e)| (e.name().to_owned(), base_idx + i)),
+                        );
                     }
                 }
             }
         }
 
-        Self(fs)
+        Self::new(fs)
     }
 }
 
 impl fmt::Display for FileSystem {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut stack = Vec::with_capacity(self.0.len());
-        stack.push((0, &self.0[0]));
+        let mut stack = Vec::with_capacity(self.entries.len());
+        stack.push((0, &self.entries[0]));
 
         while let Some((depth, entry)) = stack.pop() {
             write!(f, "{1:>0$} {2}", depth * 2 + 1, '-', entry.name())?;
@@ -444,14 +567,11 @@ impl fmt::Display for FileSystem {
                 FileSystemEntry::Directory(de) => {
                     writeln!(f, " (dir)")?;
 
-                    // Briefly cast to BTreeMap to get consistent output
                     stack.extend(
                         de.children
-                            .iter()
-                            .collect::<BTreeMap<_, _>>()
-                            .iter()
+                            .values()
                             .rev()
-                            .map(|(_, &&idx)| (depth + 1, &self.0[idx])),
+                            .map(|&idx| (depth + 1, &self.entries[idx])),
                     );
                 }
                 FileSystemEntry::File(fe) => {
@@ -466,21 +586,21 @@ impl fmt::Display for FileSystem {
 
 /// Simplified entry for a `FileSystem` to unify relevant information while calculating space used.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-struct SizeCacheEntry<'a> {
-    is_directory: bool,
-    size_bytes: usize,
-    name: &'a str,
+pub struct SizeCacheEntry<'a> {
+    pub is_directory: bool,
+    pub size_bytes: usize,
+    pub name: &'a str,
 }
 
 /// Calculates the sizes of all directories and their descendents in a `FileSystem` with a unified
 /// cache for all entries for quick lookup.
 #[derive(Clone, Debug, PartialEq, Eq)]
-struct DirectorySizer<'a>(Vec<SizeCacheEntry<'a>>);
+pub struct DirectorySizer<'a>(Vec<SizeCacheEntry<'a>>);
 
 impl<'a> DirectorySizer<'a> {
     /// Build a `DirectorySizer` tied to the lifetime of the passed `FileSystem`, visit all entries
     /// via the `FileSystemVisitor` trait and build up the internal size cache.
-    fn for_file_system(fs: &'a FileSystem) -> Self {
+    pub fn for_file_system(fs: &'a FileSystem) -> Self {
         let mut ds = Self(vec![SizeCacheEntry::default(); fs.len()]);
 
         fs.visit_depth_first(&mut ds);
@@ -490,7 +610,7 @@ impl<'a> DirectorySizer<'a> {
 
     /// Sums the sizes of all directories in the associated `FileSystem` underneath `max` size.
     /// This will double-count directories contained by other directories that fit under `max`.
-    fn sum_under(&self, max: usize) -> usize {
+    pub fn sum_under(&self, max: usize) -> usize {
         self.0
             .iter()
             .filter_map(|e| {
@@ -505,7 +625,7 @@ impl<'a> DirectorySizer<'a> {
 
     /// Finds the smallest directory to remove that would get the `FileSystem` underneath
     /// `max_used` if such a directory exists.
-    fn smallest_to_get_under(&self, max_used: usize) -> Option<(usize, &'a str)> {
+    pub fn smallest_to_get_under(&self, max_used: usize) -> Option<(usize, &'a str)> {
         let current_used = self.0[0].size_bytes;
 
         if current_used < max_used {
@@ -551,46 +671,130 @@ impl<'a> FileSystemVisitor<'a> for DirectorySizer<'a> {
     }
 }
 
-pub fn part_01(reader: Option<impl BufRead>) {
-    let reader = reader.expect("data should be available for this problem");
+const EXPLORE_COMMANDS: &[ExploreCommand] = &[
+    ExploreCommand {
+        name: "ls",
+        args: "[PATH]",
+        about: "Entries of a directory with their sizes, the root by default",
+    },
+    ExploreCommand {
+        name: "size",
+        args: "PATH",
+        about: "Size of a file or of everything in a directory",
+    },
+    ExploreCommand {
+        name: "tree",
+        args: "",
+        about: "The whole file system",
+    },
+];
+
+impl Explorable for FileSystem {
+    fn commands(&self) -> &'static [ExploreCommand] {
+        EXPLORE_COMMANDS
+    }
 
-    let fs = reader
-        .lines()
-        .flatten()
-        .map(|l| {
-            l.parse::<TerminalLine>()
-                .expect("all input lines should be terminal lines")
-        })
-        .commands()
-        .collect::<FileSystem>();
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, ExploreError> {
+        let missing = |path: &str| ExploreError::Failed(format!("nothing exists at {path}"));
 
-    let dir_sizer = DirectorySizer::for_file_system(&fs);
+        match (command, args) {
+            ("ls", [] | [_]) => {
+                let path = args.first().copied().unwrap_or("/");
 
-    println!(
-        "Sum of all directories less than 100,000 in size: {}",
-        dir_sizer.sum_under(100_000)
-    );
+                match self.index_of(path).map(|idx| &self.entries[idx]) {
+                    Some(FileSystemEntry::Directory(d)) => Ok(d
+                        .children
+                        .iter()
+                        .map(|(name, &idx)| match &self.entries[idx] {
+                            FileSystemEntry::Directory(_) => {
+                                format!("{:>10} {name}/", self.sizes()[idx])
+                            }
+                            FileSystemEntry::File(f) => format!("{:>10} {name}", f.size_bytes),
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")),
+                    Some(FileSystemEntry::File(f)) => {
+                        Ok(format!("{:>10} {}", f.size_bytes, f.name))
+                    }
+                    None => Err(missing(path)),
+                }
+            }
+            ("size", [path]) => self
+                .size_of(path)
+                .map(|size| size.to_string())
+                .ok_or_else(|| missing(path)),
+            ("tree", []) => Ok(self.to_string().trim_end().to_owned()),
+            _ => Err(explore::unrecognized(EXPLORE_COMMANDS, command)),
+        }
+    }
 }
 
-pub fn part_02(reader: Option<impl BufRead>) {
-    let reader = reader.expect("data should be available for this problem");
+pub const INFO: DayInfo = DayInfo {
+    title: "No Space Left On Device",
+    links: &[],
+};
 
-    let fs = reader
-        .lines()
-        .flatten()
-        .map(|l| {
-            l.parse::<TerminalLine>()
-                .expect("all input lines should be terminal lines")
-        })
-        .commands()
-        .collect::<FileSystem>();
+pub struct Puzzle;
 
-    let dir_sizer = DirectorySizer::for_file_system(&fs);
+impl Day for Puzzle {
+    type Input = FileSystem;
 
-    println!(
-        "Smallest directory to delete to get to 30,000,000 bytes of free space: {:?}",
-        dir_sizer.smallest_to_get_under(40_000_000)
-    );
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader
+            .lines()
+            .map(|l| {
+                l?.parse::<TerminalLine>()
+                    .map_err(|e| anyhow!("all input lines should be terminal lines: {e}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(lines.into_iter().commands().collect())
+    }
+
+    /// Sum of all directories less than 100,000 in size
+    fn part_01(input: &Self::Input) -> Answer {
+        DirectorySizer::for_file_system(input)
+            .sum_under(100_000)
+            .into()
+    }
+
+    /// Size of the smallest directory to delete to get to 30,000,000 bytes of free space
+    fn part_02(input: &Self::Input) -> Answer {
+        DirectorySizer::for_file_system(input)
+            .smallest_to_get_under(40_000_000)
+            .map(|(size, _)| size)
+            .expect("some directory should free enough space")
+            .into()
+    }
+}
+
+const EXAMPLE: &str = r"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+examples! {
+    part1: EXAMPLE => "95437",
+    part2: EXAMPLE => "24933642",
 }
 
 #[cfg(test)]
@@ -843,4 +1047,134 @@ $ ls
             Some((24_933_642usize, "d"))
         );
     }
+
+    #[test]
+    fn query_paths() {
+        let session =
+            "$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\ndir e\n50 c.txt\n$ cd e\n$ ls\n7 i";
+
+        let fs = session
+            .lines()
+            .map(|l| l.parse::<TerminalLine>().unwrap())
+            .commands()
+            .collect::<FileSystem>();
+
+        assert_eq!(fs.size_of("/"), Some(157));
+        assert_eq!(fs.size_of("/a"), Some(57));
+        assert_eq!(fs.size_of("a/e/"), Some(7));
+        assert_eq!(fs.size_of("/a/e/../c.txt"), Some(50));
+        assert_eq!(fs.size_of("/a/../../b.txt"), Some(100));
+        assert_eq!(fs.entry_at("/a/e/i").map(FileSystemEntry::name), Some("i"));
+        assert!(fs.entry_at("/a").is_some_and(FileSystemEntry::is_directory));
+
+        assert_eq!(fs.entry_at("/x"), None);
+        assert_eq!(fs.entry_at("/b.txt/c"), None);
+        assert_eq!(fs.entry_at("/a//e"), None);
+
+        assert_eq!(
+            fs.iter_sizes().collect::<Vec<_>>(),
+            [
+                ("/".to_owned(), 157),
+                ("/a".to_owned(), 57),
+                ("/a/c.txt".to_owned(), 50),
+                ("/a/e".to_owned(), 7),
+                ("/a/e/i".to_owned(), 7),
+                ("/b.txt".to_owned(), 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn jump_before_listing() {
+        let build = |session: &str| {
+            session
+                .lines()
+                .map(|l| l.parse::<TerminalLine>().unwrap())
+                .commands()
+                .collect::<FileSystem>()
+        };
+
+        let fs = build("$ cd /a/b\n$ ls\n10 x\n$ cd /\n$ ls\ndir a\n5 y\n$ cd a\n$ ls\ndir b\n1 z");
+
+        assert_eq!(fs.len(), 6);
+        assert_eq!(fs.size_of("/"), Some(16));
+        assert_eq!(fs.size_of("/a"), Some(11));
+        assert_eq!(fs.size_of("/a/b/x"), Some(10));
+
+        // Never listed at all, so the directories exist but hold nothing
+        let fs = build("$ cd q\n$ cd r\n$ cd ../s");
+
+        assert_eq!(fs.len(), 4);
+        assert_eq!(fs.size_of("/q/s"), Some(0));
+        assert!(fs
+            .entry_at("/q/r")
+            .is_some_and(FileSystemEntry::is_directory));
+
+        // Listing the same directory twice does not duplicate its entries
+        let fs = build("$ ls\n3 f\n$ ls\n3 f");
+
+        assert_eq!(fs.len(), 2);
+        assert_eq!(fs.size_of("/"), Some(3));
+    }
+
+    #[test]
+    fn listing_order_does_not_matter() {
+        struct Names(Vec<String>);
+
+        impl FileSystemVisitor<'_> for Names {
+            fn visit_directory(&mut self, _: usize, entry: &DirectoryEntry) {
+                self.0.push(entry.name.clone());
+            }
+
+            fn visit_file(&mut self, _: usize, entry: &FileEntry) {
+                self.0.push(entry.name.clone());
+            }
+        }
+
+        let build = |session: &str| {
+            session
+                .lines()
+                .map(|l| l.parse::<TerminalLine>().unwrap())
+                .commands()
+                .collect::<FileSystem>()
+        };
+
+        let listed = build("$ ls\n1 c\ndir b\n2 a\n$ cd b\n$ ls\n3 z\n4 y");
+        let reversed = build("$ ls\n2 a\ndir b\n1 c\n$ cd b\n$ ls\n4 y\n3 z");
+
+        assert_eq!(listed.to_string(), reversed.to_string());
+        assert_eq!(
+            listed.iter_sizes().collect::<Vec<_>>(),
+            reversed.iter_sizes().collect::<Vec<_>>()
+        );
+
+        for fs in [listed, reversed] {
+            let mut names = Names(Vec::new());
+
+            fs.visit_depth_first(&mut names);
+
+            assert_eq!(names.0, ["a", "y", "z", "b", "c", "/"]);
+        }
+    }
+
+    #[test]
+    fn explore_directories() {
+        let mut fs = Puzzle::parse(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(
+            fs.run("ls", &["/a"]).unwrap(),
+            "       584 e/\n     29116 f\n      2557 g\n     62596 h.lst"
+        );
+        assert_eq!(fs.run("size", &["/a/e"]).unwrap(), "584");
+        assert_eq!(fs.run("ls", &[]).unwrap().lines().count(), 4);
+        assert!(fs.run("tree", &[]).unwrap().starts_with("- / (dir)\n"));
+        assert_eq!(
+            fs.run("size", &["/x"]),
+            Err(ExploreError::Failed("nothing exists at /x".to_owned()))
+        );
+        assert_eq!(
+            fs.run("size", &[]),
+            Err(ExploreError::Usage("size PATH".to_owned()))
+        );
+    }
 }
@@ -0,0 +1,481 @@
+use std::{io::BufRead, ops::ControlFlow};
+
+use advent_core::{
+    answer::Answer,
+    context::Context,
+    day::{parse_input, Day},
+    describe::DayInfo,
+    explore::{self, Explorable, ExploreCommand, ExploreError},
+    util::grid::{ColumnStrategy, DirectionalScan, TreeMap},
+};
+
+/// Represents which cells are visible from outside the grid in row-major order
+pub struct VisibilityMap(Vec<bool>);
+
+impl VisibilityMap {
+    pub fn num_visible(&self) -> usize {
+        self.0.iter().copied().map(usize::from).sum()
+    }
+}
+
+/// Collection of the scenic scores of every cell in row-major order
+pub struct ScenicMap(Vec<u32>);
+
+impl ScenicMap {
+    pub fn highest_score(&self) -> u32 {
+        *self.0.iter().max().unwrap()
+    }
+}
+
+/// Helper to perform a marching depth test.
+/// Current height is only visible if it is the tallest seen so far.
+fn depth_op(height: u8, visible: &mut bool, tallest: &mut u8) {
+    if height > *tallest {
+        *visible = true;
+        *tallest = height;
+    }
+}
+
+/// Computes the scenic score based on the marched visible distance along an axis and uses the
+/// current cell height to update the visible distances, which are tracked for every height.
+fn scenic_op(height: u8, score: &mut u32, distances: &mut [u32]) {
+    let idx = usize::from(height);
+
+    assert!(idx < distances.len());
+
+    *score *= distances[idx];
+
+    for (i, d) in distances.iter_mut().enumerate() {
+        *d = if i > idx { *d + 1 } else { 1 };
+    }
+}
+
+/// Number of cells [`TreeVisibility::visibility_by_lanes`] processes together, a 256-bit register of bytes
+const LANES: usize = 32;
+
+/// Marks the cells in `visible` whose height is greater than the `tallest` height seen so far in
+/// the same column, then raises `tallest` to include them. Every cell is independent so the loop
+/// compiles to vector instructions when the slices have a fixed length.
+fn march_lane(tallest: &mut [u8], heights: &[u8], visible: &mut [u8]) {
+    for ((t, &h), v) in tallest.iter_mut().zip(heights).zip(visible) {
+        *v |= u8::from(h > *t);
+        *t = (*t).max(h);
+    }
+}
+
+/// Marches down every column at once, marking the cells visible from the edge of the first row
+fn march_columns<'a>(mut rows: impl Iterator<Item = (&'a [u8], &'a mut [u8])>) {
+    // The first row is on the edge so every tree in it can be seen
+    let Some((first, visible)) = rows.next() else {
+        return;
+    };
+
+    let mut tallest = first.to_vec();
+
+    visible.fill(1);
+
+    for (heights, visible) in rows {
+        let mut tallest_lanes = tallest.chunks_exact_mut(LANES);
+        let mut height_lanes = heights.chunks_exact(LANES);
+        let mut visible_lanes = visible.chunks_exact_mut(LANES);
+
+        for ((t, h), v) in (&mut tallest_lanes)
+            .zip(&mut height_lanes)
+            .zip(&mut visible_lanes)
+        {
+            let t: &mut [u8; LANES] = t.try_into().unwrap();
+            let h: &[u8; LANES] = h.try_into().unwrap();
+            let v: &mut [u8; LANES] = v.try_into().unwrap();
+
+            march_lane(t, h, v);
+        }
+
+        march_lane(
+            tallest_lanes.into_remainder(),
+            height_lanes.remainder(),
+            visible_lanes.into_remainder(),
+        );
+    }
+}
+
+/// Transposes a row-major `width` by `height` grid in square tiles so both the reads and the
+/// writes stay within a few cache lines
+fn transpose(src: &[u8], width: usize, height: usize) -> Vec<u8> {
+    let mut dest = vec![0; src.len()];
+
+    for r0 in (0..height).step_by(LANES) {
+        for c0 in (0..width).step_by(LANES) {
+            for r in r0..(r0 + LANES).min(height) {
+                for c in c0..(c0 + LANES).min(width) {
+                    dest[c * height + r] = src[r * width + c];
+                }
+            }
+        }
+    }
+
+    dest
+}
+
+/// Tallest height in the map, or 0 if it is empty
+fn max_height(map: &TreeMap) -> u8 {
+    map.heights().iter().copied().max().unwrap_or(0)
+}
+
+/// The ways of finding the visible trees and scenic scores of a [`TreeMap`], which lives in
+/// `advent_core` so it cannot have them as inherent methods
+pub trait TreeVisibility {
+    /// Compute which cells are visible along any axis from outside the grid. A cell is visible if
+    /// all cells between it and an edge are shorter. Uses [`TreeVisibility::visibility_by_lanes`] with
+    /// the `simd` feature and [`TreeVisibility::visibility_by_rows`] otherwise.
+    fn compute_visibility(&self) -> VisibilityMap;
+
+    /// Visibility found by marching each row in parallel on rayon's thread pool and then the
+    /// columns, which are also split across threads on large maps
+    fn visibility_by_rows(&self) -> VisibilityMap;
+
+    /// Visibility found like [`TreeVisibility::visibility_by_rows`] with the columns marched by
+    /// `columns`
+    fn visibility_with(&self, columns: ColumnStrategy) -> VisibilityMap;
+
+    /// Visibility found by marching all columns together a lane of cells at a time. The rows are
+    /// marched the same way after transposing the grid, which is cheaper than marching along
+    /// each row one cell at a time.
+    fn visibility_by_lanes(&self) -> VisibilityMap;
+
+    /// Computes the scenic score for every cell in the map. The scenic score is a multiplication
+    /// of how many cells can be traveled along each axis before reaching a cell of greater or
+    /// equal height (or the edge of the map).
+    fn compute_scenic_score(&self) -> ScenicMap;
+}
+
+impl TreeVisibility for TreeMap {
+    fn compute_visibility(&self) -> VisibilityMap {
+        if cfg!(feature = "simd") {
+            self.visibility_by_lanes()
+        } else {
+            self.visibility_by_rows()
+        }
+    }
+
+    fn visibility_by_rows(&self) -> VisibilityMap {
+        self.visibility_with(ColumnStrategy::for_size(self.heights().len()))
+    }
+
+    fn visibility_with(&self, columns: ColumnStrategy) -> VisibilityMap {
+        let max_height = max_height(self);
+
+        let visible = DirectionalScan::new(self.heights(), self.shape().0, false)
+            .edge_init(|v| *v = true)
+            .columns(columns)
+            .run(
+                || 0,
+                |&height, visible, tallest| {
+                    depth_op(height, visible, tallest);
+
+                    // Everything past this point must be hidden from this direction
+                    if height == max_height {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                },
+            );
+
+        VisibilityMap(visible)
+    }
+
+    fn visibility_by_lanes(&self) -> VisibilityMap {
+        let (width, height) = self.shape();
+        let heights = self.heights();
+
+        if heights.is_empty() {
+            return VisibilityMap(vec![]);
+        }
+
+        let mut visible = vec![0; heights.len()];
+
+        let rows = || heights.chunks_exact(width);
+
+        march_columns(rows().zip(visible.chunks_exact_mut(width)));
+        march_columns(rows().rev().zip(visible.chunks_exact_mut(width).rev()));
+
+        let columns = transpose(heights, width, height);
+        let mut visible_t = vec![0; columns.len()];
+
+        let rows = || columns.chunks_exact(height);
+
+        march_columns(rows().zip(visible_t.chunks_exact_mut(height)));
+        march_columns(rows().rev().zip(visible_t.chunks_exact_mut(height).rev()));
+
+        let visible_t = transpose(&visible_t, height, width);
+
+        VisibilityMap(
+            visible
+                .iter()
+                .zip(visible_t)
+                .map(|(&v, v_t)| v | v_t != 0)
+                .collect(),
+        )
+    }
+
+    fn compute_scenic_score(&self) -> ScenicMap {
+        if self.heights().is_empty() {
+            return ScenicMap(vec![]);
+        }
+
+        let levels = usize::from(max_height(self)) + 1;
+
+        let scores = DirectionalScan::new(self.heights(), self.shape().0, 1u32)
+            .columns(ColumnStrategy::for_size(self.heights().len()))
+            .run(
+                || vec![0u32; levels],
+                |&height, score, distances| {
+                    scenic_op(height, score, distances);
+
+                    ControlFlow::Continue(())
+                },
+            );
+
+        ScenicMap(scores)
+    }
+}
+
+const EXPLORE_COMMANDS: &[ExploreCommand] = &[
+    ExploreCommand {
+        name: "shape",
+        args: "",
+        about: "Number of columns and rows",
+    },
+    ExploreCommand {
+        name: "tree",
+        args: "ROW COLUMN",
+        about: "Height of a tree, whether it is visible, and its scenic score",
+    },
+    ExploreCommand {
+        name: "visible",
+        args: "",
+        about: "Number of trees visible from outside the grid",
+    },
+];
+
+/// The map as the `explore` command sees it, since [`TreeMap`] lives in `advent_core`
+pub struct TreeExplorer(pub TreeMap);
+
+/// Parses the map in `ctx` to be explored
+pub fn explore(ctx: &mut Context<'_>) -> anyhow::Result<Box<dyn Explorable>> {
+    Ok(Box::new(TreeExplorer(parse_input::<Puzzle>(
+        ctx.take_input(),
+    )?)))
+}
+
+impl Explorable for TreeExplorer {
+    fn commands(&self) -> &'static [ExploreCommand] {
+        EXPLORE_COMMANDS
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, ExploreError> {
+        match (command, args) {
+            ("shape", []) => {
+                let (columns, rows) = self.0.shape();
+
+                Ok(format!("{columns} columns, {rows} rows"))
+            }
+            ("tree", [row, column]) => {
+                let (Ok(row), Ok(column)) = (row.parse(), column.parse()) else {
+                    return Err(EXPLORE_COMMANDS[1].misused());
+                };
+                let height = self.0.get(row, column).ok_or_else(|| {
+                    ExploreError::Failed(format!("no tree at row {row}, column {column}"))
+                })?;
+                let idx = row * self.0.shape().0 + column;
+
+                Ok(format!(
+                    "height {height}, {}, scenic score {}",
+                    if self.0.compute_visibility().0[idx] {
+                        "visible"
+                    } else {
+                        "hidden"
+                    },
+                    self.0.compute_scenic_score().0[idx]
+                ))
+            }
+            ("visible", []) => Ok(self.0.compute_visibility().num_visible().to_string()),
+            _ => Err(explore::unrecognized(EXPLORE_COMMANDS, command)),
+        }
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Treetop Tree House",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = TreeMap;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TreeMap::try_from_digits(&lines)?)
+    }
+
+    /// Total trees visible from outside the grid
+    fn part_01(input: &Self::Input) -> Answer {
+        input.compute_visibility().num_visible().into()
+    }
+
+    /// Highest scenic score of any tree
+    fn part_02(input: &Self::Input) -> Answer {
+        input.compute_scenic_score().highest_score().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+    use advent_core::util::testgen::digit_grid;
+
+    const TEST_DATA: &str = r"30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn parse_input() {
+        let map = TEST_DATA.lines().collect::<TreeMap>();
+
+        assert_eq!(map.shape(), (5, 5));
+        assert_eq!(map.heights().len(), 25);
+        assert_eq!(map.get(0, 0), Some(3));
+        assert_eq!(map.get(1, 0), Some(2));
+        assert_eq!(map.get(4, 4), Some(0));
+    }
+
+    #[test]
+    fn explore_trees() {
+        let mut map = TreeExplorer(TEST_DATA.lines().collect());
+
+        assert_eq!(map.run("shape", &[]).unwrap(), "5 columns, 5 rows");
+        assert_eq!(
+            map.run("tree", &["3", "2"]).unwrap(),
+            "height 5, visible, scenic score 8"
+        );
+        assert_eq!(
+            map.run("tree", &["1", "3"]).unwrap(),
+            "height 1, hidden, scenic score 1"
+        );
+        assert_eq!(map.run("visible", &[]).unwrap(), "21");
+        assert!(map.run("tree", &["5", "0"]).is_err());
+        assert!(map.run("tree", &["a", "0"]).is_err());
+    }
+
+    #[test]
+    pub fn compute_visibility() {
+        let map = TEST_DATA.lines().collect::<TreeMap>();
+        let vis = map.compute_visibility();
+
+        assert_eq!(
+            vis.0,
+            vec![
+                true, true, true, true, true, true, true, true, false, true, true, true, false,
+                true, true, true, false, true, false, true, true, true, true, true, true,
+            ]
+        );
+
+        assert_eq!(vis.num_visible(), 21);
+    }
+
+    #[test]
+    fn lanes_match_rows() {
+        // Widths on either side of a whole number of lanes, including a single row and column
+        for (width, height) in [(1, 1), (1, 7), (7, 1), (31, 33), (32, 32), (70, 45)] {
+            let map = (0..height)
+                .map(|r| {
+                    (0..width)
+                        .map(|c| char::from(b'0' + ((r * 7 + c * 13 + r * c) % 10) as u8))
+                        .collect::<String>()
+                })
+                .collect::<TreeMap>();
+
+            assert_eq!(
+                map.visibility_by_lanes().0,
+                map.visibility_by_rows().0,
+                "{width}x{height}"
+            );
+
+            for columns in [ColumnStrategy::Transposed, ColumnStrategy::Strided] {
+                assert_eq!(
+                    map.visibility_with(columns).0,
+                    map.visibility_by_rows().0,
+                    "{width}x{height} {columns:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn tall_alphabet() {
+        // Heights past 9 and a grid that is wider than it is tall
+        let map = TreeMap::try_from_lines(["abcde", "bzayb", "edcba"], |b| {
+            b.is_ascii_lowercase().then(|| (b - b'a') * 10)
+        })
+        .unwrap();
+
+        let visible = map.visibility_by_rows();
+
+        assert_eq!(visible.0, map.visibility_by_lanes().0);
+        assert_eq!(visible.num_visible(), 14);
+        assert_eq!(map.compute_scenic_score().highest_score(), 3);
+    }
+
+    #[test]
+    fn best_scenic() {
+        let map = TEST_DATA.lines().collect::<TreeMap>();
+        let scores = map.compute_scenic_score();
+
+        assert_eq!(
+            scores.0,
+            vec![0, 0, 0, 0, 0, 0, 1, 4, 1, 0, 0, 6, 1, 2, 0, 0, 1, 8, 3, 0, 0, 0, 0, 0, 0,]
+        );
+
+        assert_eq!(scores.highest_score(), 8);
+    }
+
+    proptest! {
+        /// Raising one tree can only hide the trees behind it, never reveal them, and the tree
+        /// itself stays visible if it already was
+        #[test]
+        fn raising_a_tree_hides_others(
+            rows in digit_grid(1..12, 1..12),
+            cell in any::<prop::sample::Index>(),
+            raise in 1u8..10,
+        ) {
+            let map = rows.iter().collect::<TreeMap>();
+            let (width, _) = map.shape();
+            let idx = cell.index(map.heights().len());
+            let (r, c) = (idx / width, idx % width);
+
+            let mut raised = rows.clone();
+            let height = map.heights()[idx];
+            let digit = char::from(b'0' + (height + raise).min(9));
+
+            raised[r].replace_range(c..=c, &digit.to_string());
+
+            let raised = raised.iter().collect::<TreeMap>();
+            let before = map.compute_visibility().0;
+            let after = raised.compute_visibility().0;
+
+            prop_assert_eq!(&after, &raised.visibility_by_lanes().0);
+            prop_assert!(after[idx] || !before[idx]);
+
+            for (i, (&b, &a)) in before.iter().zip(&after).enumerate() {
+                prop_assert!(i == idx || b || !a, "cell {} became visible", i);
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashSet, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use thiserror::Error;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RopeMoveParseError {
+    #[error("moves should be a direction and a distance like 'R 4'")]
+    Malformed,
+    #[error("'{0}' is not a direction")]
+    InvalidDirection(String),
+    #[error("'{0}' is not a valid distance")]
+    InvalidDistance(String),
+}
+
+/// Moves the head of the rope `steps` cells by `(dx, dy)` one cell at a time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RopeMove {
+    pub dx: i32,
+    pub dy: i32,
+    pub steps: u32,
+}
+
+impl FromStr for RopeMove {
+    type Err = RopeMoveParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dir, steps) = s
+            .trim()
+            .split_once(' ')
+            .ok_or(RopeMoveParseError::Malformed)?;
+
+        let (dx, dy) = match dir {
+            "U" => (0, 1),
+            "D" => (0, -1),
+            "L" => (-1, 0),
+            "R" => (1, 0),
+            _ => return Err(RopeMoveParseError::InvalidDirection(dir.to_owned())),
+        };
+
+        let steps = steps
+            .parse()
+            .map_err(|_| RopeMoveParseError::InvalidDistance(steps.to_owned()))?;
+
+        Ok(Self { dx, dy, steps })
+    }
+}
+
+/// Number of distinct positions the last of `knots` knots visits as the head follows `moves`.
+/// Each knot moves one cell towards the knot ahead of it whenever they stop touching.
+pub fn tail_positions(moves: &[RopeMove], knots: usize) -> usize {
+    assert!(knots > 0, "a rope should have at least one knot");
+
+    let mut rope = vec![(0i32, 0i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+
+    for m in moves {
+        for _ in 0..m.steps {
+            rope[0].0 += m.dx;
+            rope[0].1 += m.dy;
+
+            for k in 1..knots {
+                let (hx, hy) = rope[k - 1];
+                let (tx, ty) = &mut rope[k];
+
+                if (hx - *tx).abs() <= 1 && (hy - *ty).abs() <= 1 {
+                    break;
+                }
+
+                *tx += (hx - *tx).signum();
+                *ty += (hy - *ty).signum();
+            }
+
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    visited.len()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Rope Bridge",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<RopeMove>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid move", i + 1))
+            })
+            .collect()
+    }
+
+    /// Positions visited by the tail of a two knot rope
+    fn part_01(input: &Self::Input) -> Answer {
+        tail_positions(input, 2).into()
+    }
+
+    /// Positions visited by the tail of a ten knot rope
+    fn part_02(input: &Self::Input) -> Answer {
+        tail_positions(input, 10).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
+
+    #[test]
+    fn short_rope() {
+        let moves = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(tail_positions(&moves, 2), 13);
+        assert_eq!(tail_positions(&moves, 1), 21);
+    }
+
+    #[test]
+    fn long_rope() {
+        let moves = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(tail_positions(&moves, 10), 1);
+
+        let moves = Puzzle::parse("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20".as_bytes()).unwrap();
+
+        assert_eq!(tail_positions(&moves, 10), 36);
+    }
+
+    #[test]
+    fn malformed_moves() {
+        assert_eq!(
+            "X 4".parse::<RopeMove>(),
+            Err(RopeMoveParseError::InvalidDirection("X".into()))
+        );
+        assert_eq!(
+            "R -4".parse::<RopeMove>(),
+            Err(RopeMoveParseError::InvalidDistance("-4".into()))
+        );
+        assert_eq!("R".parse::<RopeMove>(), Err(RopeMoveParseError::Malformed));
+    }
+}
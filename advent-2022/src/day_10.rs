@@ -0,0 +1,186 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{bail, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::ocr};
+
+/// Width and height of the CRT in pixels
+const SCREEN: (usize, usize) = (40, 6);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CpuInstruction {
+    /// Does nothing for one cycle
+    Noop,
+    /// Adds to the `X` register at the end of its second cycle
+    AddX(i32),
+}
+
+impl FromStr for CpuInstruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().split_once(' ') {
+            None if s.trim() == "noop" => Ok(Self::Noop),
+            Some(("addx", v)) => Ok(Self::AddX(
+                v.parse()
+                    .with_context(|| format!("'{v}' is not a number"))?,
+            )),
+            _ => bail!("'{s}' should be 'noop' or 'addx V'"),
+        }
+    }
+}
+
+/// Value of the `X` register during each cycle of `program`, starting from the first cycle. Each
+/// `addx` moves it by at most an `i32`, so it would take billions of them to overflow an `i64`.
+pub fn register_values(program: &[CpuInstruction]) -> impl Iterator<Item = i64> + '_ {
+    program
+        .iter()
+        .scan(1, |x, inst| {
+            Some(match *inst {
+                CpuInstruction::Noop => vec![*x],
+                CpuInstruction::AddX(v) => {
+                    let during = *x;
+
+                    *x += i64::from(v);
+
+                    vec![during, during]
+                }
+            })
+        })
+        .flatten()
+}
+
+/// Sum of the cycle number times `X` during the 20th cycle and every 40th one after it
+fn signal_strength(program: &[CpuInstruction]) -> i64 {
+    register_values(program)
+        .zip(1..)
+        .skip(19)
+        .step_by(40)
+        .map(|(x, cycle)| x * cycle)
+        .sum()
+}
+
+/// Draws the screen row by row, lighting a pixel with `#` when the three pixel wide sprite
+/// centred on `X` covers it as the beam passes
+fn render(program: &[CpuInstruction]) -> String {
+    let (width, height) = SCREEN;
+
+    register_values(program)
+        .take(width * height)
+        .enumerate()
+        .map(|(i, x)| {
+            let column = (i % width) as i64;
+            let pixel = if (column - x).abs() <= 1 { '#' } else { '.' };
+
+            if i % width == width - 1 && i + 1 < width * height {
+                format!("{pixel}\n")
+            } else {
+                pixel.to_string()
+            }
+        })
+        .collect()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Cathode-Ray Tube",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<CpuInstruction>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid instruction", i + 1))
+            })
+            .collect()
+    }
+
+    /// Sum of the six sampled signal strengths
+    fn part_01(input: &Self::Input) -> Answer {
+        signal_strength(input).into()
+    }
+
+    /// Eight capital letters drawn on the CRT, or the image itself if they cannot be read
+    fn part_02(input: &Self::Input) -> Answer {
+        let image = render(input);
+
+        ocr::read_letters(&image).map_or(Answer::Grid(image), Answer::Text)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn small_program() {
+        let program = Puzzle::parse("noop\naddx 3\naddx -5".as_bytes()).unwrap();
+
+        assert_eq!(
+            register_values(&program).collect::<Vec<_>>(),
+            vec![1, 1, 1, 4, 4]
+        );
+        assert!(Puzzle::parse("addx".as_bytes()).is_err());
+        assert!(Puzzle::parse("addx 2147483648".as_bytes()).is_err());
+        assert!(Puzzle::parse("addx x".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn signal() {
+        // X rises by one every two cycles, so it is 10 during the 20th cycle
+        let program = vec![CpuInstruction::AddX(1); 120];
+
+        assert_eq!(
+            signal_strength(&program),
+            20 * 10 + 60 * 30 + 100 * 50 + 140 * 70 + 180 * 90 + 220 * 110
+        );
+
+        // Adds that would take X past the range of an i32
+        let program = vec![CpuInstruction::AddX(i32::MAX); 120];
+        let x_at = |cycle: i64| 1 + (cycle - 1) / 2 * i64::from(i32::MAX);
+
+        assert_eq!(register_values(&program).nth(219), Some(x_at(220)));
+        assert_eq!(
+            signal_strength(&program),
+            [20, 60, 100, 140, 180, 220]
+                .map(|cycle| cycle * x_at(cycle))
+                .iter()
+                .sum::<i64>()
+        );
+    }
+
+    #[test]
+    fn screen() {
+        let program = vec![CpuInstruction::Noop; 240];
+        let row = format!("###{}", ".".repeat(37));
+
+        assert_eq!(render(&program), vec![row; 6].join("\n"));
+
+        // Bars down the left edge are not letters, so the image is the answer
+        assert_eq!(Puzzle::part_02(&program), Answer::Grid(render(&program)));
+
+        // The sprite jumps away after the first two pixels are drawn
+        let mut program = vec![CpuInstruction::AddX(10)];
+
+        program.extend([CpuInstruction::Noop; 238]);
+
+        let image = render(&program);
+        let mut rows = image.lines();
+
+        assert_eq!(
+            rows.next().unwrap(),
+            format!("##{}###{}", ".".repeat(8), ".".repeat(27))
+        );
+        assert_eq!(
+            rows.next().unwrap(),
+            format!("{}###{}", ".".repeat(10), ".".repeat(27))
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// How a monkey changes the worry level of an item it inspects
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    fn apply(self, old: u64) -> u64 {
+        match self {
+            Self::Add(n) => old + n,
+            Self::Multiply(n) => old * n,
+            Self::Square => old * old,
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expr = s
+            .trim()
+            .strip_prefix("new = old ")
+            .with_context(|| format!("'{s}' should look like 'new = old * 19'"))?;
+
+        Ok(match expr.split_once(' ') {
+            Some(("*", "old")) => Self::Square,
+            Some(("*", n)) => Self::Multiply(n.parse()?),
+            Some(("+", n)) => Self::Add(n.parse()?),
+            _ => bail!("'{expr}' is not a supported operation"),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    /// Items are thrown to `if_true` when their worry level is divisible by this
+    pub divisor: u64,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().skip(1).map(str::trim);
+
+        let mut field = |label: &str| {
+            lines
+                .next()
+                .and_then(|l| l.strip_prefix(label))
+                .with_context(|| format!("monkey should have a line starting with '{label}'"))
+        };
+
+        let items = field("Starting items:")?
+            .split(',')
+            .map(|i| i.trim().parse())
+            .collect::<Result<Vec<_>, _>>()
+            .context("starting items should be a list of numbers")?;
+        let operation = field("Operation:")?.parse()?;
+        let divisor = field("Test: divisible by")?.trim().parse()?;
+        let if_true = field("If true: throw to monkey")?.trim().parse()?;
+        let if_false = field("If false: throw to monkey")?.trim().parse()?;
+
+        ensure!(divisor > 0, "monkeys can not test for divisibility by zero");
+
+        Ok(Self {
+            items,
+            operation,
+            divisor,
+            if_true,
+            if_false,
+        })
+    }
+}
+
+/// Product of the two highest numbers of inspections after `rounds` rounds of keep away. With
+/// `relief` worry levels are divided by three after each inspection. Without it they grow without
+/// bound, so they are kept modulo the product of every divisor, which no test can tell apart.
+pub fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: bool) -> u64 {
+    let modulus = monkeys.iter().map(|m| m.divisor).product::<u64>();
+    let mut items = monkeys.iter().map(|m| m.items.clone()).collect::<Vec<_>>();
+    let mut inspections = vec![0u64; monkeys.len()];
+
+    for _ in 0..rounds {
+        for (i, m) in monkeys.iter().enumerate() {
+            let held = std::mem::take(&mut items[i]);
+
+            inspections[i] += held.len() as u64;
+
+            for worry in held {
+                let mut worry = m.operation.apply(worry);
+
+                if relief {
+                    worry /= 3;
+                } else {
+                    worry %= modulus;
+                }
+
+                let target = if worry % m.divisor == 0 {
+                    m.if_true
+                } else {
+                    m.if_false
+                };
+
+                items[target].push(worry);
+            }
+        }
+    }
+
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+
+    inspections.iter().take(2).product()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Monkey in the Middle",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Monkey>;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        let monkeys = text
+            .split("\n\n")
+            .enumerate()
+            .map(|(i, block)| {
+                block
+                    .parse::<Monkey>()
+                    .with_context(|| format!("monkey {i} is not valid"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        for (i, m) in monkeys.iter().enumerate() {
+            ensure!(
+                m.if_true < monkeys.len() && m.if_false < monkeys.len(),
+                "monkey {i} throws to a monkey that does not exist"
+            );
+            ensure!(
+                m.if_true != i && m.if_false != i,
+                "monkey {i} throws to itself"
+            );
+        }
+
+        Ok(monkeys)
+    }
+
+    /// Monkey business after 20 rounds with relief
+    fn part_01(input: &Self::Input) -> Answer {
+        monkey_business(input, 20, true).into()
+    }
+
+    /// Monkey business after 10000 rounds without relief
+    fn part_02(input: &Self::Input) -> Answer {
+        monkey_business(input, 10_000, false).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn parse_monkeys() {
+        let monkeys = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(monkeys.len(), 4);
+        assert_eq!(monkeys[2].operation, Operation::Square);
+        assert_eq!(monkeys[1].items, vec![54, 65, 75, 74]);
+        assert_eq!(monkeys[3].if_false, 1);
+
+        let broken = TEST_DATA.replace("monkey 3", "monkey 7");
+
+        assert!(Puzzle::parse(broken.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn keep_away() {
+        let monkeys = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(monkey_business(&monkeys, 20, true), 10605);
+        assert_eq!(monkey_business(&monkeys, 20, false), 99 * 103);
+        assert_eq!(monkey_business(&monkeys, 10_000, false), 2713310158);
+    }
+}
@@ -0,0 +1,159 @@
+use std::io::BufRead;
+
+use anyhow::{bail, ensure};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::search};
+
+/// Elevations of a rectangular area in row-major order, from 0 for `a` up to 25 for `z`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeightMap {
+    width: usize,
+    heights: Vec<u8>,
+    /// Index of the current position, `S`
+    start: usize,
+    /// Index of the best signal, `E`
+    end: usize,
+}
+
+impl HeightMap {
+    /// Positions directly next to `i` without leaving the map
+    fn neighbours(&self, i: usize) -> impl Iterator<Item = usize> + '_ {
+        let (row, column) = (i / self.width, i % self.width);
+        let height = self.heights.len() / self.width;
+
+        [
+            (row > 0).then(|| i - self.width),
+            (row + 1 < height).then(|| i + self.width),
+            (column > 0).then(|| i - 1),
+            (column + 1 < self.width).then(|| i + 1),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Positions next to `i` that can be climbed to from it, each one step away
+    fn climbs(&self, i: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(i)
+            .filter(move |&n| self.heights[n] <= self.heights[i] + 1)
+            .map(|n| (n, 1))
+    }
+
+    /// Fewest steps from any of `starts` to the best signal
+    fn shortest_from(&self, starts: impl IntoIterator<Item = usize>) -> Option<usize> {
+        search::dijkstra(starts, |&i| self.climbs(i), |&i| i == self.end).map(|path| path.cost)
+    }
+
+    /// Fewest steps from the current position to the best signal
+    pub fn shortest_from_start(&self) -> Option<usize> {
+        self.shortest_from([self.start])
+    }
+
+    /// Fewest steps to the best signal from any position at the lowest elevation
+    pub fn shortest_from_lowest(&self) -> Option<usize> {
+        self.shortest_from((0..self.heights.len()).filter(|&i| self.heights[i] == 0))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Hill Climbing Algorithm",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = HeightMap;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut heights = Vec::new();
+        let mut width = 0;
+        let (mut start, mut end) = (None, None);
+
+        for (r, l) in reader.lines().enumerate() {
+            let l = l?;
+
+            if r == 0 {
+                width = l.len();
+            }
+
+            ensure!(
+                l.len() == width && width > 0,
+                "row {} should be {width} positions wide",
+                r + 1
+            );
+
+            for b in l.bytes() {
+                let h = match b {
+                    b'S' => {
+                        start = Some(heights.len());
+                        0
+                    }
+                    b'E' => {
+                        end = Some(heights.len());
+                        25
+                    }
+                    b'a'..=b'z' => b - b'a',
+                    _ => bail!("'{}' on row {} is not an elevation", char::from(b), r + 1),
+                };
+
+                heights.push(h);
+            }
+        }
+
+        let (Some(start), Some(end)) = (start, end) else {
+            bail!("the map should mark both the start with S and the end with E");
+        };
+
+        Ok(HeightMap {
+            width,
+            heights,
+            start,
+            end,
+        })
+    }
+
+    /// Fewest steps from the current position to the best signal
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .shortest_from_start()
+            .expect("the best signal should be reachable")
+            .into()
+    }
+
+    /// Fewest steps from any lowest position to the best signal
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .shortest_from_lowest()
+            .expect("the best signal should be reachable")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+
+    #[test]
+    fn climb() {
+        let map = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(map.shortest_from_start(), Some(31));
+        assert_eq!(map.shortest_from_lowest(), Some(29));
+    }
+
+    #[test]
+    fn unreachable() {
+        // Two steps up at once can not be climbed
+        let map = Puzzle::parse("SacE".as_bytes()).unwrap();
+
+        assert_eq!(map.shortest_from_start(), None);
+
+        let map = Puzzle::parse("SbcdefghijklmnopqrstuvwxyE".as_bytes()).unwrap();
+
+        assert_eq!(map.shortest_from_start(), Some(25));
+        assert!(Puzzle::parse("Sab\nE".as_bytes()).is_err());
+        assert!(Puzzle::parse("Sab".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,206 @@
+use std::{cmp::Ordering, fmt, io::BufRead, str::FromStr};
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Either an integer or a list of packets, compared the way the distress signal expects
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    /// Parses one packet from the front of `s`, returning it with the rest of the text
+    fn parse_prefix(s: &str) -> anyhow::Result<(Self, &str)> {
+        if let Some(mut rest) = s.strip_prefix('[') {
+            let mut items = Vec::new();
+
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Ok((Self::List(items), rest));
+            }
+
+            loop {
+                let (item, after) = Self::parse_prefix(rest)?;
+
+                items.push(item);
+
+                match after.as_bytes().first() {
+                    Some(b',') => rest = &after[1..],
+                    Some(b']') => return Ok((Self::List(items), &after[1..])),
+                    _ => bail!("lists should be separated by ',' and closed by ']'"),
+                }
+            }
+        }
+
+        let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+
+        ensure!(digits > 0, "expected a list or an integer at '{s}'");
+
+        Ok((Self::Integer(s[..digits].parse()?), &s[digits..]))
+    }
+
+    /// A packet holding a list with `n` as its only item, which divides the decoder key
+    fn divider(n: u32) -> Self {
+        Self::List(vec![Self::List(vec![Self::Integer(n)])])
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+            (Self::List(a), Self::List(b)) => a.cmp(b),
+            (Self::Integer(a), Self::List(b)) => [Self::Integer(*a)][..].cmp(b),
+            (Self::List(a), Self::Integer(b)) => a[..].cmp(&[Self::Integer(*b)]),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Packet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (packet, rest) = Self::parse_prefix(s.trim())?;
+
+        ensure!(rest.is_empty(), "unexpected '{rest}' after the packet");
+
+        Ok(packet)
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::List(items) => {
+                f.write_str("[")?;
+
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write!(f, "{item}")?;
+                }
+
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// Sum of the one based indices of the pairs that are already in order
+fn ordered_pairs(packets: &[Packet]) -> usize {
+    packets
+        .chunks_exact(2)
+        .zip(1..)
+        .filter(|(pair, _)| pair[0] < pair[1])
+        .map(|(_, i)| i)
+        .sum()
+}
+
+/// Product of where the `[[2]]` and `[[6]]` dividers end up once every packet is sorted. Only
+/// their positions are needed, which is just how many packets sort before each one.
+fn decoder_key(packets: &[Packet]) -> usize {
+    let (two, six) = (Packet::divider(2), Packet::divider(6));
+    let before_two = packets.iter().filter(|&p| *p < two).count();
+    let before_six = packets.iter().filter(|&p| *p < six).count();
+
+    // Each divider is one based and the six also comes after the two
+    (before_two + 1) * (before_six + 2)
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Distress Signal",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Every packet in order, the pairs being consecutive
+    type Input = Vec<Packet>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let packets = reader
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| l.as_ref().map_or(true, |l| !l.trim().is_empty()))
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid packet", i + 1))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        ensure!(packets.len() % 2 == 0, "packets should come in pairs");
+
+        Ok(packets)
+    }
+
+    /// Sum of the indices of pairs in the right order
+    fn part_01(input: &Self::Input) -> Answer {
+        ordered_pairs(input).into()
+    }
+
+    /// Decoder key for the distress signal
+    fn part_02(input: &Self::Input) -> Answer {
+        decoder_key(input).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+    #[test]
+    fn packets() {
+        let packets = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(packets.len(), 16);
+        assert_eq!(packets[15].to_string(), "[1,[2,[3,[4,[5,6,0]]]],8,9]");
+        assert!("[1,2".parse::<Packet>().is_err());
+        assert!("[1,2]]".parse::<Packet>().is_err());
+        assert!("[a]".parse::<Packet>().is_err());
+    }
+
+    #[test]
+    fn ordering() {
+        let packets = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(ordered_pairs(&packets), 13);
+        assert_eq!(decoder_key(&packets), 140);
+    }
+}
@@ -0,0 +1,153 @@
+use std::io::BufRead;
+
+use anyhow::{ensure, Context};
+use itertools::Itertools;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Where sand pours into the cave from
+const SOURCE: (usize, usize) = (500, 0);
+
+/// A vertical slice of the cave marking which cells are rock. Sand spreads out at most one column
+/// per row, so the slice is wide enough for a pile that reaches the floor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cave {
+    width: usize,
+    blocked: Vec<bool>,
+    /// Row of the lowest rock
+    depth: usize,
+}
+
+impl Cave {
+    fn from_paths(paths: &[Vec<(usize, usize)>]) -> Self {
+        let depth = paths.iter().flatten().map(|&(_, y)| y).max().unwrap_or(0);
+        let width = SOURCE.0 + depth + 3;
+        let mut blocked = vec![false; width * (depth + 2)];
+
+        for (&(x0, y0), &(x1, y1)) in paths.iter().flat_map(|p| p.iter().tuple_windows()) {
+            for y in y0.min(y1)..=y0.max(y1) {
+                for x in x0.min(x1)..=x0.max(x1) {
+                    blocked[y * width + x] = true;
+                }
+            }
+        }
+
+        Self {
+            width,
+            blocked,
+            depth,
+        }
+    }
+
+    /// Units of sand that come to rest before sand either falls past the lowest rock or, with a
+    /// `floor` two rows below it, blocks the source. Each unit retraces the path of the one
+    /// before it from the last cell that is still free instead of starting from the source.
+    pub fn pour(&self, floor: bool) -> usize {
+        let mut blocked = self.blocked.clone();
+        let mut path = vec![SOURCE];
+        let mut resting = 0;
+
+        while let Some(&(x, y)) = path.last() {
+            if y == self.depth + 1 {
+                if !floor {
+                    break;
+                }
+            } else if let Some(next) = [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&nx| !blocked[(y + 1) * self.width + nx])
+            {
+                path.push((next, y + 1));
+                continue;
+            }
+
+            blocked[y * self.width + x] = true;
+            resting += 1;
+            path.pop();
+        }
+
+        resting
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Regolith Reservoir",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Cave;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let paths = reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let l = l?;
+
+                l.split(" -> ")
+                    .map(|p| {
+                        let (x, y) = p.split_once(',').context("points should be 'x,y'")?;
+
+                        Ok((x.trim().parse()?, y.trim().parse()?))
+                    })
+                    .collect::<anyhow::Result<Vec<(usize, usize)>>>()
+                    .with_context(|| format!("line {} is not a valid path", i + 1))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        for (i, path) in paths.iter().enumerate() {
+            ensure!(
+                path.iter()
+                    .tuple_windows()
+                    .all(|(a, b)| a.0 == b.0 || a.1 == b.1),
+                "path on line {} should only run straight across or down",
+                i + 1
+            );
+            ensure!(
+                path.iter().all(|&(x, _)| x > 0),
+                "path on line {} should not touch the left edge",
+                i + 1
+            );
+        }
+
+        Ok(Cave::from_paths(&paths))
+    }
+
+    /// Sand that comes to rest before the rest falls into the abyss
+    fn part_01(input: &Self::Input) -> Answer {
+        input.pour(false).into()
+    }
+
+    /// Sand that comes to rest on the floor before the source is blocked
+    fn part_02(input: &Self::Input) -> Answer {
+        input.pour(true).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn sand() {
+        let cave = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(cave.depth, 9);
+        assert_eq!(cave.pour(false), 24);
+        assert_eq!(cave.pour(true), 93);
+    }
+
+    #[test]
+    fn empty_cave() {
+        // Nothing can rest without rock, but the floor fills a triangle two rows tall
+        let cave = Puzzle::parse("".as_bytes()).unwrap();
+
+        assert_eq!(cave.pour(false), 0);
+        assert_eq!(cave.pour(true), 4);
+        assert!(Puzzle::parse("498,4 -> 500,6".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use std::{collections::HashSet, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::interval::{IntervalSet, SectionRange},
+};
+
+/// Row checked for positions where a beacon can not be
+const CHECKED_ROW: i64 = 2_000_000;
+
+/// Largest coordinate the distress beacon can have on either axis
+const SEARCH_LIMIT: i64 = 4_000_000;
+
+/// A sensor and the closest beacon to it by taxicab distance
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Sensor {
+    pub position: (i64, i64),
+    pub beacon: (i64, i64),
+}
+
+impl Sensor {
+    fn radius(&self) -> i64 {
+        (self.position.0 - self.beacon.0).abs() + (self.position.1 - self.beacon.1).abs()
+    }
+
+    fn covers(&self, (x, y): (i64, i64)) -> bool {
+        (self.position.0 - x).abs() + (self.position.1 - y).abs() <= self.radius()
+    }
+
+    /// Positions on `row` no closer than the beacon, if any
+    fn coverage(&self, row: i64) -> Option<SectionRange<i64>> {
+        let spread = self.radius() - (self.position.1 - row).abs();
+
+        (spread >= 0).then(|| SectionRange(self.position.0 - spread, self.position.0 + spread))
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let point = |p: &str| -> anyhow::Result<(i64, i64)> {
+            let (x, y) = p
+                .trim()
+                .strip_prefix("x=")
+                .and_then(|p| p.split_once(", y="))
+                .with_context(|| format!("'{p}' should be a point like 'x=2, y=18'"))?;
+
+            Ok((x.parse()?, y.parse()?))
+        };
+
+        let (sensor, beacon) = s
+            .trim()
+            .strip_prefix("Sensor at ")
+            .and_then(|s| s.split_once(": closest beacon is at "))
+            .context("sensors should be 'Sensor at ...: closest beacon is at ...'")?;
+
+        Ok(Self {
+            position: point(sensor)?,
+            beacon: point(beacon)?,
+        })
+    }
+}
+
+/// Positions on `row` where there can not be a beacon
+fn no_beacon_count(sensors: &[Sensor], row: i64) -> i64 {
+    let covered = sensors
+        .iter()
+        .filter_map(|s| s.coverage(row))
+        .collect::<IntervalSet<_>>();
+
+    let beacons = sensors
+        .iter()
+        .map(|s| s.beacon)
+        .filter(|&(x, y)| y == row && covered.contains(x))
+        .collect::<HashSet<_>>();
+
+    covered.len() - beacons.len() as i64
+}
+
+/// The only position within `0..=limit` on both axes not covered by any sensor. When there is
+/// only one such position it must sit just outside the edges of several sensors, so only the
+/// crossings of those edges (and the corners of the search area) need to be checked.
+fn distress_beacon(sensors: &[Sensor], limit: i64) -> Option<(i64, i64)> {
+    let mut rising = Vec::new();
+    let mut falling = Vec::new();
+
+    for s in sensors {
+        let (x, y) = s.position;
+        let r = s.radius() + 1;
+
+        rising.extend([x - y - r, x - y + r]);
+        falling.extend([x + y - r, x + y + r]);
+    }
+
+    let crossings = rising.iter().flat_map(|&c| {
+        falling
+            .iter()
+            .filter(move |&&d| (c + d) % 2 == 0)
+            .map(move |&d| ((c + d) / 2, (d - c) / 2))
+    });
+
+    let corners = [(0, 0), (0, limit), (limit, 0), (limit, limit)];
+
+    corners.into_iter().chain(crossings).find(|&(x, y)| {
+        (0..=limit).contains(&x)
+            && (0..=limit).contains(&y)
+            && sensors.iter().all(|s| !s.covers((x, y)))
+    })
+}
+
+/// Frequency a beacon at `(x, y)` is tuned to
+fn tuning_frequency((x, y): (i64, i64)) -> i64 {
+    x * 4_000_000 + y
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Beacon Exclusion Zone",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Sensor>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid sensor", i + 1))
+            })
+            .collect()
+    }
+
+    /// Positions in the checked row that can not hold a beacon
+    fn part_01(input: &Self::Input) -> Answer {
+        no_beacon_count(input, CHECKED_ROW).into()
+    }
+
+    /// Tuning frequency of the distress beacon
+    fn part_02(input: &Self::Input) -> Answer {
+        tuning_frequency(
+            distress_beacon(input, SEARCH_LIMIT).expect("there should be one uncovered position"),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn row_coverage() {
+        let sensors = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(sensors[0].radius(), 7);
+        assert_eq!(no_beacon_count(&sensors, 10), 26);
+        assert!(Puzzle::parse("Sensor at x=2: closest beacon is at x=1, y=1".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn find_distress_beacon() {
+        let sensors = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+        let beacon = distress_beacon(&sensors, 20);
+
+        assert_eq!(beacon, Some((14, 11)));
+        assert_eq!(tuning_frequency(beacon.unwrap()), 56000011);
+    }
+}
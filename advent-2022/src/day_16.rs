@@ -0,0 +1,219 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io::BufRead,
+};
+
+use anyhow::{ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Valve everyone starts next to
+const START: &str = "AA";
+
+/// The valves worth opening and how many minutes it takes to walk between any two of them. Valves
+/// with no flow are only ever walked through, so they are left out entirely.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Volcano {
+    flows: Vec<u32>,
+    /// Minutes between each pair of useful valves, plus a final row and column for the start
+    distances: Vec<Vec<u32>>,
+}
+
+impl Volcano {
+    /// Most pressure released by opening each set of valves, indexed by a bitmask of the valves,
+    /// within `minutes`. Sets that can not be opened in time are left at zero.
+    pub fn best_by_valves(&self, minutes: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flows.len()];
+
+        self.explore(self.flows.len(), minutes, 0, 0, &mut best);
+
+        best
+    }
+
+    fn explore(&self, at: usize, minutes: u32, opened: usize, released: u32, best: &mut [u32]) {
+        best[opened] = best[opened].max(released);
+
+        for (next, &flow) in self.flows.iter().enumerate() {
+            let cost = self.distances[at][next].saturating_add(1);
+
+            if opened & (1 << next) == 0 && cost < minutes {
+                let left = minutes - cost;
+
+                self.explore(next, left, opened | 1 << next, released + flow * left, best);
+            }
+        }
+    }
+
+    /// Most pressure released alone in `minutes`
+    pub fn most_pressure(&self, minutes: u32) -> u32 {
+        self.best_by_valves(minutes).into_iter().max().unwrap_or(0)
+    }
+
+    /// Most pressure released in `minutes` with a helper, each opening a separate set of valves
+    pub fn most_pressure_together(&self, minutes: u32) -> u32 {
+        let best = self.best_by_valves(minutes);
+        let full = best.len() - 1;
+
+        // Spread each set's best to every set containing it, so any set can find the best use of
+        // the valves left over by the other
+        let mut within = best.clone();
+
+        for bit in 0..self.flows.len() {
+            for set in 0..within.len() {
+                if set & (1 << bit) != 0 {
+                    within[set] = within[set].max(within[set ^ (1 << bit)]);
+                }
+            }
+        }
+
+        (0..best.len())
+            .map(|set| best[set] + within[full ^ set])
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Proboscidea Volcanium",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Volcano;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut valves = Vec::new();
+
+        for (i, l) in reader.lines().enumerate() {
+            let l = l?;
+            let (name, flow, tunnels) = l
+                .strip_prefix("Valve ")
+                .and_then(|l| l.split_once(" has flow rate="))
+                .and_then(|(name, rest)| {
+                    let (flow, tunnels) = rest.split_once("; ")?;
+                    let tunnels = tunnels
+                        .strip_prefix("tunnels lead to valves ")
+                        .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))?;
+
+                    Some((name, flow.parse::<u32>().ok()?, tunnels))
+                })
+                .with_context(|| format!("line {} is not a valid valve", i + 1))?;
+
+            valves.push((
+                name.to_owned(),
+                flow,
+                tunnels.split(", ").map(str::to_owned).collect::<Vec<_>>(),
+            ));
+        }
+
+        let index = valves
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _, _))| (name.as_str(), i))
+            .collect::<HashMap<_, _>>();
+
+        let tunnels = valves
+            .iter()
+            .map(|(name, _, tunnels)| {
+                tunnels
+                    .iter()
+                    .map(|t| {
+                        index
+                            .get(t.as_str())
+                            .copied()
+                            .with_context(|| format!("valve {name} leads to unknown valve {t}"))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let start = *index.get(START).context("there should be a valve AA")?;
+        let mut useful = (0..valves.len())
+            .filter(|&v| valves[v].1 > 0)
+            .collect::<Vec<_>>();
+
+        ensure!(
+            useful.len() < 24,
+            "too many valves with flow to try every set of them"
+        );
+
+        useful.push(start);
+
+        let distances = useful
+            .iter()
+            .map(|&from| {
+                let mut steps = vec![u32::MAX; valves.len()];
+                let mut queue = VecDeque::from([from]);
+
+                steps[from] = 0;
+
+                while let Some(v) = queue.pop_front() {
+                    for &t in &tunnels[v] {
+                        if steps[t] == u32::MAX {
+                            steps[t] = steps[v] + 1;
+                            queue.push_back(t);
+                        }
+                    }
+                }
+
+                useful.iter().map(|&to| steps[to]).collect()
+            })
+            .collect();
+
+        useful.pop();
+
+        Ok(Volcano {
+            flows: useful.iter().map(|&v| valves[v].1).collect(),
+            distances,
+        })
+    }
+
+    /// Most pressure released alone in 30 minutes
+    fn part_01(input: &Self::Input) -> Answer {
+        input.most_pressure(30).into()
+    }
+
+    /// Most pressure released with an elephant in the 26 minutes left after teaching it
+    fn part_02(input: &Self::Input) -> Answer {
+        input.most_pressure_together(26).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn parse_valves() {
+        let volcano = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(volcano.flows, vec![13, 2, 20, 3, 22, 21]);
+        // From the start to HH through DD, EE, FF, and GG
+        assert_eq!(volcano.distances[6][4], 5);
+
+        let broken = TEST_DATA.replace("valve GG", "valve ZZ");
+
+        assert!(Puzzle::parse(broken.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn release_pressure() {
+        let volcano = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(volcano.most_pressure(30), 1651);
+        assert_eq!(volcano.most_pressure_together(26), 1707);
+    }
+}
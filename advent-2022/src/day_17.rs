@@ -0,0 +1,202 @@
+use std::{collections::HashMap, io::BufRead};
+
+use anyhow::{bail, ensure};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Rows of each rock from the bottom up as they appear, with the leftmost of the chamber's seven
+/// columns as bit 6 and the rock two columns in from the left wall
+const ROCKS: [&[u8]; 5] = [
+    &[0b0011110],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0000100, 0b0000100],
+    &[0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    &[0b0011000, 0b0011000],
+];
+
+/// Rows from the top of the tower compared when looking for a repeating pattern. Rocks almost
+/// never fall further than this, so matching tops behave the same from then on.
+const SNAPSHOT_ROWS: usize = 32;
+
+/// Direction a jet of hot gas pushes a rock
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+/// A tall chamber that rocks fall into and come to rest in
+#[derive(Clone, Debug, Default)]
+struct Chamber {
+    rows: Vec<u8>,
+    /// Index of the next jet to push a rock
+    jet: usize,
+}
+
+impl Chamber {
+    fn hits(&self, rock: &[u8], y: usize) -> bool {
+        rock.iter()
+            .enumerate()
+            .any(|(i, r)| self.rows.get(y + i).is_some_and(|row| row & r != 0))
+    }
+
+    /// Pushes `rock` by `jet` if it hits neither a wall nor another rock
+    fn push(&self, rock: &mut [u8], jet: Jet, y: usize) {
+        let mut moved = [0; 4];
+        let moved = &mut moved[..rock.len()];
+
+        for (m, &r) in moved.iter_mut().zip(rock.iter()) {
+            *m = match jet {
+                Jet::Left if r & 0b1000000 == 0 => r << 1,
+                Jet::Right if r & 1 == 0 => r >> 1,
+                _ => return,
+            };
+        }
+
+        if !self.hits(moved, y) {
+            rock.copy_from_slice(moved);
+        }
+    }
+
+    /// Drops the next rock until it comes to rest
+    fn drop_rock(&mut self, shape: usize, jets: &[Jet]) {
+        let mut rock = [0; 4];
+        let rock = &mut rock[..ROCKS[shape].len()];
+        let mut y = self.rows.len() + 3;
+
+        rock.copy_from_slice(ROCKS[shape]);
+
+        loop {
+            self.push(rock, jets[self.jet], y);
+            self.jet = (self.jet + 1) % jets.len();
+
+            if y == 0 || self.hits(rock, y - 1) {
+                break;
+            }
+
+            y -= 1;
+        }
+
+        for (i, &r) in rock.iter().enumerate() {
+            if y + i >= self.rows.len() {
+                self.rows.resize(y + i + 1, 0);
+            }
+
+            self.rows[y + i] |= r;
+        }
+    }
+
+    /// The top rows of the tower, which with the next rock and jet decide how it grows
+    fn snapshot(&self) -> Vec<u8> {
+        self.rows
+            .iter()
+            .rev()
+            .take(SNAPSHOT_ROWS)
+            .copied()
+            .collect()
+    }
+}
+
+/// Height of the tower after `count` rocks have fallen. Once the rock, the jet, and the top of the
+/// tower repeat, the tower grows the same way every cycle, so whole cycles are skipped.
+pub fn tower_height(jets: &[Jet], count: u64) -> u64 {
+    let mut chamber = Chamber::default();
+    let mut seen = HashMap::new();
+    let mut skipped = 0;
+    let mut dropped = 0;
+
+    while dropped < count {
+        let shape = (dropped % ROCKS.len() as u64) as usize;
+
+        chamber.drop_rock(shape, jets);
+        dropped += 1;
+
+        if skipped > 0 {
+            continue;
+        }
+
+        let key = (shape, chamber.jet, chamber.snapshot());
+        let height = chamber.rows.len() as u64;
+
+        if let Some((before, before_height)) = seen.insert(key, (dropped, height)) {
+            let period = dropped - before;
+            let cycles = (count - dropped) / period;
+
+            skipped = cycles * (height - before_height);
+            dropped += cycles * period;
+        }
+    }
+
+    chamber.rows.len() as u64 + skipped
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Pyroclastic Flow",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Jet>;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        let jets = text
+            .trim()
+            .chars()
+            .map(|c| match c {
+                '<' => Ok(Jet::Left),
+                '>' => Ok(Jet::Right),
+                _ => bail!("'{c}' is not a jet direction"),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        ensure!(!jets.is_empty(), "there should be at least one jet");
+
+        Ok(jets)
+    }
+
+    /// Height of the tower after 2022 rocks
+    fn part_01(input: &Self::Input) -> Answer {
+        tower_height(input, 2022).into()
+    }
+
+    /// Height of the tower after a trillion rocks
+    fn part_02(input: &Self::Input) -> Answer {
+        tower_height(input, 1_000_000_000_000).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn first_rocks() {
+        let jets = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+        let mut chamber = Chamber::default();
+
+        for shape in 0..3 {
+            chamber.drop_rock(shape, &jets);
+        }
+
+        // The third rock lands on the plus, leaving it six rows tall
+        assert_eq!(chamber.rows.len(), 6);
+        assert_eq!(chamber.rows[0], 0b0011110);
+        assert!(Puzzle::parse("<>x".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn tower() {
+        let jets = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(tower_height(&jets, 2022), 3068);
+        assert_eq!(tower_height(&jets, 1_000_000_000_000), 1514285714288);
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    io::BufRead,
+};
+
+use anyhow::Context;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+type Cube = (i32, i32, i32);
+
+fn neighbours((x, y, z): Cube) -> [Cube; 6] {
+    [
+        (x - 1, y, z),
+        (x + 1, y, z),
+        (x, y - 1, z),
+        (x, y + 1, z),
+        (x, y, z - 1),
+        (x, y, z + 1),
+    ]
+}
+
+/// Unit cubes of lava scanned from a droplet
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Droplet(HashSet<Cube>);
+
+impl Droplet {
+    /// Faces of every cube that are not touching another cube, including any facing air pockets
+    pub fn surface_area(&self) -> usize {
+        self.0
+            .iter()
+            .flat_map(|&c| neighbours(c))
+            .filter(|n| !self.0.contains(n))
+            .count()
+    }
+
+    /// Faces that water and steam can reach from outside the droplet. Floods the air around the
+    /// droplet within a box one cube larger on every side, counting each face the air touches.
+    pub fn exterior_surface_area(&self) -> usize {
+        let Some(&first) = self.0.iter().next() else {
+            return 0;
+        };
+
+        let (low, high) = self.0.iter().fold((first, first), |(lo, hi), &(x, y, z)| {
+            (
+                (lo.0.min(x), lo.1.min(y), lo.2.min(z)),
+                (hi.0.max(x), hi.1.max(y), hi.2.max(z)),
+            )
+        });
+
+        let low = (low.0 - 1, low.1 - 1, low.2 - 1);
+        let high = (high.0 + 1, high.1 + 1, high.2 + 1);
+        let inside = |(x, y, z): Cube| {
+            (low.0..=high.0).contains(&x)
+                && (low.1..=high.1).contains(&y)
+                && (low.2..=high.2).contains(&z)
+        };
+
+        let mut air = HashSet::from([low]);
+        let mut queue = VecDeque::from([low]);
+        let mut faces = 0;
+
+        while let Some(c) = queue.pop_front() {
+            for n in neighbours(c) {
+                if self.0.contains(&n) {
+                    faces += 1;
+                } else if inside(n) && air.insert(n) {
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        faces
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Boiling Boulders",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Droplet;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let l = l?;
+                let mut coords = l.split(',').map(|c| c.trim().parse::<i32>());
+
+                match (coords.next(), coords.next(), coords.next(), coords.next()) {
+                    (Some(Ok(x)), Some(Ok(y)), Some(Ok(z)), None) => Ok((x, y, z)),
+                    _ => {
+                        None.with_context(|| format!("line {} should be a cube like 2,2,2", i + 1))
+                    }
+                }
+            })
+            .collect::<anyhow::Result<_>>()
+            .map(Droplet)
+    }
+
+    /// Surface area of the droplet
+    fn part_01(input: &Self::Input) -> Answer {
+        input.surface_area().into()
+    }
+
+    /// Exterior surface area of the droplet
+    fn part_02(input: &Self::Input) -> Answer {
+        input.exterior_surface_area().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    #[test]
+    fn two_cubes() {
+        let droplet = Puzzle::parse("1,1,1\n2,1,1".as_bytes()).unwrap();
+
+        assert_eq!(droplet.surface_area(), 10);
+        assert_eq!(droplet.exterior_surface_area(), 10);
+        assert!(Puzzle::parse("1,1".as_bytes()).is_err());
+        assert!(Puzzle::parse("1,1,1,1".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn air_pocket() {
+        let droplet = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(droplet.surface_area(), 64);
+        assert_eq!(droplet.exterior_surface_area(), 58);
+    }
+}
@@ -0,0 +1,241 @@
+use std::io::BufRead;
+
+use anyhow::{ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Costs of each robot in one blueprint
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Blueprint {
+    pub id: u32,
+    pub ore_robot: u32,
+    pub clay_robot: u32,
+    /// Ore and clay
+    pub obsidian_robot: (u32, u32),
+    /// Ore and obsidian
+    pub geode_robot: (u32, u32),
+}
+
+/// Resources and robots partway through building
+#[derive(Copy, Clone, Debug, Default)]
+struct Stock {
+    minutes: u32,
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geodes: u32,
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+}
+
+impl Stock {
+    /// Waits until the robots have gathered `ore` and `other` of a second resource made by
+    /// `other_robots` holding `other_held`, then spends a minute building. `None` if that takes too
+    /// long or can never happen.
+    fn build(&self, ore: u32, other: u32, other_held: u32, other_robots: u32) -> Option<Self> {
+        let wait = |need: u32, held: u32, robots: u32| match need.saturating_sub(held) {
+            0 => Some(0),
+            _ if robots == 0 => None,
+            short => Some(short.div_ceil(robots)),
+        };
+
+        let minutes =
+            wait(ore, self.ore, self.ore_robots)?.max(wait(other, other_held, other_robots)?) + 1;
+
+        (minutes < self.minutes).then(|| Self {
+            minutes: self.minutes - minutes,
+            ore: self.ore + self.ore_robots * minutes - ore,
+            clay: self.clay + self.clay_robots * minutes,
+            obsidian: self.obsidian + self.obsidian_robots * minutes,
+            geodes: self.geodes + self.geode_robots * minutes,
+            ..*self
+        })
+    }
+}
+
+impl Blueprint {
+    /// Most geodes that can be opened in `minutes` starting with a single ore robot. Rather than
+    /// stepping a minute at a time, each branch picks the next robot to build and skips ahead to
+    /// when it is built. No more robots of a kind are built than can be spent in a minute, and
+    /// branches are cut once a new geode robot every minute would not beat the best so far.
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        let start = Stock {
+            minutes,
+            ore_robots: 1,
+            ..Default::default()
+        };
+        let mut best = 0;
+
+        self.search(start, &mut best);
+
+        best
+    }
+
+    fn search(&self, s: Stock, best: &mut u32) {
+        let idle = s.geodes + s.geode_robots * s.minutes;
+
+        *best = (*best).max(idle);
+
+        if idle + s.minutes * s.minutes.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        let max_ore = self
+            .ore_robot
+            .max(self.clay_robot)
+            .max(self.obsidian_robot.0)
+            .max(self.geode_robot.0);
+
+        if let Some(mut next) = s.build(
+            self.geode_robot.0,
+            self.geode_robot.1,
+            s.obsidian,
+            s.obsidian_robots,
+        ) {
+            next.obsidian -= self.geode_robot.1;
+            next.geode_robots += 1;
+            self.search(next, best);
+        }
+
+        if s.obsidian_robots < self.geode_robot.1 {
+            if let Some(mut next) = s.build(
+                self.obsidian_robot.0,
+                self.obsidian_robot.1,
+                s.clay,
+                s.clay_robots,
+            ) {
+                next.clay -= self.obsidian_robot.1;
+                next.obsidian_robots += 1;
+                self.search(next, best);
+            }
+        }
+
+        if s.clay_robots < self.obsidian_robot.1 {
+            if let Some(mut next) = s.build(self.clay_robot, 0, 0, 0) {
+                next.clay_robots += 1;
+                self.search(next, best);
+            }
+        }
+
+        if s.ore_robots < max_ore {
+            if let Some(mut next) = s.build(self.ore_robot, 0, 0, 0) {
+                next.ore_robots += 1;
+                self.search(next, best);
+            }
+        }
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Not Enough Minerals",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Blueprint>;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        // Blueprints may be wrapped over several lines, but always list their numbers in order
+        text.split("Blueprint")
+            .filter(|b| !b.trim().is_empty())
+            .enumerate()
+            .map(|(i, b)| {
+                let numbers = b
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter(|n| !n.is_empty())
+                    .map(str::parse)
+                    .collect::<Result<Vec<u32>, _>>()?;
+
+                let [id, ore_robot, clay_robot, obs_ore, obs_clay, geode_ore, geode_obs] =
+                    numbers[..]
+                else {
+                    return None
+                        .with_context(|| format!("blueprint {} should have 7 numbers", i + 1));
+                };
+
+                ensure!(
+                    ore_robot > 0 && clay_robot > 0,
+                    "robots should cost something"
+                );
+
+                Ok(Blueprint {
+                    id,
+                    ore_robot,
+                    clay_robot,
+                    obsidian_robot: (obs_ore, obs_clay),
+                    geode_robot: (geode_ore, geode_obs),
+                })
+            })
+            .collect()
+    }
+
+    /// Sum of each blueprint's quality level after 24 minutes
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|b| b.id * b.max_geodes(24))
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Product of the geodes opened by the first three blueprints after 32 minutes
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .take(3)
+            .map(|b| u64::from(b.max_geodes(32)))
+            .product::<u64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "Blueprint 1:
+  Each ore robot costs 4 ore.
+  Each clay robot costs 2 ore.
+  Each obsidian robot costs 3 ore and 14 clay.
+  Each geode robot costs 2 ore and 7 obsidian.
+
+Blueprint 2:
+  Each ore robot costs 2 ore.
+  Each clay robot costs 3 ore.
+  Each obsidian robot costs 3 ore and 8 clay.
+  Each geode robot costs 3 ore and 12 obsidian.";
+
+    #[test]
+    fn parse_blueprints() {
+        let blueprints = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(blueprints.len(), 2);
+        assert_eq!(blueprints[1].obsidian_robot, (3, 8));
+        assert!(Puzzle::parse("Blueprint 1: Each ore robot costs 4 ore.".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn geodes() {
+        let blueprints = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(blueprints[0].max_geodes(24), 9);
+        assert_eq!(blueprints[1].max_geodes(24), 12);
+        assert_eq!(Puzzle::part_01(&blueprints), Answer::Int(33));
+    }
+
+    #[test]
+    fn longer_collection() {
+        let blueprints = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(blueprints[0].max_geodes(32), 56);
+        assert_eq!(blueprints[1].max_geodes(32), 62);
+    }
+}
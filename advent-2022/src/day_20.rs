@@ -0,0 +1,122 @@
+use std::io::BufRead;
+
+use anyhow::{ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Key each number is multiplied by before mixing to decrypt it
+const DECRYPTION_KEY: i64 = 811589153;
+
+/// Mixes `numbers` `rounds` times, moving each number in its original order forward or back by
+/// its value around the circular list. Returns the mixed order as indices into `numbers`.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<usize> {
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+
+    // A number moving all the way around passes every other number, not itself
+    let others = numbers.len() as i64 - 1;
+
+    for _ in 0..rounds {
+        for (i, &n) in numbers.iter().enumerate() {
+            let from = order
+                .iter()
+                .position(|&o| o == i)
+                .expect("every index is in the order");
+
+            order.remove(from);
+
+            let to = (from as i64 + n).rem_euclid(others) as usize;
+
+            order.insert(to, i);
+        }
+    }
+
+    order
+}
+
+/// Sum of the numbers 1000, 2000, and 3000 places after the zero once mixed
+fn grove_coordinates(numbers: &[i64], key: i64, rounds: usize) -> i64 {
+    let numbers = numbers.iter().map(|n| n * key).collect::<Vec<_>>();
+    let mixed = mix(&numbers, rounds);
+    let zero = mixed
+        .iter()
+        .position(|&i| numbers[i] == 0)
+        .expect("parsing checks there is a zero");
+
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| numbers[mixed[(zero + offset) % mixed.len()]])
+        .sum()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Grove Positioning System",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// The encrypted numbers in their original order
+    type Input = Vec<i64>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let numbers = reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.trim()
+                    .parse()
+                    .with_context(|| format!("line {} is not a valid number", i + 1))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        ensure!(
+            numbers.iter().filter(|&&n| n == 0).count() == 1,
+            "there should be exactly one zero"
+        );
+        ensure!(
+            numbers.len() > 1,
+            "there should be a number to mix besides the zero"
+        );
+
+        Ok(numbers)
+    }
+
+    /// Sum of the grove coordinates after mixing once
+    fn part_01(input: &Self::Input) -> Answer {
+        grove_coordinates(input, 1, 1).into()
+    }
+
+    /// Sum of the grove coordinates after decrypting and mixing ten times
+    fn part_02(input: &Self::Input) -> Answer {
+        grove_coordinates(input, DECRYPTION_KEY, 10).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "1\n2\n-3\n3\n-2\n0\n4";
+
+    #[test]
+    fn mix_once() {
+        let numbers = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+        let mixed = mix(&numbers, 1)
+            .into_iter()
+            .map(|i| numbers[i])
+            .collect::<Vec<_>>();
+
+        // The same circle as the example, which starts from a different place
+        assert_eq!(mixed, vec![-2, 1, 2, -3, 4, 0, 3]);
+        assert_eq!(grove_coordinates(&numbers, 1, 1), 3);
+        assert!(Puzzle::parse("1\n2".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn decrypt() {
+        let numbers = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(grove_coordinates(&numbers, DECRYPTION_KEY, 10), 1623178306);
+    }
+}
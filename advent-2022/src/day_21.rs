@@ -0,0 +1,241 @@
+use std::{collections::HashMap, io::BufRead, str::FromStr};
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    fn apply(self, a: i64, b: i64) -> i64 {
+        match self {
+            Self::Add => a + b,
+            Self::Subtract => a - b,
+            Self::Multiply => a * b,
+            Self::Divide => a / b,
+        }
+    }
+}
+
+/// What a monkey yells, either a number or the result of an operation on two other monkeys
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Job {
+    Number(i64),
+    Operation(String, Operator, String),
+}
+
+impl FromStr for Job {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split_whitespace().collect::<Vec<_>>();
+
+        Ok(match parts[..] {
+            [n] => Self::Number(n.parse()?),
+            [a, op, b] => {
+                let op = match op {
+                    "+" => Operator::Add,
+                    "-" => Operator::Subtract,
+                    "*" => Operator::Multiply,
+                    "/" => Operator::Divide,
+                    _ => bail!("'{op}' is not an operator"),
+                };
+
+                Self::Operation(a.to_owned(), op, b.to_owned())
+            }
+            _ => bail!("'{s}' should be a number or an operation like 'abcd + efgh'"),
+        })
+    }
+}
+
+/// Every monkey's job by name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monkeys(HashMap<String, Job>);
+
+impl Monkeys {
+    /// Number yelled by `name`
+    pub fn yell(&self, name: &str) -> i64 {
+        match &self.0[name] {
+            Job::Number(n) => *n,
+            Job::Operation(a, op, b) => op.apply(self.yell(a), self.yell(b)),
+        }
+    }
+
+    fn depends_on_human(&self, name: &str) -> bool {
+        name == HUMAN
+            || match &self.0[name] {
+                Job::Number(_) => false,
+                Job::Operation(a, _, b) => self.depends_on_human(a) || self.depends_on_human(b),
+            }
+    }
+
+    /// Number the human has to yell for `name` to yell `target`. Every monkey's number is used
+    /// once, so only one side of each operation depends on the human and can be undone in turn.
+    fn solve(&self, name: &str, target: i64) -> Option<i64> {
+        if name == HUMAN {
+            return Some(target);
+        }
+
+        let Job::Operation(a, op, b) = &self.0[name] else {
+            return None;
+        };
+
+        if self.depends_on_human(a) {
+            let b = self.yell(b);
+            let a_target = match op {
+                Operator::Add => target - b,
+                Operator::Subtract => target + b,
+                Operator::Multiply => target / b,
+                Operator::Divide => target * b,
+            };
+
+            self.solve(a, a_target)
+        } else {
+            let a = self.yell(a);
+            let b_target = match op {
+                Operator::Add => target - a,
+                Operator::Subtract => a - target,
+                Operator::Multiply => target / a,
+                Operator::Divide => a / target,
+            };
+
+            self.solve(b, b_target)
+        }
+    }
+
+    /// Number the human has to yell for both of the root monkey's operands to be equal
+    pub fn human_number(&self) -> Option<i64> {
+        let Job::Operation(a, _, b) = &self.0[ROOT] else {
+            return None;
+        };
+
+        let (unknown, known) = if self.depends_on_human(a) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let human = self.solve(unknown, self.yell(known))?;
+
+        // Division may have rounded along the way, so make sure the answer actually works
+        let mut fixed = self.clone();
+
+        fixed.0.insert(HUMAN.to_owned(), Job::Number(human));
+
+        (fixed.yell(unknown) == fixed.yell(known)).then_some(human)
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Monkey Math",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Monkeys;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let jobs = reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let l = l?;
+                let (name, job) = l
+                    .split_once(": ")
+                    .with_context(|| format!("line {} should be 'name: job'", i + 1))?;
+
+                Ok((
+                    name.to_owned(),
+                    job.parse()
+                        .with_context(|| format!("line {} is not a valid job", i + 1))?,
+                ))
+            })
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+
+        for (name, job) in &jobs {
+            if let Job::Operation(a, _, b) = job {
+                ensure!(
+                    jobs.contains_key(a) && jobs.contains_key(b),
+                    "monkey {name} waits on a monkey that does not exist"
+                );
+            }
+        }
+
+        ensure!(
+            matches!(jobs.get(ROOT), Some(Job::Operation(..))),
+            "the root monkey should have an operation"
+        );
+        ensure!(jobs.contains_key(HUMAN), "there should be a human");
+
+        Ok(Monkeys(jobs))
+    }
+
+    /// Number the root monkey yells
+    fn part_01(input: &Self::Input) -> Answer {
+        input.yell(ROOT).into()
+    }
+
+    /// Number to yell so the root monkey's operands match
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .human_number()
+            .expect("some number should pass the root monkey's test")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    #[test]
+    fn yell() {
+        let monkeys = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(monkeys.yell(ROOT), 152);
+        assert_eq!(monkeys.yell("sjmn"), 150);
+        assert!(Puzzle::parse("root: abcd % efgh".as_bytes()).is_err());
+        assert!(Puzzle::parse("root: abcd + efgh\nhumn: 1".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn equality() {
+        let monkeys = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(monkeys.human_number(), Some(301));
+
+        // The human is on the right of a subtraction here
+        let flipped = TEST_DATA.replace("humn - dvpt", "dvpt - humn");
+
+        assert_eq!(
+            Puzzle::parse(flipped.as_bytes()).unwrap().human_number(),
+            Some(-295)
+        );
+    }
+}
@@ -0,0 +1,452 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io::BufRead,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// One instruction of the path to follow
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+/// Row and column changes for facing right, down, left, and up, in the order facings are scored
+const FACINGS: [(isize, isize); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+/// Where someone is on the map and which of [`FACINGS`] they are looking towards
+type Position = (usize, usize, usize);
+
+/// The map of open tiles and walls with the path to follow across it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonkeyMap {
+    /// Rows padded with spaces to the same width, where a space is off the map
+    tiles: Vec<Vec<u8>>,
+    path: Vec<Step>,
+}
+
+/// A 3D vector used to fold the map into a cube
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+struct Vec3(i32, i32, i32);
+
+impl Vec3 {
+    fn dot(self, other: Self) -> i32 {
+        self.0 * other.0 + self.1 * other.1 + self.2 * other.2
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Self;
+
+    fn add(self, o: Self) -> Self {
+        Self(self.0 + o.0, self.1 + o.1, self.2 + o.2)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Self;
+
+    fn sub(self, o: Self) -> Self {
+        Self(self.0 - o.0, self.1 - o.1, self.2 - o.2)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1, -self.2)
+    }
+}
+
+impl Mul<i32> for Vec3 {
+    type Output = Self;
+
+    fn mul(self, n: i32) -> Self {
+        Self(self.0 * n, self.1 * n, self.2 * n)
+    }
+}
+
+/// How one face of the net sits on the cube: the directions its columns and rows run in and the
+/// direction it faces outwards
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Face {
+    block: (usize, usize),
+    right: Vec3,
+    down: Vec3,
+    normal: Vec3,
+}
+
+impl Face {
+    /// Direction of each of [`FACINGS`] on this face
+    fn facing(&self, facing: usize) -> Vec3 {
+        [self.right, self.down, -self.right, -self.down][facing]
+    }
+
+    /// The face folded over the edge towards `facing`
+    fn fold(&self, facing: usize, block: (usize, usize)) -> Self {
+        let Self {
+            right,
+            down,
+            normal,
+            ..
+        } = *self;
+
+        let (right, down, normal) = match facing {
+            0 => (-normal, down, right),
+            1 => (right, -normal, down),
+            2 => (normal, down, -right),
+            _ => (right, normal, -down),
+        };
+
+        Self {
+            block,
+            right,
+            down,
+            normal,
+        }
+    }
+}
+
+/// Every face of the net placed on a cube with sides `size` tiles long, keyed by the block of
+/// the net they come from
+#[derive(Clone, Debug)]
+struct Cube {
+    size: usize,
+    faces: HashMap<(usize, usize), Face>,
+}
+
+impl Cube {
+    /// Where stepping off the edge of a face leads once folded into a cube. The face on the other
+    /// side is the one facing the direction of travel, and the tile is found by where the edge
+    /// sits in 3D.
+    fn wrap(&self, pos: Position) -> Position {
+        let (row, column, facing) = pos;
+        let size = self.size;
+        let edge = size as i32 - 1;
+        let from = self.faces[&(row / size, column / size)];
+        let dir = from.facing(facing);
+        let to = self
+            .faces
+            .values()
+            .find(|f| f.normal == dir)
+            .expect("every side of a cube has a face");
+
+        // Doubled coordinates so cell centres are whole numbers with the cube's centre at 0
+        let u = 2 * (column % size) as i32 - edge;
+        let v = 2 * (row % size) as i32 - edge;
+        let here = from.normal * size as i32 + from.right * u + from.down * v;
+        let there = here + dir - from.normal;
+
+        let column = ((there.dot(to.right) + edge) / 2) as usize;
+        let row = ((there.dot(to.down) + edge) / 2) as usize;
+        let facing = (0..4)
+            .find(|&f| to.facing(f) == -from.normal)
+            .expect("the edge is shared by both faces");
+
+        (to.block.0 * size + row, to.block.1 * size + column, facing)
+    }
+}
+
+impl MonkeyMap {
+    fn tile(&self, row: usize, column: usize) -> u8 {
+        self.tiles
+            .get(row)
+            .and_then(|r| r.get(column))
+            .copied()
+            .unwrap_or(b' ')
+    }
+
+    /// The tile one step from `(row, column)` towards `facing` if it is on the map
+    fn step(&self, (row, column, facing): Position) -> Option<(usize, usize)> {
+        let (dr, dc) = FACINGS[facing];
+        let (row, column) = (row.checked_add_signed(dr)?, column.checked_add_signed(dc)?);
+
+        (self.tile(row, column) != b' ').then_some((row, column))
+    }
+
+    /// Follows the path from the leftmost open tile of the top row, using `wrap` to find where
+    /// stepping off the map leads. Returns the final password.
+    fn walk(&self, wrap: impl Fn(Position) -> Position) -> usize {
+        let column = self.tiles[0]
+            .iter()
+            .position(|&t| t == b'.')
+            .expect("parsing checks the top row has an open tile");
+        let (mut row, mut column, mut facing) = (0, column, 0);
+
+        for step in &self.path {
+            match step {
+                Step::Left => facing = (facing + 3) % 4,
+                Step::Right => facing = (facing + 1) % 4,
+                Step::Forward(n) => {
+                    for _ in 0..*n {
+                        let next = match self.step((row, column, facing)) {
+                            Some((r, c)) => (r, c, facing),
+                            None => wrap((row, column, facing)),
+                        };
+
+                        if self.tile(next.0, next.1) == b'#' {
+                            break;
+                        }
+
+                        (row, column, facing) = next;
+                    }
+                }
+            }
+        }
+
+        1000 * (row + 1) + 4 * (column + 1) + facing
+    }
+
+    /// Password when stepping off an edge comes back on the opposite edge of the same row or column
+    pub fn flat_password(&self) -> usize {
+        self.walk(|(mut row, mut column, facing)| {
+            let back = (facing + 2) % 4;
+
+            while let Some((r, c)) = self.step((row, column, back)) {
+                (row, column) = (r, c);
+            }
+
+            (row, column, facing)
+        })
+    }
+
+    /// Password when the map is folded into a cube
+    pub fn cube_password(&self) -> usize {
+        let cube = self.fold_cube();
+
+        self.walk(|pos| cube.wrap(pos))
+    }
+
+    /// Length of each side of the cube, from its surface area
+    fn face_size(&self) -> usize {
+        let area = self.tiles.iter().flatten().filter(|&&t| t != b' ').count();
+
+        ((area / 6) as f64).sqrt().round() as usize
+    }
+
+    /// Places every block of the net on a cube by walking across the net from the first block
+    fn fold_cube(&self) -> Cube {
+        let size = self.face_size();
+        let first = self.tiles[0]
+            .iter()
+            .position(|&t| t != b' ')
+            .expect("parsing checks the top row has an open tile")
+            / size;
+
+        let start = Face {
+            block: (0, first),
+            right: Vec3(1, 0, 0),
+            down: Vec3(0, 1, 0),
+            normal: Vec3(0, 0, -1),
+        };
+
+        let mut faces = HashMap::from([(start.block, start)]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(face) = queue.pop_front() {
+            let (br, bc) = face.block;
+
+            for (facing, (dr, dc)) in FACINGS.into_iter().enumerate() {
+                let (Some(r), Some(c)) = (br.checked_add_signed(dr), bc.checked_add_signed(dc))
+                else {
+                    continue;
+                };
+
+                if self.tile(r * size, c * size) != b' ' && !faces.contains_key(&(r, c)) {
+                    let next = face.fold(facing, (r, c));
+
+                    faces.insert((r, c), next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Cube { size, faces }
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Monkey Map",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = MonkeyMap;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut lines = reader.lines();
+        let mut tiles = Vec::new();
+
+        for l in &mut lines {
+            let l = l?;
+
+            if l.trim().is_empty() {
+                break;
+            }
+
+            ensure!(
+                l.bytes().all(|b| matches!(b, b' ' | b'.' | b'#')),
+                "map row {} should only hold spaces, '.', and '#'",
+                tiles.len() + 1
+            );
+
+            tiles.push(l.trim_end().as_bytes().to_vec());
+        }
+
+        let width = tiles.iter().map(Vec::len).max().unwrap_or(0);
+
+        for row in &mut tiles {
+            row.resize(width, b' ');
+        }
+
+        ensure!(
+            tiles.first().is_some_and(|r| r.contains(&b'.')),
+            "the top row of the map should have an open tile"
+        );
+
+        let path_line = lines
+            .next()
+            .context("the map should be followed by a path")??;
+        let mut path = Vec::new();
+        let mut rest = path_line.trim();
+
+        while !rest.is_empty() {
+            let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+
+            if digits > 0 {
+                path.push(Step::Forward(rest[..digits].parse()?));
+                rest = &rest[digits..];
+                continue;
+            }
+
+            path.push(match rest.as_bytes()[0] {
+                b'L' => Step::Left,
+                b'R' => Step::Right,
+                _ => bail!("'{rest}' should continue with a number, 'L', or 'R'"),
+            });
+            rest = &rest[1..];
+        }
+
+        let map = MonkeyMap { tiles, path };
+        let size = map.face_size();
+        let area = map.tiles.iter().flatten().filter(|&&t| t != b' ').count();
+
+        ensure!(
+            size > 0 && area == 6 * size * size,
+            "the map should be the net of a cube"
+        );
+
+        let cube = map.fold_cube();
+        let normals = cube
+            .faces
+            .values()
+            .map(|f| f.normal)
+            .collect::<HashSet<_>>();
+
+        ensure!(normals.len() == 6, "the map should fold into a cube");
+
+        Ok(map)
+    }
+
+    /// Password after following the path across the flat map
+    fn part_01(input: &Self::Input) -> Answer {
+        input.flat_password().into()
+    }
+
+    /// Password after following the path around the cube
+    fn part_02(input: &Self::Input) -> Answer {
+        input.cube_password().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    #[test]
+    fn parse_map() {
+        let map = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(map.tiles.len(), 12);
+        assert!(map.tiles.iter().all(|r| r.len() == 16));
+        assert_eq!(
+            map.path[..3],
+            [Step::Forward(10), Step::Right, Step::Forward(5)]
+        );
+        assert_eq!(map.face_size(), 4);
+        assert!(Puzzle::parse("..\n\n10X".as_bytes()).is_err());
+        // Six faces in a row is not a net of a cube
+        assert!(Puzzle::parse("......\n\n10".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn flat() {
+        let map = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(map.flat_password(), 6032);
+    }
+
+    #[test]
+    fn cube() {
+        let map = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+        let cube = map.fold_cube();
+
+        assert_eq!(cube.faces.len(), 6);
+        assert_eq!(map.cube_password(), 5031);
+    }
+
+    #[test]
+    fn cube_round_trip() {
+        // Stepping off any edge and turning around leads straight back to the same tile
+        let map = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+        let cube = map.fold_cube();
+        let size = cube.size;
+        let mut wrapped = 0;
+
+        for &(br, bc) in cube.faces.keys() {
+            for i in 0..size {
+                let (top, left) = (br * size, bc * size);
+                let edges = [
+                    (top + i, left + size - 1, 0),
+                    (top + size - 1, left + i, 1),
+                    (top + i, left, 2),
+                    (top, left + i, 3),
+                ];
+
+                for pos in edges.into_iter().filter(|&p| map.step(p).is_none()) {
+                    let (r, c, f) = cube.wrap(pos);
+                    let (r, c, f) = cube.wrap((r, c, (f + 2) % 4));
+
+                    assert_eq!((r, c, (f + 2) % 4), pos);
+                    wrapped += 1;
+                }
+            }
+        }
+
+        // Seven of the cube's twelve edges are cut when it is unfolded, each with two sides
+        assert_eq!(wrapped, 7 * 2 * size);
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+use anyhow::bail;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+type Elf = (i32, i32);
+
+/// Directions elves consider moving in as `(dx, dy)` with north being negative `y`, each with the
+/// three neighbours that must be empty to move that way
+const PROPOSALS: [[(i32, i32); 3]; 4] = [
+    [(0, -1), (-1, -1), (1, -1)],
+    [(0, 1), (-1, 1), (1, 1)],
+    [(-1, 0), (-1, -1), (-1, 1)],
+    [(1, 0), (1, -1), (1, 1)],
+];
+
+/// Positions of every elf as they spread out to plant star fruit
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grove {
+    elves: HashSet<Elf>,
+    /// Index into [`PROPOSALS`] of the first direction considered next round
+    first: usize,
+}
+
+impl Grove {
+    /// Runs a single round, returning whether any elf moved
+    fn round(&mut self) -> bool {
+        let mut proposed = HashMap::<Elf, Vec<Elf>>::new();
+
+        for &(x, y) in &self.elves {
+            let occupied = |(dx, dy): (i32, i32)| self.elves.contains(&(x + dx, y + dy));
+            let crowded = (-1..=1)
+                .flat_map(|dx| (-1..=1).map(move |dy| (dx, dy)))
+                .any(|d| d != (0, 0) && occupied(d));
+
+            if !crowded {
+                continue;
+            }
+
+            let choice = (0..4)
+                .map(|i| PROPOSALS[(self.first + i) % 4])
+                .find(|checks| !checks.iter().any(|&d| occupied(d)));
+
+            if let Some([(dx, dy), ..]) = choice {
+                proposed.entry((x + dx, y + dy)).or_default().push((x, y));
+            }
+        }
+
+        self.first = (self.first + 1) % 4;
+
+        let mut moved = false;
+
+        for (to, from) in proposed {
+            if let [from] = from[..] {
+                self.elves.remove(&from);
+                self.elves.insert(to);
+                moved = true;
+            }
+        }
+
+        moved
+    }
+
+    /// Empty tiles within the smallest rectangle holding every elf
+    pub fn empty_ground(&self) -> usize {
+        let (xs, ys): (Vec<_>, Vec<_>) = self.elves.iter().copied().unzip();
+        let width = xs
+            .iter()
+            .max()
+            .zip(xs.iter().min())
+            .map_or(0, |(h, l)| h - l + 1);
+        let height = ys
+            .iter()
+            .max()
+            .zip(ys.iter().min())
+            .map_or(0, |(h, l)| h - l + 1);
+
+        (width * height) as usize - self.elves.len()
+    }
+
+    /// Empty ground after `rounds` rounds
+    pub fn empty_after(&self, rounds: usize) -> usize {
+        let mut grove = self.clone();
+
+        for _ in 0..rounds {
+            grove.round();
+        }
+
+        grove.empty_ground()
+    }
+
+    /// First round in which no elf moves
+    pub fn settled_round(&self) -> usize {
+        let mut grove = self.clone();
+
+        (1..)
+            .find(|_| !grove.round())
+            .expect("rounds never run out")
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Unstable Diffusion",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Grove;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut elves = HashSet::new();
+
+        for (y, l) in reader.lines().enumerate() {
+            for (x, c) in l?.chars().enumerate() {
+                match c {
+                    '#' => {
+                        elves.insert((x as i32, y as i32));
+                    }
+                    '.' => {}
+                    _ => bail!("'{c}' on line {} is neither an elf nor ground", y + 1),
+                }
+            }
+        }
+
+        Ok(Grove { elves, first: 0 })
+    }
+
+    /// Empty ground after ten rounds
+    fn part_01(input: &Self::Input) -> Answer {
+        input.empty_after(10).into()
+    }
+
+    /// First round where no elf moves
+    fn part_02(input: &Self::Input) -> Answer {
+        input.settled_round().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn small_example() {
+        let mut grove =
+            Puzzle::parse(".....\n..##.\n..#..\n.....\n..##.\n.....".as_bytes()).unwrap();
+
+        // The elves stop needing to move after the third round
+        assert_eq!(grove.settled_round(), 4);
+        assert!(grove.round());
+        assert_eq!(
+            grove.elves,
+            HashSet::from([(2, 0), (3, 0), (2, 2), (3, 3), (2, 4)])
+        );
+        assert!(Puzzle::parse("..E".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn spread_out() {
+        let grove = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(grove.empty_after(10), 110);
+        assert_eq!(grove.settled_round(), 20);
+    }
+}
@@ -0,0 +1,214 @@
+use std::{collections::HashSet, io::BufRead};
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// A row and column where `(0, 0)` is the top left of the valley inside its walls. The entrance
+/// is in row -1 and the exit in the row below the last.
+type Position = (i32, i32);
+
+/// The valley inside the walls with the blizzard directions at the start
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Valley {
+    width: i32,
+    height: i32,
+    /// Row-major blizzards as `<`, `>`, `^`, `v`, or `.` for none
+    blizzards: Vec<u8>,
+    entrance: Position,
+    exit: Position,
+}
+
+impl Valley {
+    fn blizzard(&self, row: i32, column: i32) -> u8 {
+        self.blizzards[(row * self.width + column) as usize]
+    }
+
+    /// Whether `(row, column)` is free of blizzards at `minute`. Blizzards move in straight lines
+    /// and wrap around, so the ones that could be here started a known distance away.
+    fn clear(&self, (row, column): Position, minute: i32) -> bool {
+        if (row, column) == self.entrance || (row, column) == self.exit {
+            return true;
+        }
+
+        if !(0..self.height).contains(&row) || !(0..self.width).contains(&column) {
+            return false;
+        }
+
+        let (w, h) = (self.width, self.height);
+
+        self.blizzard(row, (column - minute).rem_euclid(w)) != b'>'
+            && self.blizzard(row, (column + minute).rem_euclid(w)) != b'<'
+            && self.blizzard((row - minute).rem_euclid(h), column) != b'v'
+            && self.blizzard((row + minute).rem_euclid(h), column) != b'^'
+    }
+
+    /// Minute of arriving at `to` when leaving `from` at `minute`, keeping track of every position
+    /// that could be reached by each minute
+    fn crossing(&self, from: Position, to: Position, minute: i32) -> Option<i32> {
+        let mut reachable = HashSet::from([from]);
+        let mut last_cycle = HashSet::new();
+
+        // The blizzards are back where they started after this many minutes. Waiting at `from` is
+        // always safe, so each cycle reaches at least what the one before did, and once a cycle
+        // adds nothing new `to` can never be reached.
+        let period = self.width * self.height;
+
+        for minute in minute + 1.. {
+            if (minute - 1) % period == 0 {
+                if reachable == last_cycle {
+                    return None;
+                }
+
+                last_cycle = reachable.clone();
+            }
+
+            reachable = reachable
+                .iter()
+                .flat_map(|&(r, c)| [(r, c), (r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)])
+                .filter(|&p| self.clear(p, minute))
+                .collect();
+
+            if reachable.contains(&to) {
+                return Some(minute);
+            }
+        }
+
+        unreachable!("minutes never run out")
+    }
+
+    /// Fewest minutes to reach the exit
+    pub fn fastest_crossing(&self) -> Option<i32> {
+        self.crossing(self.entrance, self.exit, 0)
+    }
+
+    /// Fewest minutes to reach the exit, go back for the snacks, and reach the exit again
+    pub fn snack_run(&self) -> Option<i32> {
+        let there = self.crossing(self.entrance, self.exit, 0)?;
+        let back = self.crossing(self.exit, self.entrance, there)?;
+
+        self.crossing(self.entrance, self.exit, back)
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Blizzard Basin",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Valley;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+
+        ensure!(
+            lines.len() > 2,
+            "the valley should have walls above and below"
+        );
+
+        let gap = |line: &str| {
+            let gaps = line.match_indices('.').map(|(i, _)| i).collect::<Vec<_>>();
+
+            match gaps[..] {
+                [i] if i > 0 => Ok(i as i32 - 1),
+                _ => bail!("'{line}' should be a wall with a single gap"),
+            }
+        };
+
+        let entrance = (-1, gap(&lines[0])?);
+        let inner = &lines[1..lines.len() - 1];
+        let height = inner.len() as i32;
+        let exit = (height, gap(&lines[lines.len() - 1])?);
+        let width = lines[0].len() as i32 - 2;
+
+        let mut blizzards = Vec::new();
+
+        for (i, l) in inner.iter().enumerate() {
+            let row = l
+                .strip_prefix('#')
+                .and_then(|l| l.strip_suffix('#'))
+                .with_context(|| format!("row {} should have walls on both sides", i + 2))?;
+
+            ensure!(
+                row.len() as i32 == width,
+                "row {} is the wrong width",
+                i + 2
+            );
+            ensure!(
+                row.bytes().all(|b| b".<>^v".contains(&b)),
+                "row {} should only hold ground and blizzards",
+                i + 2
+            );
+
+            blizzards.extend(row.bytes());
+        }
+
+        ensure!(
+            entrance.1 < width && exit.1 < width,
+            "the entrance and exit should be inside the valley"
+        );
+
+        Ok(Valley {
+            width,
+            height,
+            blizzards,
+            entrance,
+            exit,
+        })
+    }
+
+    /// Fewest minutes to reach the goal
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .fastest_crossing()
+            .expect("the exit should be reachable")
+            .into()
+    }
+
+    /// Fewest minutes to reach the goal, go back, and reach it again
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .snack_run()
+            .expect("the exit should be reachable")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn blizzards() {
+        let valley = Puzzle::parse(
+            "#.#####\n#.....#\n#>....#\n#.....#\n#...v.#\n#.....#\n#####.#".as_bytes(),
+        )
+        .unwrap();
+
+        assert!(!valley.clear((1, 0), 0));
+        assert!(!valley.clear((1, 3), 3));
+        assert!(!valley.clear((1, 0), 5));
+        // The downward blizzard wraps back to the top
+        assert!(!valley.clear((0, 3), 2));
+        assert!(valley.clear((3, 3), 1));
+        assert!(Puzzle::parse("#.##\n#.x#\n##.#".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn crossing() {
+        let valley = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(valley.fastest_crossing(), Some(18));
+        assert_eq!(valley.snack_run(), Some(54));
+    }
+}
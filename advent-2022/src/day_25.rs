@@ -0,0 +1,161 @@
+use std::{fmt, io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use thiserror::Error;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SnafuParseError {
+    #[error("SNAFU numbers should have at least one digit")]
+    Empty,
+    #[error("'{0}' is not a SNAFU digit")]
+    InvalidDigit(char),
+}
+
+/// A number written in base five with digits from -2 to 2, where `-` is -1 and `=` is -2
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Snafu(pub i64);
+
+impl FromStr for Snafu {
+    type Err = SnafuParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(SnafuParseError::Empty);
+        }
+
+        s.chars()
+            .try_fold(0, |n, c| {
+                let digit = match c {
+                    '2' => 2,
+                    '1' => 1,
+                    '0' => 0,
+                    '-' => -1,
+                    '=' => -2,
+                    _ => return Err(SnafuParseError::InvalidDigit(c)),
+                };
+
+                Ok(n * 5 + digit)
+            })
+            .map(Self)
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut n = self.0;
+        let mut digits = Vec::new();
+
+        loop {
+            // Shifting by two turns the remainder from 0..5 into a digit from -2 to 2
+            let d = (n + 2).rem_euclid(5) - 2;
+
+            digits.push(match d {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                _ => '=',
+            });
+
+            n = (n - d) / 5;
+
+            if n == 0 {
+                break;
+            }
+        }
+
+        f.write_str(&digits.iter().rev().collect::<String>())
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Full of Hot Air",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Fuel requirements of each hot air balloon
+    type Input = Vec<Snafu>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid SNAFU number", i + 1))
+            })
+            .collect()
+    }
+
+    /// Total fuel written as a SNAFU number for Bob's console
+    fn part_01(input: &Self::Input) -> Answer {
+        Snafu(input.iter().map(|s| s.0).sum()).to_string().into()
+    }
+
+    /// Day 25 only has one puzzle
+    fn part_02(_input: &Self::Input) -> Answer {
+        "Merry Christmas!".into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    #[test]
+    fn conversions() {
+        let table = [
+            (1, "1"),
+            (2, "2"),
+            (3, "1="),
+            (4, "1-"),
+            (5, "10"),
+            (8, "2="),
+            (10, "20"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+            (-3, "-2"),
+        ];
+
+        for (n, s) in table {
+            assert_eq!(Snafu(n).to_string(), s);
+            assert_eq!(s.parse::<Snafu>(), Ok(Snafu(n)));
+        }
+
+        assert_eq!("".parse::<Snafu>(), Err(SnafuParseError::Empty));
+        assert_eq!(
+            "1+".parse::<Snafu>(),
+            Err(SnafuParseError::InvalidDigit('+'))
+        );
+    }
+
+    #[test]
+    fn fuel() {
+        let numbers = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(numbers.iter().map(|s| s.0).sum::<i64>(), 4890);
+        assert_eq!(Puzzle::part_01(&numbers), Answer::from("2=-1=0"));
+    }
+}
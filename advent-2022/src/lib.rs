@@ -0,0 +1,54 @@
+//! Solutions to the 2022 puzzles. Each day module is public so a single day can be parsed and
+//! solved directly, as the benchmarks and integration tests do.
+
+use advent_macros::{generate_missing_test_stubs, generate_year};
+
+use advent_core::day::solve;
+use advent_core::explore::{explore_day, ExplorerFn};
+use advent_core::parsed_cache::{solve_both_cached, CachedDayFn};
+use advent_core::year::AlternativeFn;
+
+generate_year!(pub 2022 25;
+    alternatives = ALTERNATIVES,
+    explorable = EXPLORABLE,
+    parse_cached = PARSE_CACHED
+);
+generate_missing_test_stubs!(2022);
+
+/// Days of this year whose parsed input is worth keeping between runs
+pub const PARSE_CACHED: &[(i32, CachedDayFn)] = &[(8, solve_both_cached::<day_08::Puzzle>)];
+
+/// Days of this year whose parsed input can be explored from a command loop
+pub const EXPLORABLE: &[(i32, ExplorerFn)] =
+    &[(7, explore_day::<day_07::Puzzle>), (8, day_08::explore)];
+
+/// Other implementations of days in this year, selectable by name
+pub const ALTERNATIVES: &[(i32, &str, AlternativeFn)] = &[
+    (1, "fast", solve::<day_01::Fast>),
+    (6, "readable", solve::<day_06::Readable>),
+];
+
+pub use advent_core::util::grid::TreeMap;
+pub use advent_core::util::interval::SectionRange;
+pub use day_01::Elf;
+pub use day_02::{HandShape, RoundOutcome, Score};
+pub use day_03::{Item, ItemSet};
+pub use day_05::{StackMove, Stacks};
+pub use day_07::{
+    Command, Commands, DirectorySizer, FileSystem, FileSystemEntry, FileSystemVisitor, TerminalLine,
+};
+pub use day_08::{ScenicMap, TreeVisibility, VisibilityMap};
+pub use day_09::RopeMove;
+pub use day_10::CpuInstruction;
+pub use day_11::Monkey;
+pub use day_12::HeightMap;
+pub use day_13::Packet;
+pub use day_15::Sensor;
+pub use day_16::Volcano;
+pub use day_18::Droplet;
+pub use day_19::Blueprint;
+pub use day_21::Monkeys;
+pub use day_22::MonkeyMap;
+pub use day_23::Grove;
+pub use day_24::Valley;
+pub use day_25::Snafu;
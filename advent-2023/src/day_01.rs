@@ -0,0 +1,177 @@
+use std::{io::BufRead, sync::OnceLock};
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::{
+        matcher::{Matcher, State},
+        par_lines::par_lines_str,
+    },
+};
+
+/// Spelled-out numbers in [1, 9], where each word's index is one less than its value
+const NUMBER_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Matcher for `NUMBER_WORDS` built once and shared by every line
+fn number_words() -> &'static Matcher {
+    static MATCHER: OnceLock<Matcher> = OnceLock::new();
+
+    MATCHER.get_or_init(|| Matcher::new(NUMBER_WORDS))
+}
+
+/// Calibration value of a line, at most 99, so totals over any number of lines fit in a `u64`
+fn calibration_numerals(line: &str) -> u64 {
+    let mut nums = line.bytes().filter_map(|b| match b {
+        b'0'..=b'9' => Some(u64::from(b - b'0')),
+        _ => None,
+    });
+
+    let first_num = nums.next().unwrap_or(0);
+    let second_num = nums.next_back().unwrap_or(first_num);
+
+    first_num * 10 + second_num
+}
+
+fn calibration_numbers(line: &str) -> u64 {
+    let mut first_num = None::<u64>;
+    let mut second_num = None::<u64>;
+
+    let matcher = number_words();
+    let mut state = State::default();
+
+    for b in line.bytes() {
+        let words = matcher.step(&mut state, b);
+
+        let num = match b {
+            b'0'..=b'9' => Some(u64::from(b - b'0')),
+            _ => words.last().map(|&w| w as u64 + 1),
+        };
+
+        if let Some(num) = num {
+            if first_num.is_none() {
+                first_num = Some(num);
+            }
+
+            second_num = Some(num);
+        }
+    }
+
+    first_num.unwrap_or(0) * 10 + second_num.unwrap_or(0)
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Trebuchet?!",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Every line is calibrated on its own, so the lines are kept together and summed in parallel
+    type Input = String;
+
+    fn parse(mut reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut text = String::new();
+
+        reader.read_to_string(&mut text)?;
+
+        Ok(text)
+    }
+
+    /// Calibration total using only numerals
+    fn part_01(input: &Self::Input) -> Answer {
+        par_lines_str(input, || 0, calibration_numerals, |a, b| a + b).into()
+    }
+
+    /// Calibration total using numerals and spelled out numbers
+    fn part_02(input: &Self::Input) -> Answer {
+        par_lines_str(input, || 0, calibration_numbers, |a, b| a + b).into()
+    }
+
+    const STREAMING: bool = true;
+
+    fn part_01_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut total = 0;
+
+        for l in reader.lines() {
+            total += calibration_numerals(&l?);
+        }
+
+        Ok(total.into())
+    }
+
+    fn part_02_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+        let mut total = 0;
+
+        for l in reader.lines() {
+            total += calibration_numbers(&l?);
+        }
+
+        Ok(total.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufReader;
+
+    use super::*;
+
+    #[test]
+    fn extract_numerals() {
+        let input = r"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+        let vals = BufReader::new(input.as_bytes())
+            .lines()
+            .filter_map(|l| l.ok().as_deref().map(calibration_numerals))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vals, vec![12, 38, 15, 77]);
+    }
+
+    #[test]
+    fn extract_numbers() {
+        let input = r"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+        let vals = BufReader::new(input.as_bytes())
+            .lines()
+            .filter_map(|l| l.ok().as_deref().map(calibration_numbers))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vals, vec![29, 83, 13, 24, 42, 14, 76]);
+    }
+
+    #[test]
+    fn overlapping_words() {
+        let cases = [
+            ("oneight", 18),
+            ("twone", 21),
+            ("threeight", 38),
+            ("fiveight", 58),
+            ("sevenine", 79),
+            ("eightwo", 82),
+            ("eighthree", 83),
+            ("nineight", 98),
+            ("ninine", 99),
+            ("sevseven", 77),
+            ("oneightwoneight", 18),
+            ("xyz", 0),
+        ];
+
+        for (line, expected) in cases {
+            assert_eq!(calibration_numbers(line), expected, "{line}");
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::Context;
+use thiserror::Error;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GameParseError {
+    #[error("game should be of the format 'Game ID: draws'")]
+    MissingDraws,
+    #[error("'{0}' is not a valid game ID")]
+    InvalidId(String),
+    #[error("draw '{0}' should be of the format '# color'")]
+    InvalidDraw(String),
+    #[error("'{0}' is not one of red, green, or blue")]
+    UnknownColor(String),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FromStr for Color {
+    type Err = GameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            "blue" => Ok(Self::Blue),
+            _ => Err(GameParseError::UnknownColor(s.to_owned())),
+        }
+    }
+}
+
+/// A number of cubes of one color pulled from the bag, written as `3 blue`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Draw {
+    pub count: u32,
+    pub color: Color,
+}
+
+impl FromStr for Draw {
+    type Err = GameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || GameParseError::InvalidDraw(s.to_owned());
+        let (count, color) = s.split_once(' ').ok_or_else(invalid)?;
+
+        Ok(Self {
+            count: count.parse().map_err(|_| invalid())?,
+            color: color.parse()?,
+        })
+    }
+}
+
+/// Number of cubes of each color, such as those shown together in one handful
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CubeSet {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl CubeSet {
+    pub fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// The most cubes of each color between the two sets
+    pub fn max(self, other: Self) -> Self {
+        Self::new(
+            self.red.max(other.red),
+            self.green.max(other.green),
+            self.blue.max(other.blue),
+        )
+    }
+
+    /// Product of the cube counts
+    pub fn power(self) -> u64 {
+        u64::from(self.red) * u64::from(self.green) * u64::from(self.blue)
+    }
+
+    /// Whether a bag holding `bag` could have produced this set
+    pub fn fits_within(self, bag: Self) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+}
+
+/// Draws of the same color are added together
+impl FromIterator<Draw> for CubeSet {
+    fn from_iter<T: IntoIterator<Item = Draw>>(iter: T) -> Self {
+        let mut set = Self::default();
+
+        for draw in iter {
+            match draw.color {
+                Color::Red => set.red += draw.count,
+                Color::Green => set.green += draw.count,
+                Color::Blue => set.blue += draw.count,
+            }
+        }
+
+        set
+    }
+}
+
+/// A handful of comma separated draws such as `3 blue, 4 red`
+impl FromStr for CubeSet {
+    type Err = GameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',').map(str::parse::<Draw>).collect()
+    }
+}
+
+/// Every handful of cubes revealed during one game
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub handfuls: Vec<CubeSet>,
+}
+
+impl Game {
+    /// Fewest cubes of each color that could have been in the bag
+    pub fn max_per_color(&self) -> CubeSet {
+        self.handfuls
+            .iter()
+            .fold(CubeSet::default(), |a, &h| a.max(h))
+    }
+
+    /// Power of the fewest cubes that could have been in the bag
+    pub fn power(&self) -> u64 {
+        self.max_per_color().power()
+    }
+
+    /// Whether every handful could have been drawn from `bag`
+    pub fn possible_with(&self, bag: CubeSet) -> bool {
+        self.handfuls.iter().all(|h| h.fits_within(bag))
+    }
+}
+
+/// Games are written as `Game ID: ` followed by handfuls separated by `;`
+impl FromStr for Game {
+    type Err = GameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, handfuls) = s.split_once(':').ok_or(GameParseError::MissingDraws)?;
+
+        let id = game
+            .strip_prefix("Game ")
+            .and_then(|id| id.parse().ok())
+            .ok_or_else(|| GameParseError::InvalidId(game.to_owned()))?;
+
+        Ok(Self {
+            id,
+            handfuls: handfuls
+                .split(';')
+                .map(str::parse)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Cube Conundrum",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Game>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse::<Game>()
+                    .with_context(|| format!("line {} is not a valid game", i + 1))
+            })
+            .collect()
+    }
+
+    /// Sum of IDs for games possible with 12 red, 13 green, and 14 blue cubes
+    fn part_01(input: &Self::Input) -> Answer {
+        let bag = CubeSet::new(12, 13, 14);
+
+        input
+            .iter()
+            .filter(|g| g.possible_with(bag))
+            .map(|g| g.id)
+            .sum::<u32>()
+            .into()
+    }
+
+    /// Sum of the powers of the minimum set of cubes for each game
+    fn part_02(input: &Self::Input) -> Answer {
+        input.iter().map(Game::power).sum::<u64>().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    fn games() -> Vec<Game> {
+        Puzzle::parse(EXAMPLE.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn parse_games() {
+        assert_eq!(
+            "Game 12: 3 blue, 4 red; 2 green".parse(),
+            Ok(Game {
+                id: 12,
+                handfuls: vec![CubeSet::new(4, 0, 3), CubeSet::new(0, 2, 0)],
+            })
+        );
+
+        assert_eq!(
+            "Game 1 3 blue".parse::<Game>(),
+            Err(GameParseError::MissingDraws)
+        );
+        assert_eq!(
+            "Round 1: 3 blue".parse::<Game>(),
+            Err(GameParseError::InvalidId("Round 1".into()))
+        );
+        assert_eq!(
+            "Game 1: 3blue".parse::<Game>(),
+            Err(GameParseError::InvalidDraw("3blue".into()))
+        );
+        assert_eq!(
+            "Game 1: 3 pink".parse::<Game>(),
+            Err(GameParseError::UnknownColor("pink".into()))
+        );
+    }
+
+    #[test]
+    fn possible_games() {
+        let bag = CubeSet::new(12, 13, 14);
+
+        let possible = games()
+            .iter()
+            .filter(|g| g.possible_with(bag))
+            .map(|g| g.id)
+            .collect::<Vec<_>>();
+
+        assert_eq!(possible, vec![1, 2, 5]);
+    }
+
+    #[test]
+    fn power_of_games() {
+        let games = games();
+
+        assert_eq!(games[0].max_per_color(), CubeSet::new(4, 2, 6));
+
+        let powers = games.iter().map(Game::power).collect::<Vec<_>>();
+
+        assert_eq!(powers, vec![48, 12, 1560, 630, 36]);
+    }
+}
@@ -0,0 +1,147 @@
+use std::io::BufRead;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// A number in the schematic and the columns it spans on its row, both inclusive
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SchematicNumber {
+    pub value: u32,
+    pub row: usize,
+    pub columns: (usize, usize),
+}
+
+impl SchematicNumber {
+    /// Whether `(row, column)` touches the number, diagonals included
+    fn adjacent(&self, row: usize, column: usize) -> bool {
+        row + 1 >= self.row
+            && row <= self.row + 1
+            && column + 1 >= self.columns.0
+            && column <= self.columns.1 + 1
+    }
+}
+
+/// Every number and symbol in an engine schematic, ignoring the periods between them
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Schematic {
+    pub numbers: Vec<SchematicNumber>,
+    /// Row, column, and the symbol itself
+    pub symbols: Vec<(usize, usize, char)>,
+}
+
+impl Schematic {
+    /// Numbers next to at least one symbol
+    pub fn part_numbers(&self) -> impl Iterator<Item = &SchematicNumber> {
+        self.numbers.iter().filter(|n| {
+            self.symbols
+                .iter()
+                .any(|&(row, column, _)| n.adjacent(row, column))
+        })
+    }
+
+    /// Product of the two numbers next to each `*` that touches exactly two numbers
+    pub fn gear_ratios(&self) -> impl Iterator<Item = u64> + '_ {
+        self.symbols
+            .iter()
+            .filter(|&&(_, _, s)| s == '*')
+            .filter_map(|&(row, column, _)| {
+                let mut near = self.numbers.iter().filter(|n| n.adjacent(row, column));
+
+                match (near.next(), near.next(), near.next()) {
+                    (Some(a), Some(b), None) => Some(u64::from(a.value) * u64::from(b.value)),
+                    _ => None,
+                }
+            })
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Gear Ratios",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Schematic;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut schematic = Schematic::default();
+
+        for (row, l) in reader.lines().enumerate() {
+            let l = l?;
+            let mut chars = l.char_indices().peekable();
+
+            while let Some((column, c)) = chars.next() {
+                if let Some(mut value) = c.to_digit(10) {
+                    let mut end = column;
+
+                    while let Some(d) = chars.peek().and_then(|&(_, c)| c.to_digit(10)) {
+                        value = value * 10 + d;
+                        end += 1;
+                        chars.next();
+                    }
+
+                    schematic.numbers.push(SchematicNumber {
+                        value,
+                        row,
+                        columns: (column, end),
+                    });
+                } else if c != '.' && !c.is_whitespace() {
+                    schematic.symbols.push((row, column, c));
+                }
+            }
+        }
+
+        Ok(schematic)
+    }
+
+    /// Sum of every part number
+    fn part_01(input: &Self::Input) -> Answer {
+        input.part_numbers().map(|n| n.value).sum::<u32>().into()
+    }
+
+    /// Sum of every gear ratio
+    fn part_02(input: &Self::Input) -> Answer {
+        input.gear_ratios().sum::<u64>().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    #[test]
+    fn part_numbers() {
+        let schematic = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.numbers[1].columns, (5, 7));
+        assert_eq!(schematic.part_numbers().map(|n| n.value).sum::<u32>(), 4361);
+    }
+
+    #[test]
+    fn gears() {
+        let schematic = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(
+            schematic.gear_ratios().collect::<Vec<_>>(),
+            vec![467 * 35, 755 * 598]
+        );
+
+        // A star touching three numbers is not a gear
+        let crowded = Puzzle::parse("1.2\n.*.\n3..".as_bytes()).unwrap();
+
+        assert_eq!(crowded.gear_ratios().count(), 0);
+    }
+}
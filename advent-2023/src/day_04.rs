@@ -0,0 +1,177 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::bitset::SmallSet};
+
+/// Numbers on a scratchcard, which are all below 100
+pub type CardNumbers = SmallSet<100>;
+
+/// A scratchcard's winning numbers and the numbers it has
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Scratchcard {
+    pub id: usize,
+    pub winning: CardNumbers,
+    pub have: CardNumbers,
+}
+
+impl Scratchcard {
+    /// How many of the numbers on the card are winning numbers
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(self.have).len()
+    }
+
+    /// One point for the first match, doubled for every match after it. Cards can match up to 100
+    /// numbers, so this is `None` past the 64 matches a `u64` can count.
+    pub fn points(&self) -> Option<u64> {
+        match self.matches() {
+            0 => Some(0),
+            n => 1_u64.checked_shl(u32::try_from(n - 1).ok()?),
+        }
+    }
+}
+
+impl FromStr for Scratchcard {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, numbers) = s
+            .strip_prefix("Card")
+            .and_then(|s| s.split_once(':'))
+            .context("cards should start with 'Card N:'")?;
+        let (winning, have) = numbers
+            .split_once('|')
+            .context("winning numbers should be separated by '|'")?;
+
+        let set = |numbers: &str| -> anyhow::Result<CardNumbers> {
+            numbers
+                .split_whitespace()
+                .map(|n| {
+                    let n = n.parse()?;
+
+                    ensure!(n < 100, "{n} is too large for a scratchcard");
+
+                    Ok(n)
+                })
+                .collect()
+        };
+
+        Ok(Self {
+            id: id.trim().parse()?,
+            winning: set(winning)?,
+            have: set(have)?,
+        })
+    }
+}
+
+/// Total cards held once every match wins a copy of each of the following cards. Copies win
+/// exactly like originals, so each card's count is added to the cards it wins in one pass. Counts
+/// can double with every card, so this is `None` once they no longer fit in a `u64`.
+fn total_cards(cards: &[Scratchcard]) -> Option<u64> {
+    let mut counts = vec![1_u64; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let end = (i + 1 + card.matches()).min(cards.len());
+
+        for j in i + 1..end {
+            counts[j] = counts[j].checked_add(counts[i])?;
+        }
+    }
+
+    counts
+        .iter()
+        .try_fold(0_u64, |total, &n| total.checked_add(n))
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Scratchcards",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Scratchcard>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid scratchcard", i + 1))
+            })
+            .collect()
+    }
+
+    /// Total points of every card
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .try_fold(0_u64, |total, card| total.checked_add(card.points()?))
+            .expect("total points should fit in a u64")
+            .into()
+    }
+
+    /// Total scratchcards after winning copies
+    fn part_02(input: &Self::Input) -> Answer {
+        total_cards(input)
+            .expect("total cards should fit in a u64")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn points() {
+        let cards = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(
+            cards.iter().map(Scratchcard::points).collect::<Vec<_>>(),
+            vec![Some(8), Some(2), Some(2), Some(1), Some(0), Some(0)]
+        );
+        assert!(Puzzle::parse("Card 1: 41 100 | 1".as_bytes()).is_err());
+        assert!(Puzzle::parse("Card 1: 41 48".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn copies() {
+        let cards = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(total_cards(&cards), Some(30));
+    }
+
+    /// A card matching the first `n` of the numbers below 100
+    fn matching(id: usize, n: usize) -> Scratchcard {
+        let numbers = (0..n).collect::<CardNumbers>();
+
+        Scratchcard {
+            id,
+            winning: numbers,
+            have: numbers,
+        }
+    }
+
+    #[test]
+    fn overflowing_cards() {
+        assert_eq!(matching(1, 64).points(), Some(1 << 63));
+        assert_eq!(matching(1, 65).points(), None);
+        assert_eq!(matching(1, 100).points(), None);
+
+        // Every card wins a copy of every card after it, doubling the count each time
+        let doubling = |n: usize| (1..=n).map(|id| matching(id, n)).collect::<Vec<_>>();
+
+        assert_eq!(total_cards(&doubling(64)), Some(u64::MAX));
+        assert_eq!(total_cards(&doubling(65)), None);
+    }
+}
@@ -0,0 +1,243 @@
+use std::io::BufRead;
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::interval::SectionRange};
+
+/// Shifts every value in `source` by `offset`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapRange {
+    pub source: SectionRange<i64>,
+    pub offset: i64,
+}
+
+/// Converts values from one category to the next. Values outside every range keep their number.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CategoryMap {
+    pub ranges: Vec<MapRange>,
+}
+
+impl CategoryMap {
+    /// Where a single value ends up
+    pub fn map(&self, value: i64) -> i64 {
+        self.ranges
+            .iter()
+            .find(|r| r.source.contains(value))
+            .map_or(value, |r| value + r.offset)
+    }
+
+    /// Where every value in `ranges` ends up. Each range is split wherever it crosses the edge of
+    /// a map range, so the result can hold more ranges than went in.
+    pub fn map_ranges(&self, ranges: Vec<SectionRange<i64>>) -> Vec<SectionRange<i64>> {
+        let mut unmapped = ranges;
+        let mut mapped = Vec::new();
+
+        for rule in &self.ranges {
+            let mut remaining = Vec::new();
+
+            for range in unmapped {
+                match range.intersection(&rule.source) {
+                    Some(SectionRange(start, end)) => {
+                        mapped.push(SectionRange(start + rule.offset, end + rule.offset));
+
+                        let (before, after) = range.difference(&rule.source);
+
+                        remaining.extend(before);
+                        remaining.extend(after);
+                    }
+                    None => remaining.push(range),
+                }
+            }
+
+            unmapped = remaining;
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+/// Seeds that need planting and the maps that lead from a seed to its location
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<CategoryMap>,
+}
+
+impl Almanac {
+    /// Lowest location of any individual seed
+    pub fn lowest_location(&self) -> Option<i64> {
+        self.seeds
+            .iter()
+            .map(|&s| self.maps.iter().fold(s, |v, m| m.map(v)))
+            .min()
+    }
+
+    /// Lowest location when the seeds are pairs of a start and a length
+    pub fn lowest_range_location(&self) -> Option<i64> {
+        let seeds = self
+            .seeds
+            .chunks_exact(2)
+            .filter(|pair| pair[1] > 0)
+            .map(|pair| SectionRange(pair[0], pair[0] + pair[1] - 1))
+            .collect();
+
+        self.maps
+            .iter()
+            .fold(seeds, |ranges, m| m.map_ranges(ranges))
+            .iter()
+            .map(|r| r.0)
+            .min()
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "If You Give A Seed A Fertilizer",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Almanac;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut lines = reader.lines().enumerate();
+        let mut almanac = Almanac::default();
+
+        let (_, first) = lines.next().context("the almanac should list seeds")?;
+
+        almanac.seeds = first?
+            .strip_prefix("seeds:")
+            .context("the first line should list seeds")?
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .context("seeds should be numbers")?;
+
+        for (i, l) in lines {
+            let l = l?;
+
+            if l.is_empty() {
+                continue;
+            }
+
+            if l.ends_with("map:") {
+                almanac.maps.push(CategoryMap::default());
+                continue;
+            }
+
+            let Some(map) = almanac.maps.last_mut() else {
+                bail!("line {} should be a map header", i + 1);
+            };
+
+            let numbers = l
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<i64>, _>>()
+                .with_context(|| format!("line {} is not a valid map range", i + 1))?;
+
+            let [destination, source, length] = numbers[..] else {
+                bail!(
+                    "line {} should have a destination, source, and length",
+                    i + 1
+                );
+            };
+
+            ensure!(length > 0, "line {} has an empty range", i + 1);
+
+            map.ranges.push(MapRange {
+                source: SectionRange(source, source + length - 1),
+                offset: destination - source,
+            });
+        }
+
+        Ok(almanac)
+    }
+
+    /// Lowest location of any seed
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .lowest_location()
+            .expect("there should be seeds")
+            .into()
+    }
+
+    /// Lowest location of any seed in the seed ranges
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .lowest_range_location()
+            .expect("there should be seed ranges")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn seeds() {
+        let almanac = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(almanac.maps.len(), 7);
+        assert_eq!(
+            [79, 14, 55, 13].map(|s| almanac.maps[0].map(s)),
+            [81, 14, 57, 13]
+        );
+        assert_eq!(almanac.lowest_location(), Some(35));
+        assert!(Puzzle::parse("seeds: 1\n\nmap:\n1 2".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn seed_ranges() {
+        let almanac = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+        let mut split = almanac.maps[0].map_ranges(vec![SectionRange(45, 99)]);
+
+        split.sort_by_key(|r| r.0);
+
+        assert_eq!(
+            split,
+            vec![
+                SectionRange(45, 49),
+                SectionRange(50, 51),
+                SectionRange(52, 99)
+            ]
+        );
+        assert_eq!(almanac.lowest_range_location(), Some(46));
+    }
+}
@@ -0,0 +1,144 @@
+use std::io::BufRead;
+
+use anyhow::{ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// A race's length in milliseconds and the record distance in millimeters
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub record: u64,
+}
+
+impl Race {
+    /// Number of ways to hold the button that beat the record. Holding for `h` travels
+    /// `h * (time - h)`, which is symmetric around half the time, so only the shortest winning
+    /// hold needs to be found.
+    pub fn ways_to_win(&self) -> u64 {
+        let distance = |hold: u64| hold * (self.time - hold);
+        let half = self.time / 2;
+
+        if distance(half) <= self.record {
+            return 0;
+        }
+
+        // Estimate the smaller root, then nudge it to the exact first winning hold
+        let t = self.time as f64;
+        let root = (t - (t * t - 4.0 * self.record as f64).max(0.0).sqrt()) / 2.0;
+        let mut shortest = (root as u64).min(half);
+
+        while shortest > 0 && distance(shortest - 1) > self.record {
+            shortest -= 1;
+        }
+
+        while distance(shortest) <= self.record {
+            shortest += 1;
+        }
+
+        self.time + 1 - 2 * shortest
+    }
+}
+
+/// Reads the numbers after the label on one line
+fn numbers(line: &str, label: &str) -> anyhow::Result<Vec<String>> {
+    Ok(line
+        .strip_prefix(label)
+        .with_context(|| format!("the line should start with '{label}'"))?
+        .split_whitespace()
+        .map(String::from)
+        .collect())
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Wait For It",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Digits of each race's time and record, kept as text since part two joins them together
+    type Input = Vec<(String, String)>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut lines = reader.lines();
+        let times = numbers(&lines.next().context("missing times")??, "Time:")?;
+        let records = numbers(&lines.next().context("missing distances")??, "Distance:")?;
+
+        ensure!(
+            times.len() == records.len(),
+            "every race should have a time and a distance"
+        );
+        ensure!(
+            times
+                .iter()
+                .chain(&records)
+                .all(|n| n.parse::<u64>().is_ok()),
+            "times and distances should be numbers"
+        );
+
+        Ok(times.into_iter().zip(records).collect())
+    }
+
+    /// Product of the ways to win each race
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|(time, record)| {
+                Race {
+                    time: time.parse().expect("times should be numbers"),
+                    record: record.parse().expect("distances should be numbers"),
+                }
+                .ways_to_win()
+            })
+            .product::<u64>()
+            .into()
+    }
+
+    /// Ways to win the single race written with bad kerning
+    fn part_02(input: &Self::Input) -> Answer {
+        let (time, record): (String, String) = input.iter().cloned().unzip();
+
+        Race {
+            time: time.parse().expect("the joined time should fit"),
+            record: record.parse().expect("the joined distance should fit"),
+        }
+        .ways_to_win()
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn races() {
+        let races = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(races.len(), 3);
+        assert_eq!(Race { time: 7, record: 9 }.ways_to_win(), 4);
+        assert_eq!(
+            Race {
+                time: 30,
+                record: 200
+            }
+            .ways_to_win(),
+            9
+        );
+        assert_eq!(Race { time: 4, record: 4 }.ways_to_win(), 0);
+        assert_eq!(Puzzle::part_01(&races), Answer::from(288));
+        assert!(Puzzle::parse("Time: 7 15\nDistance: 9".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn kerning() {
+        let races = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_02(&races), Answer::from(71503));
+    }
+}
@@ -0,0 +1,174 @@
+use std::{io::BufRead, str::FromStr};
+
+use anyhow::{bail, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Strength of a hand's card counts, weakest first
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+/// Card values from 2 to 14 where an ace is high
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: [u8; 5],
+    pub bid: u64,
+}
+
+const JACK: u8 = 11;
+
+impl Hand {
+    /// Hand type from the counts of each card. With `jokers` the jacks join whichever card is
+    /// already most common, which always makes the best hand.
+    pub fn hand_type(&self, jokers: bool) -> HandType {
+        let mut counts = [0; 15];
+
+        for &c in &self.cards {
+            counts[c as usize] += 1;
+        }
+
+        let wild = if jokers {
+            std::mem::take(&mut counts[JACK as usize])
+        } else {
+            0
+        };
+
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+
+        match (counts[0] + wild, counts[1]) {
+            (5, _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    /// Key that orders hands by type, then card by card. Jokers are the weakest individual card.
+    fn strength(&self, jokers: bool) -> (HandType, [u8; 5]) {
+        let cards = self.cards.map(|c| if jokers && c == JACK { 1 } else { c });
+
+        (self.hand_type(jokers), cards)
+    }
+}
+
+impl FromStr for Hand {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cards, bid) = s
+            .split_once(' ')
+            .context("hands should be cards followed by a bid")?;
+
+        let values = cards
+            .chars()
+            .map(|c| match c {
+                '2'..='9' => Ok(c as u8 - b'0'),
+                'T' => Ok(10),
+                'J' => Ok(JACK),
+                'Q' => Ok(12),
+                'K' => Ok(13),
+                'A' => Ok(14),
+                _ => bail!("'{c}' is not a card"),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            cards: values
+                .try_into()
+                .map_err(|_| anyhow::anyhow!("hands should have five cards"))?,
+            bid: bid.trim().parse()?,
+        })
+    }
+}
+
+/// Sum of each bid multiplied by its hand's rank, where the weakest hand is rank 1
+fn winnings(hands: &[Hand], jokers: bool) -> u64 {
+    let mut ranked = hands.to_vec();
+
+    ranked.sort_by_cached_key(|h| h.strength(jokers));
+    ranked.iter().zip(1..).map(|(h, rank)| h.bid * rank).sum()
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Camel Cards",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<Hand>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.parse()
+                    .with_context(|| format!("line {} is not a valid hand", i + 1))
+            })
+            .collect()
+    }
+
+    /// Total winnings
+    fn part_01(input: &Self::Input) -> Answer {
+        winnings(input, false).into()
+    }
+
+    /// Total winnings when jacks are jokers
+    fn part_02(input: &Self::Input) -> Answer {
+        winnings(input, true).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+    #[test]
+    fn hand_types() {
+        let hands = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(
+            hands.iter().map(|h| h.hand_type(false)).collect::<Vec<_>>(),
+            vec![
+                HandType::OnePair,
+                HandType::ThreeOfAKind,
+                HandType::TwoPair,
+                HandType::TwoPair,
+                HandType::ThreeOfAKind
+            ]
+        );
+        assert_eq!(
+            "JJJJJ 1".parse::<Hand>().unwrap().hand_type(true),
+            HandType::FiveOfAKind
+        );
+        assert!("32T3X 1".parse::<Hand>().is_err());
+        assert!("32T3 1".parse::<Hand>().is_err());
+    }
+
+    #[test]
+    fn total_winnings() {
+        let hands = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(winnings(&hands, false), 6440);
+        assert_eq!(winnings(&hands, true), 5905);
+    }
+}
@@ -0,0 +1,212 @@
+use std::{collections::HashMap, io::BufRead};
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo, util::numth};
+
+/// Left and right instructions followed by the nodes they lead through
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Network {
+    /// `true` for every right turn
+    pub turns: Vec<bool>,
+    pub names: Vec<String>,
+    /// Indices into `names` of each node's left and right neighbours
+    pub nodes: Vec<(usize, usize)>,
+}
+
+impl Network {
+    fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    /// Steps from `start` until reaching a node that satisfies `end`, or `None` if the path
+    /// loops forever without finding one
+    pub fn steps(&self, start: usize, end: impl Fn(&str) -> bool) -> Option<usize> {
+        let mut node = start;
+
+        // Once every node has been visited at every point in the instructions it must be looping
+        for step in 0..=self.nodes.len() * self.turns.len() {
+            if step > 0 && end(&self.names[node]) {
+                return Some(step);
+            }
+
+            let (left, right) = self.nodes[node];
+
+            node = if self.turns[step % self.turns.len()] {
+                right
+            } else {
+                left
+            };
+        }
+
+        None
+    }
+
+    /// Steps from `AAA` to `ZZZ`
+    pub fn camel_steps(&self) -> Option<usize> {
+        self.steps(self.index("AAA")?, |n| n == "ZZZ")
+    }
+
+    /// Steps until every node ending in `A` is on a node ending in `Z` at once. Each ghost's path
+    /// returns to its end on a fixed cycle that matches the first trip, so they all line up at
+    /// the least common multiple. `None` if some ghost never reaches an end or the total does not
+    /// fit in a `u64`.
+    pub fn ghost_steps(&self) -> Option<u64> {
+        self.names
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.ends_with('A'))
+            .map(|(i, _)| self.steps(i, |n| n.ends_with('Z')))
+            .try_fold(1, |total, steps| numth::checked_lcm(total, steps? as u64))
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Haunted Wasteland",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Network;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut lines = reader.lines();
+
+        let turns = lines
+            .next()
+            .context("the map should start with instructions")??
+            .chars()
+            .map(|c| match c {
+                'L' => Ok(false),
+                'R' => Ok(true),
+                _ => bail!("'{c}' is not a direction"),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ensure!(
+            !turns.is_empty(),
+            "there should be at least one instruction"
+        );
+
+        let mut connections = Vec::new();
+
+        for (i, l) in lines.enumerate() {
+            let l = l?;
+
+            if l.is_empty() {
+                continue;
+            }
+
+            let parsed = l.split_once(" = (").and_then(|(name, rest)| {
+                let (left, right) = rest.strip_suffix(')')?.split_once(", ")?;
+
+                Some((name.to_string(), left.to_string(), right.to_string()))
+            });
+
+            connections
+                .push(parsed.with_context(|| format!("line {} is not a valid node", i + 2))?);
+        }
+
+        let names = connections
+            .iter()
+            .map(|(n, _, _)| n.clone())
+            .collect::<Vec<_>>();
+        let indices = names
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.as_str(), i))
+            .collect::<HashMap<_, _>>();
+        let index = |name: &str| {
+            indices
+                .get(name)
+                .copied()
+                .with_context(|| format!("'{name}' is not a node"))
+        };
+
+        let nodes = connections
+            .iter()
+            .map(|(_, l, r)| Ok((index(l)?, index(r)?)))
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Network {
+            turns,
+            names,
+            nodes,
+        })
+    }
+
+    /// Steps from `AAA` to `ZZZ`
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .camel_steps()
+            .expect("ZZZ should be reachable from AAA")
+            .into()
+    }
+
+    /// Steps until every ghost is on an end node
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .ghost_steps()
+            .expect("every ghost should reach an end")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn camel() {
+        let direct = Puzzle::parse(
+            "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"
+                .as_bytes(),
+        )
+        .unwrap();
+        let repeating = Puzzle::parse(
+            "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+                .as_bytes(),
+        )
+        .unwrap();
+        let stuck = Puzzle::parse("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)".as_bytes()).unwrap();
+
+        assert_eq!(direct.camel_steps(), Some(2));
+        assert_eq!(repeating.camel_steps(), Some(6));
+        assert_eq!(stuck.camel_steps(), None);
+        assert!(Puzzle::parse("L\n\nAAA = (AAA, BBB)".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn ghosts() {
+        let network = Puzzle::parse(
+            "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(network.ghost_steps(), Some(6));
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::BufRead;
+
+use anyhow::Context;
+
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+/// Next value in the sequence, found by repeatedly taking differences until they are all zero
+/// and then adding the last value of every level back up
+pub fn extrapolate(history: &[i64]) -> i64 {
+    let mut level = history.to_vec();
+    let mut next = 0;
+
+    while level.iter().any(|&v| v != 0) {
+        next += level[level.len() - 1];
+        level = level.windows(2).map(|w| w[1] - w[0]).collect();
+    }
+
+    next
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Mirage Maintenance",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    /// Each value's history from the OASIS report
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        reader
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l?.split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()
+                    .with_context(|| format!("line {} is not a valid history", i + 1))
+            })
+            .collect()
+    }
+
+    /// Sum of the next value of each history
+    fn part_01(input: &Self::Input) -> Answer {
+        input.iter().map(|h| extrapolate(h)).sum::<i64>().into()
+    }
+
+    /// Sum of the value before each history, which is the next value of it reversed
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|h| {
+                let mut reversed = h.clone();
+
+                reversed.reverse();
+                extrapolate(&reversed)
+            })
+            .sum::<i64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_DATA: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn forwards() {
+        let histories = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(
+            histories.iter().map(|h| extrapolate(h)).collect::<Vec<_>>(),
+            vec![18, 28, 68]
+        );
+        assert_eq!(Puzzle::part_01(&histories), Answer::from(114));
+        assert_eq!(extrapolate(&[]), 0);
+        assert!(Puzzle::parse("1 2 x".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn backwards() {
+        let histories = Puzzle::parse(TEST_DATA.as_bytes()).unwrap();
+
+        assert_eq!(Puzzle::part_02(&histories), Answer::from(2));
+    }
+}
@@ -0,0 +1,275 @@
+use std::{io::BufRead, ops::ControlFlow};
+
+use anyhow::{bail, ensure, Context};
+
+use advent_core::{
+    answer::Answer,
+    day::Day,
+    describe::DayInfo,
+    util::grid::{DirectionalScan, Edge},
+};
+
+/// Row and column offsets for north, east, south, and west
+const DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Directions a pipe connects as indices into [`DIRECTIONS`]
+fn connections(tile: u8) -> Option<[usize; 2]> {
+    match tile {
+        b'|' => Some([0, 2]),
+        b'-' => Some([1, 3]),
+        b'L' => Some([0, 1]),
+        b'J' => Some([0, 3]),
+        b'7' => Some([2, 3]),
+        b'F' => Some([1, 2]),
+        _ => None,
+    }
+}
+
+/// A field of pipes with the animal's starting tile
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PipeMaze {
+    rows: Vec<Vec<u8>>,
+    start: (i32, i32),
+}
+
+impl PipeMaze {
+    fn tile(&self, (row, column): (i32, i32)) -> u8 {
+        usize::try_from(row)
+            .ok()
+            .zip(usize::try_from(column).ok())
+            .and_then(|(r, c)| self.rows.get(r)?.get(c))
+            .copied()
+            .unwrap_or(b'.')
+    }
+
+    /// Every tile of the loop through the start, in order. The start has no pipe of its own, so
+    /// the loop leaves it through the first neighbour that connects back.
+    pub fn main_loop(&self) -> Option<Vec<(i32, i32)>> {
+        let step = |(r, c): (i32, i32), d: usize| (r + DIRECTIONS[d].0, c + DIRECTIONS[d].1);
+        let mut heading = (0..4).find(|&d| {
+            connections(self.tile(step(self.start, d))).is_some_and(|c| c.contains(&((d + 2) % 4)))
+        })?;
+
+        let mut position = self.start;
+        let mut path = Vec::new();
+
+        loop {
+            path.push(position);
+            position = step(position, heading);
+
+            if position == self.start {
+                return Some(path);
+            }
+
+            // The pipe is entered from the opposite side of the heading and left by the other end
+            let [a, b] = connections(self.tile(position))?;
+            let entry = (heading + 2) % 4;
+
+            heading = match entry {
+                _ if entry == a => b,
+                _ if entry == b => a,
+                _ => return None,
+            };
+        }
+    }
+
+    /// Steps along the loop to the tile farthest from the start
+    pub fn farthest(&self) -> Option<usize> {
+        self.main_loop().map(|path| path.len() / 2)
+    }
+
+    /// Tiles enclosed by the loop. The shoelace formula gives the area inside the path through
+    /// tile centers, and Pick's theorem removes the half tiles the loop itself covers.
+    pub fn enclosed(&self) -> Option<usize> {
+        let path = self.main_loop()?;
+        let twice_area = path
+            .iter()
+            .zip(path.iter().cycle().skip(1))
+            .map(|(a, b)| (a.0 * b.1 - a.1 * b.0) as i64)
+            .sum::<i64>()
+            .abs();
+
+        Some(((twice_area - path.len() as i64) / 2 + 1) as usize)
+    }
+
+    /// Tiles enclosed by the loop, found by crossing each row from the left. A tile is inside once
+    /// an odd number of loop pipes reaching north have been crossed, which counts a run of pipe
+    /// along the row as one crossing only when it turns back the other way.
+    pub fn enclosed_by_scan(&self) -> Option<usize> {
+        let path = self.main_loop()?;
+        let width = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut tiles = vec![b'.'; width * self.rows.len()];
+
+        for &(row, column) in &path {
+            tiles[row as usize * width + column as usize] = self.tile((row, column));
+        }
+
+        // The start has no pipe of its own, so it is given whichever joins the loop's ends
+        let heading = |to: (i32, i32)| {
+            let offset = (to.0 - self.start.0, to.1 - self.start.1);
+
+            DIRECTIONS.iter().position(|&d| d == offset)
+        };
+        let mut ends = [heading(path[1])?, heading(*path.last()?)?];
+
+        ends.sort_unstable();
+
+        tiles[self.start.0 as usize * width + self.start.1 as usize] =
+            *b"|-LJ7F".iter().find(|&&t| connections(t) == Some(ends))?;
+
+        let inside = DirectionalScan::new(&tiles, width, false)
+            .from_edges(&[Edge::Left])
+            .run(
+                || false,
+                |&tile, enclosed, inside| {
+                    match tile {
+                        b'|' | b'L' | b'J' => *inside = !*inside,
+                        b'.' => *enclosed = *inside,
+                        _ => {}
+                    }
+
+                    ControlFlow::Continue(())
+                },
+            );
+
+        Some(inside.into_iter().filter(|&enclosed| enclosed).count())
+    }
+}
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Pipe Maze",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = PipeMaze;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        let mut rows = Vec::new();
+        let mut start = None;
+
+        for (r, l) in reader.lines().enumerate() {
+            let row = l?.into_bytes();
+
+            for (c, &tile) in row.iter().enumerate() {
+                match tile {
+                    b'S' if start.is_some() => bail!("there should only be one start"),
+                    b'S' => start = Some((r as i32, c as i32)),
+                    b'.' => {}
+                    _ => ensure!(
+                        connections(tile).is_some(),
+                        "'{}' on line {} is not a pipe",
+                        tile as char,
+                        r + 1
+                    ),
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok(PipeMaze {
+            rows,
+            start: start.context("the maze should have a start")?,
+        })
+    }
+
+    /// Steps to the farthest point of the loop
+    fn part_01(input: &Self::Input) -> Answer {
+        input
+            .farthest()
+            .expect("the start should be on a loop")
+            .into()
+    }
+
+    /// Tiles enclosed by the loop
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .enclosed()
+            .expect("the start should be on a loop")
+            .into()
+    }
+}
+
+/// Counts the enclosed tiles by crossing each row rather than measuring the loop's area
+pub struct Scanning;
+
+impl Day for Scanning {
+    type Input = PipeMaze;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Puzzle::parse(reader)
+    }
+
+    fn part_01(input: &Self::Input) -> Answer {
+        Puzzle::part_01(input)
+    }
+
+    fn part_02(input: &Self::Input) -> Answer {
+        input
+            .enclosed_by_scan()
+            .expect("the start should be on a loop")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn farthest() {
+        let square = Puzzle::parse(".....\n.S-7.\n.|.|.\n.L-J.\n.....".as_bytes()).unwrap();
+        let winding = Puzzle::parse("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...".as_bytes()).unwrap();
+        let broken = Puzzle::parse(".....\n.S-7.\n.|.|.\n.L-..\n.....".as_bytes()).unwrap();
+
+        assert_eq!(square.farthest(), Some(4));
+        assert_eq!(winding.farthest(), Some(8));
+        assert_eq!(broken.farthest(), None);
+        assert!(Puzzle::parse("S-7\n|.X".as_bytes()).is_err());
+        assert!(Puzzle::parse("S-S".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn enclosed() {
+        let squeezed = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+        let larger = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+        let touching = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+        // Pipes inside the loop that are not part of it still count as enclosed
+        let junk = ".....\n.S-7.\n.|F|.\n.L-J.\n.....";
+
+        for (maze, inside) in [(squeezed, 4), (touching, 4), (larger, 8), (junk, 1)] {
+            let maze = Puzzle::parse(maze.as_bytes()).unwrap();
+
+            assert_eq!(maze.enclosed(), Some(inside));
+            assert_eq!(maze.enclosed_by_scan(), Some(inside));
+        }
+    }
+}
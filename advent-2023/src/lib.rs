@@ -0,0 +1,21 @@
+//! Solutions to the 2023 puzzles.
+
+use advent_macros::{generate_missing_test_stubs, generate_year};
+
+use advent_core::day::solve;
+use advent_core::year::AlternativeFn;
+
+generate_year!(2023 10; alternatives = ALTERNATIVES);
+generate_missing_test_stubs!(2023);
+
+/// Other implementations of days in this year, selectable by name
+pub const ALTERNATIVES: &[(i32, &str, AlternativeFn)] = &[(10, "scan", solve::<day_10::Scanning>)];
+
+pub use day_03::{Schematic, SchematicNumber};
+pub use day_04::{CardNumbers, Scratchcard};
+pub use day_05::{Almanac, CategoryMap, MapRange};
+pub use day_06::Race;
+pub use day_07::{Hand, HandType};
+pub use day_08::Network;
+pub use day_09::extrapolate;
+pub use day_10::PipeMaze;
@@ -0,0 +1,257 @@
+//! The stable `extern "C"` entry every year crate exports, so the year can be built as a dynamic
+//! library and solved by a binary that was compiled separately, as the `watch` command does to
+//! re-run the day being worked on without relinking everything else. Only integers, pointers, and
+//! a callback cross the boundary, so neither side depends on the other's layout of Rust types or on
+//! its allocator.
+
+use std::{
+    ffi::c_void,
+    io::{self, BufRead},
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use crate::{context::Context, year::Year};
+
+/// Changed whenever [`EntryFn`], [`Sink`], or [`Status`] change, so a library built against another
+/// version is refused instead of misread
+pub const ABI_VERSION: u32 = 1;
+
+/// Signature of the entry, taking the [`ABI_VERSION`] the caller was built with, the day, the part,
+/// the input as a pointer and length (null for no input), and where to write the answer or error.
+/// Returns a [`Status`].
+pub type EntryFn = unsafe extern "C" fn(u32, i32, i32, *const u8, usize, Sink) -> i32;
+
+/// Name of the entry exported by the crate for `year`, such as `advent_entry_2023`. Each year has
+/// its own so that linking several year crates into one binary does not clash.
+pub fn entry_symbol(year: i32) -> String {
+    format!("advent_entry_{year}")
+}
+
+/// What an entry call did
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Status {
+    /// The answer was written to the sink
+    Solved = 0,
+    /// The solution failed or panicked and its error was written to the sink
+    Failed = 1,
+    /// The year has no solution for the part
+    Unsolved = 2,
+    /// The caller expects another [`ABI_VERSION`], which was written to the sink
+    VersionMismatch = 3,
+}
+
+impl Status {
+    /// The status an entry returned, if it is one
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        [
+            Self::Solved,
+            Self::Failed,
+            Self::Unsolved,
+            Self::VersionMismatch,
+        ]
+        .into_iter()
+        .find(|&s| s as i32 == raw)
+    }
+}
+
+/// Receives text from the library, which must be copied before `write` returns
+#[repr(C)]
+pub struct Sink {
+    pub state: *mut c_void,
+    pub write: extern "C" fn(state: *mut c_void, text: *const u8, len: usize),
+}
+
+extern "C" fn append(state: *mut c_void, text: *const u8, len: usize) {
+    // SAFETY: `Sink::appending` made `state` from a `&mut String` that outlives the call, and the
+    // library passes the pointer and length of a `str`
+    let (out, text) = unsafe {
+        (
+            &mut *state.cast::<String>(),
+            slice::from_raw_parts(text, len),
+        )
+    };
+
+    out.push_str(&String::from_utf8_lossy(text));
+}
+
+impl Sink {
+    /// A sink appending to `out`, which must outlive the entry call it is passed to
+    pub fn appending(out: &mut String) -> Self {
+        Self {
+            state: (out as *mut String).cast(),
+            write: append,
+        }
+    }
+
+    fn send(&self, text: &str) {
+        (self.write)(self.state, text.as_ptr(), text.len());
+    }
+}
+
+/// Solves `part` of `day` from `year` for its entry, writing the answer or error to `sink`. Anything
+/// the part reports besides its answer goes to stderr, and panics are caught rather than unwinding
+/// across the boundary.
+/// # Safety
+/// `input` must be null or point to `len` readable bytes, and `sink` must be safe to call with its
+/// own state
+pub unsafe fn solve(
+    year: &dyn Year,
+    version: u32,
+    day: i32,
+    part: i32,
+    input: *const u8,
+    len: usize,
+    sink: Sink,
+) -> i32 {
+    if version != ABI_VERSION {
+        sink.send(&format!(
+            "the library was built for ABI version {ABI_VERSION} but the caller expects {version}"
+        ));
+
+        return Status::VersionMismatch as i32;
+    }
+
+    let Some(&(_, _, solution, ..)) = year
+        .solutions()
+        .iter()
+        .find(|&&(d, p, ..)| (d, p) == (day, part))
+    else {
+        return Status::Unsolved as i32;
+    };
+
+    // SAFETY: the caller guarantees `input` points to `len` bytes whenever it is not null
+    let mut input = (!input.is_null()).then(|| unsafe { slice::from_raw_parts(input, len) });
+    let mut ctx =
+        Context::new(input.as_mut().map(|i| i as &mut dyn BufRead)).with_output(io::stderr());
+
+    match panic::catch_unwind(AssertUnwindSafe(|| solution(&mut ctx))) {
+        Ok(Ok(answer)) => {
+            sink.send(&answer.to_string());
+
+            Status::Solved as i32
+        }
+        Ok(Err(e)) => {
+            sink.send(&format!("{e:#}"));
+
+            Status::Failed as i32
+        }
+        Err(_) => {
+            sink.send("the solution panicked");
+
+            Status::Failed as i32
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::ptr;
+
+    use super::*;
+    use crate::{
+        answer::Answer,
+        context,
+        describe::Description,
+        lint::LintFn,
+        year::{YearDays, YearSolutions},
+    };
+
+    fn sum(ctx: &mut context::Context<'_>) -> anyhow::Result<Answer> {
+        let mut text = String::new();
+
+        ctx.take_input()
+            .ok_or_else(|| anyhow::anyhow!("no input"))?
+            .read_to_string(&mut text)?;
+
+        Ok(Answer::Int(
+            text.split_whitespace()
+                .map(str::parse::<i64>)
+                .sum::<Result<_, _>>()?,
+        ))
+    }
+
+    fn panics(_: &mut context::Context<'_>) -> anyhow::Result<Answer> {
+        panic!("on purpose")
+    }
+
+    struct TestYear;
+
+    impl Year for TestYear {
+        fn year(&self) -> i32 {
+            2099
+        }
+
+        fn solutions(&self) -> YearSolutions {
+            &[(1, 1, sum, None, &[]), (1, 2, panics, None, &[])]
+        }
+
+        fn days(&self) -> YearDays {
+            &[]
+        }
+
+        fn descriptions(&self) -> &'static [Description] {
+            &[]
+        }
+
+        fn linters(&self) -> &'static [(i32, LintFn)] {
+            &[]
+        }
+
+        fn days_solved(&self) -> i32 {
+            1
+        }
+
+        fn parts_solved(&self, _: i32) -> i32 {
+            2
+        }
+
+        fn run_solution(&self, _: i32, _: i32) {}
+    }
+
+    fn call(version: u32, day: i32, part: i32, input: Option<&[u8]>) -> (Option<Status>, String) {
+        let mut out = String::new();
+        let (ptr, len) = input.map_or((ptr::null(), 0), |i| (i.as_ptr(), i.len()));
+
+        // SAFETY: the pointer and length come from a live slice or are null
+        let status = unsafe {
+            solve(
+                &TestYear,
+                version,
+                day,
+                part,
+                ptr,
+                len,
+                Sink::appending(&mut out),
+            )
+        };
+
+        (Status::from_raw(status), out)
+    }
+
+    #[test]
+    fn solves_through_entry() {
+        assert_eq!(
+            call(ABI_VERSION, 1, 1, Some(b"1 2\n3")),
+            (Some(Status::Solved), "6".to_owned())
+        );
+        assert_eq!(
+            call(ABI_VERSION, 1, 1, None),
+            (Some(Status::Failed), "no input".to_owned())
+        );
+        assert_eq!(call(ABI_VERSION, 2, 1, None).0, Some(Status::Unsolved));
+        assert_eq!(
+            call(ABI_VERSION + 1, 1, 1, None).0,
+            Some(Status::VersionMismatch)
+        );
+    }
+
+    #[test]
+    fn panics_stay_in_library() {
+        assert_eq!(
+            call(ABI_VERSION, 1, 2, Some(b"")),
+            (Some(Status::Failed), "the solution panicked".to_owned())
+        );
+    }
+}
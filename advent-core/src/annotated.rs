@@ -0,0 +1,125 @@
+//! Solutions registered by annotating their functions with [`advent_macros::advent`] rather than
+//! by a year's `generate_year!`. Every annotated part is collected at link time, so a year can
+//! skip days, split a day across files, or share helper modules without following the `day_##`
+//! layout. The `advent-solutions` registry lists these parts alongside the generated ones.
+
+use std::io::Read;
+
+use crate::{
+    answer::Answer,
+    context::Context,
+    normalize::{Normalize, Normalized},
+    year::PartFn,
+};
+
+/// A part registered with `#[advent(year = .., day = ..)]`
+#[derive(Clone, Copy)]
+pub struct AnnotatedPart {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    /// Wrapper generated around the annotated function
+    pub run: PartFn,
+}
+
+inventory::collect!(AnnotatedPart);
+
+/// Every annotated part ordered by year, day, and then part
+pub fn parts() -> Vec<AnnotatedPart> {
+    let mut parts = inventory::iter::<AnnotatedPart>
+        .into_iter()
+        .copied()
+        .collect::<Vec<_>>();
+
+    parts.sort_by_key(|p| (p.year, p.day, p.part));
+    parts
+}
+
+/// Return values an annotated function may give
+pub trait IntoAnswerResult {
+    fn into_answer_result(self) -> anyhow::Result<Answer>;
+}
+
+impl<T: Into<Answer>> IntoAnswerResult for T {
+    fn into_answer_result(self) -> anyhow::Result<Answer> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>, E: Into<anyhow::Error>> IntoAnswerResult for Result<T, E> {
+    fn into_answer_result(self) -> anyhow::Result<Answer> {
+        self.map(Into::into).map_err(Into::into)
+    }
+}
+
+/// Reads the whole input in `ctx`, cleaned up as days are by default, and hands it to `solve`,
+/// which is how the wrappers generated by the attribute call the annotated function. A missing
+/// input is passed on as empty.
+pub fn solve_text<T: IntoAnswerResult>(
+    ctx: &mut Context<'_>,
+    solve: fn(&str) -> T,
+) -> anyhow::Result<Answer> {
+    let mut text = String::new();
+
+    if let Some(reader) = ctx.take_input() {
+        Normalized::new(reader, Normalize::DEFAULT).read_to_string(&mut text)?;
+    }
+
+    solve(&text).into_answer_result()
+}
+
+#[cfg(test)]
+mod test {
+    use advent_macros::advent;
+
+    use super::*;
+
+    // Registered under a year no puzzles will ever have, with day 1 skipped
+
+    #[advent(year = 2099, day = 2)]
+    fn part1(input: &str) -> usize {
+        input.lines().count()
+    }
+
+    #[advent(year = 2099, day = 2)]
+    fn part_02(input: &str) -> anyhow::Result<i64> {
+        input
+            .lines()
+            .map(|l| Ok(l.parse::<i64>()?))
+            .sum::<anyhow::Result<i64>>()
+    }
+
+    #[advent(year = 2099, day = 5, part = 1)]
+    fn longest_line(input: &str) -> String {
+        input
+            .lines()
+            .max_by_key(|l| l.len())
+            .unwrap_or("")
+            .to_owned()
+    }
+
+    #[test]
+    fn registered() {
+        let found = parts()
+            .iter()
+            .filter(|p| p.year == 2099)
+            .map(|p| (p.day, p.part))
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, vec![(2, 1), (2, 2), (5, 1)]);
+
+        let solve = |day, part, input: &[u8]| {
+            let found = parts()
+                .into_iter()
+                .find(|p| (p.year, p.day, p.part) == (2099, day, part))
+                .unwrap();
+
+            (found.run)(&mut Context::new(Some(&mut &input[..])))
+        };
+
+        assert_eq!(solve(2, 1, b"3\n4").unwrap(), Answer::Int(2));
+        assert_eq!(solve(2, 2, b"3\n4").unwrap(), Answer::Int(7));
+        assert!(solve(2, 2, b"x").is_err());
+        assert_eq!(solve(5, 1, b"ab\nabc").unwrap(), Answer::from("abc"));
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::ocr;
+
+/// The result of solving one part of a puzzle. Most puzzles are answered with a number, but some
+/// expect text such as a password or a sequence of letters, and a few draw those letters as a
+/// grid of pixels that has to be read before it can be submitted.
+///
+/// Numbers compare by value, so an answer counted in a `usize` equals the same answer computed as
+/// an `i64`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+    /// Rows of pixels separated by newlines, such as `#` and `.` drawn on a screen
+    Grid(String),
+}
+
+impl Answer {
+    /// The value of a numeric answer, wide enough to hold both kinds
+    pub fn as_number(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(i128::from(*n)),
+            Answer::UInt(n) => Some(i128::from(*n)),
+            Answer::Text(_) | Answer::Grid(_) => None,
+        }
+    }
+
+    /// What to send to the site, which for a grid is the letters it draws if they can be read
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Grid(g) => ocr::read_letters(g).ok(),
+            _ => Some(self.to_string()),
+        }
+    }
+
+    /// Whether this answer is the one written as `expected`, such as in a puzzle description.
+    /// Numbers match however they are written, and grids ignore whitespace around their rows.
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = expected.trim();
+
+        match self {
+            Answer::Int(_) | Answer::UInt(_) => expected.parse::<i128>().ok() == self.as_number(),
+            Answer::Text(s) => s == expected,
+            Answer::Grid(g) => g
+                .trim()
+                .lines()
+                .map(str::trim)
+                .eq(expected.lines().map(str::trim)),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) | (Answer::Grid(a), Answer::Grid(b)) => a == b,
+            _ => self.as_number().is_some() && self.as_number() == other.as_number(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashed the same way for both kinds of number, since equal numbers must hash equally
+        match self {
+            Answer::Int(_) | Answer::UInt(_) => self.as_number().hash(state),
+            Answer::Text(s) => (0_u8, s).hash(state),
+            Answer::Grid(g) => (1_u8, g).hash(state),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::Text(s) | Answer::Grid(s) => f.write_str(s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($variant:ident: $wide:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(<$wide>::try_from(value).expect("numeric answers should fit in 64 bits"))
+            }
+        })*
+    };
+}
+
+answer_from_int!(Int: i64, i8, i16, i32, i64, isize);
+answer_from_int!(UInt: u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert!(matches!(Answer::from(42_usize), Answer::UInt(42)));
+        assert!(matches!(Answer::from(-3), Answer::Int(-3)));
+        assert_eq!(Answer::from("hxbxxyzz").to_string(), "hxbxxyzz");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+    }
+
+    #[test]
+    fn numbers_compare_by_value() {
+        assert_eq!(Answer::from(42_usize), Answer::Int(42));
+        assert_ne!(Answer::from(-1), Answer::from(u64::MAX));
+        assert_ne!(Answer::from(1), Answer::from("1"));
+        assert_ne!(Answer::from("#"), Answer::Grid("#".to_owned()));
+        assert_eq!(
+            HashSet::from([Answer::Int(7), Answer::UInt(7), Answer::from("7")]).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn matching_expected() {
+        let grid = Answer::Grid("#.#\n.#.".to_owned());
+
+        assert!(Answer::UInt(24_933_642).matches("24933642"));
+        assert!(Answer::Int(-5).matches(" -5\n"));
+        assert!(!Answer::Int(5).matches("five"));
+        assert!(Answer::from("2=-1=0").matches("2=-1=0"));
+        assert!(grid.matches("\n    #.#\n    .#.\n"));
+        assert!(!grid.matches("#.#"));
+        assert_eq!(grid.submission(), None);
+        assert_eq!(
+            Answer::Grid(["#..#", "#..#", "####", "#..#", "#..#", "#..#"].join("\n"))
+                .submission()
+                .as_deref(),
+            Some("H")
+        );
+        assert_eq!(Answer::UInt(3).submission().as_deref(), Some("3"));
+    }
+}
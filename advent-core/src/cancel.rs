@@ -0,0 +1,185 @@
+//! Giving up on parts that run too long, since brute force days can take forever on inputs they
+//! were not written against. [`run_with_timeout`] solves on a worker thread and stops waiting once
+//! the time is up, cancelling the [`CancelToken`] of the run so days that poll [`current`] in their
+//! outer loops can stop early. Days that never poll are left to finish on their own thread, which
+//! ends with the process.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use thiserror::Error;
+
+use crate::threads;
+
+/// Token handed to the part being solved under a timeout, if there is one
+static CURRENT: Mutex<Option<CancelToken>> = Mutex::new(None);
+
+/// Held by tests that solve under a timeout, since only one run can own [`CURRENT`] at a time
+#[cfg(test)]
+pub(crate) static TIMEOUT_TESTS: Mutex<()> = Mutex::new(());
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("gave up after {0:?}")]
+pub struct TimedOut(pub Duration);
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("'{0}' is not a duration such as 30s, 500ms, or 2m")]
+pub struct DurationError(String);
+
+/// Shared flag telling a solution to stop. Cloning it is cheap and every clone sees the same flag.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The token of the part being solved. Outside [`run_with_timeout`] it is never cancelled. Days
+/// should fetch it once and poll the token itself, which is cheaper than calling this in a loop.
+pub fn current() -> CancelToken {
+    CURRENT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Parses a duration such as `30s`, `500ms`, or `2m`. A bare number is a count of seconds.
+pub fn parse_duration(value: &str) -> Result<Duration, DurationError> {
+    let invalid = || DurationError(value.to_owned());
+    let trimmed = value.trim();
+    let split = trimmed
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    let number = number.parse::<f64>().map_err(|_| invalid())?;
+
+    let seconds = match unit {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        "h" => number * 60.0 * 60.0,
+        _ => return Err(invalid()),
+    };
+
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|d| !d.is_zero())
+        .ok_or_else(invalid)
+}
+
+/// Runs `op` on the configured thread pool, or with `timeout` set, on a worker thread that is
+/// waited on for at most that long. When the time runs out the run's token is cancelled and
+/// [`TimedOut`] is returned without waiting for `op` to notice.
+pub fn run_with_timeout<R, F>(timeout: Option<Duration>, op: F) -> Result<R, TimedOut>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Ok(threads::install(op));
+    };
+
+    let token = CancelToken::new();
+    let (sender, receiver) = mpsc::channel();
+
+    *CURRENT.lock().unwrap_or_else(|e| e.into_inner()) = Some(token.clone());
+
+    // Solutions that recurse deeply expect a main thread's stack
+    let worker = thread::Builder::new()
+        .name("advent-solve".to_owned())
+        .stack_size(threads::STACK_SIZE)
+        .spawn(move || {
+            // The runner may have stopped listening by the time this finishes
+            let _ = sender.send(threads::install(op));
+        })
+        .expect("solving thread should start");
+
+    let result = receiver.recv_timeout(timeout);
+
+    *CURRENT.lock().unwrap_or_else(|e| e.into_inner()) = None;
+
+    match result {
+        Ok(value) => Ok(value),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+
+            Err(TimedOut(timeout))
+        }
+        // The worker panicked without sending anything, so surface the panic here
+        Err(mpsc::RecvTimeoutError::Disconnected) => match worker.join() {
+            Err(panic) => std::panic::resume_unwind(panic),
+            Ok(()) => unreachable!("the worker always sends before finishing"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration(" 2h "), Ok(Duration::from_secs(7200)));
+
+        for bad in ["", "s", "0s", "-1s", "10 parsecs", "1.2.3s"] {
+            assert!(parse_duration(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn timeouts() {
+        let _serial = TIMEOUT_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+
+        assert_eq!(run_with_timeout(None, || 7), Ok(7));
+        assert_eq!(run_with_timeout(Some(Duration::from_secs(60)), || 7), Ok(7));
+        assert!(!current().is_cancelled());
+
+        // Polls its token the way a brute force day would, reporting whether it was cancelled
+        let (sender, receiver) = mpsc::channel();
+        let limit = Duration::from_millis(50);
+
+        let result = run_with_timeout(Some(limit), move || {
+            let cancel = current();
+
+            while !cancel.is_cancelled() {
+                thread::sleep(Duration::from_millis(5));
+            }
+
+            sender.send(()).unwrap();
+        });
+
+        assert_eq!(result, Err(TimedOut(limit)));
+        assert!(receiver.recv_timeout(Duration::from_secs(10)).is_ok());
+        assert!(!current().is_cancelled());
+    }
+
+    #[test]
+    #[should_panic(expected = "solver failed")]
+    fn panics_pass_through() {
+        let _serial = TIMEOUT_TESTS.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = run_with_timeout(Some(Duration::from_secs(60)), || -> () {
+            panic!("solver failed")
+        });
+    }
+}
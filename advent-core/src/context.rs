@@ -0,0 +1,213 @@
+//! Everything a part is handed besides its own code. A [`Context`] carries the input to parse along
+//! with the options of the run: a writer for anything worth reporting that is not the answer,
+//! whether progress is drawn, how many threads parallel work is spread over, the token to poll for
+//! cancellation, and puzzle parameters such as the length of a race given with `--param`. The
+//! dispatchers generated by `generate_year!` build one for every part they solve.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, BufRead, Write},
+    str::FromStr,
+    sync::Mutex,
+};
+
+use thiserror::Error;
+
+use crate::{
+    cancel::{self, CancelToken},
+    progress::{self, Progress},
+    threads,
+};
+
+/// Parameters given with `--param` that every [`Context`] made from now on starts with
+static PARAMS: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    #[error("parameters should look like name=value but got '{0}'")]
+    Malformed(String),
+    #[error("'{value}' is not a valid value for the parameter {name}")]
+    Invalid { name: String, value: String },
+}
+
+/// Parses a parameter as given to `--param`, such as `seconds=1000`
+pub fn parse_param(arg: &str) -> Result<(String, String), ParamError> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_owned(), value.trim().to_owned()))
+        }
+        _ => Err(ParamError::Malformed(arg.to_owned())),
+    }
+}
+
+/// A parameter a day reads from its [`Context`], as listed in its `Day::PARAMS`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// What the parameter changes and the value the puzzle uses
+    pub about: &'static str,
+}
+
+/// Names of the parameters set with [`set_param`], in order
+pub fn param_names() -> Vec<String> {
+    PARAMS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .cloned()
+        .collect()
+}
+
+/// Sets a parameter for every [`Context`] made from now on, replacing any earlier value
+pub fn set_param(name: impl Into<String>, value: impl Into<String>) {
+    PARAMS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(name.into(), value.into());
+}
+
+/// The input and options one part is solved with
+pub struct Context<'a> {
+    input: Option<&'a mut dyn BufRead>,
+    output: Box<dyn Write + 'a>,
+    progress: bool,
+    threads: usize,
+    cancel: CancelToken,
+    params: BTreeMap<String, String>,
+}
+
+impl<'a> Context<'a> {
+    /// A context for solving from `input` with the options set for this process, writing nothing
+    pub fn new(input: Option<&'a mut dyn BufRead>) -> Self {
+        Self {
+            input,
+            output: Box::new(io::sink()),
+            progress: progress::enabled(),
+            threads: threads::current(),
+            cancel: cancel::current(),
+            params: PARAMS.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+        }
+    }
+
+    /// Sends whatever the part writes to `output`
+    pub fn with_output(mut self, output: impl Write + 'a) -> Self {
+        self.output = Box::new(output);
+        self
+    }
+
+    /// Sets the parameter `name` for this context only
+    pub fn with_param(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.params.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// Hands over the input to be parsed. Later calls get `None`, as does a missing input file.
+    pub fn take_input(&mut self) -> Option<&'a mut dyn BufRead> {
+        self.input.take()
+    }
+
+    /// Where the part can write anything other than its answer
+    pub fn output(&mut self) -> &mut dyn Write {
+        &mut self.output
+    }
+
+    /// A bar for `total` units of work, drawn only when the run shows progress
+    pub fn progress(&self, label: &'static str, total: u64) -> Progress {
+        if self.progress {
+            Progress::new(label, total)
+        } else {
+            Progress::hidden()
+        }
+    }
+
+    /// A running count of work with no known end, drawn only when the run shows progress
+    pub fn counter(&self, label: &'static str) -> Progress {
+        if self.progress {
+            Progress::counter(label)
+        } else {
+            Progress::hidden()
+        }
+    }
+
+    /// Threads that parallel work is spread across
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// The token to poll in long searches, cancelled once the run has timed out
+    pub fn cancel(&self) -> &CancelToken {
+        &self.cancel
+    }
+
+    /// The parameter `name` if it was given, otherwise `default`
+    pub fn param<T: FromStr>(&self, name: &str, default: T) -> Result<T, ParamError> {
+        match self.params.get(name) {
+            Some(value) => value.parse().map_err(|_| ParamError::Invalid {
+                name: name.to_owned(),
+                value: value.clone(),
+            }),
+            None => Ok(default),
+        }
+    }
+}
+
+impl fmt::Debug for Context<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context")
+            .field("input", &self.input.is_some())
+            .field("progress", &self.progress)
+            .field("threads", &self.threads)
+            .field("cancel", &self.cancel)
+            .field("params", &self.params)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn params() {
+        assert_eq!(
+            parse_param("seconds = 1000"),
+            Ok(("seconds".to_owned(), "1000".to_owned()))
+        );
+        assert_eq!(parse_param("name="), Ok(("name".to_owned(), String::new())));
+
+        for bad in ["seconds", "=5", ""] {
+            assert!(matches!(parse_param(bad), Err(ParamError::Malformed(_))));
+        }
+
+        let ctx = Context::new(None)
+            .with_param("seconds", 1000)
+            .with_param("name", "comet");
+
+        assert_eq!(ctx.param("seconds", 2503), Ok(1000));
+        assert_eq!(ctx.param("steps", 100), Ok(100));
+        assert!(ctx.param::<u32>("name", 0).is_err());
+    }
+
+    #[test]
+    fn input_and_output() {
+        let mut written = Vec::new();
+        let mut input = b"abc".as_slice();
+
+        {
+            let mut ctx = Context::new(Some(&mut input)).with_output(&mut written);
+            let mut text = String::new();
+
+            ctx.take_input().unwrap().read_to_string(&mut text).unwrap();
+
+            assert_eq!(text, "abc");
+            assert!(ctx.take_input().is_none());
+            assert!(!ctx.cancel().is_cancelled());
+            assert!(!ctx.progress("steps", 10).is_visible());
+
+            write!(ctx.output(), "found {text}").unwrap();
+        }
+
+        assert_eq!(written, b"found abc");
+    }
+}
@@ -0,0 +1,313 @@
+use std::io::{self, BufRead};
+
+use anyhow::bail;
+use thiserror::Error;
+
+use crate::{
+    answer::Answer,
+    context::{Context, Param},
+    lint::Malformed,
+    normalize::{Normalize, Normalized},
+    trace::{trace_event, trace_span},
+};
+
+/// A single day's puzzle. Input is parsed once into [`Day::Input`] and both parts are solved from
+/// that shared structure, which keeps parsing separate from solving for reuse and timing.
+pub trait Day {
+    /// Structure the puzzle input is parsed into before either part is solved
+    type Input;
+
+    /// Whether the puzzle reads its input from a file. Puzzles whose input is short enough to be
+    /// embedded in the source set this to `false` and are parsed from an empty reader.
+    const NEEDS_INPUT: bool = true;
+
+    /// How the input is cleaned up before the day reads it. Every day has a byte order mark dropped
+    /// and `\r\n` read as `\n`, and blank lines at the end are dropped unless the day keeps them.
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+    /// Parses the raw puzzle input
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input>;
+
+    fn part_01(input: &Self::Input) -> Answer;
+
+    fn part_02(input: &Self::Input) -> Answer;
+
+    /// Parameters [`Day::part_01_with`] and [`Day::part_02_with`] read from their context, so
+    /// names given to a run that no part reads can be pointed out
+    const PARAMS: &'static [Param] = &[];
+
+    /// Solves part 1 with the options of the run, for days that take puzzle parameters or report
+    /// progress. Every other day answers with [`Day::part_01`].
+    fn part_01_with(input: &Self::Input, _ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(Self::part_01(input))
+    }
+
+    /// Solves part 2 with the options of the run, as [`Day::part_01_with`] does for part 1
+    fn part_02_with(input: &Self::Input, _ctx: &mut Context<'_>) -> anyhow::Result<Answer> {
+        Ok(Self::part_02(input))
+    }
+
+    /// Whether [`Day::part_01_streaming`] and [`Day::part_02_streaming`] are implemented
+    const STREAMING: bool = false;
+
+    /// Solves part 1 while reading the input, using the same memory no matter how long it is
+    fn part_01_streaming(_reader: impl BufRead) -> anyhow::Result<Answer> {
+        bail!("this puzzle cannot be solved while streaming its input")
+    }
+
+    /// Solves part 2 while reading the input, using the same memory no matter how long it is
+    fn part_02_streaming(_reader: impl BufRead) -> anyhow::Result<Answer> {
+        bail!("this puzzle cannot be solved while streaming its input")
+    }
+
+    /// Whether [`Day::validate`] is implemented
+    const VALIDATES: bool = false;
+
+    /// Checks the structure of the cleaned up input without solving it, rejecting the first
+    /// malformed line, for the `lint-input` command. Usually built on [`crate::lint::each_line`].
+    fn validate(_input: &str) -> Result<(), Malformed> {
+        Ok(())
+    }
+}
+
+/// How a part is solved from its input
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Parse the whole input into [`Day::Input`] before solving
+    #[default]
+    Collect,
+    /// Solve while reading the input, for days where [`Day::STREAMING`] is set
+    Streaming,
+    /// Solve with the named implementation from a year's `ALTERNATIVES` list
+    Alternative(&'static str),
+}
+
+impl Strategy {
+    /// Reads a strategy from its name: `collect`, `streaming`, or one of the alternative `names`
+    /// registered by the years being run
+    pub fn parse(s: &str, names: &[&'static str]) -> anyhow::Result<Self> {
+        match s {
+            "collect" => Ok(Strategy::Collect),
+            "streaming" => Ok(Strategy::Streaming),
+            _ => match names.iter().find(|&&n| n == s) {
+                Some(name) => Ok(Strategy::Alternative(name)),
+                None => bail!(
+                    "unknown implementation '{s}', expected 'collect', 'streaming', or one of: {}",
+                    names.join(", ")
+                ),
+            },
+        }
+    }
+}
+
+/// An input that [`Day::parse`] rejected, kept apart from failures while solving so a malformed
+/// input can be reported as such. It shows the day's own message.
+#[derive(Error, Debug)]
+#[error(transparent)]
+pub struct ParseError(pub anyhow::Error);
+
+impl ParseError {
+    /// The line of the input that was rejected, read from the `line N` days name it with, as in
+    /// `line 3 is not a valid present`
+    pub fn line(&self) -> Option<usize> {
+        self.0.chain().find_map(|cause| {
+            let message = cause.to_string();
+
+            message.match_indices("line ").find_map(|(i, prefix)| {
+                message[i + prefix.len()..]
+                    .split(|c: char| !c.is_ascii_digit())
+                    .next()?
+                    .parse()
+                    .ok()
+            })
+        })
+    }
+}
+
+/// That a puzzle needing an input file was run without one, kept apart from other failures so a
+/// missing input can be reported as such
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("this puzzle requires an input file")]
+pub struct InputRequired;
+
+/// Parses the input for `D` once cleaned up as [`Day::NORMALIZE`] says, failing with a
+/// [`ParseError`] when the day rejects it. A missing reader is only accepted for puzzles that do
+/// not need an input file, and fails with [`InputRequired`] for the rest.
+pub fn parse_input<D: Day>(reader: Option<&mut dyn BufRead>) -> anyhow::Result<D::Input> {
+    trace_span!("parse", day = std::any::type_name::<D>());
+
+    let parsed = match reader {
+        Some(reader) => D::parse(Normalized::new(reader, D::NORMALIZE)),
+        None if !D::NEEDS_INPUT => D::parse(io::empty()),
+        None => bail!(InputRequired),
+    };
+
+    Ok(parsed.map_err(ParseError)?)
+}
+
+/// Parses the input in `ctx` for `D` and solves the requested `part`
+pub fn solve<D: Day>(ctx: &mut Context<'_>, part: i32) -> anyhow::Result<Answer> {
+    let input = parse_input::<D>(ctx.take_input())?;
+
+    solve_part::<D>(&input, part, ctx)
+}
+
+/// Solves the requested `part` of `D` from its parsed input
+pub fn solve_part<D: Day>(
+    input: &D::Input,
+    part: i32,
+    ctx: &mut Context<'_>,
+) -> anyhow::Result<Answer> {
+    trace_span!("solve", day = std::any::type_name::<D>(), part);
+
+    let answer = match part {
+        1 => D::part_01_with(input, ctx)?,
+        2 => D::part_02_with(input, ctx)?,
+        _ => bail!("puzzles only have parts 1 and 2, not {part}"),
+    };
+
+    trace_event!(%answer, "solved");
+
+    Ok(answer)
+}
+
+/// Solves the requested `part` of `D` while streaming the input in `ctx`
+pub fn solve_streaming<D: Day>(ctx: &mut Context<'_>, part: i32) -> anyhow::Result<Answer> {
+    trace_span!("solve_streaming", day = std::any::type_name::<D>(), part);
+
+    let Some(reader) = ctx.take_input() else {
+        bail!(InputRequired);
+    };
+    let reader = Normalized::new(reader, D::NORMALIZE);
+
+    match part {
+        1 => D::part_01_streaming(reader),
+        2 => D::part_02_streaming(reader),
+        _ => bail!("puzzles only have parts 1 and 2, not {part}"),
+    }
+}
+
+/// Parses the input in `ctx` for `D` once and solves both parts from it
+pub fn solve_both<D: Day>(ctx: &mut Context<'_>) -> anyhow::Result<[Answer; 2]> {
+    let input = parse_input::<D>(ctx.take_input())?;
+
+    Ok([
+        solve_part::<D>(&input, 1, ctx)?,
+        solve_part::<D>(&input, 2, ctx)?,
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Context as _;
+
+    use super::*;
+
+    struct Sum;
+
+    impl Day for Sum {
+        type Input = Vec<i32>;
+
+        fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+            reader
+                .lines()
+                .enumerate()
+                .map(|(i, l)| {
+                    l?.parse()
+                        .with_context(|| format!("line {} is not a number", i + 1))
+                })
+                .collect::<anyhow::Result<_>>()
+        }
+
+        fn part_01(input: &Self::Input) -> Answer {
+            input.iter().sum::<i32>().into()
+        }
+
+        fn part_02(input: &Self::Input) -> Answer {
+            input.iter().product::<i32>().into()
+        }
+
+        const STREAMING: bool = true;
+
+        fn part_01_streaming(reader: impl BufRead) -> anyhow::Result<Answer> {
+            let mut sum = 0;
+
+            for l in reader.lines() {
+                sum += l?.parse::<i32>()?;
+            }
+
+            Ok(sum.into())
+        }
+    }
+
+    #[test]
+    fn solve_parts() {
+        let data = b"2\n3\n4";
+
+        assert_eq!(
+            solve::<Sum>(&mut Context::new(Some(&mut data.as_slice())), 1).unwrap(),
+            Answer::Int(9)
+        );
+
+        assert_eq!(
+            solve::<Sum>(&mut Context::new(Some(&mut data.as_slice())), 2).unwrap(),
+            Answer::Int(24)
+        );
+
+        assert_eq!(
+            solve_both::<Sum>(&mut Context::new(Some(&mut data.as_slice()))).unwrap(),
+            [Answer::Int(9), Answer::Int(24)]
+        );
+
+        assert!(solve::<Sum>(&mut Context::new(Some(&mut data.as_slice())), 3).is_err());
+        assert!(solve::<Sum>(&mut Context::new(None), 1).is_err());
+        assert!(solve::<Sum>(&mut Context::new(Some(&mut b"x".as_slice())), 1).is_err());
+    }
+
+    #[test]
+    fn parse_errors_name_their_line() {
+        let error =
+            solve::<Sum>(&mut Context::new(Some(&mut b"2\n3\nx".as_slice())), 1).unwrap_err();
+        let parse = error.downcast_ref::<ParseError>().unwrap();
+
+        assert_eq!(parse.to_string(), "line 3 is not a number");
+        assert_eq!(parse.line(), Some(3));
+
+        // Failing to find the input at all is not a parse error
+        let missing = solve::<Sum>(&mut Context::new(None), 1).unwrap_err();
+
+        assert!(!missing.is::<ParseError>());
+        assert_eq!(ParseError(anyhow::anyhow!("no lines")).line(), None);
+    }
+
+    #[test]
+    fn solve_streaming_parts() {
+        let data = b"2\n3\n4";
+
+        assert_eq!(
+            solve_streaming::<Sum>(&mut Context::new(Some(&mut data.as_slice())), 1).unwrap(),
+            Answer::Int(9)
+        );
+
+        // Parts without a streaming solution keep the default that refuses to solve
+        assert!(solve_streaming::<Sum>(&mut Context::new(Some(&mut data.as_slice())), 2).is_err());
+        assert!(solve_streaming::<Sum>(&mut Context::new(Some(&mut data.as_slice())), 3).is_err());
+        assert!(solve_streaming::<Sum>(&mut Context::new(None), 1).is_err());
+
+        let names = ["fast", "readable"];
+
+        assert_eq!(
+            Strategy::parse("streaming", &names).unwrap(),
+            Strategy::Streaming
+        );
+        assert_eq!(
+            Strategy::parse("collect", &names).unwrap(),
+            Strategy::Collect
+        );
+        assert_eq!(
+            Strategy::parse("readable", &names).unwrap(),
+            Strategy::Alternative("readable")
+        );
+        assert!(Strategy::parse("lazy", &names).is_err());
+    }
+}
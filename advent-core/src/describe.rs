@@ -0,0 +1,74 @@
+//! What each day is about, for the `describe` command. Every day module exports a [`DayInfo`] as
+//! `INFO`, which `generate_year!` requires, and the macro pairs it with what it reads from the
+//! day's source: the module's doc comment, the docs on `part_01` and `part_02`, and the `util`
+//! modules the day uses, along with a version hashed from those sources. Those land in each year's
+//! `DESCRIPTIONS` list.
+
+use std::fmt;
+
+/// Facts about a day written by hand in its module
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayInfo {
+    /// Title of the puzzle as shown on its page
+    pub title: &'static str,
+    /// Pages worth reading alongside the puzzle, such as background on the technique used
+    pub links: &'static [&'static str],
+}
+
+/// A day's [`DayInfo`] along with what `generate_year!` found in its source
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Description {
+    pub year: i32,
+    pub day: i32,
+    pub info: DayInfo,
+    /// The module's `//!` comment, empty when it has none
+    pub doc: &'static str,
+    /// Doc comments of `part_01` and `part_02`, empty where missing
+    pub parts: [&'static str; 2],
+    /// Modules of `advent_core::util` the day imports, sorted
+    pub utilities: &'static [&'static str],
+    /// Hash of the day's source and that of the utilities it imports, which changes whenever
+    /// any of them is edited
+    pub version: u64,
+}
+
+impl Description {
+    /// The puzzle's page on the Advent of Code site
+    pub fn url(&self) -> String {
+        format!("https://adventofcode.com/{}/day/{}", self.year, self.day)
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day {} of {}: {}", self.day, self.year, self.info.title)?;
+        writeln!(f, "{}", self.url())?;
+
+        if !self.doc.is_empty() {
+            writeln!(f, "\n{}", self.doc)?;
+        }
+
+        writeln!(f)?;
+
+        for (part, doc) in (1..).zip(self.parts) {
+            match doc {
+                "" => writeln!(f, "Part {part}: (undocumented)")?,
+                doc => writeln!(f, "Part {part}: {doc}")?,
+            }
+        }
+
+        if !self.utilities.is_empty() {
+            writeln!(f, "\nUses util::{}", self.utilities.join(", util::"))?;
+        }
+
+        if !self.info.links.is_empty() {
+            writeln!(f, "\nSee also:")?;
+
+            for link in self.info.links {
+                writeln!(f, "  {link}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
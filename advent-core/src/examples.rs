@@ -0,0 +1,231 @@
+//! Examples from the puzzle descriptions, declared once per day with [`examples!`] and used both
+//! as tests and by the `check` command, which solves them without needing the real input. Each
+//! declaration is collected at link time and found again by the `day_##` module it was made in,
+//! within either a year crate such as `advent_2022` or a `year_####` module.
+
+use std::fmt;
+
+use crate::{answer::Answer, context::Context};
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __examples {
+    (@part part1) => { 1 };
+    (@part part2) => { 2 };
+    ($($part:ident: $input:expr => $expected:expr),+ $(,)?) => {
+        /// Examples from the puzzle description and the answers they should give
+        pub const EXAMPLES: &[$crate::examples::Example] = &[$(
+            $crate::examples::Example {
+                part: $crate::examples::examples!(@part $part),
+                input: $input,
+                expected: $expected,
+            }
+        ),+];
+
+        $crate::__private::inventory::submit! {
+            $crate::examples::ExampleSet {
+                module: module_path!(),
+                examples: EXAMPLES,
+                solve: $crate::day::solve::<Puzzle>,
+            }
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn examples() {
+            $crate::examples::ExampleSet {
+                module: module_path!(),
+                examples: EXAMPLES,
+                solve: $crate::day::solve::<Puzzle>,
+            }
+            .assert_all();
+        }
+    };
+}
+
+/// Declares examples from the puzzle description with their expected answers, for the day's
+/// `Puzzle`:
+///
+/// ```ignore
+/// examples! {
+///     part1: "$ cd /\n$ ls\n..." => "95437",
+///     part2: "$ cd /\n$ ls\n..." => "24933642",
+/// }
+/// ```
+///
+/// This defines an `EXAMPLES` list in the day module, a test that checks every entry in it, and
+/// registers the list so [`find`] can run the examples for the day.
+pub use __examples as examples;
+
+/// Signature of [`crate::day::solve`], which parses an input and solves one part of it
+pub type ExampleFn = fn(&mut Context<'_>, i32) -> anyhow::Result<Answer>;
+
+/// An example input and the answer it should give for one part
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: i32,
+    pub input: &'static str,
+    pub expected: &'static str,
+}
+
+/// Every example declared for one day
+#[derive(Clone, Copy)]
+pub struct ExampleSet {
+    /// Path of the module the examples were declared in, such as `advent_2022::day_07`
+    pub module: &'static str,
+    pub examples: &'static [Example],
+    pub solve: ExampleFn,
+}
+
+inventory::collect!(ExampleSet);
+
+/// What solving an example gave
+#[derive(Debug)]
+pub struct Checked {
+    pub example: Example,
+    pub answer: anyhow::Result<Answer>,
+}
+
+impl Checked {
+    pub fn passed(&self) -> bool {
+        self.answer
+            .as_ref()
+            .is_ok_and(|a| a.matches(self.example.expected))
+    }
+}
+
+impl fmt::Display for Checked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.answer {
+            Ok(answer) if self.passed() => write!(f, "ok ({answer})"),
+            Ok(answer) => write!(
+                f,
+                "FAILED: expected {} but got {answer}",
+                self.example.expected
+            ),
+            Err(e) => write!(f, "FAILED: {e}"),
+        }
+    }
+}
+
+impl ExampleSet {
+    /// The year and day of the module the examples were declared in
+    pub fn puzzle(&self) -> Option<(i32, i32)> {
+        let number = |prefix: &str| {
+            self.module
+                .split("::")
+                .find_map(|segment| segment.strip_prefix(prefix)?.parse().ok())
+        };
+
+        let year = number("advent_").or_else(|| number("year_"))?;
+
+        Some((year, number("day_")?))
+    }
+
+    /// Solves every example, in the order they were declared
+    pub fn check(&self) -> Vec<Checked> {
+        self.examples
+            .iter()
+            .map(|&example| Checked {
+                example,
+                answer: (self.solve)(
+                    &mut Context::new(Some(&mut example.input.as_bytes())),
+                    example.part,
+                ),
+            })
+            .collect()
+    }
+
+    /// Panics with every failing example, which is how the tests generated by [`examples!`]
+    /// report them
+    pub fn assert_all(&self) {
+        let failures = self
+            .check()
+            .into_iter()
+            .filter(|c| !c.passed())
+            .map(|c| format!("part {}: {c}", c.example.part))
+            .collect::<Vec<_>>();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
+
+/// The examples declared for `year`/`day`, if any
+pub fn find(year: i32, day: i32) -> Option<&'static ExampleSet> {
+    inventory::iter::<ExampleSet>
+        .into_iter()
+        .find(|set| set.puzzle() == Some((year, day)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod year_2099 {
+        pub mod day_01 {
+            use std::io::BufRead;
+
+            use crate::{answer::Answer, day::Day};
+
+            /// Counts the numbers in its input, or sums them for part 2
+            pub struct Puzzle;
+
+            impl Day for Puzzle {
+                type Input = Vec<i64>;
+
+                fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+                    reader.lines().map(|l| Ok(l?.parse()?)).collect()
+                }
+
+                fn part_01(input: &Self::Input) -> Answer {
+                    input.len().into()
+                }
+
+                fn part_02(input: &Self::Input) -> Answer {
+                    input.iter().sum::<i64>().into()
+                }
+            }
+
+            crate::examples::examples! {
+                part1: "1\n2\n3" => "3",
+                part2: "1\n2\n3" => "6",
+            }
+        }
+    }
+
+    #[test]
+    fn declared_examples() {
+        let set = find(2099, 1).unwrap();
+
+        assert_eq!(set.puzzle(), Some((2099, 1)));
+        assert!(set.check().iter().all(Checked::passed));
+        assert!(find(2099, 2).is_none());
+    }
+
+    #[test]
+    fn report_failures() {
+        const WRONG: Example = Example {
+            part: 1,
+            input: "1\n2",
+            expected: "99",
+        };
+
+        let set = ExampleSet {
+            module: "advent_2022::day_07",
+            examples: &[WRONG, Example { part: 3, ..WRONG }],
+            solve: crate::day::solve::<year_2099::day_01::Puzzle>,
+        };
+
+        assert_eq!(set.puzzle(), Some((2022, 7)));
+
+        let checked = set.check();
+
+        assert_eq!(checked[0].to_string(), "FAILED: expected 99 but got 2");
+        assert_eq!(
+            checked[1].to_string(),
+            "FAILED: puzzles only have parts 1 and 2, not 3"
+        );
+        assert!(checked.iter().all(|c| !c.passed()));
+        assert!(std::panic::catch_unwind(|| set.assert_all()).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+//! Poking around a day's parsed input from a small command loop, for the `explore` command. Days
+//! opt in by listing themselves in their year's `EXPLORABLE` list, which needs their
+//! [`Day::Input`] to implement [`Explorable`]. Seeing the structure an answer was computed from,
+//! such as the size of one directory or the signal on one wire, narrows down where a wrong answer
+//! went astray.
+
+use std::io::{self, BufRead, Write};
+
+use thiserror::Error;
+
+use crate::{
+    context::Context,
+    day::{parse_input, Day},
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ExploreError {
+    #[error("unknown command '{0}', try help")]
+    UnknownCommand(String),
+    #[error("usage: {0}")]
+    Usage(String),
+    #[error("{0}")]
+    Failed(String),
+}
+
+/// A command an [`Explorable`] understands
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExploreCommand {
+    pub name: &'static str,
+    /// Arguments after the name, such as `PATH`
+    pub args: &'static str,
+    /// What the command shows
+    pub about: &'static str,
+}
+
+impl ExploreCommand {
+    /// How the command is written, such as `ls PATH`
+    pub fn usage(&self) -> String {
+        match self.args {
+            "" => self.name.to_owned(),
+            args => format!("{} {args}", self.name),
+        }
+    }
+
+    /// The error for this command given the wrong arguments
+    pub fn misused(&self) -> ExploreError {
+        ExploreError::Usage(self.usage())
+    }
+}
+
+/// The error for `command` when [`Explorable::run`] cannot make sense of it: a usage message when
+/// it is one of `commands` given the wrong arguments, and an unknown command otherwise
+pub fn unrecognized(commands: &[ExploreCommand], command: &str) -> ExploreError {
+    match commands.iter().find(|c| c.name == command) {
+        Some(known) => known.misused(),
+        None => ExploreError::UnknownCommand(command.to_owned()),
+    }
+}
+
+/// A parsed input that can be queried with commands
+pub trait Explorable {
+    /// Commands understood by [`Explorable::run`]
+    fn commands(&self) -> &'static [ExploreCommand];
+
+    /// What `command` shows given `args`. Commands may change the input, such as overriding a
+    /// wire, which later commands then see.
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, ExploreError>;
+}
+
+/// Type-erased parser of a day's input into something to explore
+pub type ExplorerFn = fn(&mut Context<'_>) -> anyhow::Result<Box<dyn Explorable>>;
+
+/// Parses the input in `ctx` for `D` to be explored
+pub fn explore_day<D>(ctx: &mut Context<'_>) -> anyhow::Result<Box<dyn Explorable>>
+where
+    D: Day,
+    D::Input: Explorable + 'static,
+{
+    Ok(Box::new(parse_input::<D>(ctx.take_input())?))
+}
+
+/// What a line typed at the prompt shows, with `help` listing the commands. Blank lines show
+/// nothing.
+pub fn execute(explorable: &mut dyn Explorable, line: &str) -> Result<String, ExploreError> {
+    let mut words = line.split_whitespace();
+
+    let Some(command) = words.next() else {
+        return Ok(String::new());
+    };
+
+    let args = words.collect::<Vec<_>>();
+
+    if command != "help" {
+        return explorable.run(command, &args);
+    }
+
+    let commands = explorable.commands();
+    let width = commands
+        .iter()
+        .map(|c| c.usage().len())
+        .max()
+        .unwrap_or(0)
+        .max("quit".len());
+
+    let mut help = commands
+        .iter()
+        .map(|c| format!("{:width$}  {}", c.usage(), c.about))
+        .collect::<Vec<_>>();
+
+    help.push(format!("{:width$}  {}", "quit", "Stop exploring"));
+
+    Ok(help.join("\n"))
+}
+
+/// Reads commands from `input` until it ends or `quit` is given, writing a prompt before each and
+/// what it shows after. Errors are shown in place of output so exploring can go on.
+pub fn command_loop(
+    explorable: &mut dyn Explorable,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    write!(output, "> ")?;
+    output.flush()?;
+
+    for line in input.lines() {
+        let line = line?;
+
+        if matches!(line.trim(), "quit" | "exit") {
+            break;
+        }
+
+        match execute(explorable, &line) {
+            Ok(shown) if shown.is_empty() => {}
+            Ok(shown) => writeln!(output, "{shown}")?,
+            Err(e) => writeln!(output, "{e}")?,
+        }
+
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A counter that can be read and added to
+    struct Counter(i64);
+
+    const COMMANDS: &[ExploreCommand] = &[
+        ExploreCommand {
+            name: "get",
+            args: "",
+            about: "Value of the counter",
+        },
+        ExploreCommand {
+            name: "add",
+            args: "N",
+            about: "Adds N to the counter",
+        },
+    ];
+
+    impl Explorable for Counter {
+        fn commands(&self) -> &'static [ExploreCommand] {
+            COMMANDS
+        }
+
+        fn run(&mut self, command: &str, args: &[&str]) -> Result<String, ExploreError> {
+            match (command, args) {
+                ("get", []) => Ok(self.0.to_string()),
+                ("add", [n]) => {
+                    self.0 += n.parse::<i64>().map_err(|_| COMMANDS[1].misused())?;
+
+                    Ok(String::new())
+                }
+                _ => Err(unrecognized(COMMANDS, command)),
+            }
+        }
+    }
+
+    #[test]
+    fn loop_until_quit() {
+        let mut counter = Counter(1);
+        let mut output = Vec::new();
+
+        command_loop(
+            &mut counter,
+            "get\nadd 4\n\nget\nadd x\nhelp\npop\nquit\nget\n".as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "> 1\n> > > 5\n> usage: add N\n\
+             > get    Value of the counter\nadd N  Adds N to the counter\nquit   Stop exploring\n\
+             > unknown command 'pop', try help\n> "
+        );
+    }
+}
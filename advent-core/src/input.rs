@@ -0,0 +1,103 @@
+//! Where puzzle inputs are kept. Downloaded inputs live in a data directory as `YYYY-DD.txt`,
+//! alongside a `YYYY-DD` directory per day of alternate inputs such as examples, and a path
+//! template in [`INPUT_TEMPLATE_VAR`] can point default inputs somewhere else entirely.
+
+use std::path::{Path, PathBuf};
+
+/// Name of the input in a day's input directory that is used when no other is requested
+pub const DEFAULT_INPUT_NAME: &str = "input";
+
+/// Location of the downloaded puzzle input for `year`/`day` within `data_dir`
+pub fn input_path(year: i32, day: i32, data_dir: &Path) -> PathBuf {
+    data_dir.join(format!("{year}-{day:02}.txt"))
+}
+
+/// Directory within `data_dir` holding alternate inputs for `year`/`day` such as examples, each
+/// stored as `<name>.txt`
+pub fn input_dir(year: i32, day: i32, data_dir: &Path) -> PathBuf {
+    data_dir.join(format!("{year}-{day:02}"))
+}
+
+/// Environment variable holding a path template, such as `inputs/{year}/day{day}.txt`, that
+/// replaces the usual layout of default inputs for anyone who keeps theirs elsewhere
+pub const INPUT_TEMPLATE_VAR: &str = "ADVENT_INPUT_TEMPLATE";
+
+/// The input path template set through [`INPUT_TEMPLATE_VAR`], if any
+pub fn input_template() -> Option<String> {
+    std::env::var(INPUT_TEMPLATE_VAR)
+        .ok()
+        .filter(|t| !t.is_empty())
+}
+
+/// Fills in the `{year}`, `{day}`, and zero padded `{day:02}` placeholders of an input path
+/// template
+pub fn fill_input_template(template: &str, year: i32, day: i32) -> PathBuf {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day:02}", &format!("{day:02}"))
+        .replace("{day}", &day.to_string())
+        .into()
+}
+
+/// Location of the input to use for `year`/`day`. A named input always comes from the day's
+/// input directory. Otherwise a template in [`INPUT_TEMPLATE_VAR`] decides, and without one the
+/// downloaded input is preferred, falling back to the default input in the day's directory when
+/// only that exists.
+pub fn resolve_input(year: i32, day: i32, data_dir: &Path, name: Option<&str>) -> PathBuf {
+    let named = |name: &str| input_dir(year, day, data_dir).join(format!("{name}.txt"));
+
+    if let Some(name) = name {
+        return named(name);
+    }
+
+    if let Some(template) = input_template() {
+        return fill_input_template(&template, year, day);
+    }
+
+    let downloaded = input_path(year, day, data_dir);
+    let fallback = named(DEFAULT_INPUT_NAME);
+
+    if !downloaded.exists() && fallback.exists() {
+        fallback
+    } else {
+        downloaded
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn layered_inputs() {
+        let data_dir = std::env::temp_dir().join(format!("layered-inputs-{}", std::process::id()));
+        let day_dir = input_dir(2015, 1, &data_dir);
+        let resolve = |name| resolve_input(2015, 1, &data_dir, name);
+
+        // Nothing exists yet, so the default is where a download would be saved
+        assert_eq!(resolve(None), data_dir.join("2015-01.txt"));
+        assert_eq!(resolve(Some("example")), day_dir.join("example.txt"));
+
+        std::fs::create_dir_all(&day_dir).unwrap();
+        std::fs::write(day_dir.join("input.txt"), "(").unwrap();
+        assert_eq!(resolve(None), day_dir.join("input.txt"));
+
+        std::fs::write(data_dir.join("2015-01.txt"), ")").unwrap();
+        assert_eq!(resolve(None), data_dir.join("2015-01.txt"));
+        assert_eq!(resolve(Some("input")), day_dir.join("input.txt"));
+
+        std::fs::remove_dir_all(&data_dir).unwrap();
+    }
+
+    #[test]
+    fn input_templates() {
+        assert_eq!(
+            fill_input_template("inputs/{year}/day{day}.txt", 2022, 8),
+            PathBuf::from("inputs/2022/day8.txt")
+        );
+        assert_eq!(
+            fill_input_template("/aoc/{year}-{day:02}/{day:02}.in", 2015, 3),
+            PathBuf::from("/aoc/2015-03/03.in")
+        );
+    }
+}
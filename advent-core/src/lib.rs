@@ -0,0 +1,38 @@
+//! # Summary
+//! Everything a year of solutions is built from, kept apart from the years themselves so each
+//! year can be its own crate and compile in parallel with the others. Every day implements
+//! [`day::Day`], and each year crate calls [`advent_macros::generate_year`] to register its days
+//! along with a [`year::Year`] describing them. The `advent-solutions` binary collects those into
+//! its registry, while the reusable structures extracted from individual days live in [`util`].
+
+// Lets the paths generated by `advent_macros` name this crate from within it too
+extern crate self as advent_core;
+
+pub mod abi;
+pub mod annotated;
+pub mod answer;
+pub mod cancel;
+pub mod context;
+pub mod day;
+pub mod describe;
+pub mod examples;
+pub mod explore;
+pub mod input;
+pub mod lint;
+pub mod normalize;
+pub mod parsed_cache;
+pub mod progress;
+pub mod threads;
+pub mod trace;
+pub mod util;
+pub mod visualize;
+pub mod year;
+
+/// Crates that code generated by `advent_macros` and this crate's own macros refers to, so the
+/// crates using them need not depend on these directly
+#[doc(hidden)]
+pub mod __private {
+    pub use inventory;
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+}
@@ -0,0 +1,254 @@
+//! Checks for the `lint-input` command that an input has the structure its day expects, without
+//! solving it. Every input is checked for being an HTML page, which is what gets saved when a
+//! download runs into the site's rate limit or login page, while days that implement
+//! [`Day::validate`] also check their own structure and report the first malformed line. Days
+//! without a check of their own are parsed instead, so an input the day cannot read is caught too.
+//! `generate_year!` registers [`lint`] for every day in each year's `LINTERS` list.
+
+use std::io::Read;
+
+use thiserror::Error;
+
+use crate::{
+    day::{self, Day, ParseError},
+    normalize::Normalized,
+};
+
+/// Longest part of a malformed line that is shown, as an HTML page may be on a single line
+const SHOWN_CHARS: usize = 60;
+
+/// A line of an input that does not have the structure its day expects
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("line {line} {reason}: {text}")]
+pub struct Malformed {
+    /// Counting from 1
+    pub line: usize,
+    /// Why the line is rejected, such as `is not a move`
+    pub reason: String,
+    /// The start of the line
+    pub text: String,
+}
+
+impl Malformed {
+    /// The line numbered `line` with the contents `text` rejected for `reason`
+    pub fn new(line: usize, reason: impl Into<String>, text: &str) -> Self {
+        let mut shown = text.chars().take(SHOWN_CHARS).collect::<String>();
+
+        if shown.len() < text.len() {
+            shown.push_str("...");
+        }
+
+        Self {
+            line,
+            reason: reason.into(),
+            text: shown,
+        }
+    }
+}
+
+/// What checking an input found
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lint {
+    /// The day checked the input and found nothing wrong
+    Valid,
+    /// The day has no check of its own, but the input is not an HTML page and parses
+    Unchecked,
+    Malformed(Malformed),
+    /// The day has no check of its own and [`Day::parse`] rejected the input, on `line` when the
+    /// day named one
+    Unparsable {
+        line: Option<usize>,
+        message: String,
+    },
+}
+
+/// Checks an input for a day, as [`lint`] does
+pub type LintFn = fn(&[u8]) -> Lint;
+
+/// Checks each line of `input`, numbered from 1, with `check`, rejecting the first line it gives a
+/// reason for
+pub fn each_line(
+    input: &str,
+    mut check: impl FnMut(&str) -> Result<(), String>,
+) -> Result<(), Malformed> {
+    for (i, line) in input.lines().enumerate() {
+        check(line).map_err(|reason| Malformed::new(i + 1, reason, line))?;
+    }
+
+    Ok(())
+}
+
+/// Rejects the first line of `input` with anything on it if that starts an HTML page
+fn html(input: &str) -> Result<(), Malformed> {
+    let Some((i, line)) = input
+        .lines()
+        .enumerate()
+        .find(|(_, l)| !l.trim().is_empty())
+    else {
+        return Ok(());
+    };
+
+    let start = line.trim_start().to_ascii_lowercase();
+
+    if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        return Err(Malformed::new(
+            i + 1,
+            "starts an HTML page rather than a puzzle input, so its download probably failed",
+            line,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks `input` for `D` once cleaned up as [`Day::NORMALIZE`] says, rejecting text that is not
+/// UTF-8 or is an HTML page before checking it with [`Day::validate`] where the day has one, or
+/// parsing it where it does not
+pub fn lint<D: Day>(input: &[u8]) -> Lint {
+    if let Err(e) = std::str::from_utf8(input) {
+        let valid = &input[..e.valid_up_to()];
+        let line = 1 + valid.iter().filter(|&&b| b == b'\n').count();
+        let start = valid.rsplit(|&b| b == b'\n').next().unwrap_or_default();
+
+        return Lint::Malformed(Malformed::new(
+            line,
+            "is not UTF-8 text",
+            &String::from_utf8_lossy(start),
+        ));
+    }
+
+    let mut text = String::new();
+
+    Normalized::new(input, D::NORMALIZE)
+        .read_to_string(&mut text)
+        .expect("UTF-8 in memory can always be read");
+
+    match html(&text) {
+        Err(malformed) => Lint::Malformed(malformed),
+        Ok(()) if !D::VALIDATES => match day::parse_input::<D>(Some(&mut &input[..])) {
+            Ok(_) => Lint::Unchecked,
+            Err(e) => Lint::Unparsable {
+                line: e.downcast_ref::<ParseError>().and_then(ParseError::line),
+                message: format!("{e:#}"),
+            },
+        },
+        Ok(()) => match D::validate(&text) {
+            Ok(()) => Lint::Valid,
+            Err(malformed) => Lint::Malformed(malformed),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufRead;
+
+    use super::*;
+    use crate::answer::Answer;
+
+    struct Numbers;
+
+    impl Day for Numbers {
+        type Input = ();
+
+        fn parse(_: impl BufRead) -> anyhow::Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part_01(_: &Self::Input) -> Answer {
+            Answer::Int(0)
+        }
+
+        fn part_02(_: &Self::Input) -> Answer {
+            Answer::Int(0)
+        }
+
+        const VALIDATES: bool = true;
+
+        fn validate(input: &str) -> Result<(), Malformed> {
+            each_line(input, |l| match l.parse::<i32>() {
+                Ok(_) => Ok(()),
+                Err(_) => Err("is not a number".to_owned()),
+            })
+        }
+    }
+
+    struct Anything;
+
+    impl Day for Anything {
+        type Input = ();
+
+        fn parse(input: impl BufRead) -> anyhow::Result<Self::Input> {
+            for (i, line) in input.lines().enumerate() {
+                if line? == "nothing" {
+                    anyhow::bail!("line {} is nothing", i + 1);
+                }
+            }
+
+            Ok(())
+        }
+
+        fn part_01(_: &Self::Input) -> Answer {
+            Answer::Int(0)
+        }
+
+        fn part_02(_: &Self::Input) -> Answer {
+            Answer::Int(0)
+        }
+    }
+
+    #[test]
+    fn first_malformed_line() {
+        assert_eq!(lint::<Numbers>(b"1\r\n2\r\n3\r\n\r\n"), Lint::Valid);
+        assert_eq!(
+            lint::<Numbers>(b"1\n2\nthree\nfour"),
+            Lint::Malformed(Malformed::new(3, "is not a number", "three"))
+        );
+        assert_eq!(lint::<Anything>(b"three"), Lint::Unchecked);
+    }
+
+    #[test]
+    fn unchecked_days_are_parsed() {
+        assert_eq!(
+            lint::<Anything>(
+                b"one
+nothing
+"
+            ),
+            Lint::Unparsable {
+                line: Some(2),
+                message: "line 2 is nothing".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn html_pages() {
+        let page = b"\n<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>";
+
+        for malformed in [lint::<Numbers>(page), lint::<Anything>(page)] {
+            let Lint::Malformed(malformed) = malformed else {
+                panic!("an HTML page should be malformed");
+            };
+
+            assert_eq!(malformed.line, 2);
+            assert_eq!(malformed.text, "<!DOCTYPE html>");
+        }
+
+        assert_eq!(
+            lint::<Anything>(b"ok\n\xffbad"),
+            Lint::Malformed(Malformed::new(2, "is not UTF-8 text", ""))
+        );
+    }
+
+    #[test]
+    fn long_lines_are_cut() {
+        let malformed = Malformed::new(1, "is too long", &"x".repeat(100));
+
+        assert_eq!(malformed.text, format!("{}...", "x".repeat(60)));
+        assert_eq!(
+            Malformed::new(4, "is not a move", "mvoe 1").to_string(),
+            "line 4 is not a move: mvoe 1"
+        );
+    }
+}
@@ -0,0 +1,266 @@
+//! Cleaning up puzzle inputs before a day reads them, so the same data files work however they
+//! were saved. A byte order mark at the start is dropped and `\r\n` line endings become `\n`, which
+//! spares every parser from handling either. Whitespace-only lines at the end, as editors and
+//! copying from a browser tend to leave, are also dropped unless the day keeps them. Inputs are
+//! cleaned as they are read, so days that stream their input still use the same memory.
+
+use std::io::{self, BufRead, Read};
+
+/// UTF-8 byte order mark
+const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// How a day's input is cleaned up beyond dropping a byte order mark and `\r` before `\n`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Normalize {
+    /// Whether lines holding nothing but whitespace at the end of the input are dropped. The last
+    /// line with anything else on it keeps its line ending.
+    pub trim_trailing_blank_lines: bool,
+}
+
+impl Normalize {
+    /// What every day gets unless it says otherwise
+    pub const DEFAULT: Self = Self {
+        trim_trailing_blank_lines: true,
+    };
+
+    /// For days where blank lines at the end mean something
+    pub const KEEP_TRAILING_BLANK_LINES: Self = Self {
+        trim_trailing_blank_lines: false,
+    };
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A reader that cleans up the input from another as described by a [`Normalize`]
+pub struct Normalized<R> {
+    inner: R,
+    options: Normalize,
+    /// Bytes read so far while they could still be a byte order mark
+    start: Vec<u8>,
+    /// Whether the start of the input has been checked for a byte order mark
+    started: bool,
+    /// A `\r` waiting to see whether `\n` follows it
+    carriage_return: bool,
+    /// Whitespace from the start of a line onwards, held back until something other than
+    /// whitespace shows it is not at the end of the input
+    blank: Vec<u8>,
+    /// Whether the last byte passed on ended a line, or nothing has been passed on yet
+    line_start: bool,
+    /// Cleaned bytes ready to be read, from `pos` onwards
+    out: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: BufRead> Normalized<R> {
+    pub fn new(inner: R, options: Normalize) -> Self {
+        Self {
+            inner,
+            options,
+            start: Vec::with_capacity(BOM.len()),
+            started: false,
+            carriage_return: false,
+            blank: Vec::new(),
+            line_start: true,
+            out: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Passes on `b` once any `\r` before it has been dealt with
+    fn push(&mut self, b: u8) {
+        let trim = self.options.trim_trailing_blank_lines;
+
+        if trim && (self.line_start || !self.blank.is_empty()) && b.is_ascii_whitespace() {
+            self.blank.push(b);
+            return;
+        }
+
+        self.out.append(&mut self.blank);
+        self.out.push(b);
+        self.line_start = b == b'\n';
+    }
+
+    /// Cleans up `bytes` read from the inner reader
+    fn process(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            if self.carriage_return {
+                self.carriage_return = false;
+
+                if b != b'\n' {
+                    self.push(b'\r');
+                }
+            }
+
+            if b == b'\r' {
+                self.carriage_return = true;
+            } else {
+                self.push(b);
+            }
+        }
+    }
+
+    /// Passes on whatever was held back for more input once there is none
+    fn finish(&mut self) {
+        if !self.started {
+            self.started = true;
+
+            let start = std::mem::take(&mut self.start);
+
+            self.process(&start);
+        }
+
+        if std::mem::take(&mut self.carriage_return) {
+            self.push(b'\r');
+        }
+
+        if !self.options.trim_trailing_blank_lines {
+            self.out.append(&mut self.blank);
+        }
+
+        self.blank.clear();
+    }
+
+    /// Reads from the inner reader until there is cleaned output or nothing left to read
+    fn refill(&mut self) -> io::Result<()> {
+        self.out.clear();
+        self.pos = 0;
+
+        while self.out.is_empty() {
+            let chunk = self.inner.fill_buf()?;
+
+            if chunk.is_empty() {
+                self.finish();
+                break;
+            }
+
+            let chunk = chunk.to_vec();
+
+            self.inner.consume(chunk.len());
+
+            if self.started {
+                self.process(&chunk);
+                continue;
+            }
+
+            // A byte order mark may be split across reads, so the start of the input is gathered
+            // until it either is one or cannot be
+            let needed = BOM.len() - self.start.len();
+            let (head, rest) = chunk.split_at(needed.min(chunk.len()));
+
+            self.start.extend_from_slice(head);
+
+            if self.start.len() < BOM.len() && BOM.starts_with(&self.start) {
+                continue;
+            }
+
+            let start = std::mem::take(&mut self.start);
+
+            self.started = true;
+
+            if start != BOM {
+                self.process(&start);
+            }
+
+            self.process(rest);
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for Normalized<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+
+        Ok(n)
+    }
+}
+
+impl<R: BufRead> BufRead for Normalized<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.out.len() {
+            self.refill()?;
+        }
+
+        Ok(&self.out[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.out.len());
+    }
+}
+
+/// The whole of `input` cleaned up as described by `options`
+pub fn normalize(input: &[u8], options: Normalize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+
+    Normalized::new(input, options)
+        .read_to_end(&mut out)
+        .expect("reading from memory cannot fail");
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn clean(input: &str) -> String {
+        String::from_utf8(normalize(input.as_bytes(), Normalize::DEFAULT)).unwrap()
+    }
+
+    #[test]
+    fn line_endings_and_bom() {
+        assert_eq!(clean("\u{FEFF}a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(clean("a\rb\r"), "a\rb\r");
+        assert_eq!(clean("\u{FEFF}"), "");
+        assert_eq!(clean("a\u{FEFF}"), "a\u{FEFF}");
+        assert_eq!(clean("\r\n\r\nx"), "\n\nx");
+        assert_eq!(clean(""), "");
+    }
+
+    #[test]
+    fn trailing_blank_lines() {
+        assert_eq!(clean("a \nb\n\n  \n\t\r\n"), "a \nb\n");
+        assert_eq!(clean("a\n\nb"), "a\n\nb");
+        assert_eq!(clean("a\n   b  "), "a\n   b  ");
+        assert_eq!(clean("a"), "a");
+        assert_eq!(clean("\n\n"), "");
+
+        let kept = normalize(b"a\r\n\r\n \n", Normalize::KEEP_TRAILING_BLANK_LINES);
+
+        assert_eq!(kept, b"a\n\n \n");
+    }
+
+    #[test]
+    fn split_reads() {
+        let input = b"\xEF\xBB\xBFline one\r\nline two\r\n\r\n\n";
+
+        // Reads of a byte at a time split the byte order mark and every `\r\n`
+        let mut out = Vec::new();
+
+        Normalized::new(
+            io::BufReader::with_capacity(1, &input[..]),
+            Normalize::DEFAULT,
+        )
+        .read_to_end(&mut out)
+        .unwrap();
+
+        assert_eq!(out, b"line one\nline two\n");
+
+        let lines = Normalized::new(&input[..], Normalize::DEFAULT)
+            .lines()
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(lines, ["line one", "line two"]);
+    }
+}
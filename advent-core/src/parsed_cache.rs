@@ -0,0 +1,156 @@
+//! Keeping parsed inputs on disk so repeated runs on the same input can skip parsing. Days opt in
+//! by listing themselves in their year's `PARSE_CACHED` list, which needs their
+//! [`Day::Input`] to implement serde's traits. Entries are keyed by a hash of the raw input, so
+//! an edited input file is parsed again rather than answered from stale data.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use md5::{Digest, Md5};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    answer::Answer,
+    context::Context,
+    day::{parse_input, solve_part, Day},
+};
+
+/// Type-erased solver for both parts of a day that reads and writes its parsed input through a
+/// [`ParsedCache`]
+pub type CachedDayFn =
+    fn(&ParsedCache, i32, i32, &[u8], &mut Context<'_>) -> anyhow::Result<[Answer; 2]>;
+
+/// Directory of serialized inputs, one file per day and input hash
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParsedCache {
+    dir: PathBuf,
+}
+
+impl ParsedCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The cache kept alongside the puzzle inputs in `data_dir`
+    pub fn in_data_dir(data_dir: &Path) -> Self {
+        Self::new(data_dir.join("parsed"))
+    }
+
+    /// File holding the parse of `input` for `year`/`day`
+    fn path(&self, year: i32, day: i32, input: &[u8]) -> PathBuf {
+        let hash = Md5::digest(input)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>();
+
+        self.dir.join(format!("{year}-{day:02}-{hash}.bin"))
+    }
+
+    /// The parse of `input` saved by an earlier run, or a fresh parse that is saved for the next.
+    /// A cache that cannot be read or written only costs the time to parse, so those failures
+    /// fall back to parsing rather than being reported.
+    pub fn load_or_parse<D>(&self, year: i32, day: i32, input: &[u8]) -> anyhow::Result<D::Input>
+    where
+        D: Day,
+        D::Input: Serialize + DeserializeOwned,
+    {
+        let path = self.path(year, day, input);
+
+        if let Some(parsed) = fs::read(&path)
+            .ok()
+            .and_then(|bytes| bincode::deserialize(&bytes).ok())
+        {
+            return Ok(parsed);
+        }
+
+        let parsed = parse_input::<D>(Some(&mut &input[..]))?;
+
+        if let Ok(bytes) = bincode::serialize(&parsed) {
+            let _ = fs::create_dir_all(&self.dir).and_then(|_| fs::write(&path, bytes));
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Solves both parts of `D` from a cached parse of `input` with the options in `ctx`
+pub fn solve_both_cached<D>(
+    cache: &ParsedCache,
+    year: i32,
+    day: i32,
+    input: &[u8],
+    ctx: &mut Context<'_>,
+) -> anyhow::Result<[Answer; 2]>
+where
+    D: Day,
+    D::Input: Serialize + DeserializeOwned,
+{
+    let parsed = cache.load_or_parse::<D>(year, day, input)?;
+
+    Ok([
+        solve_part::<D>(&parsed, 1, ctx)?,
+        solve_part::<D>(&parsed, 2, ctx)?,
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::BufRead,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counted;
+
+    impl Day for Counted {
+        type Input = Vec<i64>;
+
+        fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+            PARSES.fetch_add(1, Ordering::SeqCst);
+
+            reader.lines().map(|l| Ok(l?.parse()?)).collect()
+        }
+
+        fn part_01(input: &Self::Input) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part_02(input: &Self::Input) -> Answer {
+            input.len().into()
+        }
+    }
+
+    #[test]
+    fn skips_parsing() {
+        let dir = std::env::temp_dir().join(format!("parsed-cache-{}", std::process::id()));
+        let cache = ParsedCache::new(&dir);
+        let solve = |input: &[u8]| {
+            solve_both_cached::<Counted>(&cache, 1, 1, input, &mut Context::new(None)).unwrap()
+        };
+
+        assert_eq!(solve(b"1\n2\n3"), [Answer::Int(6), Answer::Int(3)]);
+        assert_eq!(solve(b"1\n2\n3"), [Answer::Int(6), Answer::Int(3)]);
+        assert_eq!(PARSES.load(Ordering::SeqCst), 1);
+
+        // A different input gets its own entry
+        assert_eq!(solve(b"4"), [Answer::Int(4), Answer::Int(1)]);
+        assert_eq!(PARSES.load(Ordering::SeqCst), 2);
+
+        // Corrupt entries are parsed again and replaced
+        for entry in fs::read_dir(&dir).unwrap() {
+            fs::write(entry.unwrap().path(), b"junk").unwrap();
+        }
+
+        assert_eq!(solve(b"4"), [Answer::Int(4), Answer::Int(1)]);
+        assert_eq!(PARSES.load(Ordering::SeqCst), 3);
+        assert!(solve_both_cached::<Counted>(&cache, 1, 1, b"x", &mut Context::new(None)).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
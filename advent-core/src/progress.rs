@@ -0,0 +1,137 @@
+//! Progress bars for days that take long enough to be worth watching, drawn to stderr through
+//! `indicatif` when built with the `progress` feature. Days create a [`Progress`] around their
+//! slow loop and tick it as they go. Nothing is drawn unless [`enable`] has been called, which the
+//! CLI does for `--progress` when stdout is a terminal, so the TUI, benchmarks, and tests are
+//! never interrupted by bars and a disabled handle costs a single branch per tick.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether progress bars are compiled in
+pub const AVAILABLE: bool = cfg!(feature = "progress");
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns drawing on or off for every [`Progress`] created from now on
+pub fn enable(on: bool) {
+    ENABLED.store(on, Ordering::Relaxed);
+}
+
+/// Whether a [`Progress`] created now would be drawn
+pub fn enabled() -> bool {
+    AVAILABLE && ENABLED.load(Ordering::Relaxed)
+}
+
+/// A bar or counter for one long running loop, cleared once dropped. It can be ticked through a
+/// shared reference from any thread.
+#[derive(Debug, Default)]
+pub struct Progress {
+    #[cfg(feature = "progress")]
+    bar: Option<indicatif::ProgressBar>,
+}
+
+impl Progress {
+    /// A bar filling up as `total` units of work labelled `label` are done
+    pub fn new(label: &'static str, total: u64) -> Self {
+        #[cfg(feature = "progress")]
+        if enabled() {
+            return Self::drawn(
+                indicatif::ProgressBar::new(total),
+                "{msg} [{bar:40}] {human_pos}/{human_len} ({eta})",
+                label,
+            );
+        }
+
+        let _ = (label, total);
+
+        Self::hidden()
+    }
+
+    /// A running count of work with no known end, such as numbers checked while searching
+    pub fn counter(label: &'static str) -> Self {
+        #[cfg(feature = "progress")]
+        if enabled() {
+            return Self::drawn(
+                indicatif::ProgressBar::no_length(),
+                "{spinner} {msg} {human_pos} ({elapsed})",
+                label,
+            );
+        }
+
+        let _ = label;
+
+        Self::hidden()
+    }
+
+    /// A handle that never draws anything, for running code that reports progress quietly
+    pub fn hidden() -> Self {
+        Self::default()
+    }
+
+    #[cfg(feature = "progress")]
+    fn drawn(bar: indicatif::ProgressBar, template: &str, label: &'static str) -> Self {
+        let style = indicatif::ProgressStyle::with_template(template)
+            .expect("progress templates should be valid")
+            .progress_chars("=> ");
+
+        bar.set_style(style);
+        bar.set_message(label);
+
+        Self { bar: Some(bar) }
+    }
+
+    /// Records one more unit of work
+    pub fn tick(&self) {
+        self.inc(1);
+    }
+
+    /// Records `n` more units of work
+    pub fn inc(&self, n: u64) {
+        #[cfg(feature = "progress")]
+        if let Some(bar) = &self.bar {
+            bar.inc(n);
+        }
+
+        let _ = n;
+    }
+
+    /// Whether ticking this draws anything
+    pub fn is_visible(&self) -> bool {
+        #[cfg(feature = "progress")]
+        return self.bar.is_some();
+
+        #[cfg(not(feature = "progress"))]
+        false
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        #[cfg(feature = "progress")]
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn disabled_by_default() {
+        assert!(!enabled());
+
+        let bar = Progress::new("steps", 10);
+        let counter = Progress::counter("hashes");
+
+        for _ in 0..10 {
+            bar.tick();
+        }
+
+        counter.inc(1 << 14);
+
+        assert!(!bar.is_visible());
+        assert!(!counter.is_visible());
+        assert!(!Progress::hidden().is_visible());
+    }
+}
@@ -0,0 +1,129 @@
+//! The thread pool parallel solutions run on. Without any configuration rayon's global pool is
+//! used, which starts a thread per core. Choosing a count builds a dedicated pool instead, so
+//! timings can be compared at a fixed parallelism and shared machines are not taken over.
+
+use std::{num::NonZeroUsize, sync::OnceLock};
+
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+use thiserror::Error;
+
+/// Environment variable holding the number of threads to use when `--threads` is not given
+pub const THREADS_VAR: &str = "ADVENT_THREADS";
+
+/// Stack given to each thread of a configured pool, matching a typical main thread so recursive
+/// solutions that run there today do not overflow on a worker
+pub(crate) const STACK_SIZE: usize = 8 << 20;
+
+static POOL: OnceLock<ThreadPool> = OnceLock::new();
+
+#[derive(Error, Debug)]
+pub enum ThreadsError {
+    #[error("thread counts should be a positive whole number but got '{0}'")]
+    Invalid(String),
+    #[error("the thread pool has already been configured")]
+    AlreadyConfigured,
+    #[error("could not start {threads} threads: {source}")]
+    Build {
+        threads: NonZeroUsize,
+        source: ThreadPoolBuildError,
+    },
+}
+
+/// Parses a thread count as given to `--threads` or in [`THREADS_VAR`]
+pub fn parse_count(value: &str) -> Result<NonZeroUsize, ThreadsError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| ThreadsError::Invalid(value.to_owned()))
+}
+
+/// The thread count asked for by `flag`, falling back to [`THREADS_VAR`] when it is set and not
+/// empty. `None` leaves rayon's global pool in charge.
+pub fn requested(flag: Option<&str>) -> Result<Option<NonZeroUsize>, ThreadsError> {
+    let var = std::env::var(THREADS_VAR).ok();
+
+    choose(flag, var.as_deref())
+}
+
+fn choose(flag: Option<&str>, var: Option<&str>) -> Result<Option<NonZeroUsize>, ThreadsError> {
+    flag.or(var.filter(|v| !v.trim().is_empty()))
+        .map(parse_count)
+        .transpose()
+}
+
+/// Builds the pool every later [`install`] runs on. Can only be done once per process.
+pub fn configure(threads: NonZeroUsize) -> Result<(), ThreadsError> {
+    if POOL.get().is_some() {
+        return Err(ThreadsError::AlreadyConfigured);
+    }
+
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(threads.get())
+        .stack_size(STACK_SIZE)
+        .thread_name(|i| format!("advent-{i}"))
+        .build()
+        .map_err(|source| ThreadsError::Build { threads, source })?;
+
+    POOL.set(pool).map_err(|_| ThreadsError::AlreadyConfigured)
+}
+
+/// Threads that parallel work inside [`install`] is spread across
+pub fn current() -> usize {
+    POOL.get()
+        .map_or_else(rayon::current_num_threads, ThreadPool::current_num_threads)
+}
+
+/// Runs `op` so that any parallel work inside it uses the configured pool, or directly on the
+/// calling thread when no pool has been configured
+pub fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+    match POOL.get() {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counts() {
+        assert_eq!(parse_count("4").unwrap().get(), 4);
+        assert_eq!(parse_count(" 1\n").unwrap().get(), 1);
+
+        for bad in ["0", "-2", "many", ""] {
+            assert!(
+                matches!(parse_count(bad), Err(ThreadsError::Invalid(_))),
+                "{bad}"
+            );
+        }
+
+        // The flag wins over the environment, which is ignored when blank
+        assert_eq!(
+            choose(Some("2"), Some("8")).unwrap().map(NonZeroUsize::get),
+            Some(2)
+        );
+        assert_eq!(
+            choose(None, Some("8")).unwrap().map(NonZeroUsize::get),
+            Some(8)
+        );
+        assert_eq!(choose(None, Some(" ")).unwrap(), None);
+        assert_eq!(choose(None, None).unwrap(), None);
+        assert!(choose(None, Some("zero")).is_err());
+    }
+
+    #[test]
+    fn configured_pool() {
+        let threads = NonZeroUsize::new(3).unwrap();
+
+        configure(threads).unwrap();
+
+        assert_eq!(current(), 3);
+        assert_eq!(install(rayon::current_num_threads), 3);
+        assert!(install(rayon::current_thread_index).is_some());
+        assert!(matches!(
+            configure(threads),
+            Err(ThreadsError::AlreadyConfigured)
+        ));
+    }
+}
@@ -0,0 +1,60 @@
+//! Logging of what solutions are doing through the `tracing` crate when built with the `tracing`
+//! feature. Days mark phases with [`trace_span!`] and milestones with [`trace_event!`], both of
+//! which take `tracing`'s usual arguments and expand to nothing without the feature, so leaving
+//! them in hot code costs nothing in normal builds. The feature is checked here rather than in the
+//! crate using the macros, so year crates need no feature of their own.
+
+/// Enters a debug level span until the end of the enclosing block
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_span {
+    ($($args:tt)*) => {
+        let _span = $crate::__private::tracing::debug_span!($($args)*).entered();
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_span {
+    ($($args:tt)*) => {};
+}
+
+/// Records a debug level event
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_event {
+    ($($args:tt)*) => {
+        $crate::__private::tracing::debug!($($args)*);
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_event {
+    ($($args:tt)*) => {};
+}
+
+pub use __trace_event as trace_event;
+pub use __trace_span as trace_span;
+
+/// Whether spans and events are compiled in
+pub const ENABLED: bool = cfg!(feature = "tracing");
+
+/// Prints spans and events to stderr from now on. Does nothing without the `tracing` feature or
+/// when a subscriber has already been installed.
+pub fn install_subscriber() {
+    #[cfg(feature = "tracing")]
+    {
+        use tracing_subscriber::fmt::format::FmtSpan;
+
+        let _ = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_span_events(FmtSpan::CLOSE)
+            .with_writer(std::io::stderr)
+            .try_init();
+    }
+}
@@ -0,0 +1,193 @@
+//! Sets over a small universe of members `0..N` where every member is a single bit, so set
+//! operations are a handful of integer instructions and sets are cheap to copy and hash.
+
+use thiserror::Error;
+
+/// A set of members in `0..N` stored as the bits of one `u128`, which limits `N` to 128
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SmallSet<const N: usize>(u128);
+
+impl<const N: usize> SmallSet<N> {
+    /// Creates a set with no members, failing to compile when `N` is over 128
+    pub fn new() -> Self {
+        const { assert!(N <= 128, "SmallSet can hold at most 128 members") };
+
+        Self(0)
+    }
+
+    /// Creates a set with every member of the universe `0..N`
+    pub fn full() -> Self {
+        Self::new().complement()
+    }
+
+    fn bit(member: usize) -> u128 {
+        assert!(member < N, "member {member} is outside of 0..{N}");
+
+        1 << member
+    }
+
+    /// Adds `member` to the set and returns whether it was not already present
+    pub fn insert(&mut self, member: usize) -> bool {
+        let had = self.contains(member);
+
+        self.0 |= Self::bit(member);
+
+        !had
+    }
+
+    /// Removes `member` from the set and returns whether it was present
+    pub fn remove(&mut self, member: usize) -> bool {
+        let had = self.contains(member);
+
+        self.0 &= !Self::bit(member);
+
+        had
+    }
+
+    pub fn contains(self, member: usize) -> bool {
+        member < N && self.0 & (1 << member) != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Members of `self` that are not in `other`
+    pub fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Members of the universe `0..N` that are not in `self`
+    pub fn complement(self) -> Self {
+        let universe = if N == 128 { u128::MAX } else { (1 << N) - 1 };
+
+        Self(!self.0 & universe)
+    }
+
+    /// Members in ascending order
+    pub fn iter(self) -> Iter {
+        Iter(self.0)
+    }
+}
+
+impl<const N: usize> Default for SmallSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Ascending members of a [`SmallSet`]
+#[derive(Clone, Debug)]
+pub struct Iter(u128);
+
+impl Iterator for Iter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            None
+        } else {
+            let member = self.0.trailing_zeros() as usize;
+
+            // Clear the lowest set bit
+            self.0 &= self.0 - 1;
+
+            Some(member)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl<const N: usize> IntoIterator for SmallSet<N> {
+    type Item = usize;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for SmallSet<N> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = Self::new();
+
+        for member in iter {
+            set.insert(member);
+        }
+
+        set
+    }
+}
+
+#[derive(Error, Copy, Clone, Debug, PartialEq, Eq)]
+#[error("set has {0} members instead of exactly one")]
+pub struct NotSingleError(pub usize);
+
+/// Extracts the only member of a set
+impl<const N: usize> TryFrom<SmallSet<N>> for usize {
+    type Error = NotSingleError;
+
+    fn try_from(value: SmallSet<N>) -> Result<Self, Self::Error> {
+        if value.len() == 1 {
+            Ok(value.0.trailing_zeros() as usize)
+        } else {
+            Err(NotSingleError(value.len()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let a = [1, 3, 5, 99].into_iter().collect::<SmallSet<100>>();
+        let b = [3, 4, 5].into_iter().collect::<SmallSet<100>>();
+
+        assert_eq!(a.union(b).iter().collect::<Vec<_>>(), [1, 3, 4, 5, 99]);
+        assert_eq!(a.intersection(b).iter().collect::<Vec<_>>(), [3, 5]);
+        assert_eq!(a.difference(b).iter().collect::<Vec<_>>(), [1, 99]);
+        assert_eq!(a.complement().len(), 96);
+        assert!(a.contains(99) && !a.contains(4) && !a.contains(100));
+
+        assert_eq!(SmallSet::<128>::full().len(), 128);
+        assert_eq!(SmallSet::<7>::full().iter().last(), Some(6));
+        assert!(SmallSet::<7>::new().is_empty());
+        assert_eq!(SmallSet::<7>::default(), SmallSet::new());
+
+        let mut c = SmallSet::<8>::new();
+
+        assert!(c.insert(2));
+        assert!(!c.insert(2));
+        assert_eq!(usize::try_from(c), Ok(2));
+        assert!(c.remove(2));
+        assert_eq!(usize::try_from(c), Err(NotSingleError(0)));
+        assert_eq!(usize::try_from(b), Err(NotSingleError(3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of 0..8")]
+    fn reject_members_outside_universe() {
+        SmallSet::<8>::new().insert(8);
+    }
+}
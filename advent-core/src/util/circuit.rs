@@ -0,0 +1,572 @@
+//! Circuits of 16-bit wires connected by logic gates, evaluated lazily so only the wires a query
+//! depends on are ever computed. Those are evaluated once each in topological order, found without
+//! recursion so long chains of gates cannot overflow the stack. Each wire also remembers which
+//! wires read it, so changing one only forgets the signals downstream of it. Wire names are
+//! interned, so wires are kept in vectors indexed by their symbol rather than maps keyed by name.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use thiserror::Error;
+
+use crate::{
+    trace::trace_event,
+    util::{
+        collections::FastHashMap,
+        intern::{Interner, Symbol},
+    },
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    #[error("could not parse connection '{0}'")]
+    Parse(String),
+    #[error("wire '{0}' is not connected to anything")]
+    UnknownWire(String),
+    #[error("wires form a cycle: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+}
+
+/// Gate names, which are operators rather than wires even though they are spelled like wires
+const GATES: [&str; 5] = ["NOT", "AND", "OR", "LSHIFT", "RSHIFT"];
+
+enum Token<'s> {
+    Literal(&'s str),
+    Ident(&'s str),
+    Op(&'s str),
+}
+
+impl Token<'_> {
+    fn parse(piece: &str) -> Option<Token<'_>> {
+        if piece.is_empty() {
+            None
+        } else {
+            let first_char = piece.chars().next().unwrap();
+
+            if first_char.is_ascii_digit() {
+                Some(Token::Literal(piece))
+            } else if GATES.contains(&piece) {
+                Some(Token::Op(piece))
+            } else if first_char.is_alphabetic() {
+                Some(Token::Ident(piece))
+            } else if first_char.is_ascii_punctuation() {
+                Some(Token::Op(piece))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Literal(s) | Token::Ident(s) | Token::Op(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Signal of every wire evaluated so far, by wire name
+pub type WireState = BTreeMap<String, u16>;
+
+/// Signal of each wire by symbol, `None` until it is evaluated
+type Signals = Vec<Option<u16>>;
+
+#[derive(Clone, Debug)]
+enum WireValue {
+    Literal(u16),
+    Ident(Symbol),
+}
+
+impl WireValue {
+    fn from_token(token: &Token, names: &mut Interner) -> Option<Self> {
+        match *token {
+            Token::Literal(l) => Some(WireValue::Literal(l.parse().ok()?)),
+            Token::Ident(s) => Some(WireValue::Ident(names.intern(s))),
+            Token::Op(_) => None,
+        }
+    }
+
+    fn wire(&self) -> Option<Symbol> {
+        match *self {
+            WireValue::Literal(_) => None,
+            WireValue::Ident(i) => Some(i),
+        }
+    }
+
+    fn val(&self, signals: &Signals) -> Option<u16> {
+        match *self {
+            WireValue::Literal(v) => Some(v),
+            WireValue::Ident(i) => signals[i.index()],
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum LogicGate {
+    Not(WireValue),
+    And(WireValue, WireValue),
+    Or(WireValue, WireValue),
+    LShift(WireValue, WireValue),
+    RShift(WireValue, WireValue),
+}
+
+impl LogicGate {
+    fn binary_from_tokens(val1: WireValue, op: &Token, val2: WireValue) -> Option<LogicGate> {
+        match op.to_string().as_str() {
+            "AND" => Some(LogicGate::And(val1, val2)),
+            "OR" => Some(LogicGate::Or(val1, val2)),
+            "LSHIFT" => Some(LogicGate::LShift(val1, val2)),
+            "RSHIFT" => Some(LogicGate::RShift(val1, val2)),
+            _ => None,
+        }
+    }
+
+    fn val(&self, signals: &Signals) -> Option<u16> {
+        Some(match *self {
+            LogicGate::Not(ref v) => !v.val(signals)?,
+            LogicGate::And(ref lhs, ref rhs) => lhs.val(signals)? & rhs.val(signals)?,
+            LogicGate::Or(ref lhs, ref rhs) => lhs.val(signals)? | rhs.val(signals)?,
+            LogicGate::LShift(ref lhs, ref rhs) => lhs.val(signals)? << rhs.val(signals)?,
+            LogicGate::RShift(ref lhs, ref rhs) => lhs.val(signals)? >> rhs.val(signals)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+enum WireSource {
+    Gate(LogicGate),
+    Value(WireValue),
+}
+
+impl WireSource {
+    /// Parses the source side of a connection, i.e. everything before `->`, interning the names
+    /// of the wires it reads
+    fn parse(tokens: &[Token], names: &mut Interner) -> Option<Self> {
+        Some(match tokens {
+            [not, operand] if not.to_string() == "NOT" => {
+                WireSource::Gate(LogicGate::Not(WireValue::from_token(operand, names)?))
+            }
+            [value] => WireSource::Value(WireValue::from_token(value, names)?),
+            [val1, op, val2] => WireSource::Gate(LogicGate::binary_from_tokens(
+                WireValue::from_token(val1, names)?,
+                op,
+                WireValue::from_token(val2, names)?,
+            )?),
+            _ => return None,
+        })
+    }
+
+    /// The wires the source reads
+    fn inputs(&self) -> impl Iterator<Item = Symbol> {
+        let (first, second) = match self {
+            WireSource::Value(v) | WireSource::Gate(LogicGate::Not(v)) => (v, None),
+            WireSource::Gate(
+                LogicGate::And(lhs, rhs)
+                | LogicGate::Or(lhs, rhs)
+                | LogicGate::LShift(lhs, rhs)
+                | LogicGate::RShift(lhs, rhs),
+            ) => (lhs, Some(rhs)),
+        };
+
+        [first.wire(), second.and_then(WireValue::wire)]
+            .into_iter()
+            .flatten()
+    }
+
+    /// The signal the source produces, or `None` if a wire it reads has not been evaluated
+    fn val(&self, signals: &Signals) -> Option<u16> {
+        match *self {
+            WireSource::Gate(ref g) => g.val(signals),
+            WireSource::Value(ref v) => v.val(signals),
+        }
+    }
+}
+
+/// A circuit of wires connected by logic gates that can be evaluated lazily per wire. Evaluated
+/// signals are cached until a wire they depend on is changed.
+#[derive(Clone, Debug, Default)]
+pub struct LogicWires {
+    names: Interner,
+    /// Source of each wire by symbol, `None` for wires that are read but never connected
+    sources: Vec<Option<WireSource>>,
+    signals: Signals,
+    /// Wires whose sources read each wire, by symbol
+    dependents: Vec<BTreeSet<Symbol>>,
+}
+
+impl LogicWires {
+    /// Adds a connection such as `x AND y -> z`, replacing any existing source of the wire
+    pub fn add_connection(&mut self, statement: &str) -> Result<(), CircuitError> {
+        let parse_error = || CircuitError::Parse(statement.to_owned());
+
+        let tokens = statement
+            .split_ascii_whitespace()
+            .map(Token::parse)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(parse_error)?;
+
+        let (wire, source) = match tokens.as_slice() {
+            [source @ .., arrow, Token::Ident(wire)] if arrow.to_string() == "->" => (
+                wire,
+                WireSource::parse(source, &mut self.names).ok_or_else(parse_error)?,
+            ),
+            _ => return Err(parse_error()),
+        };
+
+        let wire = self.symbol(wire);
+
+        self.connect(wire, source);
+
+        Ok(())
+    }
+
+    /// Forces `wire` to carry `signal`, replacing whatever it was connected to. Only the signals
+    /// of wires that depend on it have to be evaluated again.
+    pub fn override_wire(&mut self, wire: &str, signal: u16) {
+        let wire = self.symbol(wire);
+
+        self.connect(wire, WireSource::Value(WireValue::Literal(signal)));
+    }
+
+    /// Interns `name`, making room for every wire interned so far
+    fn symbol(&mut self, name: &str) -> Symbol {
+        let symbol = self.names.intern(name);
+        let wires = self.names.len();
+
+        self.sources.resize(wires, None);
+        self.signals.resize(wires, None);
+        self.dependents.resize(wires, BTreeSet::new());
+
+        symbol
+    }
+
+    /// Replaces the source of `wire`, keeping the reverse dependencies in step
+    fn connect(&mut self, wire: Symbol, source: WireSource) {
+        for input in source.inputs() {
+            self.dependents[input.index()].insert(wire);
+        }
+
+        if let Some(old) = self.sources[wire.index()].replace(source) {
+            let still_read = self.sources[wire.index()]
+                .iter()
+                .flat_map(WireSource::inputs)
+                .collect::<Vec<_>>();
+
+            for input in old.inputs().filter(|i| !still_read.contains(i)) {
+                self.dependents[input.index()].remove(&wire);
+            }
+        }
+
+        self.forget(wire);
+    }
+
+    /// Forgets the evaluated signal of `wire` and of every wire depending on it, directly or
+    /// through other wires, leaving the rest cached. Returns how many signals were forgotten.
+    pub fn invalidate(&mut self, wire: &str) -> usize {
+        self.names.get(wire).map_or(0, |wire| self.forget(wire))
+    }
+
+    fn forget(&mut self, wire: Symbol) -> usize {
+        let mut stale = vec![wire];
+        let mut forgotten = 0;
+
+        while let Some(wire) = stale.pop() {
+            // Nothing downstream can have been evaluated without this wire, and a wire reached
+            // twice has already been forgotten the first time
+            if self.signals[wire.index()].take().is_none() {
+                continue;
+            }
+
+            forgotten += 1;
+            stale.extend(&self.dependents[wire.index()]);
+        }
+
+        trace_event!(wire = self.names.resolve(wire), forgotten, "invalidated");
+
+        forgotten
+    }
+
+    /// The signal on `wire` if it has been evaluated since it last changed
+    pub fn cached(&self, wire: &str) -> Option<u16> {
+        self.signals[self.names.get(wire)?.index()]
+    }
+
+    /// Evaluates the signal on `wire`, along with every wire it depends on
+    pub fn val(&mut self, wire: &str) -> Result<u16, CircuitError> {
+        let wire = self
+            .names
+            .get(wire)
+            .ok_or_else(|| CircuitError::UnknownWire(wire.to_owned()))?;
+
+        for w in self.order(wire)? {
+            let v = self.sources[w.index()]
+                .as_ref()
+                .and_then(|source| source.val(&self.signals))
+                .expect("inputs are evaluated before the wires reading them");
+
+            trace_event!(wire = self.names.resolve(w), signal = v, "resolved");
+
+            self.signals[w.index()] = Some(v);
+        }
+
+        Ok(self.signals[wire.index()].expect("the wire was evaluated last"))
+    }
+
+    /// Wires that must be evaluated for the signal on `wire` to be known, each after every wire
+    /// it reads, ending with `wire` itself. Wires already evaluated are left out.
+    pub fn evaluation_order(&self, wire: &str) -> Result<Vec<String>, CircuitError> {
+        let wire = self
+            .names
+            .get(wire)
+            .ok_or_else(|| CircuitError::UnknownWire(wire.to_owned()))?;
+
+        Ok(self
+            .order(wire)?
+            .into_iter()
+            .map(|w| self.names.resolve(w).to_owned())
+            .collect())
+    }
+
+    fn order(&self, wire: Symbol) -> Result<Vec<Symbol>, CircuitError> {
+        let mut order = Vec::new();
+
+        if self.signals[wire.index()].is_some() {
+            return Ok(order);
+        }
+
+        let name = |wire: Symbol| self.names.resolve(wire).to_owned();
+        let visit = |wire: Symbol| {
+            self.sources[wire.index()]
+                .as_ref()
+                .map(|source| (wire, source.inputs()))
+                .ok_or_else(|| CircuitError::UnknownWire(name(wire)))
+        };
+
+        // A chain of wires each read by the one below it, along with the inputs of each still to
+        // be ordered. Wires are marked `false` while in the chain, so reaching one of those again
+        // means there is a cycle, and `true` once ordered.
+        let mut chain = vec![visit(wire)?];
+        let mut marks = FastHashMap::from_iter([(wire, false)]);
+
+        while let Some((wire, inputs)) = chain.last_mut() {
+            match inputs.next() {
+                Some(input) => match marks.get(&input) {
+                    Some(true) => {}
+                    Some(false) => {
+                        let start = chain.iter().position(|&(w, _)| w == input).unwrap();
+                        let mut cycle = chain[start..]
+                            .iter()
+                            .map(|&(w, _)| name(w))
+                            .collect::<Vec<_>>();
+
+                        cycle.push(name(input));
+
+                        return Err(CircuitError::Cycle(cycle));
+                    }
+                    None if self.signals[input.index()].is_some() => {}
+                    None => {
+                        chain.push(visit(input)?);
+                        marks.insert(input, false);
+                    }
+                },
+                None => {
+                    let wire = *wire;
+
+                    marks.insert(wire, true);
+                    order.push(wire);
+                    chain.pop();
+                }
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Evaluates every wire in the circuit in name order, returning all of their signals
+    pub fn settle(&mut self) -> Result<WireState, CircuitError> {
+        let mut wires = self
+            .names
+            .iter()
+            .filter(|&(w, _)| self.sources[w.index()].is_some())
+            .map(|(_, name)| name.to_owned())
+            .collect::<Vec<_>>();
+
+        wires.sort_unstable();
+
+        for wire in &wires {
+            self.val(wire)?;
+        }
+
+        Ok(wires
+            .into_iter()
+            .filter_map(|wire| Some((wire.clone(), self.cached(&wire)?)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn circuit(connections: &str) -> LogicWires {
+        let mut wires = LogicWires::default();
+
+        for l in connections.lines() {
+            wires.add_connection(l).unwrap();
+        }
+
+        wires
+    }
+
+    #[test]
+    fn bitwise() {
+        let mut wires = circuit(
+            r"123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i",
+        );
+
+        let expected: Vec<(&str, u16)> = vec![
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ];
+
+        for (wire, val) in &expected {
+            assert_eq!(Ok(*val), wires.val(wire));
+        }
+
+        let settled = wires.settle().unwrap();
+
+        // Settled wires come back in name order, not the order they were connected in
+        assert_eq!(
+            settled
+                .iter()
+                .map(|(w, &v)| (w.as_str(), v))
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn override_wires() {
+        let mut wires = circuit("b -> a\n3 -> b\na LSHIFT 1 -> c");
+
+        assert_eq!(wires.val("c"), Ok(6));
+
+        wires.override_wire("b", 5);
+
+        assert_eq!(wires.val("a"), Ok(5));
+        assert_eq!(wires.val("c"), Ok(10));
+    }
+
+    #[test]
+    fn evaluation_order() {
+        let mut wires = circuit("x AND y -> a\n3 -> x\nx -> y\nNOT y -> b");
+
+        assert_eq!(wires.evaluation_order("a").unwrap(), ["x", "y", "a"]);
+        assert_eq!(wires.val("y"), Ok(3));
+        assert_eq!(wires.evaluation_order("b").unwrap(), ["b"]);
+        assert!(wires.evaluation_order("x").unwrap().is_empty());
+
+        // Deep enough to overflow the stack if evaluated recursively
+        let chain = (1..50_000)
+            .map(|i| format!("w{} -> w{i}", i - 1))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut wires = circuit(&format!("7 -> w0\n{chain}"));
+
+        assert_eq!(wires.val("w49999"), Ok(7));
+    }
+
+    #[test]
+    fn invalidate_dependents() {
+        let mut wires = circuit("x AND y -> a\n3 -> x\n5 -> y\n7 -> z\nz OR y -> b\na -> c");
+
+        wires.settle().unwrap();
+
+        assert_eq!(wires.invalidate("x"), 3);
+        assert_eq!(wires.cached("c"), None);
+        assert_eq!(wires.cached("b"), Some(7));
+        assert_eq!(wires.invalidate("x"), 0);
+
+        wires.settle().unwrap();
+        wires.override_wire("x", 4);
+        wires.override_wire("z", 8);
+
+        assert_eq!(wires.cached("y"), Some(5));
+        assert_eq!(wires.cached("a"), None);
+        assert_eq!(wires.cached("b"), None);
+        assert_eq!(wires.val("c"), Ok(4));
+        assert_eq!(wires.val("b"), Ok(13));
+
+        wires.override_wire("y", 1);
+
+        assert_eq!(wires.cached("z"), Some(8));
+        assert_eq!(wires.val("c"), Ok(0));
+        assert_eq!(wires.val("b"), Ok(9));
+
+        // Once rewired, `c` no longer depends on anything upstream of `a`
+        wires.add_connection("z -> c").unwrap();
+
+        assert_eq!(wires.val("c"), Ok(8));
+        assert_eq!(wires.invalidate("a"), 1);
+        assert_eq!(wires.cached("c"), Some(8));
+    }
+
+    #[test]
+    fn detect_problems() {
+        let mut wires = circuit("b AND c -> a\nNOT a -> b\n1 -> c\nd -> d\ne -> f");
+
+        assert_eq!(
+            wires.val("a"),
+            Err(CircuitError::Cycle(vec![
+                "a".to_owned(),
+                "b".to_owned(),
+                "a".to_owned()
+            ]))
+        );
+
+        assert_eq!(
+            wires.val("d"),
+            Err(CircuitError::Cycle(vec!["d".to_owned(), "d".to_owned()]))
+        );
+
+        assert_eq!(wires.val("c"), Ok(1));
+        assert_eq!(
+            wires.val("f"),
+            Err(CircuitError::UnknownWire("e".to_owned()))
+        );
+        assert_eq!(
+            wires.val("z"),
+            Err(CircuitError::UnknownWire("z".to_owned()))
+        );
+        assert!(wires.settle().is_err());
+
+        for bad in [
+            "x AND -> y",
+            "1 -> 2",
+            "NOT -> y",
+            "x XOR y -> z",
+            "70000 -> x",
+        ] {
+            assert_eq!(
+                LogicWires::default().add_connection(bad),
+                Err(CircuitError::Parse(bad.to_owned()))
+            );
+        }
+    }
+}
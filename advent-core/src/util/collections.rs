@@ -0,0 +1,113 @@
+//! Hash maps and sets that hash with [`FxHasher`] rather than the standard library's SipHash.
+//! SipHash resists keys crafted to collide, which puzzle inputs never are, and costs several times
+//! as much on the small keys days hash most often, such as positions, names, and short vectors.
+//! [`ShardedMap`] shares one such map between threads.
+
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+    sync::Mutex,
+};
+
+pub use rustc_hash::{FxBuildHasher, FxHasher};
+
+/// A [`HashMap`] hashing with [`FxHasher`], made with `FastHashMap::default()`
+pub type FastHashMap<K, V> = HashMap<K, V, FxBuildHasher>;
+
+/// A [`HashSet`] hashing with [`FxHasher`], made with `FastHashSet::default()`
+pub type FastHashSet<T> = HashSet<T, FxBuildHasher>;
+
+/// A [`FastHashMap`] split by hash into shards that are locked separately, so threads inserting
+/// different keys rarely wait on each other
+#[derive(Debug)]
+pub struct ShardedMap<K, V> {
+    shards: Box<[Mutex<FastHashMap<K, V>>]>,
+}
+
+impl<K: Hash + Eq, V> ShardedMap<K, V> {
+    /// Creates an empty map of `shards` shards, at least one
+    pub fn new(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1)).map(|_| Mutex::default()).collect(),
+        }
+    }
+
+    fn shard(&self, key: &K) -> &Mutex<FastHashMap<K, V>> {
+        // The low bits pick a bucket within the shard, so the shard is picked from the high bits
+        let hash = FxBuildHasher.hash_one(key) >> 32;
+
+        &self.shards[hash as usize % self.shards.len()]
+    }
+
+    /// Inserts `value` under `key` unless the key is already present, returning whether it was
+    /// inserted
+    pub fn insert(&self, key: K, value: V) -> bool {
+        let mut shard = self.shard(&key).lock().unwrap_or_else(|e| e.into_inner());
+
+        match shard.entry(key) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                true
+            }
+        }
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.shard(key)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|s| s.lock().unwrap_or_else(|e| e.into_inner()).len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Keeps only the entries for which `keep` returns true
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &mut V) -> bool) {
+        for shard in &mut self.shards {
+            shard
+                .get_mut()
+                .unwrap_or_else(|e| e.into_inner())
+                .retain(&mut keep);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rayon::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn sharded_inserts() {
+        let mut map = ShardedMap::new(8);
+        let inserted = (0..10_000)
+            .into_par_iter()
+            .filter(|&i| map.insert(i % 1000, i))
+            .count();
+
+        assert_eq!(inserted, 1000);
+        assert_eq!(map.len(), 1000);
+        assert!(map.contains_key(&999) && !map.contains_key(&1000));
+
+        map.retain(|&k, _| k % 2 == 0);
+
+        assert_eq!(map.len(), 500);
+        assert!(!map.contains_key(&999));
+
+        let single = ShardedMap::new(0);
+
+        assert!(single.insert(1, ()));
+        assert!(!single.insert(1, ()));
+    }
+}
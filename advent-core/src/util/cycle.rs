@@ -0,0 +1,148 @@
+//! Cycle detection for puzzles that repeat a step far more times than could be simulated, such
+//! as an automaton run for a billion generations. Once the states start repeating, the state
+//! after any number of steps is one already seen.
+//!
+//! States are compared by a key, which can be the state itself or something smaller that still
+//! tells states apart, such as a hash or the packed cells of a grid. Brent's algorithm only keeps
+//! a couple of keys at once, so finding a long cycle does not hold on to every state along it.
+
+/// Where the states of an iteration start repeating
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// Steps taken before the first state that repeats
+    pub start: usize,
+    /// Steps it takes to return to a repeated state
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The fewest steps that reach the same state as `steps` do
+    pub fn equivalent(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+}
+
+/// Finds the cycle that repeatedly applying `step` to `initial` falls into, telling states apart
+/// by `key`. Never returns if the states do not repeat.
+pub fn find_cycle<S, K>(
+    initial: &S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle
+where
+    S: Clone,
+    K: PartialEq,
+{
+    // Brent: the tortoise waits at successive powers of two for the hare to come back around
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = key(initial);
+    let mut hare = step(initial);
+
+    while key(&hare) != tortoise {
+        if power == length {
+            tortoise = key(&hare);
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a whole cycle ahead, the two meet where the cycle starts
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after applying `step` to `initial` `steps` times, skipping every full trip around the
+/// cycle the states fall into
+pub fn fast_forward<S, K>(
+    initial: &S,
+    steps: usize,
+    mut step: impl FnMut(&S) -> S,
+    key: impl FnMut(&S) -> K,
+) -> S
+where
+    S: Clone,
+    K: PartialEq,
+{
+    let cycle = find_cycle(initial, &mut step, key);
+
+    (0..cycle.equivalent(steps)).fold(initial.clone(), |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rho_shaped() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3, three steps leading into a loop of four
+        let next = [1, 2, 3, 4, 5, 6, 3];
+        let cycle = find_cycle(&0, |&s| next[s], |&s| s);
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 3,
+                length: 4
+            }
+        );
+        assert_eq!(cycle.equivalent(2), 2);
+        assert_eq!(cycle.equivalent(7), 3);
+        assert_eq!(cycle.equivalent(1_000_000_001), 5);
+    }
+
+    #[test]
+    fn fixed_points() {
+        assert_eq!(
+            find_cycle(&5, |&s: &i32| s, |&s| s),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+        assert_eq!(
+            fast_forward(&10_u64, usize::MAX, |&s| s.saturating_sub(1), |&s| s),
+            0
+        );
+    }
+
+    #[test]
+    fn keyed_by_part_of_the_state() {
+        // The step counter grows forever, but only the position decides what happens next
+        let step = |&(pos, n): &(u32, u64)| ((pos + 3) % 7, n + 1);
+        let cycle = find_cycle(&(0, 0), step, |&(pos, _)| pos);
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 7
+            }
+        );
+        assert_eq!(
+            fast_forward(&(0, 0), 1_000_000, step, |&(pos, _)| pos).0,
+            1_000_000 * 3 % 7
+        );
+    }
+}
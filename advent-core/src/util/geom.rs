@@ -0,0 +1,365 @@
+//! Points, directions, and bounding boxes on an integer plane. Like the rows of a puzzle input,
+//! `y` grows downwards, so [`Dir4::Up`] is towards negative `y` and turning right is clockwise as
+//! drawn on screen.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use thiserror::Error;
+
+/// A position or offset on the plane. Points order by `x` and then `y`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Point2<i64> {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    /// Steps along the grid lines from here to `other`
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Moves a king's steps from here to `other`, where diagonal steps count as one
+    pub fn chebyshev(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The neighbouring point in `dir`
+    pub fn step(self, dir: impl Into<Self>) -> Self {
+        self + dir.into()
+    }
+
+    /// Rotated a quarter turn clockwise about the origin
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotated a quarter turn anticlockwise about the origin
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The four points sharing an edge with this one
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Dir4::ALL.into_iter().map(move |d| self.step(d))
+    }
+
+    /// The eight points sharing an edge or a corner with this one
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Dir8::ALL.into_iter().map(move |d| self.step(d))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("'{0}' is not a direction, which should be one of ^>v< or UDLR")]
+pub struct ParseDirError(pub char);
+
+/// One of the four directions along the grid lines
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Every direction, clockwise from up
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// Offset of one step in this direction
+    pub fn offset(self) -> Point2<i64> {
+        match self {
+            Self::Up => Point2::new(0, -1),
+            Self::Right => Point2::new(1, 0),
+            Self::Down => Point2::new(0, 1),
+            Self::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// Reads an arrow such as `^` or a letter such as `U`
+impl TryFrom<char> for Dir4 {
+    type Error = ParseDirError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' => Ok(Self::Up),
+            '>' | 'R' => Ok(Self::Right),
+            'v' | 'D' => Ok(Self::Down),
+            '<' | 'L' => Ok(Self::Left),
+            _ => Err(ParseDirError(c)),
+        }
+    }
+}
+
+impl From<Dir4> for Point2<i64> {
+    fn from(dir: Dir4) -> Self {
+        dir.offset()
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[2 * dir.index()]
+    }
+}
+
+/// One of the eight compass directions, including the diagonals
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Every direction, clockwise from north
+    pub const ALL: [Self; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turned an eighth of a turn clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turned an eighth of a turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// Offset of one step in this direction, with north being up
+    pub fn offset(self) -> Point2<i64> {
+        let (x, y) = match self {
+            Self::N => (0, -1),
+            Self::NE => (1, -1),
+            Self::E => (1, 0),
+            Self::SE => (1, 1),
+            Self::S => (0, 1),
+            Self::SW => (-1, 1),
+            Self::W => (-1, 0),
+            Self::NW => (-1, -1),
+        };
+
+        Point2::new(x, y)
+    }
+}
+
+impl From<Dir8> for Point2<i64> {
+    fn from(dir: Dir8) -> Self {
+        dir.offset()
+    }
+}
+
+/// The smallest rectangle holding a set of points, with both corners inclusive
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point2<i64>,
+    pub max: Point2<i64>,
+}
+
+impl Bounds {
+    /// Bounds of a single point
+    pub fn at(p: Point2<i64>) -> Self {
+        Self { min: p, max: p }
+    }
+
+    /// Bounds of every point given, or `None` if there are none
+    pub fn of(points: impl IntoIterator<Item = Point2<i64>>) -> Option<Self> {
+        points.into_iter().fold(None, |b, p| {
+            Some(b.map_or(Self::at(p), |b: Self| b.including(p)))
+        })
+    }
+
+    /// Grown just enough to hold `p`
+    pub fn including(self, p: Point2<i64>) -> Self {
+        Self {
+            min: Point2::new(self.min.x.min(p.x), self.min.y.min(p.y)),
+            max: Point2::new(self.max.x.max(p.x), self.max.y.max(p.y)),
+        }
+    }
+
+    pub fn contains(&self, p: Point2<i64>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> u64 {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+
+    /// Every point inside, a row at a time from the top
+    pub fn points(&self) -> impl Iterator<Item = Point2<i64>> {
+        let Self { min, max } = *self;
+
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.offset().rotate_right(), dir.turn_right().offset());
+            assert_eq!(dir.offset().rotate_left(), dir.turn_left().offset());
+            assert_eq!(Dir8::from(dir).offset(), dir.offset());
+        }
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.reverse().offset(), -dir.offset());
+        }
+
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+    }
+
+    #[test]
+    fn parse_directions() {
+        let arrows = "^>v<".chars().map(Dir4::try_from);
+        let letters = "URDL".chars().map(Dir4::try_from);
+
+        assert!(arrows.eq(Dir4::ALL.map(Ok)));
+        assert!(letters.eq(Dir4::ALL.map(Ok)));
+        assert_eq!(Dir4::try_from('x'), Err(ParseDirError('x')));
+    }
+
+    #[test]
+    fn distances() {
+        let p = Point2::new(3, -4);
+
+        assert_eq!(p.manhattan(Point2::ORIGIN), 7);
+        assert_eq!(p.chebyshev(Point2::ORIGIN), 4);
+        assert_eq!(p.step(Dir4::Up), Point2::new(3, -5));
+        assert_eq!(p - p.step(Dir8::SE), Point2::new(-1, -1));
+        assert_eq!(Point2::new(1, 2) * 3, Point2::new(3, 6));
+        assert_eq!(p.neighbours4().count(), 4);
+        assert!(p.neighbours8().all(|n| n.chebyshev(p) == 1));
+    }
+
+    #[test]
+    fn bounds() {
+        let points = [(2, 1), (-1, 3), (0, 0)].map(Point2::from);
+        let b = Bounds::of(points).unwrap();
+
+        assert_eq!(b.min, Point2::new(-1, 0));
+        assert_eq!(b.max, Point2::new(2, 3));
+        assert_eq!((b.width(), b.height()), (4, 4));
+        assert!(points.iter().all(|&p| b.contains(p)));
+        assert!(!b.contains(Point2::new(3, 0)));
+        assert_eq!(b.points().count(), 16);
+        assert_eq!(b.points().next(), Some(b.min));
+        assert_eq!(Bounds::of([]), None);
+    }
+}
@@ -0,0 +1,855 @@
+//! Rectangular grids stored row-major in a single allocation. Light operations work on whole row
+//! slices of a [`Region`] at a time, which keeps the inner loops simple enough for the compiler to
+//! vectorize. [`TreeMap`] holds a height per cell parsed from lines of text, and a
+//! [`DirectionalScan`] computes a value for every cell from what lies between it and each edge.
+
+use std::{borrow::Borrow, ops::ControlFlow};
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// An inclusive rectangle of cells within a grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Region {
+    left: usize,
+    top: usize,
+    right: usize,
+    bottom: usize,
+}
+
+impl Region {
+    /// Creates the region spanning from `upper_left` to `lower_right`, both `(x, y)` and inclusive
+    pub fn new(upper_left: (usize, usize), lower_right: (usize, usize)) -> Self {
+        assert!(
+            upper_left.0 <= lower_right.0 && upper_left.1 <= lower_right.1,
+            "the upper left corner of a region should not be past its lower right corner"
+        );
+
+        Region {
+            left: upper_left.0,
+            top: upper_left.1,
+            right: lower_right.0,
+            bottom: lower_right.1,
+        }
+    }
+
+    /// Whether the region lies entirely within a grid of the given dimensions
+    pub fn fits(&self, width: usize, height: usize) -> bool {
+        self.right < width && self.bottom < height
+    }
+
+    /// Panics unless the region lies within `cells` laid out in rows of `width`
+    fn assert_fits(&self, cells: &[usize], width: usize) {
+        assert!(
+            self.fits(width, cells.len() / width),
+            "the region {self:?} should fit within the {width} by {} grid",
+            cells.len() / width
+        );
+    }
+
+    /// Slices out the part of each row of `cells` that lies within this region, panicking if it
+    /// does not fit
+    fn rows<'a>(&self, cells: &'a [usize], width: usize) -> impl Iterator<Item = &'a [usize]> {
+        self.assert_fits(cells, width);
+
+        let columns = self.left..=self.right;
+
+        cells
+            .chunks_exact(width)
+            .skip(self.top)
+            .take(self.bottom - self.top + 1)
+            .map(move |row| &row[columns.clone()])
+    }
+
+    /// Mutable version of [`Region::rows`]
+    fn rows_mut<'a>(
+        &self,
+        cells: &'a mut [usize],
+        width: usize,
+    ) -> impl Iterator<Item = &'a mut [usize]> {
+        self.assert_fits(cells, width);
+
+        let columns = self.left..=self.right;
+
+        cells
+            .chunks_exact_mut(width)
+            .skip(self.top)
+            .take(self.bottom - self.top + 1)
+            .map(move |row| &mut row[columns.clone()])
+    }
+}
+
+/// A `WIDTH` by `HEIGHT` grid of lights that can be switched on, off, or toggled by region. How
+/// each action changes a light is defined on a contiguous row slice by the implementor.
+///
+/// Region operations panic if the region does not fit within the grid.
+pub trait LightChanger {
+    const WIDTH: usize;
+
+    const HEIGHT: usize;
+
+    fn turn_on_row(row: &mut [usize]);
+
+    fn turn_off_row(row: &mut [usize]);
+
+    fn toggle_row(row: &mut [usize]);
+
+    /// Every light in row-major order
+    fn lights(&self) -> &[usize];
+
+    fn lights_mut(&mut self) -> &mut [usize];
+
+    fn turn_on(&mut self, region: &Region) {
+        region
+            .rows_mut(self.lights_mut(), Self::WIDTH)
+            .for_each(Self::turn_on_row);
+    }
+
+    fn turn_off(&mut self, region: &Region) {
+        region
+            .rows_mut(self.lights_mut(), Self::WIDTH)
+            .for_each(Self::turn_off_row);
+    }
+
+    fn toggle(&mut self, region: &Region) {
+        region
+            .rows_mut(self.lights_mut(), Self::WIDTH)
+            .for_each(Self::toggle_row);
+    }
+
+    /// Brightness of the light at `(x, y)`, or `None` if it is outside the grid
+    fn light(&self, x: usize, y: usize) -> Option<usize> {
+        (x < Self::WIDTH && y < Self::HEIGHT).then(|| self.lights()[y * Self::WIDTH + x])
+    }
+
+    /// Total brightness of the lights within `region`
+    fn brightness_in(&self, region: &Region) -> usize {
+        region
+            .rows(self.lights(), Self::WIDTH)
+            .map(|row| row.iter().sum::<usize>())
+            .sum()
+    }
+
+    /// Total brightness of the whole grid, which for on/off lights is the number that are on
+    fn count_on(&self) -> usize {
+        self.lights().iter().sum::<usize>()
+    }
+}
+
+/// Lights that are either on (1) or off (0)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimpleLights<const WIDTH: usize, const HEIGHT: usize> {
+    lights: Vec<usize>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Default for SimpleLights<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        SimpleLights {
+            lights: vec![0; WIDTH * HEIGHT],
+        }
+    }
+}
+
+impl<const W: usize, const H: usize> LightChanger for SimpleLights<W, H> {
+    const WIDTH: usize = W;
+
+    const HEIGHT: usize = H;
+
+    fn turn_on_row(row: &mut [usize]) {
+        row.fill(1);
+    }
+
+    fn turn_off_row(row: &mut [usize]) {
+        row.fill(0);
+    }
+
+    fn toggle_row(row: &mut [usize]) {
+        row.iter_mut().for_each(|l| *l ^= 1);
+    }
+
+    fn lights(&self) -> &[usize] {
+        &self.lights
+    }
+
+    fn lights_mut(&mut self) -> &mut [usize] {
+        &mut self.lights
+    }
+}
+
+/// Lights with a brightness that can be raised indefinitely but never drops below zero. Turning a
+/// light on raises it by 1, toggling raises it by 2, and turning it off lowers it by 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariableLights<const WIDTH: usize, const HEIGHT: usize> {
+    lights: Vec<usize>,
+}
+
+impl<const WIDTH: usize, const HEIGHT: usize> Default for VariableLights<WIDTH, HEIGHT> {
+    fn default() -> Self {
+        VariableLights {
+            lights: vec![0; WIDTH * HEIGHT],
+        }
+    }
+}
+
+impl<const W: usize, const H: usize> LightChanger for VariableLights<W, H> {
+    const WIDTH: usize = W;
+
+    const HEIGHT: usize = H;
+
+    fn turn_on_row(row: &mut [usize]) {
+        row.iter_mut().for_each(|l| *l += 1);
+    }
+
+    fn turn_off_row(row: &mut [usize]) {
+        row.iter_mut().for_each(|l| *l = l.saturating_sub(1));
+    }
+
+    fn toggle_row(row: &mut [usize]) {
+        row.iter_mut().for_each(|l| *l += 2);
+    }
+
+    fn lights(&self) -> &[usize] {
+        &self.lights
+    }
+
+    fn lights_mut(&mut self) -> &mut [usize] {
+        &mut self.lights
+    }
+}
+
+/// Reasons lines of text cannot become a [`TreeMap`]. Rows and columns are numbered from 1.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TreeMapError {
+    #[error("row {} is {found} cells wide but the rows before it are {expected}", .row + 1)]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("'{}' at row {}, column {} is not a height", char::from(*.byte), .row + 1, .column + 1)]
+    InvalidHeight { row: usize, column: usize, byte: u8 },
+}
+
+/// A row-major collection of tree heights for a rectangular grid
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TreeMap {
+    shape: (usize, usize),
+    data: Vec<u8>,
+}
+
+impl TreeMap {
+    /// Builds a map one line at a time, each byte becoming the height `height` maps it to.
+    /// Fails on the first byte without a height or line with a different width than the first.
+    pub fn try_from_lines<S, I, H>(lines: I, height: H) -> Result<Self, TreeMapError>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+        H: Fn(u8) -> Option<u8>,
+    {
+        let mut width = None;
+        let mut rows = 0;
+        let mut data = Vec::new();
+
+        for (row, l) in lines.into_iter().enumerate() {
+            let l = l.as_ref().as_bytes();
+            let expected = *width.get_or_insert(l.len());
+
+            if l.len() != expected {
+                return Err(TreeMapError::Ragged {
+                    row,
+                    expected,
+                    found: l.len(),
+                });
+            }
+
+            for (column, &byte) in l.iter().enumerate() {
+                data.push(height(byte).ok_or(TreeMapError::InvalidHeight { row, column, byte })?);
+            }
+
+            rows += 1;
+        }
+
+        Ok(TreeMap {
+            shape: (width.unwrap_or(0), rows),
+            data,
+        })
+    }
+
+    /// Builds a map from lines of the digits 0 through 9
+    pub fn try_from_digits<S, I>(lines: I) -> Result<Self, TreeMapError>
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+    {
+        Self::try_from_lines(lines, |b| b.is_ascii_digit().then(|| b - b'0'))
+    }
+
+    /// Number of `(columns, rows)` in the map
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// Height of the tree at `column` of `row`, if there is one
+    pub fn get(&self, row: usize, column: usize) -> Option<u8> {
+        (row < self.shape.1 && column < self.shape.0)
+            .then(|| self.data[row * self.shape.0 + column])
+    }
+
+    /// Every height in row-major order
+    pub fn heights(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Each row of heights from top to bottom
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[u8]> {
+        let width = self.shape.0;
+
+        (0..self.shape.1).map(move |r| &self.data[r * width..(r + 1) * width])
+    }
+}
+
+/// Builds a map from lines of digits, panicking on anything [`TreeMap::try_from_digits`] rejects
+impl<S> FromIterator<S> for TreeMap
+where
+    S: AsRef<str>,
+{
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self::try_from_digits(iter).expect("A TreeMap can only be built from rows of ascii numbers")
+    }
+}
+
+/// A side of a grid that a [`DirectionalScan`] can march in from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Edge {
+    pub const ALL: [Edge; 4] = [Edge::Left, Edge::Right, Edge::Top, Edge::Bottom];
+}
+
+/// Grids with at least this many cells have their columns scanned in parallel by
+/// [`ColumnStrategy::for_size`]. Below it, splitting the work costs more than it saves.
+pub const PARALLEL_COLUMNS_MIN_CELLS: usize = 1 << 20;
+
+/// How a [`DirectionalScan`] marches down and up the columns of a grid. Rows are contiguous so
+/// they are always scanned in parallel, while a column's cells are a row apart.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColumnStrategy {
+    /// Every column together on one thread, a row at a time so each row is read in order
+    #[default]
+    Sequential,
+    /// Transposes the grid so columns become rows to scan in parallel, then transposes the
+    /// outputs back. Copying a reference to every cell and the outputs twice makes it several
+    /// times slower than the others without many threads to spread the scan over.
+    Transposed,
+    /// Splits the columns into a band per thread, each marched a row at a time like
+    /// [`ColumnStrategy::Sequential`]
+    Strided,
+}
+
+impl ColumnStrategy {
+    /// [`ColumnStrategy::Strided`] for grids of at least [`PARALLEL_COLUMNS_MIN_CELLS`], and
+    /// [`ColumnStrategy::Sequential`] for smaller ones
+    pub fn for_size(cells: usize) -> Self {
+        if cells >= PARALLEL_COLUMNS_MIN_CELLS {
+            Self::Strided
+        } else {
+            Self::Sequential
+        }
+    }
+}
+
+/// What a [`DirectionalScan`] does to the output of each cell on the border before scanning
+type EdgeInit<'a, T> = Box<dyn Fn(&mut T) + 'a>;
+
+/// Computes an output for every cell of a row-major grid by marching in from its edges. Each
+/// line of cells, a row from the left or right or a column from the top or bottom, is folded
+/// over in order with its own state, letting each cell's output take in everything between it and
+/// that edge.
+///
+/// The trees visible from outside a grid are those taller than everything between them and some
+/// edge, so a line's state is the tallest tree seen so far. Trees on the border are always
+/// visible, which the edge initializer marks before the scan starts, and a line can stop once it
+/// reaches the tallest height since nothing beyond is visible from that edge:
+///
+/// ```
+/// use std::ops::ControlFlow;
+///
+/// use advent_core::util::grid::DirectionalScan;
+///
+/// let heights: [usize; 15] = [3, 0, 3, 7, 3, 2, 5, 5, 1, 2, 6, 5, 3, 3, 2];
+/// let visible = DirectionalScan::new(&heights, 5, false)
+///     .edge_init(|v| *v = true)
+///     .run(
+///         || 0,
+///         |&h, visible, tallest| {
+///             *visible |= h > *tallest;
+///             *tallest = h.max(*tallest);
+///
+///             // Nothing can be seen past the tallest possible tree
+///             if h == 9 {
+///                 ControlFlow::Break(())
+///             } else {
+///                 ControlFlow::Continue(())
+///             }
+///         },
+///     );
+///
+/// assert_eq!(visible.iter().filter(|&&v| v).count(), 14);
+/// ```
+///
+/// A tree's scenic score multiplies how many trees it can see towards each edge. Marching from an
+/// edge, a line's state holds the viewing distance for every height, which is the distance to the
+/// nearest tree at least that tall. Each tree's score is multiplied by the distance for its own
+/// height, so starting every score at 1 gives the product over all four edges:
+///
+/// ```
+/// use std::ops::ControlFlow;
+///
+/// use advent_core::util::grid::DirectionalScan;
+///
+/// let heights: [usize; 15] = [3, 0, 3, 7, 3, 2, 5, 5, 1, 2, 6, 5, 3, 3, 2];
+/// let scores = DirectionalScan::new(&heights, 5, 1).run(
+///     || [0; 10],
+///     |&h, score, distances| {
+///         *score *= distances[h];
+///
+///         for (height, d) in distances.iter_mut().enumerate() {
+///             *d = if height > h { *d + 1 } else { 1 };
+///         }
+///
+///         ControlFlow::Continue(())
+///     },
+/// );
+///
+/// assert_eq!(scores.iter().max(), Some(&2));
+/// ```
+pub struct DirectionalScan<'a, C, T> {
+    cells: &'a [C],
+    width: usize,
+    initial: T,
+    edges: Vec<Edge>,
+    edge_init: Option<EdgeInit<'a, T>>,
+    columns: ColumnStrategy,
+}
+
+impl<'a, C, T> DirectionalScan<'a, C, T>
+where
+    C: Sync,
+    T: Clone + Send,
+{
+    /// A scan of `cells` laid out in rows of `width` from all four edges, with every output
+    /// starting as `initial`
+    pub fn new(cells: &'a [C], width: usize, initial: T) -> Self {
+        Self {
+            cells,
+            width,
+            initial,
+            edges: Edge::ALL.to_vec(),
+            edge_init: None,
+            columns: ColumnStrategy::Sequential,
+        }
+    }
+
+    /// Only marches in from `edges` rather than all four
+    pub fn from_edges(mut self, edges: &[Edge]) -> Self {
+        self.edges = edges.to_vec();
+        self
+    }
+
+    /// Applies `init` to the output of every cell on the border of the grid before scanning
+    pub fn edge_init(mut self, init: impl Fn(&mut T) + 'a) -> Self {
+        self.edge_init = Some(Box::new(init));
+        self
+    }
+
+    /// Marches the columns in the way `strategy` describes rather than
+    /// [`ColumnStrategy::Sequential`]
+    pub fn columns(mut self, strategy: ColumnStrategy) -> Self {
+        self.columns = strategy;
+        self
+    }
+
+    /// The output of every cell in row-major order. Each line from each edge starts with state
+    /// made by `state`, then `step` is given every cell in the line in order along with its
+    /// output and the state, until it breaks to leave the rest of that line alone. Rows are
+    /// marched in parallel, while columns are marched as set by [`DirectionalScan::columns`].
+    pub fn run<S, F>(self, state: impl Fn() -> S + Sync, step: F) -> Vec<T>
+    where
+        S: Send,
+        F: Fn(&C, &mut T, &mut S) -> ControlFlow<()> + Sync,
+    {
+        let Self {
+            cells,
+            width,
+            initial,
+            edges,
+            edge_init,
+            columns,
+        } = self;
+
+        let mut outputs = vec![initial; cells.len()];
+
+        if width == 0 || cells.is_empty() {
+            return outputs;
+        }
+
+        let height = cells.len() / width;
+
+        if let Some(init) = edge_init {
+            for (i, output) in outputs.iter_mut().enumerate() {
+                let (row, column) = (i / width, i % width);
+
+                if row == 0 || row + 1 == height || column == 0 || column + 1 == width {
+                    init(output);
+                }
+            }
+        }
+
+        let rows = (edges.contains(&Edge::Left), edges.contains(&Edge::Right));
+
+        if rows.0 || rows.1 {
+            scan_lines(cells, &mut outputs, width, rows, &state, &step);
+        }
+
+        let (top, bottom) = (edges.contains(&Edge::Top), edges.contains(&Edge::Bottom));
+
+        if !top && !bottom {
+            return outputs;
+        }
+
+        match columns {
+            ColumnStrategy::Sequential => {
+                if top {
+                    let rows = cells.chunks(width).zip(outputs.chunks_mut(width));
+
+                    march_columns(rows, width, &state, &step);
+                }
+
+                if bottom {
+                    let rows = cells.chunks(width).zip(outputs.chunks_mut(width)).rev();
+
+                    march_columns(rows, width, &state, &step);
+                }
+            }
+            ColumnStrategy::Transposed => {
+                let mut cells_t = vec![&cells[0]; cells.len()];
+                let mut outputs_t = outputs.clone();
+
+                transpose_into(cells, &mut cells_t, width, height, |c| c);
+                transpose_into(&outputs, &mut outputs_t, width, height, T::clone);
+                scan_lines(
+                    &cells_t,
+                    &mut outputs_t,
+                    height,
+                    (top, bottom),
+                    &state,
+                    &step,
+                );
+                transpose_into(&outputs_t, &mut outputs, height, width, T::clone);
+            }
+            ColumnStrategy::Strided => {
+                let band = width.div_ceil(rayon::current_num_threads());
+                let mut bands = (0..width.div_ceil(band))
+                    .map(|_| Vec::with_capacity(height))
+                    .collect::<Vec<_>>();
+
+                for row in outputs.chunks_mut(width) {
+                    for (rows, part) in bands.iter_mut().zip(row.chunks_mut(band)) {
+                        rows.push(part);
+                    }
+                }
+
+                bands
+                    .into_par_iter()
+                    .enumerate()
+                    .for_each(|(i, mut outputs)| {
+                        let start = i * band;
+                        let end = (start + band).min(width);
+                        let cells = || cells.chunks(width).map(|row| &row[start..end]);
+
+                        if top {
+                            let rows = cells().zip(outputs.iter_mut().map(|o| &mut **o));
+
+                            march_columns(rows, end - start, &state, &step);
+                        }
+
+                        if bottom {
+                            let rows = cells()
+                                .rev()
+                                .zip(outputs.iter_mut().rev().map(|o| &mut **o));
+
+                            march_columns(rows, end - start, &state, &step);
+                        }
+                    });
+            }
+        }
+
+        outputs
+    }
+}
+
+/// Folds `step` over `line` with fresh state until it breaks
+fn fold_line<'a, C, X, T, S>(
+    line: impl Iterator<Item = (&'a X, &'a mut T)>,
+    state: impl Fn() -> S,
+    step: impl Fn(&C, &mut T, &mut S) -> ControlFlow<()>,
+) where
+    X: Borrow<C> + 'a,
+    T: 'a,
+{
+    let mut state = state();
+
+    for (cell, output) in line {
+        if step(cell.borrow(), output, &mut state).is_break() {
+            break;
+        }
+    }
+}
+
+/// Folds `step` over every row of `cells` laid out in rows of `len` in parallel, from the start
+/// of each row and from its end as chosen by `(forward, backward)`
+fn scan_lines<C, X, T, S>(
+    cells: &[X],
+    outputs: &mut [T],
+    len: usize,
+    (forward, backward): (bool, bool),
+    state: &(impl Fn() -> S + Sync),
+    step: &(impl Fn(&C, &mut T, &mut S) -> ControlFlow<()> + Sync),
+) where
+    X: Borrow<C> + Sync,
+    T: Send,
+{
+    cells
+        .par_chunks(len)
+        .zip(outputs.par_chunks_mut(len))
+        .for_each(|(cells, outputs)| {
+            if forward {
+                fold_line(cells.iter().zip(outputs.iter_mut()), state, step);
+            }
+
+            if backward {
+                fold_line(cells.iter().zip(outputs.iter_mut()).rev(), state, step);
+            }
+        });
+}
+
+/// Folds `step` down the `len` columns of `rows` together, one row at a time so that each row is
+/// read in order. A column that breaks is left alone from then on.
+fn march_columns<'c, 'o, C: 'c, T: 'o, S>(
+    rows: impl Iterator<Item = (&'c [C], &'o mut [T])>,
+    len: usize,
+    state: impl Fn() -> S,
+    step: impl Fn(&C, &mut T, &mut S) -> ControlFlow<()>,
+) {
+    let mut states = (0..len).map(|_| Some(state())).collect::<Vec<_>>();
+
+    for (cells, outputs) in rows {
+        for ((cell, output), line) in cells.iter().zip(outputs).zip(&mut states) {
+            if let Some(s) = line {
+                if step(cell, output, s).is_break() {
+                    *line = None;
+                }
+            }
+        }
+    }
+}
+
+/// Writes the row-major `width` by `height` grid `src` into `dest` column-major, mapping each
+/// cell with `f`. Works in square tiles so both the reads and the writes stay within a few cache
+/// lines.
+fn transpose_into<'s, X, Y>(
+    src: &'s [X],
+    dest: &mut [Y],
+    width: usize,
+    height: usize,
+    f: impl Fn(&'s X) -> Y,
+) {
+    const TILE: usize = 32;
+
+    for r0 in (0..height).step_by(TILE) {
+        for c0 in (0..width).step_by(TILE) {
+            for r in r0..(r0 + TILE).min(height) {
+                for c in c0..(c0 + TILE).min(width) {
+                    dest[c * height + r] = f(&src[r * width + c]);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn simple_regions() {
+        let mut lights = SimpleLights::<4, 3>::default();
+
+        lights.turn_on(&Region::new((1, 0), (3, 1)));
+        lights.toggle(&Region::new((0, 1), (1, 2)));
+
+        assert_eq!(lights.lights(), [0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0]);
+        assert_eq!(lights.count_on(), 8);
+        assert_eq!(lights.brightness_in(&Region::new((0, 1), (3, 2))), 5);
+        assert_eq!(lights.light(1, 1), Some(0));
+        assert_eq!(lights.light(4, 0), None);
+    }
+
+    #[test]
+    fn variable_regions() {
+        let mut lights = VariableLights::<3, 3>::default();
+
+        lights.toggle(&Region::new((0, 0), (2, 2)));
+        lights.turn_off(&Region::new((1, 1), (1, 1)));
+        lights.turn_off(&Region::new((1, 1), (2, 1)));
+        lights.turn_off(&Region::new((1, 1), (1, 1)));
+        lights.turn_on(&Region::new((0, 2), (0, 2)));
+
+        assert_eq!(lights.light(1, 1), Some(0));
+        assert_eq!(lights.light(2, 1), Some(1));
+        assert_eq!(lights.light(0, 2), Some(3));
+        assert_eq!(lights.count_on(), 2 * 9 - 2 - 1 + 1);
+        assert!(Region::new((0, 0), (2, 2)).fits(3, 3));
+        assert!(!Region::new((0, 0), (3, 2)).fits(3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "should fit within the 3 by 3 grid")]
+    fn regions_past_the_bottom() {
+        SimpleLights::<3, 3>::default().turn_on(&Region::new((0, 1), (2, 3)));
+    }
+
+    #[test]
+    fn tree_maps() {
+        let map = TreeMap::try_from_digits(["012", "345"]).unwrap();
+
+        assert_eq!(map.shape(), (3, 2));
+        assert_eq!(map.get(1, 2), Some(5));
+        assert_eq!(map.get(2, 0), None);
+        assert_eq!(map.get(0, 3), None);
+        assert_eq!(
+            map.iter_rows().rev().collect::<Vec<_>>(),
+            [[3, 4, 5], [0, 1, 2]]
+        );
+
+        let letters = TreeMap::try_from_lines(["az", "Za"], |b| {
+            b.is_ascii_alphabetic()
+                .then(|| b.to_ascii_lowercase() - b'a')
+        })
+        .unwrap();
+
+        assert_eq!(letters.heights(), [0, 25, 25, 0]);
+
+        let empty_rows = TreeMap::try_from_digits(["", ""]).unwrap();
+
+        assert_eq!(empty_rows.shape(), (0, 2));
+        assert_eq!(empty_rows.iter_rows().count(), 2);
+
+        assert_eq!(
+            TreeMap::try_from_digits(["012", "34"]),
+            Err(TreeMapError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+
+        let err = TreeMap::try_from_digits(["012", "3x5"]).unwrap_err();
+
+        assert_eq!(
+            err,
+            TreeMapError::InvalidHeight {
+                row: 1,
+                column: 1,
+                byte: b'x'
+            }
+        );
+        assert_eq!(err.to_string(), "'x' at row 2, column 2 is not a height");
+    }
+
+    #[test]
+    fn directional_scans() {
+        // Each cell's output is the number of cells before it from every edge scanned
+        let cells = [(); 12];
+        let count = |edges: &[Edge], strategy| {
+            DirectionalScan::new(&cells, 4, 0)
+                .from_edges(edges)
+                .columns(strategy)
+                .run(
+                    || 0,
+                    |_, seen, before| {
+                        *seen += *before;
+                        *before += 1;
+
+                        ControlFlow::Continue(())
+                    },
+                )
+        };
+
+        // More threads than this machine might have, so columns are split into several bands
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(3)
+            .build()
+            .unwrap();
+
+        pool.install(|| {
+            for strategy in [
+                ColumnStrategy::Sequential,
+                ColumnStrategy::Transposed,
+                ColumnStrategy::Strided,
+            ] {
+                assert_eq!(
+                    count(&[Edge::Left], strategy),
+                    [0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3]
+                );
+                assert_eq!(
+                    count(&[Edge::Bottom], strategy),
+                    [2, 2, 2, 2, 1, 1, 1, 1, 0, 0, 0, 0]
+                );
+                assert_eq!(
+                    count(&[Edge::Right, Edge::Top], strategy),
+                    [3, 2, 1, 0, 4, 3, 2, 1, 5, 4, 3, 2]
+                );
+                assert_eq!(count(&Edge::ALL, strategy), [5; 12]);
+
+                // Lines stop at the first break, and edges are marked before scanning
+                let stopped = DirectionalScan::new(&[1, 2, 3, 4, 5, 6], 3, 0)
+                    .from_edges(&[Edge::Top])
+                    .edge_init(|o| *o = 10)
+                    .columns(strategy)
+                    .run(
+                        || (),
+                        |&c, o, _| {
+                            *o += 1;
+
+                            if c < 3 {
+                                ControlFlow::Break(())
+                            } else {
+                                ControlFlow::Continue(())
+                            }
+                        },
+                    );
+
+                assert_eq!(stopped, [11, 11, 11, 10, 10, 11]);
+            }
+        });
+
+        assert!(DirectionalScan::new(&[0u8; 0], 0, 0)
+            .run(|| (), |_, _, _| ControlFlow::Continue(()))
+            .is_empty());
+        assert_eq!(
+            ColumnStrategy::for_size(PARALLEL_COLUMNS_MIN_CELLS),
+            ColumnStrategy::Strided
+        );
+        assert_eq!(
+            ColumnStrategy::for_size(99 * 99),
+            ColumnStrategy::Sequential
+        );
+    }
+}
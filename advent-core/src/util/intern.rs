@@ -0,0 +1,157 @@
+//! Interning of names that puzzles use as keys, such as wires, locations, and guests. Each
+//! distinct name is stored once in a single buffer and stood in for by a [`Symbol`], which is cheap
+//! to copy, hash, and compare, and which numbers names densely from 0 so they can index a `Vec`.
+
+use std::hash::{Hash, Hasher};
+
+use crate::util::collections::{FastHashMap, FxHasher};
+
+/// A name interned by an [`Interner`], only meaningful to the interner that produced it
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Position of the symbol among those interned, counting from 0 in the order first seen
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Distinct names mapped to [`Symbol`]s and back
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    /// Every name end to end
+    text: String,
+    /// Where each symbol's name ends in `text`
+    ends: Vec<u32>,
+    /// The latest symbol interned with each hash, earlier ones chained through `collisions`
+    by_hash: FastHashMap<u64, Symbol>,
+    collisions: Vec<Option<Symbol>>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn hash(name: &str) -> u64 {
+        let mut hasher = FxHasher::default();
+
+        name.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Looks for `name` among the symbols with its hash
+    fn find(&self, hash: u64, name: &str) -> Option<Symbol> {
+        let mut candidate = self.by_hash.get(&hash).copied();
+
+        while let Some(symbol) = candidate {
+            if self.resolve(symbol) == name {
+                return Some(symbol);
+            }
+
+            candidate = self.collisions[symbol.index()];
+        }
+
+        None
+    }
+
+    /// The symbol for `name`, interning it if it has not been seen before
+    pub fn intern(&mut self, name: &str) -> Symbol {
+        let hash = Self::hash(name);
+
+        if let Some(symbol) = self.find(hash, name) {
+            return symbol;
+        }
+
+        let symbol = Symbol(u32::try_from(self.ends.len()).expect("fewer than 2³² names"));
+
+        self.text.push_str(name);
+        self.ends
+            .push(u32::try_from(self.text.len()).expect("names fit in 4GiB"));
+        self.collisions.push(self.by_hash.insert(hash, symbol));
+
+        symbol
+    }
+
+    /// The symbol for `name` if it has been interned
+    pub fn get(&self, name: &str) -> Option<Symbol> {
+        self.find(Self::hash(name), name)
+    }
+
+    /// The name `symbol` stands for
+    ///
+    /// # Panics
+    /// If `symbol` came from a different interner with more names
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        let end = self.ends[symbol.index()] as usize;
+        let start = match symbol.index() {
+            0 => 0,
+            i => self.ends[i - 1] as usize,
+        };
+
+        &self.text[start..end]
+    }
+
+    /// Number of distinct names interned
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Every symbol with its name, in the order interned
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        (0..self.ends.len()).map(|i| {
+            let symbol = Symbol(i as u32);
+
+            (symbol, self.resolve(symbol))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn intern_and_resolve() {
+        let mut names = Interner::new();
+
+        let london = names.intern("London");
+        let dublin = names.intern("Dublin");
+
+        assert_eq!(names.intern("London"), london);
+        assert_ne!(london, dublin);
+        assert_eq!((london.index(), dublin.index()), (0, 1));
+        assert_eq!(names.resolve(dublin), "Dublin");
+        assert_eq!(names.get("Dublin"), Some(dublin));
+        assert_eq!(names.get("Belfast"), None);
+        assert_eq!(names.len(), 2);
+
+        let empty = names.intern("");
+
+        assert_eq!(names.resolve(empty), "");
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            [(london, "London"), (dublin, "Dublin"), (empty, "")]
+        );
+    }
+
+    #[test]
+    fn many_names() {
+        let mut names = Interner::new();
+        let symbols = (0..10_000)
+            .map(|i| names.intern(&format!("w{i}")))
+            .collect::<Vec<_>>();
+
+        assert_eq!(names.len(), 10_000);
+
+        for (i, &symbol) in symbols.iter().enumerate() {
+            assert_eq!(names.resolve(symbol), format!("w{i}"));
+            assert_eq!(names.get(&format!("w{i}")), Some(symbol));
+        }
+    }
+}
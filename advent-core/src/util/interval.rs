@@ -0,0 +1,276 @@
+//! Inclusive ranges of values and sets of them. Operations that need to know whether two ranges
+//! are next to each other or how many values they hold require a [`Discrete`] index such as the
+//! integer types.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+/// Index types where every value has a neighbour exactly one away, so ranges like `2-4` and `5-7`
+/// cover the same values as `2-7`
+pub trait Discrete: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + From<u8> {}
+
+impl<T> Discrete for T where T: Copy + Ord + Add<Output = T> + Sub<Output = T> + From<u8> {}
+
+/// Simple type to capture the start and end of a 1D range. Both ends are inclusive and the start
+/// should not be after the end.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SectionRange<Idx>(pub Idx, pub Idx);
+
+impl<Idx> SectionRange<Idx>
+where
+    Idx: PartialEq + PartialOrd + Copy,
+{
+    /// Determines if this `SectionRange` inclusively contains the given value
+    pub fn contains(&self, value: Idx) -> bool {
+        value >= self.0 && value <= self.1
+    }
+
+    /// Determines if this `SectionRange` inclusively contains the other `SectionRange`
+    pub fn contains_range(&self, other: &Self) -> bool {
+        self.0 <= other.0 && self.1 >= other.1
+    }
+
+    /// Determines if this `SectionRange` inclusively overlaps the other `SectionRange`
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.contains(other.0)
+            || self.contains(other.1)
+            || other.contains(self.0)
+            || other.contains(self.1)
+    }
+}
+
+impl<Idx: Discrete> SectionRange<Idx> {
+    /// Number of values in the range
+    pub fn len(&self) -> Idx {
+        self.1 - self.0 + Idx::from(1)
+    }
+
+    /// Always `false` since both ends are part of the range
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Whether the ranges overlap or one ends right before the other starts
+    pub fn touches(&self, other: &Self) -> bool {
+        let (first, second) = if self.0 <= other.0 {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        second.0 <= first.1 || second.0 - first.1 == Idx::from(1)
+    }
+
+    /// Values in both ranges, if there are any
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let start = self.0.max(other.0);
+        let end = self.1.min(other.1);
+
+        (start <= end).then_some(SectionRange(start, end))
+    }
+
+    /// Values in either range in ascending order. Ranges that touch become a single range and the
+    /// second is only present when there is a gap between them.
+    pub fn union(&self, other: &Self) -> (Self, Option<Self>) {
+        let (first, second) = if self.0 <= other.0 {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        if first.touches(second) {
+            (SectionRange(first.0, first.1.max(second.1)), None)
+        } else {
+            (first.clone(), Some(second.clone()))
+        }
+    }
+
+    /// Values of this range that are not in `other`, split into the part before `other` and the
+    /// part after it
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if self.intersection(other).is_none() {
+            return (Some(self.clone()), None);
+        }
+
+        let one = Idx::from(1);
+        let before = (self.0 < other.0).then(|| SectionRange(self.0, other.0 - one));
+        let after = (self.1 > other.1).then(|| SectionRange(other.1 + one, self.1));
+
+        (before, after)
+    }
+}
+
+/// A `SectionRange` is represented by two numbers separated with a hyphen
+impl<D, I> FromStr for SectionRange<I>
+where
+    D: Debug,
+    I: FromStr<Err = D>,
+{
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_once('-')
+            .map(|(start, end)| {
+                SectionRange(
+                    start.parse().expect("range start should be a number"),
+                    end.parse().expect("range end should be a number"),
+                )
+            })
+            .ok_or("Provided str does not represent a range")
+    }
+}
+
+/// Values covered by any number of ranges, kept as ascending ranges that do not touch each other
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<Idx>(Vec<SectionRange<Idx>>);
+
+impl<Idx: Discrete> IntervalSet<Idx> {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Adds every value in `range`, merging it with any ranges it touches
+    pub fn insert(&mut self, range: SectionRange<Idx>) {
+        // Ranges entirely before `range` with a gap between them are left alone
+        let start = self
+            .0
+            .partition_point(|r| r.1 < range.0 && !r.touches(&range));
+        let end = start + self.0[start..].partition_point(|r| r.touches(&range));
+
+        let merged = self.0[start..end].iter().fold(range, |a, r| a.union(r).0);
+
+        self.0.splice(start..end, [merged]);
+    }
+
+    /// Takes every value in `range` out of the set, splitting any range it falls within
+    pub fn remove(&mut self, range: &SectionRange<Idx>) {
+        let start = self.0.partition_point(|r| r.1 < range.0);
+        let end = start + self.0[start..].partition_point(|r| r.0 <= range.1);
+
+        let remaining = self.0[start..end]
+            .iter()
+            .flat_map(|r| {
+                let (before, after) = r.difference(range);
+
+                before.into_iter().chain(after)
+            })
+            .collect::<Vec<_>>();
+
+        self.0.splice(start..end, remaining);
+    }
+
+    pub fn contains(&self, value: Idx) -> bool {
+        let idx = self.0.partition_point(|r| r.1 < value);
+
+        self.0.get(idx).is_some_and(|r| r.contains(value))
+    }
+
+    /// The disjoint ranges making up the set in ascending order
+    pub fn ranges(&self) -> &[SectionRange<Idx>] {
+        &self.0
+    }
+
+    /// Total number of values covered by the set
+    pub fn len(&self) -> Idx {
+        self.0.iter().fold(Idx::from(0), |total, r| total + r.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<Idx: Discrete> FromIterator<SectionRange<Idx>> for IntervalSet<Idx> {
+    fn from_iter<T: IntoIterator<Item = SectionRange<Idx>>>(iter: T) -> Self {
+        let mut set = Self::new();
+
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn range_operations() {
+        let r = SectionRange(2u32, 6);
+
+        assert_eq!(r.len(), 5);
+        assert_eq!(
+            r.intersection(&SectionRange(4, 8)),
+            Some(SectionRange(4, 6))
+        );
+        assert_eq!(r.intersection(&SectionRange(7, 8)), None);
+
+        assert_eq!(r.union(&SectionRange(7, 8)), (SectionRange(2, 8), None));
+        assert_eq!(r.union(&SectionRange(0, 3)), (SectionRange(0, 6), None));
+        assert_eq!(
+            r.union(&SectionRange(8, 9)),
+            (SectionRange(2, 6), Some(SectionRange(8, 9)))
+        );
+        assert_eq!(
+            SectionRange(8, 9).union(&r),
+            (SectionRange(2, 6), Some(SectionRange(8, 9)))
+        );
+
+        assert_eq!(
+            r.difference(&SectionRange(3, 4)),
+            (Some(SectionRange(2, 2)), Some(SectionRange(5, 6)))
+        );
+        assert_eq!(
+            r.difference(&SectionRange(0, 4)),
+            (None, Some(SectionRange(5, 6)))
+        );
+        assert_eq!(r.difference(&SectionRange(0, 9)), (None, None));
+        assert_eq!(r.difference(&SectionRange(7, 9)), (Some(r.clone()), None));
+
+        // Nothing is past the largest value, so touching is checked without overflowing
+        assert!(SectionRange(0u8, 254).touches(&SectionRange(255, 255)));
+    }
+
+    #[test]
+    fn interval_sets() {
+        let mut set = [
+            SectionRange(10i64, 12),
+            SectionRange(1, 3),
+            SectionRange(20, 25),
+            SectionRange(4, 5),
+            SectionRange(-3, -1),
+        ]
+        .into_iter()
+        .collect::<IntervalSet<_>>();
+
+        assert_eq!(
+            set.ranges(),
+            [
+                SectionRange(-3, -1),
+                SectionRange(1, 5),
+                SectionRange(10, 12),
+                SectionRange(20, 25)
+            ]
+        );
+        assert_eq!(set.len(), 17);
+
+        set.insert(SectionRange(0, 11));
+
+        assert_eq!(set.ranges(), [SectionRange(-3, 12), SectionRange(20, 25)]);
+        assert!(set.contains(-3) && set.contains(12) && !set.contains(13));
+
+        set.remove(&SectionRange(0, 21));
+
+        assert_eq!(set.ranges(), [SectionRange(-3, -1), SectionRange(22, 25)]);
+
+        set.remove(&SectionRange(-10, 30));
+
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+    }
+}
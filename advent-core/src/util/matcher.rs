@@ -0,0 +1,160 @@
+//! Searching for many byte patterns at once with an Aho-Corasick automaton. Every byte of the
+//! haystack is a single table lookup no matter how many patterns there are or how they overlap,
+//! and the haystack can be fed in a byte at a time as it arrives.
+
+use std::collections::VecDeque;
+
+/// Position within the automaton after some number of bytes have been fed to a [`Matcher`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct State(usize);
+
+/// Occurrence of a pattern in a haystack where `pattern` is its index in the list given to
+/// [`Matcher::new`] and `start..end` is where it was found
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub pattern: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Finds every occurrence of a fixed set of patterns, including ones that overlap
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    /// Next state for every state and byte, with failures already followed
+    transitions: Vec<[usize; 256]>,
+    /// Patterns that end after reaching each state
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl Matcher {
+    /// Builds the automaton for `patterns`. Panics if any pattern is empty.
+    pub fn new<I, P>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        // State 0 is the root, which no transition of the trie leads back to, so it doubles as
+        // "no child yet" while the trie is built
+        let mut transitions = vec![[0; 256]];
+        let mut outputs = vec![Vec::new()];
+        let mut lengths = Vec::new();
+
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+
+            assert!(!pattern.is_empty(), "pattern {id} should not be empty");
+
+            let mut state = 0;
+
+            for &b in pattern {
+                if transitions[state][usize::from(b)] == 0 {
+                    transitions.push([0; 256]);
+                    outputs.push(Vec::new());
+                    transitions[state][usize::from(b)] = transitions.len() - 1;
+                }
+
+                state = transitions[state][usize::from(b)];
+            }
+
+            outputs[state].push(id);
+            lengths.push(pattern.len());
+        }
+
+        // Breadth first so the failure state of every state is complete before it is needed
+        let mut failures = vec![0; transitions.len()];
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(state) = queue.pop_front() {
+            for b in (u8::MIN..=u8::MAX).map(usize::from) {
+                let child = transitions[state][b];
+                let fallback = transitions[failures[state]][b];
+
+                if child == 0 {
+                    transitions[state][b] = fallback;
+                } else {
+                    failures[child] = if state == 0 { 0 } else { fallback };
+
+                    let inherited = outputs[failures[child]].clone();
+
+                    outputs[child].extend(inherited);
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            outputs,
+            lengths,
+        }
+    }
+
+    /// Feeds the next byte of the haystack to the automaton and returns the indices of the
+    /// patterns that end with it
+    pub fn step(&self, state: &mut State, byte: u8) -> &[usize] {
+        state.0 = self.transitions[state.0][usize::from(byte)];
+
+        &self.outputs[state.0]
+    }
+
+    /// Every occurrence of every pattern in `haystack`, ordered by where they end
+    pub fn find_iter<'a>(&'a self, haystack: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        let mut state = State::default();
+
+        haystack.iter().enumerate().flat_map(move |(i, &b)| {
+            self.step(&mut state, b).iter().map(move |&pattern| Match {
+                pattern,
+                start: i + 1 - self.lengths[pattern],
+                end: i + 1,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn matches(matcher: &Matcher, haystack: &str) -> Vec<(usize, usize)> {
+        matcher
+            .find_iter(haystack.as_bytes())
+            .map(|m| (m.pattern, m.start))
+            .collect()
+    }
+
+    #[test]
+    fn overlapping_patterns() {
+        let matcher = Matcher::new(["he", "she", "his", "hers"]);
+
+        assert_eq!(matches(&matcher, "ushers"), [(1, 1), (0, 2), (3, 2)]);
+        assert_eq!(matches(&matcher, "ahishe"), [(2, 1), (1, 3), (0, 4)]);
+        assert_eq!(matches(&matcher, "xyz"), []);
+
+        let matcher = Matcher::new(["aa", "a"]);
+
+        assert_eq!(
+            matches(&matcher, "aaa"),
+            [(1, 0), (0, 0), (1, 1), (0, 1), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn streaming() {
+        let matcher = Matcher::new(["abc", "bcd"]);
+        let mut state = State::default();
+
+        let ends = b"zabcd"
+            .iter()
+            .map(|&b| matcher.step(&mut state, b).to_vec())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ends, [vec![], vec![], vec![], vec![0], vec![1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "should not be empty")]
+    fn reject_empty_patterns() {
+        Matcher::new(["a", ""]);
+    }
+}
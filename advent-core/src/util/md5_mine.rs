@@ -0,0 +1,241 @@
+//! Searching for the numbers that, appended to a key, give an MD5 hash of a particular shape.
+//! Each hash is independent, so ranges of numbers are checked in parallel with rayon while still
+//! returning the lowest match, and searches can pick up from any number to find later matches.
+//! A [`ZeroesCheckpoint`] carries what one search ruled out over to the next, since a hash short
+//! of some number of leading zeroes is short of any larger number too.
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+use md5::{Digest, Md5};
+use rayon::prelude::*;
+
+use crate::{cancel, progress::Progress};
+
+/// Numbers checked at once before looking for a match among them. Large enough to keep every
+/// thread busy, small enough that little is wasted past an early match.
+const CHUNK: u64 = 1 << 14;
+
+pub type Md5Digest = [u8; 16];
+
+/// Whether the hex form of `digest` starts with at least `nibbles` zeroes
+pub fn has_leading_zeroes(digest: &Md5Digest, nibbles: usize) -> bool {
+    let bytes = nibbles / 2;
+
+    nibbles <= 32
+        && digest[..bytes].iter().all(|&b| b == 0)
+        && (nibbles.is_multiple_of(2) || digest[bytes] & 0xF0 == 0)
+}
+
+/// Lowest number from `start` onwards whose hash when appended to `key` passes `predicate`,
+/// along with that hash. `progress` counts the numbers checked. Keys that never match search
+/// forever, so this is `None` once the run is cancelled.
+pub fn find_suffix_by<F>(
+    key: &str,
+    start: u64,
+    predicate: F,
+    progress: &Progress,
+) -> Option<(u64, Md5Digest)>
+where
+    F: Fn(&Md5Digest) -> bool + Sync,
+{
+    let prefix = Md5::new_with_prefix(key);
+    let cancel = cancel::current();
+
+    (start..)
+        .step_by(CHUNK as usize)
+        .take_while(|_| !cancel.is_cancelled())
+        .find_map(|chunk| {
+            let found = (chunk..chunk.saturating_add(CHUNK))
+                .into_par_iter()
+                .map(|i| {
+                    let mut hasher = prefix.clone();
+
+                    hasher.update(i.to_string());
+
+                    (i, hasher.finalize().into())
+                })
+                .find_first(|(_, digest)| predicate(digest));
+
+            progress.inc(CHUNK);
+
+            found
+        })
+}
+
+/// Lowest number from `start` onwards whose hash when appended to `key` starts with
+/// `leading_zero_nibbles` zeroes in hex, along with that hash, unless cancelled first
+pub fn find_suffix(key: &str, leading_zero_nibbles: usize, start: u64) -> Option<(u64, Md5Digest)> {
+    find_suffix_by(
+        key,
+        start,
+        |d| has_leading_zeroes(d, leading_zero_nibbles),
+        &Progress::hidden(),
+    )
+}
+
+/// How far searches of one key's hashes for leading zeroes have got, so that a search for more
+/// zeroes can start where a search for fewer stopped. Shared by reference between searches, such
+/// as the two parts of a day solved from one parse of its input.
+#[derive(Debug, Default)]
+pub struct ZeroesCheckpoint {
+    /// For each number of leading zeroes searched for, the number below which none give that many
+    searched: Mutex<BTreeMap<usize, u64>>,
+}
+
+impl ZeroesCheckpoint {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lowest number that could give a hash with `nibbles` leading zeroes, as far as is known
+    pub fn start(&self, nibbles: usize) -> u64 {
+        self.searched
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .range(..=nibbles)
+            .map(|(_, &end)| end)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Records that no number below `end` gives a hash with `nibbles` leading zeroes
+    pub fn record(&self, nibbles: usize, end: u64) {
+        let mut searched = self.searched.lock().unwrap_or_else(|e| e.into_inner());
+        let known = searched.entry(nibbles).or_default();
+
+        *known = (*known).max(end);
+    }
+}
+
+/// Lowest number whose hash when appended to `key` starts with `nibbles` zeroes in hex, along with
+/// that hash, searching from where `checkpoint` says and recording the match there. `progress`
+/// counts the numbers checked. This is `None` if the run is cancelled first.
+pub fn find_zeroes_from(
+    key: &str,
+    nibbles: usize,
+    checkpoint: &ZeroesCheckpoint,
+    progress: &Progress,
+) -> Option<(u64, Md5Digest)> {
+    let found = find_suffix_by(
+        key,
+        checkpoint.start(nibbles),
+        |d| has_leading_zeroes(d, nibbles),
+        progress,
+    )?;
+
+    checkpoint.record(nibbles, found.0);
+
+    Some(found)
+}
+
+/// Every match of [`find_suffix`] from `start` onwards in order, ending early if cancelled
+pub fn mine(
+    key: &str,
+    leading_zero_nibbles: usize,
+    start: u64,
+) -> impl Iterator<Item = (u64, Md5Digest)> + '_ {
+    let mut next = start;
+
+    std::iter::from_fn(move || {
+        let found = find_suffix(key, leading_zero_nibbles, next)?;
+
+        next = found.0 + 1;
+        Some(found)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::mpsc, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn leading_zeroes() {
+        let mut digest = [0xFF; 16];
+
+        digest[..2].fill(0);
+        digest[2] = 0x0F;
+
+        assert!(has_leading_zeroes(&digest, 4));
+        assert!(has_leading_zeroes(&digest, 5));
+        assert!(!has_leading_zeroes(&digest, 6));
+        assert!(has_leading_zeroes(&[0; 16], 32));
+        assert!(!has_leading_zeroes(&[0; 16], 33));
+    }
+
+    #[test]
+    fn resumable() {
+        // The first two hashes of "abc" with five leading zeroes, searching from just before each
+        let (first, digest) = find_suffix("abc", 5, 3_231_900).unwrap();
+
+        assert_eq!(first, 3_231_929);
+        assert_eq!(digest[2], 0x01);
+        assert_eq!(find_suffix("abc", 5, 5_017_300).unwrap().0, 5_017_308);
+        assert_eq!(
+            mine("abc", 5, 5_017_300)
+                .take(2)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>(),
+            vec![5_017_308, 5_278_568]
+        );
+    }
+
+    #[test]
+    fn custom_predicate() {
+        // 2015 day 04's first example, found by looking for the hash itself
+        let (index, _) = find_suffix_by(
+            "abcdef",
+            609_000,
+            |d| d[..3] == [0, 0, 0x01],
+            &Progress::hidden(),
+        )
+        .unwrap();
+
+        assert_eq!(index, 609_043);
+    }
+
+    #[test]
+    fn resumed_from_checkpoint() {
+        let checkpoint = ZeroesCheckpoint::new();
+        let hidden = Progress::hidden();
+
+        assert_eq!(checkpoint.start(4), 0);
+
+        let three = find_zeroes_from("abcdef", 3, &checkpoint, &hidden).unwrap();
+
+        assert_eq!(checkpoint.start(3), three.0);
+        assert_eq!(checkpoint.start(4), three.0);
+        assert_eq!(checkpoint.start(2), 0);
+
+        let four = find_zeroes_from("abcdef", 4, &checkpoint, &hidden).unwrap();
+
+        assert_eq!(four, find_suffix("abcdef", 4, 0).unwrap());
+        assert_eq!(checkpoint.start(5), four.0);
+
+        // Searching again finds the same match rather than moving past it
+        assert_eq!(
+            find_zeroes_from("abcdef", 3, &checkpoint, &hidden),
+            Some(three)
+        );
+    }
+
+    #[test]
+    fn cancelled() {
+        let _serial = cancel::TIMEOUT_TESTS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let (sender, receiver) = mpsc::channel();
+        let limit = Duration::from_millis(50);
+
+        // Nothing matches, so only cancelling can end the search
+        let result = cancel::run_with_timeout(Some(limit), move || {
+            sender
+                .send(find_suffix_by("abc", 0, |_| false, &Progress::hidden()))
+                .unwrap();
+        });
+
+        assert_eq!(result, Err(cancel::TimedOut(limit)));
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(10)), Ok(None));
+    }
+}
@@ -0,0 +1,208 @@
+//! Caches for recursive solvers that reach the same subproblem many times. The cache is handed
+//! to the computation of each new entry so the recursion can go back through it, and hit counts
+//! can be reported once a solution finishes to see how much work was saved.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static REPORTS: Mutex<Vec<MemoStats>> = Mutex::new(Vec::new());
+
+/// Starts or stops keeping the stats of every [`Memo`] when it is dropped
+pub fn record_stats(enabled: bool) {
+    RECORDING.store(enabled, Ordering::Relaxed);
+}
+
+/// Stats of every [`Memo`] dropped while recording, oldest first
+pub fn take_stats() -> Vec<MemoStats> {
+    std::mem::take(&mut *REPORTS.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// How well a [`Memo`] avoided repeating work
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub label: &'static str,
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that had to be computed
+    pub misses: u64,
+    pub entries: usize,
+}
+
+/// Results of a function keyed by its arguments
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    label: &'static str,
+    cache: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    /// An empty cache identified by `label` in its stats
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// The cached value for `key`, or the result of `compute` which is cached for next time.
+    /// `compute` receives the cache so it can look up smaller subproblems.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+
+            return value.clone();
+        }
+
+        self.misses += 1;
+
+        let value = compute(self);
+
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// The cached value for `key` without computing or counting anything
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            label: self.label,
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        }
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        if RECORDING.load(Ordering::Relaxed) {
+            let stats = MemoStats {
+                label: self.label,
+                hits: self.hits,
+                misses: self.misses,
+                entries: self.cache.len(),
+            };
+
+            REPORTS
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(stats);
+        }
+    }
+}
+
+/// Hands out a small id for each distinct value so large keys are only stored once and caches
+/// can be keyed by the id instead
+#[derive(Clone, Debug)]
+pub struct Interner<T> {
+    ids: HashMap<T, usize>,
+    values: Vec<T>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> Interner<T> {
+    /// Id of `value`, which is the same every time equal values are interned
+    pub fn intern(&mut self, value: T) -> usize {
+        if let Some(&id) = self.ids.get(&value) {
+            return id;
+        }
+
+        let id = self.values.len();
+
+        self.values.push(value.clone());
+        self.ids.insert(value, id);
+        id
+    }
+
+    /// The value an id was handed out for
+    pub fn resolve(&self, id: usize) -> Option<&T> {
+        self.values.get(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+
+        memo.get_or_compute(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+    }
+
+    #[test]
+    fn recursion() {
+        let mut memo = Memo::new("fibonacci");
+
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+
+        // Every value from 2 to 90 is computed once, and each one past 3 finds n - 2 cached
+        let stats = memo.stats();
+
+        assert_eq!((stats.misses, stats.hits, stats.entries), (89, 87, 89));
+        assert_eq!(memo.get(&10), Some(&55));
+        assert_eq!(memo.get(&1), None);
+    }
+
+    #[test]
+    fn reports() {
+        record_stats(true);
+        drop(Memo::<u8, u8>::new("memo::test::reports"));
+        record_stats(false);
+        drop(Memo::<u8, u8>::new("memo::test::unrecorded"));
+
+        // Other tests may drop caches while recording, so only look for the ones made here
+        let labels = take_stats()
+            .into_iter()
+            .map(|s| s.label)
+            .filter(|l| l.starts_with("memo::test"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(labels, vec!["memo::test::reports"]);
+    }
+
+    #[test]
+    fn interning() {
+        let mut interner = Interner::default();
+
+        assert!(interner.is_empty());
+        assert_eq!(interner.intern(vec![1, 2]), 0);
+        assert_eq!(interner.intern(vec![3]), 1);
+        assert_eq!(interner.intern(vec![1, 2]), 0);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.resolve(1), Some(&vec![3]));
+        assert_eq!(interner.resolve(2), None);
+    }
+}
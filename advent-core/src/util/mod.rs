@@ -0,0 +1,27 @@
+//! Structures extracted from individual days that are general enough to reuse in other puzzles
+
+pub mod bitset;
+pub mod circuit;
+pub mod collections;
+pub mod cycle;
+pub mod geom;
+pub mod grid;
+pub mod intern;
+pub mod interval;
+pub mod matcher;
+pub mod md5_mine;
+pub mod memo;
+pub mod numth;
+pub mod ocr;
+pub mod par_lines;
+pub mod perm;
+pub mod quadrants;
+pub mod rle;
+pub mod search;
+pub mod strings;
+pub mod subset_sum;
+pub mod synth;
+#[cfg(any(test, feature = "testgen"))]
+pub mod testgen;
+pub mod vm;
+pub mod windows;
@@ -0,0 +1,241 @@
+//! Number theory for puzzles about things that line up on different schedules, such as buses
+//! leaving every few minutes or discs turning at different rates. Modular work is done in `i128`
+//! without intermediate overflow, and combining moduli reports an error rather than wrapping when
+//! the result does not fit.
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    #[error("moduli should be positive but {0} was given")]
+    Modulus(i128),
+    #[error("the congruences contradict each other")]
+    NoSolution,
+    #[error("the combined modulus does not fit in an i128")]
+    Overflow,
+}
+
+/// Greatest common divisor, where `gcd(0, 0)` is 0
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, where anything with 0 is 0. Panics if it does not fit in a `u64`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).expect("least common multiple should fit in a u64")
+}
+
+/// Least common multiple, or `None` if it does not fit in a `u64`
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// `(g, x, y)` where `g` is the non-negative greatest common divisor of `a` and `b`, and
+/// `a * x + b * y == g`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a * b` reduced into `0..m`, without overflowing for any `m` that fits in an `i128`
+pub fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Double and add, each step staying below 2m
+    let mut product = 0;
+
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, m);
+        }
+
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+
+    product
+}
+
+/// `a + b` reduced into `0..m`, for `a` and `b` already in `0..m`
+fn add_mod(a: i128, b: i128, m: i128) -> i128 {
+    // Subtracting first keeps the sum in range even when `m` is close to `i128::MAX`
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// The `x` in `0..m` with `a * x` congruent to 1 modulo `m`, if `a` and `m` are coprime
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` at once, giving the smallest
+/// non-negative `x` along with the modulus every solution repeats at. Moduli do not have to be
+/// coprime, as long as the congruences agree wherever they overlap. With no congruences every
+/// number is a solution, which is `(0, 1)`.
+pub fn crt(congruences: &[(i128, i128)]) -> Result<(i128, i128), CrtError> {
+    congruences.iter().try_fold((0, 1), |(r1, m1), &(r2, m2)| {
+        if m2 <= 0 {
+            return Err(CrtError::Modulus(m2));
+        }
+
+        let r2 = r2.rem_euclid(m2);
+        let (g, p, _) = extended_gcd(m1, m2);
+
+        if (r2 - r1) % g != 0 {
+            return Err(CrtError::NoSolution);
+        }
+
+        // Solving m1 * k ≡ r2 - r1 (mod m2) for k, which only matters modulo m2 / g
+        let step = m2 / g;
+        let k = mul_mod((r2 - r1) / g, p, step);
+        let m = m1.checked_mul(step).ok_or(CrtError::Overflow)?;
+
+        Ok((add_mod(r1, mul_mod(m1, k, m), m), m))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        for a in 0..=60_u64 {
+            for b in 0..=60 {
+                let divides_both = |d: u64| a % d == 0 && b % d == 0;
+                let expected = (1..=a.max(b)).rev().find(|&d| divides_both(d));
+
+                assert_eq!(gcd(a, b), expected.unwrap_or(0), "gcd({a}, {b})");
+
+                let multiple = (1..=a * b).find(|&m| m % a == 0 && m % b == 0);
+
+                assert_eq!(lcm(a, b), multiple.unwrap_or(0), "lcm({a}, {b})");
+            }
+        }
+
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(1 << 40, 1 << 50), 1 << 50);
+    }
+
+    #[test]
+    fn bezout() {
+        for a in -30..=30 {
+            for b in -30..=30 {
+                let (g, x, y) = extended_gcd(a, b);
+
+                assert_eq!(
+                    g,
+                    i128::from(gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64))
+                );
+                assert_eq!(a * x + b * y, g, "extended_gcd({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn inverses() {
+        for m in 1..=60_i128 {
+            for a in -60..=60 {
+                let expected = (0..m).find(|&x| (a * x).rem_euclid(m) == 1 % m);
+
+                assert_eq!(mod_inverse(a, m), expected, "{a}^-1 mod {m}");
+            }
+        }
+
+        assert_eq!(mod_inverse(3, 0), None);
+    }
+
+    #[test]
+    fn large_products() {
+        let m = i128::MAX - 2;
+
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+        assert_eq!(mul_mod(-1, m - 1, m), 1);
+
+        // 2^101 is -1 modulo 2^101 + 1, so 2^200 is -(2^99)
+        let m = (1 << 101) + 1;
+
+        assert_eq!(mul_mod(1 << 100, 1 << 100, m), m - (1 << 99));
+    }
+
+    #[test]
+    fn small_systems() {
+        for m1 in 1..=12 {
+            for m2 in 1..=12 {
+                for r1 in 0..m1 {
+                    for r2 in 0..m2 {
+                        let modulus = m1 * m2 / i128::from(gcd(m1 as u64, m2 as u64));
+                        let expected = (0..modulus).find(|x| x % m1 == r1 && x % m2 == r2);
+
+                        assert_eq!(
+                            crt(&[(r1, m1), (r2, m2)]),
+                            expected.map(|x| (x, modulus)).ok_or(CrtError::NoSolution),
+                            "x = {r1} mod {m1}, x = {r2} mod {m2}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn systems() {
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt(&[(-1, 4)]), Ok((3, 4)));
+        assert_eq!(crt(&[(1, 0)]), Err(CrtError::Modulus(0)));
+
+        // Bus schedules with offsets: 7,13,x,x,59,x,31,19 leaves at 1068781
+        let buses = [(7, 0), (13, 1), (59, 4), (31, 6), (19, 7)].map(|(id, offset)| (-offset, id));
+
+        assert_eq!(crt(&buses), Ok((1_068_781, 7 * 13 * 59 * 31 * 19)));
+
+        // Primes whose product is too large for an i64
+        let p = (1 << 61) - 1;
+        let q = (1 << 31) - 1;
+        let (x, m) = crt(&[(p - 1, p), (q - 2, q)]).unwrap();
+
+        assert_eq!(m, p * q);
+        assert_eq!((x % p, x % q), (p - 1, q - 2));
+        assert_eq!(
+            crt(&[(0, i128::MAX), (1, i128::MAX - 1)]),
+            Err(CrtError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Reads the capital letters some puzzles draw as their answer, each 4 pixels wide and 6 tall
+//! with a blank column between them, where `#` is a lit pixel and anything else is dark.
+
+use thiserror::Error;
+
+/// Height of every letter in pixels
+pub const LETTER_HEIGHT: usize = 6;
+
+/// Width of every letter in pixels, not counting the blank column that follows it
+pub const LETTER_WIDTH: usize = 4;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    #[error("letters are {LETTER_HEIGHT} rows tall but the image has {0}")]
+    Height(usize),
+    #[error("letter {position} does not match any known letter:\n{glyph}")]
+    Unknown { position: usize, glyph: String },
+}
+
+/// Every letter the puzzles have been seen to draw, row by row
+const FONT: &[(char, [&str; LETTER_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Bits of a 4x6 letter, first row in the highest bits
+fn glyph_bits(rows: impl IntoIterator<Item = impl IntoIterator<Item = bool>>) -> u32 {
+    rows.into_iter()
+        .flatten()
+        .fold(0, |bits, lit| bits << 1 | u32::from(lit))
+}
+
+/// The letters drawn in `grid`, which may be surrounded by blank lines. Only the lit pixels
+/// matter, so `.` and space both work for dark ones.
+pub fn read_letters(grid: &str) -> Result<String, OcrError> {
+    let rows = grid
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|r| r.is_empty())
+        .collect::<Vec<_>>();
+    let rows = rows
+        .iter()
+        .rposition(|r| !r.is_empty())
+        .map_or(&[][..], |last| &rows[..=last]);
+
+    if rows.len() != LETTER_HEIGHT {
+        return Err(OcrError::Height(rows.len()));
+    }
+
+    let pixels = rows
+        .iter()
+        .map(|r| r.bytes().map(|b| b == b'#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |row: usize, column: usize| pixels[row].get(column).copied().unwrap_or(false);
+
+    (0..width.div_ceil(LETTER_WIDTH + 1))
+        .map(|position| {
+            let left = position * (LETTER_WIDTH + 1);
+            let bits = glyph_bits(
+                (0..LETTER_HEIGHT).map(|row| (left..left + LETTER_WIDTH).map(move |c| lit(row, c))),
+            );
+
+            FONT.iter()
+                .find(|(_, rows)| glyph_bits(rows.map(|r| r.bytes().map(|b| b == b'#'))) == bits)
+                .map(|&(letter, _)| letter)
+                .ok_or_else(|| OcrError::Unknown {
+                    position: position + 1,
+                    glyph: (0..LETTER_HEIGHT)
+                        .map(|row| {
+                            (left..left + LETTER_WIDTH)
+                                .map(|c| if lit(row, c) { '#' } else { '.' })
+                                .collect::<String>()
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Draws `letters` from the font the way a puzzle would
+    fn draw(letters: &str) -> String {
+        (0..LETTER_HEIGHT)
+            .map(|row| {
+                letters
+                    .chars()
+                    .map(|l| FONT.iter().find(|&&(c, _)| c == l).unwrap().1[row])
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn whole_font() {
+        let alphabet = FONT.iter().map(|&(c, _)| c).collect::<String>();
+
+        assert_eq!(read_letters(&draw(&alphabet)), Ok(alphabet));
+
+        // Every letter is distinct, or reading could pick the wrong one
+        for (i, (a, rows)) in FONT.iter().enumerate() {
+            assert!(FONT[i + 1..].iter().all(|(_, other)| other != rows), "{a}");
+        }
+    }
+
+    #[test]
+    fn layout() {
+        // Dark pixels drawn as spaces leave rows of different lengths
+        let spaced = format!("\n{}\n\n", draw("IZ").replace('.', " "));
+
+        assert_eq!(read_letters(&spaced).as_deref(), Ok("IZ"));
+        assert_eq!(read_letters("#..#\n#..#"), Err(OcrError::Height(2)));
+        assert_eq!(read_letters(""), Err(OcrError::Height(0)));
+    }
+
+    #[test]
+    fn unknown_letter() {
+        // A solid block after an A
+        let grid = draw("A")
+            .lines()
+            .map(|r| format!("{r}.####"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            read_letters(&grid),
+            Err(OcrError::Unknown {
+                position: 2,
+                glyph: ["####"; LETTER_HEIGHT].join("\n")
+            })
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Orderings and selections of items rearranged in place, for brute force searches that try every
+//! one of them. `itertools` hands out a new `Vec` for each permutation or combination, which
+//! costs more than the work done with it when there are millions of them. These lend a slice of
+//! the current arrangement instead, so they cannot be `Iterator`s; loop over them with
+//! `while let`.
+
+/// Every ordering of a list, each one swap away from the last (Heap's algorithm)
+#[derive(Clone, Debug)]
+pub struct Permutations<T> {
+    items: Vec<T>,
+    /// For each prefix length, how many swaps have been made at that level
+    counters: Vec<usize>,
+    level: usize,
+    started: bool,
+}
+
+impl<T> Permutations<T> {
+    /// Orderings of `items`, starting with the order given
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            counters: vec![0; items.len()],
+            items,
+            level: 1,
+            started: false,
+        }
+    }
+
+    /// The next ordering, or `None` once all `n!` have been given
+    pub fn next_permutation(&mut self) -> Option<&[T]> {
+        if !self.started {
+            self.started = true;
+
+            return Some(&self.items);
+        }
+
+        while self.level < self.items.len() {
+            let i = self.level;
+
+            if self.counters[i] < i {
+                let j = if i.is_multiple_of(2) {
+                    0
+                } else {
+                    self.counters[i]
+                };
+
+                self.items.swap(j, i);
+                self.counters[i] += 1;
+                self.level = 1;
+
+                return Some(&self.items);
+            }
+
+            self.counters[i] = 0;
+            self.level += 1;
+        }
+
+        None
+    }
+}
+
+impl Permutations<usize> {
+    /// Orderings of the indices `0..n`
+    pub fn of_indices(n: usize) -> Self {
+        Self::new((0..n).collect())
+    }
+}
+
+/// Every way to choose `k` of the indices `0..n`, each in increasing order and the choices in
+/// lexicographic order
+#[derive(Clone, Debug)]
+pub struct Combinations {
+    indices: Vec<usize>,
+    n: usize,
+    started: bool,
+}
+
+impl Combinations {
+    pub fn new(n: usize, k: usize) -> Self {
+        Self {
+            indices: (0..k).collect(),
+            n,
+            started: false,
+        }
+    }
+
+    /// The next choice of indices, or `None` once every one has been given. Choosing more than
+    /// there are gives nothing, and choosing none gives the empty choice once.
+    pub fn next_combination(&mut self) -> Option<&[usize]> {
+        let k = self.indices.len();
+
+        if k > self.n {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+
+            return Some(&self.indices);
+        }
+
+        // The rightmost index that can still move right, with room for those after it
+        let i = (0..k).rev().find(|&i| self.indices[i] < self.n - k + i)?;
+
+        self.indices[i] += 1;
+
+        for j in i + 1..k {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+
+        Some(&self.indices)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn all_permutations(n: usize) -> Vec<Vec<usize>> {
+        let mut perms = Permutations::of_indices(n);
+        let mut all = Vec::new();
+
+        while let Some(p) = perms.next_permutation() {
+            all.push(p.to_vec());
+        }
+
+        all
+    }
+
+    fn all_combinations(n: usize, k: usize) -> Vec<Vec<usize>> {
+        let mut combos = Combinations::new(n, k);
+        let mut all = Vec::new();
+
+        while let Some(c) = combos.next_combination() {
+            all.push(c.to_vec());
+        }
+
+        all
+    }
+
+    #[test]
+    fn permutations_match_itertools() {
+        for n in 0..=6 {
+            let mut ours = all_permutations(n);
+
+            // Each ordering follows from the last by swapping one pair
+            assert!(ours.windows(2).all(|w| w[0]
+                .iter()
+                .zip(&w[1])
+                .filter(|(a, b)| a != b)
+                .count()
+                == 2));
+
+            ours.sort();
+
+            assert_eq!(ours, (0..n).permutations(n).collect_vec(), "n = {n}");
+        }
+
+        let mut words = Permutations::new(vec!["a", "b"]);
+
+        assert_eq!(words.next_permutation(), Some(&["a", "b"][..]));
+        assert_eq!(words.next_permutation(), Some(&["b", "a"][..]));
+        assert_eq!(words.next_permutation(), None);
+        assert_eq!(words.next_permutation(), None);
+    }
+
+    #[test]
+    fn combinations_match_itertools() {
+        for n in 0..=7 {
+            for k in 0..=n + 1 {
+                assert_eq!(
+                    all_combinations(n, k),
+                    (0..n).combinations(k).collect_vec(),
+                    "{n} choose {k}"
+                );
+
+                let mut done = Combinations::new(n, k);
+
+                while done.next_combination().is_some() {}
+
+                assert_eq!(done.next_combination(), None);
+            }
+        }
+    }
+}
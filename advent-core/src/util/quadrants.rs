@@ -0,0 +1,137 @@
+//! Dense storage for cells anywhere on the plane, for walks whose extent is not known up front.
+//! The plane is split into four quadrants around the origin, each a list of rows that only grow as
+//! far out as the furthest cell written, so a cell is found by indexing rather than hashing.
+
+use super::geom::Point2;
+
+/// A cell of type `T` at every point, where cells never written read as `T::default()`
+#[derive(Clone, Debug, Default)]
+pub struct QuadrantGrid<T> {
+    /// Quadrants indexed by `2 * (y < 0) + (x < 0)`, each holding rows by distance from the x
+    /// axis and cells by distance from the y axis, counting the row or column next to an axis as 0
+    quadrants: [Vec<Vec<T>>; 4],
+    allocated: usize,
+}
+
+/// Distance of `v` from the axis on its side and whether that side is negative
+fn fold(v: i64) -> (usize, usize) {
+    let (negative, distance) = if v < 0 { (1, -1 - v) } else { (0, v) };
+
+    (
+        negative,
+        usize::try_from(distance).expect("coordinates should fit in memory"),
+    )
+}
+
+fn unfold(negative: usize, distance: usize) -> i64 {
+    let distance = distance as i64;
+
+    if negative == 1 {
+        -1 - distance
+    } else {
+        distance
+    }
+}
+
+impl<T: Copy + Default> QuadrantGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The quadrant, row, and column holding `p`
+    fn locate(p: Point2<i64>) -> (usize, usize, usize) {
+        let (left, column) = fold(p.x);
+        let (below, row) = fold(p.y);
+
+        (2 * below + left, row, column)
+    }
+
+    /// The cell at `p`
+    pub fn get(&self, p: Point2<i64>) -> T {
+        let (quadrant, row, column) = Self::locate(p);
+
+        self.quadrants[quadrant]
+            .get(row)
+            .and_then(|r| r.get(column))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The cell at `p`, growing its quadrant to reach it if needed
+    pub fn get_mut(&mut self, p: Point2<i64>) -> &mut T {
+        let (quadrant, row, column) = Self::locate(p);
+        let rows = &mut self.quadrants[quadrant];
+
+        if rows.len() <= row {
+            rows.resize_with(row + 1, Vec::new);
+        }
+
+        let cells = &mut rows[row];
+
+        if cells.len() <= column {
+            self.allocated += column + 1 - cells.len();
+            cells.resize(column + 1, T::default());
+        }
+
+        &mut cells[column]
+    }
+
+    /// Cells stored across every quadrant, written or not
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    /// Every cell stored with its position, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, T)> + '_ {
+        self.quadrants
+            .iter()
+            .enumerate()
+            .flat_map(|(quadrant, rows)| {
+                rows.iter().enumerate().flat_map(move |(row, cells)| {
+                    cells.iter().enumerate().map(move |(column, &cell)| {
+                        let x = unfold(quadrant % 2, column);
+                        let y = unfold(quadrant / 2, row);
+
+                        (Point2::new(x, y), cell)
+                    })
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grows_into_every_quadrant() {
+        let mut grid = QuadrantGrid::<u32>::new();
+        let points =
+            [(0, 0), (-1, 0), (0, -1), (-1, -1), (3, -2), (-5, 4)].map(|(x, y)| Point2::new(x, y));
+
+        for (i, &p) in points.iter().enumerate() {
+            *grid.get_mut(p) += i as u32 + 1;
+        }
+
+        for (i, &p) in points.iter().enumerate() {
+            assert_eq!(grid.get(p), i as u32 + 1);
+        }
+
+        assert_eq!(grid.get(Point2::new(100, -100)), 0);
+        assert_eq!(grid.allocated(), grid.iter().count());
+
+        let mut written = grid
+            .iter()
+            .filter(|&(_, c)| c > 0)
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>();
+
+        written.sort_unstable();
+
+        let mut expected = points.to_vec();
+
+        expected.sort_unstable();
+
+        assert_eq!(written, expected);
+    }
+}
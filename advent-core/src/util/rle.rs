@@ -0,0 +1,80 @@
+//! Run-length encoding of bytes, where each run of a repeated byte is stored as how many times it
+//! repeats along with the byte itself, as in `aaab` becoming `[(3, b'a'), (1, b'b')]`.
+
+use std::iter::FusedIterator;
+
+/// Runs of equal bytes in a slice, in order, as `(count, byte)`
+#[derive(Clone, Debug)]
+pub struct Runs<'a> {
+    rest: &'a [u8],
+}
+
+impl Iterator for Runs<'_> {
+    type Item = (usize, u8);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &first = self.rest.first()?;
+        let count = self.rest.iter().take_while(|&&b| b == first).count();
+
+        self.rest = &self.rest[count..];
+
+        Some((count, first))
+    }
+}
+
+impl FusedIterator for Runs<'_> {}
+
+/// Runs of equal bytes in `bytes`, without collecting them
+pub fn runs(bytes: &[u8]) -> Runs<'_> {
+    Runs { rest: bytes }
+}
+
+/// Every run of equal bytes in `bytes`, in order
+pub fn encode(bytes: &[u8]) -> Vec<(usize, u8)> {
+    runs(bytes).collect()
+}
+
+/// Expands runs back into the bytes they were encoded from
+pub fn decode(runs: &[(usize, u8)]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(runs.iter().map(|&(count, _)| count).sum());
+
+    for &(count, byte) in runs {
+        bytes.resize(bytes.len() + count, byte);
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(
+            encode(b"aaabccdddd"),
+            [(3, b'a'), (1, b'b'), (2, b'c'), (4, b'd')]
+        );
+        assert_eq!(encode(b""), []);
+
+        for bytes in [&b"aaabccdddd"[..], b"", b"x", b"xyxy", &[0; 300]] {
+            assert_eq!(decode(&encode(bytes)), bytes);
+        }
+
+        assert_eq!(decode(&[(0, b'a'), (2, b'b')]), b"bb");
+    }
+
+    #[test]
+    fn runs_never_repeat_a_byte() {
+        let encoded = encode(b"112223333111");
+
+        assert!(encoded.windows(2).all(|w| w[0].1 != w[1].1));
+        assert_eq!(encoded.len(), 4);
+
+        let mut all = runs(b"11");
+
+        assert_eq!(all.next(), Some((2, b'1')));
+        assert_eq!(all.next(), None);
+        assert_eq!(all.next(), None);
+    }
+}
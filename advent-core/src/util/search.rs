@@ -0,0 +1,267 @@
+//! Best-first search over any kind of state, for puzzles that ask for the cheapest way to reach a
+//! goal: fewest steps through a maze, least mana to win a fight, and so on. A day only describes
+//! the moves out of a state and what they cost; the frontier, the costs found so far, and the
+//! path back to the start are kept here.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::cancel;
+
+/// What moves cost, added up along a path. Zero is the default.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// The cheapest path found to a goal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// Every state along the path, from the start it began at to the goal
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("paths hold at least their start")
+    }
+
+    /// Moves taken, one fewer than the states visited
+    pub fn steps(&self) -> usize {
+        self.states.len() - 1
+    }
+}
+
+/// A state reached by the search, with the cheapest cost known to reach it
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>,
+    /// Whether the node has been expanded at its current cost
+    closed: bool,
+}
+
+/// Finds the cheapest path from any of `starts` to a state that satisfies `goal`, where
+/// `neighbours` gives the states one move away and what each move costs. States are expanded in
+/// order of their cost so far plus `heuristic`, which must never overestimate the cost left to a
+/// goal or a more expensive path might be returned. `None` if no goal can be reached, or if the run
+/// is cancelled before one is.
+pub fn a_star<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes = Vec::<Node<S, C>>::new();
+    let mut index = HashMap::new();
+    // Ties go to the state reached first, which keeps equal-cost searches in a stable order
+    let mut frontier = BinaryHeap::new();
+    let cancel = cancel::current();
+
+    for start in starts {
+        if index.contains_key(&start) {
+            continue;
+        }
+
+        frontier.push(Reverse((heuristic(&start), nodes.len())));
+        index.insert(start.clone(), nodes.len());
+        nodes.push(Node {
+            state: start,
+            cost: C::default(),
+            parent: None,
+            closed: false,
+        });
+    }
+
+    while let Some(Reverse((_, id))) = frontier.pop() {
+        if cancel.is_cancelled() {
+            return None;
+        }
+
+        if nodes[id].closed {
+            continue;
+        }
+
+        nodes[id].closed = true;
+
+        if goal(&nodes[id].state) {
+            return Some(path_to(&nodes, id));
+        }
+
+        let cost = nodes[id].cost;
+
+        for (next, step) in neighbours(&nodes[id].state) {
+            let next_cost = cost + step;
+
+            let next_id = match index.get(&next) {
+                Some(&j) if nodes[j].cost <= next_cost => continue,
+                Some(&j) => {
+                    // A cheaper way to a state already seen, so it has to be expanded again
+                    nodes[j].cost = next_cost;
+                    nodes[j].parent = Some(id);
+                    nodes[j].closed = false;
+                    j
+                }
+                None => {
+                    index.insert(next.clone(), nodes.len());
+                    nodes.push(Node {
+                        state: next,
+                        cost: next_cost,
+                        parent: Some(id),
+                        closed: false,
+                    });
+                    nodes.len() - 1
+                }
+            };
+
+            frontier.push(Reverse((
+                next_cost + heuristic(&nodes[next_id].state),
+                next_id,
+            )));
+        }
+    }
+
+    None
+}
+
+/// [`a_star`] without a heuristic, expanding states strictly in order of their cost so far
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(starts, neighbours, |_| C::default(), goal)
+}
+
+/// Follows parents back from `id` to the start it was reached from
+fn path_to<S: Clone, C: Copy>(nodes: &[Node<S, C>], id: usize) -> Path<S, C> {
+    let mut states = Vec::new();
+    let mut at = Some(id);
+
+    while let Some(i) = at {
+        states.push(nodes[i].state.clone());
+        at = nodes[i].parent;
+    }
+
+    states.reverse();
+
+    Path {
+        states,
+        cost: nodes[id].cost,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::geom::Point2;
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##.#.#.
+..#...#E";
+
+    /// Open cells of [`MAZE`] next to `p`, each a single step away
+    fn open_neighbours(p: &Point2<i64>) -> Vec<(Point2<i64>, u32)> {
+        let rows = MAZE.lines().collect::<Vec<_>>();
+
+        p.neighbours4()
+            .filter(|n| {
+                let cell = usize::try_from(n.y)
+                    .ok()
+                    .and_then(|y| rows.get(y)?.as_bytes().get(usize::try_from(n.x).ok()?));
+
+                cell.is_some_and(|&c| c != b'#')
+            })
+            .map(|n| (n, 1))
+            .collect()
+    }
+
+    #[test]
+    fn maze() {
+        let end = Point2::new(7, 4);
+        let found = dijkstra([Point2::ORIGIN], open_neighbours, |&p| p == end).unwrap();
+
+        assert_eq!(found.cost, 15);
+        assert_eq!(found.steps(), 15);
+        assert_eq!(found.states[0], Point2::ORIGIN);
+        assert_eq!(*found.goal(), end);
+        assert!(found.states.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        let guided = a_star(
+            [Point2::ORIGIN],
+            open_neighbours,
+            |p| p.manhattan(end) as u32,
+            |&p| p == end,
+        )
+        .unwrap();
+
+        assert_eq!(guided.cost, found.cost);
+
+        // Points off the map are never reached
+        assert_eq!(
+            dijkstra([Point2::ORIGIN], open_neighbours, |&p| p
+                == Point2::new(9, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn weighted() {
+        // The direct edge costs more than going around
+        let edges = |&n: &u8| match n {
+            0 => vec![(1, 10), (2, 1)],
+            2 => vec![(3, 1)],
+            3 => vec![(1, 1)],
+            _ => vec![],
+        };
+
+        let found = dijkstra([0], edges, |&n| n == 1).unwrap();
+
+        assert_eq!(found.states, vec![0, 2, 3, 1]);
+        assert_eq!(found.cost, 3);
+
+        // Starting from several places finds the cheapest of them, including a start that is
+        // already a goal
+        assert_eq!(
+            dijkstra([0, 3], edges, |&n| n == 1).unwrap().states,
+            vec![3, 1]
+        );
+        assert_eq!(dijkstra([1, 0], edges, |&n| n == 1).unwrap().cost, 0);
+        assert_eq!(dijkstra([], edges, |_| true), None::<Path<u8, i32>>);
+    }
+
+    #[test]
+    fn inconsistent_heuristic() {
+        // Never overestimates, but jumps by more than the edge from 1 to 2 costs, so 2 is first
+        // reached the expensive way and has to be expanded again
+        let edges = |&n: &u8| match n {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 10)],
+            _ => vec![],
+        };
+        let heuristic = |&n: &u8| if n == 1 { 6 } else { 0 };
+
+        let found = a_star([0], edges, heuristic, |&n| n == 3).unwrap();
+
+        assert_eq!(found.cost, 12);
+        assert_eq!(found.states, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,139 @@
+//! Double quoted string literals where `\\`, `\"`, and `\xHH` are the only escape sequences
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EscapeError {
+    #[error("string literals must start and end with '\"'")]
+    MissingQuotes,
+    #[error("unescaped '\"' at {0}")]
+    UnescapedQuote(usize),
+    #[error("unknown escape sequence '\\{0}' at {1}")]
+    UnknownEscape(char, usize),
+    #[error("escape at {0} should be followed by two hex digits")]
+    InvalidHex(usize),
+    #[error("string literal ends in the middle of the escape at {0}")]
+    UnfinishedEscape(usize),
+}
+
+/// Converts a quoted literal such as `"a\"b\x27"` into the string it represents. `\xHH` produces
+/// the character with code point `HH`, so every escape becomes exactly one character. Positions in
+/// errors are byte offsets into `literal`.
+pub fn unescape(literal: &str) -> Result<String, EscapeError> {
+    let inner = literal
+        .strip_prefix('"')
+        .and_then(|l| l.strip_suffix('"'))
+        .ok_or(EscapeError::MissingQuotes)?;
+
+    let mut out = String::with_capacity(inner.len());
+    // Offset by one for the opening quote so positions refer to `literal`
+    let mut chars = inner.char_indices().map(|(i, c)| (i + 1, c));
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Err(EscapeError::UnescapedQuote(i)),
+            '\\' => match chars.next() {
+                Some((_, c @ ('\\' | '"'))) => out.push(c),
+                Some((_, 'x')) => {
+                    let mut digit = || {
+                        chars
+                            .next()
+                            .ok_or(EscapeError::UnfinishedEscape(i))?
+                            .1
+                            .to_digit(16)
+                            .ok_or(EscapeError::InvalidHex(i))
+                    };
+
+                    let code = digit()? * 16 + digit()?;
+
+                    out.push(char::from_u32(code).expect("two hex digits are always a valid char"));
+                }
+                Some((_, c)) => return Err(EscapeError::UnknownEscape(c, i)),
+                None => return Err(EscapeError::UnfinishedEscape(i)),
+            },
+            c => out.push(c),
+        }
+    }
+
+    Ok(out)
+}
+
+/// Converts `s` into a quoted literal that [`unescape`] turns back into `s`. Quotes and
+/// backslashes gain a backslash and ASCII control characters are written as `\xHH`.
+pub fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+
+    out.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if c.is_ascii_control() => out.push_str(&format!("\\x{:02x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn unescape_literals() {
+        assert_eq!(unescape(r#""""#).unwrap(), "");
+        assert_eq!(unescape(r#""aaa\"aaa""#).unwrap(), "aaa\"aaa");
+        assert_eq!(unescape(r#""\\\x27\xFf""#).unwrap(), "\\'\u{ff}");
+
+        assert_eq!(unescape("abc"), Err(EscapeError::MissingQuotes));
+        assert_eq!(unescape("\""), Err(EscapeError::MissingQuotes));
+        assert_eq!(unescape(r#""a"b""#), Err(EscapeError::UnescapedQuote(2)));
+        assert_eq!(unescape(r#""\n""#), Err(EscapeError::UnknownEscape('n', 1)));
+        assert_eq!(unescape(r#""\xzz""#), Err(EscapeError::InvalidHex(1)));
+        assert_eq!(unescape(r#""a\x2""#), Err(EscapeError::UnfinishedEscape(2)));
+        assert_eq!(unescape(r#""\""#), Err(EscapeError::UnfinishedEscape(1)));
+    }
+
+    #[test]
+    fn escape_strings() {
+        assert_eq!(escape(""), r#""""#);
+        assert_eq!(escape("aaa\"aaa"), r#""aaa\"aaa""#);
+        assert_eq!(escape(r#""\x27""#), r#""\"\\x27\"""#);
+        assert_eq!(escape("\n"), r#""\x0a""#);
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip(s in any::<String>()) {
+            prop_assert_eq!(unescape(&escape(&s)), Ok(s));
+        }
+
+        #[test]
+        fn unescape_any_sequence(
+            pieces in prop::collection::vec((any::<bool>(), any::<u8>()), 0..32),
+        ) {
+            // Each piece is either a plain letter or the `\xHH` escape of any byte
+            let letter = |b: u8| char::from(b'a' + b % 26);
+
+            let literal = pieces
+                .iter()
+                .map(|&(hex, b)| if hex { format!("\\x{b:02X}") } else { letter(b).to_string() })
+                .collect::<String>();
+
+            let expected = pieces
+                .iter()
+                .map(|&(hex, b)| if hex { char::from(b) } else { letter(b) })
+                .collect::<String>();
+
+            prop_assert_eq!(unescape(&format!("\"{literal}\"")), Ok(expected));
+        }
+    }
+}
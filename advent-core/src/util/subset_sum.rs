@@ -0,0 +1,90 @@
+//! Counting the ways to pick items that add up to an exact total. Items are told apart by their
+//! position rather than their value, so two items of the same size make two different subsets.
+
+/// How many subsets of some items add up to a target, split up by how many items each subset uses
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubsetCounts(Vec<u64>);
+
+impl SubsetCounts {
+    /// Counts the subsets of `items` that sum to exactly `target` with a table of how many ways
+    /// every smaller sum can be made from each number of items, adding one item at a time. This
+    /// takes time proportional to `target` times the square of the number of items.
+    pub fn new(target: usize, items: &[usize]) -> Self {
+        // ways[sum][count] for the items added so far
+        let mut ways = vec![vec![0u64; items.len() + 1]; target + 1];
+
+        ways[0][0] = 1;
+
+        for (added, &item) in items.iter().enumerate() {
+            // Descending so each item is only used once
+            for sum in (item..=target).rev() {
+                for count in (0..=added).rev() {
+                    ways[sum][count + 1] += ways[sum - item][count];
+                }
+            }
+        }
+
+        Self(ways.swap_remove(target))
+    }
+
+    /// Number of subsets using each number of items, from none up to all of them
+    pub fn by_count(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Number of subsets of any size
+    pub fn total(&self) -> u64 {
+        self.0.iter().sum()
+    }
+
+    /// The fewest items any subset needs along with how many subsets use that many, if the target
+    /// can be reached at all
+    pub fn fewest(&self) -> Option<(usize, u64)> {
+        self.0
+            .iter()
+            .copied()
+            .enumerate()
+            .find(|&(_, ways)| ways > 0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn count_subsets() {
+        let counts = SubsetCounts::new(25, &[20, 15, 10, 5, 5]);
+
+        assert_eq!(counts.by_count(), [0, 0, 3, 1, 0, 0]);
+        assert_eq!(counts.total(), 4);
+        assert_eq!(counts.fewest(), Some((2, 3)));
+
+        // Equal items are still different choices
+        assert_eq!(SubsetCounts::new(5, &[5, 5, 5]).by_count(), [0, 3, 0, 0]);
+        assert_eq!(SubsetCounts::new(0, &[0, 1]).by_count(), [1, 1, 0]);
+        assert_eq!(SubsetCounts::new(7, &[2, 4]).fewest(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn match_brute_force(items in prop::collection::vec(0usize..12, 0..10), target in 0usize..40) {
+            let mut expected = vec![0u64; items.len() + 1];
+
+            for mask in 0u32..(1 << items.len()) {
+                let sum = (0..items.len())
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| items[i])
+                    .sum::<usize>();
+
+                if sum == target {
+                    expected[mask.count_ones() as usize] += 1;
+                }
+            }
+
+            prop_assert_eq!(SubsetCounts::new(target, &items), SubsetCounts(expected));
+        }
+    }
+}
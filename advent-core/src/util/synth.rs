@@ -0,0 +1,196 @@
+//! Generators for valid inputs far larger than any real puzzle input, so performance work can be
+//! measured against the same stress cases every time without committing the files themselves.
+//! Each generator is seeded, and the same size and seed always write the same input.
+
+use std::io::{self, Write};
+
+/// Seed used when none is given
+pub const DEFAULT_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// Small xorshift generator. Stress inputs only need to look arbitrary and be reproducible, not
+/// to be statistically sound.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift never leaves zero, so that seed is nudged to one that works
+        Self(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `0..n`
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+/// Writes an input of the given size, in whatever unit the generator counts, to the writer
+pub type GenerateFn = fn(&mut dyn Write, usize, &mut Rng) -> io::Result<()>;
+
+/// A stress input generator for one day
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub year: i32,
+    pub day: i32,
+    /// What the size counts, such as `lines` or `trees per side`
+    pub unit: &'static str,
+    pub default_size: usize,
+    pub generate: GenerateFn,
+}
+
+/// Every day with a generator
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        year: 2015,
+        day: 2,
+        unit: "presents",
+        default_size: 1_000_000,
+        generate: presents,
+    },
+    Generator {
+        year: 2015,
+        day: 6,
+        unit: "instructions",
+        default_size: 100_000,
+        generate: light_instructions,
+    },
+    Generator {
+        year: 2015,
+        day: 7,
+        unit: "gates",
+        default_size: 100_000,
+        generate: circuit,
+    },
+    Generator {
+        year: 2022,
+        day: 8,
+        unit: "trees per side",
+        default_size: 5_000,
+        generate: tree_map,
+    },
+];
+
+/// The generator for `year`/`day` if it has one
+pub fn find(year: i32, day: i32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.year == year && g.day == day)
+}
+
+/// 2015 day 02: presents as `LxWxH`, each side 1 to 30 feet like the real lists
+pub fn presents(out: &mut dyn Write, count: usize, rng: &mut Rng) -> io::Result<()> {
+    for _ in 0..count {
+        let [l, w, h] = [(); 3].map(|_| 1 + rng.below(30));
+
+        writeln!(out, "{l}x{w}x{h}")?;
+    }
+
+    Ok(())
+}
+
+/// 2015 day 06: instructions over rectangles anywhere on the 1000x1000 grid
+pub fn light_instructions(out: &mut dyn Write, count: usize, rng: &mut Rng) -> io::Result<()> {
+    for _ in 0..count {
+        let action = ["turn on", "turn off", "toggle"][rng.below(3) as usize];
+        let [x0, x1, y0, y1] = [(); 4].map(|_| rng.below(1000));
+
+        writeln!(
+            out,
+            "{action} {},{} through {},{}",
+            x0.min(x1),
+            y0.min(y1),
+            x0.max(x1),
+            y0.max(y1)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// 2015 day 07: a circuit fed by a signal on `b` where each gate reads the gate before it, and
+/// sometimes another earlier wire, ending in `a`. The chain is as deep as the circuit is large.
+pub fn circuit(out: &mut dyn Write, gates: usize, rng: &mut Rng) -> io::Result<()> {
+    // Lowercase names that skip `a` and `b`, which the puzzle gives meaning to
+    let name = |i: usize| {
+        let mut n = i + 3;
+        let mut name = Vec::new();
+
+        while n > 0 {
+            n -= 1;
+            name.push(b'a' + (n % 26) as u8);
+            n /= 26;
+        }
+
+        name.reverse();
+        String::from_utf8(name).expect("names are ASCII")
+    };
+
+    writeln!(out, "{} -> b", rng.below(1 << 16))?;
+
+    let mut previous = "b".to_owned();
+
+    for i in 0..gates.saturating_sub(2) {
+        let wire = name(i);
+        let other = match i {
+            0 => "b".to_owned(),
+            _ => name(rng.below(i as u64) as usize),
+        };
+
+        match rng.below(5) {
+            0 => writeln!(out, "{previous} AND {other} -> {wire}")?,
+            1 => writeln!(out, "{previous} OR {other} -> {wire}")?,
+            2 => writeln!(out, "{previous} LSHIFT {} -> {wire}", rng.below(4))?,
+            3 => writeln!(out, "{previous} RSHIFT {} -> {wire}", rng.below(4))?,
+            _ => writeln!(out, "NOT {previous} -> {wire}")?,
+        }
+
+        previous = wire;
+    }
+
+    writeln!(out, "{previous} -> a")
+}
+
+/// 2022 day 08: a square map of tree heights
+pub fn tree_map(out: &mut dyn Write, size: usize, rng: &mut Rng) -> io::Result<()> {
+    let mut row = vec![0; size + 1];
+
+    row[size] = b'\n';
+
+    for _ in 0..size {
+        for cell in &mut row[..size] {
+            *cell = b'0' + rng.below(10) as u8;
+        }
+
+        out.write_all(&row)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate(generator: &Generator, size: usize, seed: u64) -> Vec<u8> {
+        let mut out = Vec::new();
+
+        (generator.generate)(&mut out, size, &mut Rng::new(seed)).unwrap();
+
+        out
+    }
+
+    #[test]
+    fn reproducible() {
+        for generator in GENERATORS {
+            assert_eq!(generate(generator, 50, 7), generate(generator, 50, 7));
+            assert_ne!(generate(generator, 50, 7), generate(generator, 50, 8));
+        }
+
+        assert_eq!(Rng::new(0).next_u64(), Rng::new(1).next_u64());
+    }
+}
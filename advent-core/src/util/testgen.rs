@@ -0,0 +1,82 @@
+//! Proptest strategies for the shapes puzzle inputs commonly take, so property tests of different
+//! days can generate grids, ranges, and moves the same way instead of each building their own.
+
+use std::ops::Range;
+
+use proptest::{collection::vec, prelude::*};
+
+use crate::util::interval::SectionRange;
+
+/// Rows of digits that all have the same width, as in a height map
+pub fn digit_grid(width: Range<usize>, height: Range<usize>) -> impl Strategy<Value = Vec<String>> {
+    (width, height).prop_flat_map(|(width, height)| {
+        vec(vec(prop::char::range('0', '9'), width), height)
+            .prop_map(|rows| rows.into_iter().map(String::from_iter).collect())
+    })
+}
+
+/// Ranges with both ends below `max`, the start never after the end
+pub fn section_range(max: u32) -> impl Strategy<Value = SectionRange<u32>> {
+    (0..max, 0..max).prop_map(|(a, b)| SectionRange(a.min(b), a.max(b)))
+}
+
+/// Stacks of lettered crates, some of which may be empty
+pub fn crate_stacks(
+    stacks: Range<usize>,
+    height: Range<usize>,
+) -> impl Strategy<Value = Vec<Vec<char>>> {
+    vec(vec(prop::char::range('A', 'Z'), height), stacks)
+}
+
+/// Unconstrained `(from, to, count)` choices for a list of moves, which a test reduces to a valid
+/// move given the state at the time, such as with [`pick_move`]
+pub fn move_picks(len: Range<usize>) -> impl Strategy<Value = Vec<(usize, usize, usize)>> {
+    vec((any::<usize>(), any::<usize>(), any::<usize>()), len)
+}
+
+/// Turns a pick from [`move_picks`] into `(from, to, count)` that takes at least one item from a
+/// non-empty pile and puts it on a different pile. There is no such move when every pile is empty
+/// or there is only one pile.
+pub fn pick_move<T>(
+    piles: &[Vec<T>],
+    (from, to, count): (usize, usize, usize),
+) -> Option<(usize, usize, usize)> {
+    let non_empty = (0..piles.len())
+        .filter(|&p| !piles[p].is_empty())
+        .collect::<Vec<_>>();
+
+    if piles.len() < 2 || non_empty.is_empty() {
+        return None;
+    }
+
+    let from = non_empty[from % non_empty.len()];
+    let to = (from + 1 + to % (piles.len() - 1)) % piles.len();
+
+    Some((from, to, 1 + count % piles[from].len()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn grids_are_rectangular(rows in digit_grid(1..8, 1..8)) {
+            prop_assert!(rows.iter().all(|r| r.len() == rows[0].len()));
+            prop_assert!(rows.iter().flat_map(|r| r.chars()).all(|c| c.is_ascii_digit()));
+        }
+
+        #[test]
+        fn picked_moves_are_valid(piles in crate_stacks(0..5, 0..4), picks in move_picks(0..8)) {
+            for pick in picks {
+                let Some((from, to, count)) = pick_move(&piles, pick) else {
+                    prop_assert!(piles.len() < 2 || piles.iter().all(Vec::is_empty));
+                    continue;
+                };
+
+                prop_assert_ne!(from, to);
+                prop_assert!((1..=piles[from].len()).contains(&count));
+            }
+        }
+    }
+}
@@ -0,0 +1,331 @@
+//! A register machine for puzzles that interpret small assembly languages. Each puzzle supplies
+//! its instruction set through [`Instruction`], and [`Machine`] keeps track of the program
+//! counter, registers, and input and output queues so the puzzle only describes what each
+//! instruction does.
+
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProgramError<E> {
+    #[error("could not read the program")]
+    Io(#[from] io::Error),
+    #[error("line {line} is not a valid instruction")]
+    Decode {
+        line: usize,
+        #[source]
+        source: E,
+    },
+}
+
+/// Reads one instruction per line, numbering lines from 1 in errors
+pub fn load<I: FromStr>(reader: impl BufRead) -> Result<Vec<I>, ProgramError<I::Err>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            l?.parse().map_err(|source| ProgramError::Decode {
+                line: i + 1,
+                source,
+            })
+        })
+        .collect()
+}
+
+/// What the machine should do once an instruction has run
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Control {
+    /// Continue with the following instruction
+    Next,
+    /// Move the program counter by this many instructions
+    Jump(isize),
+    /// Stop running for good
+    Halt,
+    /// The instruction needs more input, so it will run again once some is provided
+    Blocked,
+}
+
+/// State of the machine after a step or run
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// There is another instruction to run
+    Ready,
+    /// The program halted or the program counter left the program
+    Halted,
+    /// The current instruction is waiting on input
+    Blocked,
+}
+
+/// An instruction that changes the registers and I/O of a [`Cpu`]
+pub trait Instruction {
+    /// Type held by each register and passed through input and output
+    type Value: Copy + Default;
+
+    /// Number of registers the machine needs for this instruction set
+    const REGISTERS: usize;
+
+    fn execute(&self, cpu: &mut Cpu<Self::Value>) -> Control;
+}
+
+/// Everything an instruction can read and change
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cpu<V> {
+    pub registers: Vec<V>,
+    pub input: VecDeque<V>,
+    pub output: Vec<V>,
+}
+
+impl<V> Cpu<V> {
+    /// Next value of input, if any has been provided
+    pub fn receive(&mut self) -> Option<V> {
+        self.input.pop_front()
+    }
+
+    pub fn send(&mut self, value: V) {
+        self.output.push(value);
+    }
+}
+
+/// Runs a program of `I` instructions one at a time
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Machine<I: Instruction> {
+    program: Vec<I>,
+    pc: usize,
+    cpu: Cpu<I::Value>,
+    steps: u64,
+    halted: bool,
+}
+
+impl<I: Instruction> Machine<I> {
+    /// A machine at the start of `program` with every register at its default value
+    pub fn new(program: Vec<I>) -> Self {
+        Self {
+            program,
+            pc: 0,
+            cpu: Cpu {
+                registers: vec![I::Value::default(); I::REGISTERS],
+                input: VecDeque::new(),
+                output: Vec::new(),
+            },
+            steps: 0,
+            halted: false,
+        }
+    }
+
+    /// Index of the next instruction to run
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Number of instructions that have run
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// The instruction that runs on the next step, if the machine has not halted
+    pub fn current(&self) -> Option<&I> {
+        if self.halted {
+            None
+        } else {
+            self.program.get(self.pc)
+        }
+    }
+
+    /// The program, which can be changed for instruction sets that modify themselves
+    pub fn program_mut(&mut self) -> &mut [I] {
+        &mut self.program
+    }
+
+    pub fn registers(&self) -> &[I::Value] {
+        &self.cpu.registers
+    }
+
+    pub fn register(&self, index: usize) -> I::Value {
+        self.cpu.registers[index]
+    }
+
+    pub fn set_register(&mut self, index: usize, value: I::Value) {
+        self.cpu.registers[index] = value;
+    }
+
+    /// Queues a value for instructions that read input
+    pub fn push_input(&mut self, value: I::Value) {
+        self.cpu.input.push_back(value);
+    }
+
+    /// Every value sent since the last time output was taken
+    pub fn take_output(&mut self) -> Vec<I::Value> {
+        std::mem::take(&mut self.cpu.output)
+    }
+
+    fn status(&self) -> Status {
+        if self.current().is_some() {
+            Status::Ready
+        } else {
+            Status::Halted
+        }
+    }
+
+    /// Runs a single instruction, returning what the machine can do next
+    pub fn step(&mut self) -> Status {
+        let Some(instruction) = self.program.get(self.pc).filter(|_| !self.halted) else {
+            return Status::Halted;
+        };
+
+        let next = match instruction.execute(&mut self.cpu) {
+            Control::Next => self.pc.checked_add(1),
+            Control::Jump(offset) => self.pc.checked_add_signed(offset),
+            Control::Halt => None,
+            Control::Blocked => return Status::Blocked,
+        };
+
+        self.steps += 1;
+
+        match next {
+            Some(pc) => self.pc = pc,
+            None => self.halted = true,
+        }
+
+        self.status()
+    }
+
+    /// Runs until the program halts or needs more input
+    pub fn run(&mut self) -> Status {
+        loop {
+            match self.step() {
+                Status::Ready => {}
+                status => return status,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Tiny instruction set with a register per letter from `a` to `c`
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    enum Toy {
+        Add(usize, i64),
+        JumpIfNotZero(usize, isize),
+        In(usize),
+        Out(usize),
+        Stop,
+    }
+
+    impl FromStr for Toy {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let parts = s.split_whitespace().collect::<Vec<_>>();
+            let reg = |r: &str| match r {
+                "a" => Ok(0),
+                "b" => Ok(1),
+                "c" => Ok(2),
+                _ => Err(format!("'{r}' is not a register")),
+            };
+            let num = |n: &str| n.parse::<i64>().map_err(|e| e.to_string());
+
+            match parts[..] {
+                ["add", r, n] => Ok(Self::Add(reg(r)?, num(n)?)),
+                ["jnz", r, n] => Ok(Self::JumpIfNotZero(reg(r)?, num(n)? as isize)),
+                ["in", r] => Ok(Self::In(reg(r)?)),
+                ["out", r] => Ok(Self::Out(reg(r)?)),
+                ["stop"] => Ok(Self::Stop),
+                _ => Err(format!("'{s}' is not an instruction")),
+            }
+        }
+    }
+
+    impl Instruction for Toy {
+        type Value = i64;
+
+        const REGISTERS: usize = 3;
+
+        fn execute(&self, cpu: &mut Cpu<i64>) -> Control {
+            match *self {
+                Self::Add(r, n) => cpu.registers[r] += n,
+                Self::JumpIfNotZero(r, o) if cpu.registers[r] != 0 => return Control::Jump(o),
+                Self::JumpIfNotZero(..) => {}
+                Self::In(r) => match cpu.receive() {
+                    Some(v) => cpu.registers[r] = v,
+                    None => return Control::Blocked,
+                },
+                Self::Out(r) => cpu.send(cpu.registers[r]),
+                Self::Stop => return Control::Halt,
+            }
+
+            Control::Next
+        }
+    }
+
+    #[test]
+    fn single_step() {
+        let program = load::<Toy>("add a 2\nadd b 5\nadd a -1\njnz a -2".as_bytes()).unwrap();
+        let mut machine = Machine::new(program);
+
+        assert_eq!(machine.current(), Some(&Toy::Add(0, 2)));
+        assert_eq!(machine.step(), Status::Ready);
+        assert_eq!(machine.registers(), [2, 0, 0]);
+        assert_eq!(machine.step(), Status::Ready);
+        assert_eq!(machine.step(), Status::Ready);
+        assert_eq!(machine.step(), Status::Ready);
+        assert_eq!(machine.pc(), 1);
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.registers(), [0, 10, 0]);
+        assert_eq!(machine.steps(), 7);
+        assert_eq!(machine.current(), None);
+        assert_eq!(machine.step(), Status::Halted);
+    }
+
+    #[test]
+    fn input_and_output() {
+        let program = load::<Toy>("in a\nout a\nadd a 1\nout a\nstop\nout a".as_bytes()).unwrap();
+        let mut machine = Machine::new(program);
+
+        assert_eq!(machine.run(), Status::Blocked);
+        assert_eq!(machine.pc(), 0);
+        assert_eq!(machine.steps(), 0);
+
+        machine.push_input(41);
+
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.take_output(), vec![41, 42]);
+        assert!(machine.take_output().is_empty());
+    }
+
+    #[test]
+    fn changing_state() {
+        let program = load::<Toy>("jnz c -1\nadd b 1".as_bytes()).unwrap();
+        let mut machine = Machine::new(program);
+
+        // Jumping before the first instruction halts
+        machine.set_register(2, 1);
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.register(1), 0);
+
+        let mut machine = Machine::new(load::<Toy>("jnz c -1\nadd b 1".as_bytes()).unwrap());
+
+        machine.set_register(2, 1);
+        machine.program_mut()[0] = Toy::Add(2, 1);
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.registers(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn decoding() {
+        match load::<Toy>("add a 1\nadd d 1".as_bytes()) {
+            Err(ProgramError::Decode { line, source }) => {
+                assert_eq!(line, 2);
+                assert_eq!(source, "'d' is not a register");
+            }
+            other => panic!("expected a decode error, found {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Properties of sliding windows over a sequence that are tracked incrementally as the window
+//! moves, so each item is only looked at when entering and leaving the window.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Finds the first `len` items in a row that are all different and returns the index just past
+/// them. A table of how often each item appears in the window is kept up to date as items enter
+/// and leave, so this takes a single pass.
+pub fn first_distinct_run<T, I>(items: I, len: usize) -> Option<usize>
+where
+    T: Hash + Eq + Clone,
+    I: IntoIterator<Item = T>,
+{
+    if len == 0 {
+        return Some(0);
+    }
+
+    let mut window = VecDeque::with_capacity(len);
+    let mut counts = HashMap::<T, usize>::with_capacity(len);
+
+    for (i, item) in items.into_iter().enumerate() {
+        if window.len() == len {
+            let leaving = window.pop_front().expect("window is full");
+            let count = counts
+                .get_mut(&leaving)
+                .expect("items in the window are counted");
+
+            *count -= 1;
+
+            if *count == 0 {
+                counts.remove(&leaving);
+            }
+        }
+
+        *counts.entry(item.clone()).or_default() += 1;
+        window.push_back(item);
+
+        if counts.len() == len {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+/// [`first_distinct_run`] for bytes. When every byte is lowercase ASCII and `len` is at most 26,
+/// the window is a mask with a bit per letter that each byte toggles when entering and leaving.
+/// Pairs of a letter cancel out, so the window is distinct exactly when `len` bits are set.
+pub fn first_distinct_byte_run(bytes: &[u8], len: usize) -> Option<usize> {
+    if len == 0 || len > 26 || !bytes.iter().all(u8::is_ascii_lowercase) {
+        return first_distinct_run(bytes.iter().copied(), len);
+    }
+
+    let bit = |b: u8| 1u32 << (b - b'a');
+    let mut mask = 0;
+
+    for (i, &b) in bytes.iter().enumerate() {
+        mask ^= bit(b);
+
+        if i >= len {
+            mask ^= bit(bytes[i - len]);
+        }
+
+        if mask.count_ones() as usize == len {
+            return Some(i + 1);
+        }
+    }
+
+    None
+}
+
+/// [`first_distinct_run`] found by checking every window on its own, comparing each item with
+/// those before it in the window. Simpler than tracking the window as it slides, but each item is
+/// looked at once for every window it is part of.
+pub fn first_distinct_window<T: PartialEq>(items: &[T], len: usize) -> Option<usize> {
+    if len == 0 {
+        return Some(0);
+    }
+
+    items
+        .windows(len)
+        .position(|w| (1..w.len()).all(|i| !w[..i].contains(&w[i])))
+        .map(|p| p + len)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn distinct_runs() {
+        assert_eq!(first_distinct_byte_run(b"abcd", 4), Some(4));
+        assert_eq!(first_distinct_byte_run(b"aAbB1", 5), Some(5));
+        assert_eq!(first_distinct_byte_run(b"aaAA11", 2), Some(3));
+        assert_eq!(first_distinct_byte_run(b"abcabc", 4), None);
+        assert_eq!(first_distinct_byte_run(b"", 0), Some(0));
+        assert_eq!(first_distinct_run([3, 1, 3, 3, 7, 2], 3), Some(6));
+        assert_eq!(first_distinct_run(["x", "y", "x"], 3), None);
+    }
+
+    proptest! {
+        #[test]
+        fn match_every_window(bytes in "[a-fA-C0-2]{0,64}", len in 1usize..8) {
+            let bytes = bytes.as_bytes();
+            let lowercase = bytes.to_ascii_lowercase();
+
+            prop_assert_eq!(first_distinct_byte_run(bytes, len), first_distinct_window(bytes, len));
+            prop_assert_eq!(
+                first_distinct_byte_run(&lowercase, len),
+                first_distinct_window(&lowercase, len)
+            );
+        }
+    }
+}
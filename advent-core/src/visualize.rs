@@ -0,0 +1,81 @@
+use anyhow::bail;
+
+use crate::{
+    context::Context,
+    day::{parse_input, Day},
+};
+
+/// Characters used to shade a cell from empty to full
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+/// Days that can render the progress of a solution as a sequence of text frames
+pub trait Visualizer: Day {
+    /// Solves `part` of the puzzle with the parameters in `ctx`, passing each rendered frame to
+    /// `frame` along the way
+    fn visualize(
+        input: &Self::Input,
+        part: i32,
+        ctx: &Context<'_>,
+        frame: &mut dyn FnMut(&str),
+    ) -> anyhow::Result<()>;
+}
+
+/// Type-erased visualizer so days from any year can be looked up and run uniformly
+pub type VisualizeFn = fn(&mut Context<'_>, i32, &mut dyn FnMut(&str)) -> anyhow::Result<()>;
+
+/// Parses the input in `ctx` for `V` and renders the frames of the requested `part`
+pub fn visualize<V: Visualizer>(
+    ctx: &mut Context<'_>,
+    part: i32,
+    frame: &mut dyn FnMut(&str),
+) -> anyhow::Result<()> {
+    if !(1..=2).contains(&part) {
+        bail!("puzzles only have parts 1 and 2, not {part}");
+    }
+
+    let input = parse_input::<V>(ctx.take_input())?;
+
+    V::visualize(&input, part, ctx, frame)
+}
+
+/// Renders a row-major grid of `width` columns, shrinking it by combining each `block` of
+/// `(columns, rows)` cells into one character shaded by its average value relative to `max`.
+pub fn shade_grid(values: &[usize], width: usize, block: (usize, usize), max: usize) -> String {
+    let height = values.len() / width;
+    let (bw, bh) = (block.0.max(1), block.1.max(1));
+    let max = max.max(1);
+    let mut out = String::with_capacity((width / bw + 1) * (height / bh));
+
+    for y in (0..height).step_by(bh) {
+        for x in (0..width).step_by(bw) {
+            let rows = y..(y + bh).min(height);
+            let cols = x..(x + bw).min(width);
+            let count = rows.len() * cols.len();
+
+            let total = rows
+                .flat_map(|r| values[r * width + cols.start..r * width + cols.end].iter())
+                .sum::<usize>();
+
+            let level = (total * (SHADES.len() - 1)).div_ceil(count * max);
+
+            out.push(char::from(SHADES[level.min(SHADES.len() - 1)]));
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shade_blocks() {
+        let values = [0, 0, 1, 1, 0, 0, 1, 0];
+
+        assert_eq!(shade_grid(&values, 4, (1, 1), 1), "  @@\n  @ \n");
+        assert_eq!(shade_grid(&values, 4, (2, 2), 1), " #\n");
+    }
+}
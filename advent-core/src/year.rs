@@ -0,0 +1,73 @@
+//! What a year crate exposes to the tools that run it. `generate_year!` implements [`Year`] for a
+//! `ThisYear` unit struct in every year crate, so the `advent-solutions` registry can hold each
+//! year as a `&dyn Year` and dispatch to it without naming any of its days.
+
+use crate::{
+    answer::Answer, context, describe::Description, explore::ExplorerFn, lint::LintFn,
+    parsed_cache::CachedDayFn, visualize::VisualizeFn,
+};
+
+/// Type-erased signature shared by every registered part so solutions from any day can be stored
+/// and invoked uniformly. The context holds no input when no input file exists.
+pub type PartFn = fn(&mut context::Context<'_>) -> anyhow::Result<Answer>;
+
+/// Type-erased signature for solving both parts of a day from a single parse of its input
+pub type DayFn = fn(&mut context::Context<'_>) -> anyhow::Result<[Answer; 2]>;
+
+/// Type-erased solver for either part of an alternative, as given by [`crate::day::solve`]
+pub type AlternativeFn = fn(&mut context::Context<'_>, i32) -> anyhow::Result<Answer>;
+
+/// The `(day, part, solution, streaming, params)` list `generate_year!` emits as `SOLUTIONS` for a
+/// year
+pub type YearSolutions = &'static [(i32, i32, PartFn, Option<PartFn>, &'static [context::Param])];
+
+/// The `(day, solution)` list `generate_year!` emits as `DAYS` for a year
+pub type YearDays = &'static [(i32, DayFn)];
+
+/// A year of solutions as generated by `generate_year!`. The optional lists are empty unless the
+/// year names its own in the macro, as in `generate_year!(2022 25; alternatives = ALTERNATIVES)`.
+pub trait Year: Sync {
+    /// The year the puzzles were released
+    fn year(&self) -> i32;
+
+    /// Every solved part, as in the year's `SOLUTIONS`
+    fn solutions(&self) -> YearSolutions;
+
+    /// Every day with both parts solved, as in the year's `DAYS`
+    fn days(&self) -> YearDays;
+
+    /// What each day is about, as in the year's `DESCRIPTIONS`
+    fn descriptions(&self) -> &'static [Description];
+
+    /// The check of each day's input, as in the year's `LINTERS`
+    fn linters(&self) -> &'static [(i32, LintFn)];
+
+    /// The highest day with a solution
+    fn days_solved(&self) -> i32;
+
+    /// How many parts of `day` are solved, counting from part 1
+    fn parts_solved(&self, day: i32) -> i32;
+
+    /// Solves `part` of `day` from its default input, printing the answer
+    fn run_solution(&self, day: i32, part: i32);
+
+    /// Other implementations of days as `(day, name, solver)`
+    fn alternatives(&self) -> &'static [(i32, &'static str, AlternativeFn)] {
+        &[]
+    }
+
+    /// Days whose parsed input can be explored from a command loop
+    fn explorable(&self) -> &'static [(i32, ExplorerFn)] {
+        &[]
+    }
+
+    /// Days whose parsed input is worth keeping between runs
+    fn parse_cached(&self) -> &'static [(i32, CachedDayFn)] {
+        &[]
+    }
+
+    /// Days that can render their progress
+    fn visualizers(&self) -> &'static [(i32, VisualizeFn)] {
+        &[]
+    }
+}
@@ -1,51 +1,336 @@
 //! # Summary
 //! The primary macro here ([`advent_macros::generate_year`]) is a convenient way to select a day
 //! and part solution for any implemented days using only two numbers: year and highest solved day.
-//! The macro generates `use` and `mod` declarations as well as a
-//! `pub fn run_solution(day: i32, part: i32)` that tries to load any input from a `data` folder
-//! and passes it to the matching `day_##::part_##(reader: Option<impl BufRead>)`, if it exists,
-//! and a `pub fn days_solved() -> i32` to check how many days have solutions.
+//! Each year is its own crate with its days in `src/day_##.rs`, each holding a `Puzzle` that
+//! implements `advent_core::day::Day`, and the generated code names everything through
+//! `::advent_core`.
 //!
+//! # Generated items
+//! - `use` and `mod` declarations for every day
+//! - `pub fn run_solution(day: i32, part: i32)`, which loads the day's default input from the
+//!   `data` folder as resolved by `advent_core::input::resolve_input` and solves the requested
+//!   part
+//! - `pub fn days_solved() -> i32`, the highest day with a solution
+//! - `pub const SOLUTIONS`, every part along with a streaming solver for days that have one, so
+//!   tooling such as benchmarks can enumerate them
+//! - `pub const DAYS`, every day with a solver for both parts from one parse of the input
+//!
+//! # `Year` impl
+//! Everything generated is gathered behind a `pub struct ThisYear` implementing
+//! `advent_core::year::Year`, which is what the `advent-solutions` registry dispatches through.
 //!
 //! # Example
 //! To generate a method that will select from the first 19 days (inclusive) of the year 2015
 //! simply requires calling the macro with the two numbers.
 //!
-//! ```
+//! ```ignore
 //! use advent_macros::generate_year;
 //!
 //! generate_year!(2015 19);
 //! ```
+//!
+//! # Options
+//! The day modules are private by default. Starting with a visibility makes them reachable from
+//! outside the year crate instead.
+//!
+//! ```ignore
+//! generate_year!(pub 2022 8);
+//! ```
+//!
+//! Inputs kept somewhere other than the `data` folder can be found through a path template, in
+//! which `{year}`, `{day}`, and `{day:02}` are filled in. Setting the `ADVENT_INPUT_TEMPLATE`
+//! environment variable to a template overrides this at runtime.
+//!
+//! ```ignore
+//! generate_year!(2022 8, "inputs/{year}/day{day}.txt");
+//! ```
+//!
+//! When only the first part of the latest day is done, ending with `; last_day_parts = 1` leaves
+//! its second part unregistered, and a generated `parts_solved(day)` reports how many parts each
+//! day has.
+//!
+//! ```ignore
+//! generate_year!(2023 5; last_day_parts = 1);
+//! ```
+//!
+//! The year's optional lists are named the same way, so its `Year` returns them instead of
+//! nothing: `alternatives`, `explorable`, `parse_cached`, and `visualizers`.
+//!
+//! ```ignore
+//! generate_year!(pub 2022 25; alternatives = ALTERNATIVES, parse_cached = PARSE_CACHED);
+//! ```
+//!
+//! # Other macros
+//! Days that have no tests of their own can be covered with
+//! [`advent_macros::generate_missing_test_stubs`], which adds a test solving each such day's
+//! committed input, or an ignored stub where there is none, until the day gets real tests.
+//!
+//! ```ignore
+//! generate_missing_test_stubs!(2015);
+//! ```
+//!
+//! Alternatively [`advent_macros::advent`] registers a single part without any numbering scheme,
+//! which suits years with skipped days or days spread across several files.
+//!
+//! ```ignore
+//! use advent_macros::advent;
+//!
+//! #[advent(year = 2023, day = 3)]
+//! fn part1(input: &str) -> usize {
+//!     input.lines().count()
+//! }
+//! ```
+
+use std::{fs, path::PathBuf};
 
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse::Parse, parse_macro_input, LitInt};
+use syn::{
+    parse::Parse, parse_macro_input, AttributeArgs, Ident, ItemFn, Lit, LitInt, LitStr, Meta,
+    NestedMeta, Path, Token, Visibility,
+};
 
 struct YearInput {
+    visibility: Visibility,
     year: LitInt,
     max_day: LitInt,
+    input_template: Option<LitStr>,
+    last_day_parts: Option<LitInt>,
+    /// Lists of the year's own named by `alternatives = ..` and the like, keyed by that name
+    lists: Vec<(Ident, Path)>,
 }
 
+/// Options that name one of the year's lists for its `Year` implementation to return
+const LIST_OPTIONS: [&str; 4] = ["alternatives", "explorable", "parse_cached", "visualizers"];
+
 impl Parse for YearInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let visibility = input.parse()?;
+        let year = input.parse()?;
+        let max_day = input.parse()?;
+
+        let input_template = match input.parse::<Option<Token![,]>>()? {
+            Some(_) => Some(input.parse()?),
+            None => None,
+        };
+
+        let mut last_day_parts = None;
+        let mut lists = Vec::new();
+
+        if input.parse::<Option<Token![;]>>()?.is_some() {
+            loop {
+                let name = input.parse::<Ident>()?;
+
+                input.parse::<Token![=]>()?;
+
+                if name == "last_day_parts" {
+                    last_day_parts = Some(input.parse()?);
+                } else if LIST_OPTIONS.iter().any(|o| name == o) {
+                    lists.push((name, input.parse()?));
+                } else {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!(
+                            "expected `last_day_parts` or one of `{}`",
+                            LIST_OPTIONS.join("`, `")
+                        ),
+                    ));
+                }
+
+                if input.is_empty() || input.parse::<Option<Token![,]>>()?.is_none() {
+                    break;
+                }
+            }
+        }
+
         Ok(Self {
-            year: input.parse()?,
-            max_day: input.parse()?,
+            visibility,
+            year,
+            max_day,
+            input_template,
+            last_day_parts,
+            lists,
         })
     }
 }
 
-/// A top-level convenience macro for avoiding year module boilerplate. This macro creates a
-/// `run_solution(day: i32, part: i32)` function that takes care of matching the given day and part
-/// to `day_##::part_##(reader: Option<impl BufRead>)` if such a solution exists. It also creates a
-/// `days_solved() -> i32` function to see how many days have solutions. The macro expects to be
-/// called with two integar literals such as `generate_year!(2015 19);` with the literals
-/// representing the modules year and highest solved day (inclusive) respectively.
+/// What `generate_year!` reads from a day's source to describe it
+struct DaySource {
+    /// The module's `//!` comment
+    doc: String,
+    /// Doc comments of `part_01` and `part_02`
+    parts: [String; 2],
+    /// Modules of `advent_core::util` that are imported or named, sorted without repeats
+    utilities: Vec<String>,
+}
+
+impl DaySource {
+    fn read(source: &str) -> Self {
+        let lines = source.lines().map(str::trim).collect::<Vec<_>>();
+
+        let doc = lines
+            .iter()
+            .filter_map(|l| l.strip_prefix("//!"))
+            .map(|l| l.strip_prefix(' ').unwrap_or(l))
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_owned();
+
+        // The `///` lines directly above a part's signature, stepping over any attributes
+        let part_doc = |name: &str| {
+            let signature = format!("fn {name}(");
+
+            let Some(at) = lines.iter().position(|l| l.contains(&signature)) else {
+                return String::new();
+            };
+
+            let mut doc = lines[..at]
+                .iter()
+                .rev()
+                .skip_while(|l| l.starts_with("#["))
+                .map_while(|l| l.strip_prefix("///"))
+                .map(str::trim)
+                .collect::<Vec<_>>();
+
+            doc.reverse();
+            doc.join(" ")
+        };
+
+        let mut utilities = Vec::new();
+
+        for (at, _) in source.match_indices("util::") {
+            let preceded_by_ident = source[..at]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+            if preceded_by_ident {
+                continue;
+            }
+
+            let rest = &source[at + "util::".len()..];
+
+            match rest.strip_prefix('{') {
+                // Only the first segment of each path in the group names a utility
+                Some(group) => {
+                    let mut depth = 0;
+                    let end = group
+                        .find(|c| {
+                            match c {
+                                '{' => depth += 1,
+                                '}' if depth == 0 => return true,
+                                '}' => depth -= 1,
+                                _ => {}
+                            }
+
+                            false
+                        })
+                        .unwrap_or(group.len());
+
+                    let mut depth = 0;
+                    let mut start = 0;
+
+                    for (i, c) in group[..end].char_indices().chain([(end, ',')]) {
+                        match c {
+                            '{' => depth += 1,
+                            '}' => depth -= 1,
+                            ',' if depth == 0 => {
+                                utilities.push(leading_ident(&group[start..i]));
+                                start = i + 1;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                None => utilities.push(leading_ident(rest)),
+            }
+        }
+
+        utilities.retain(|u| !u.is_empty());
+        utilities.sort();
+        utilities.dedup();
+
+        Self {
+            doc,
+            parts: [part_doc("part_01"), part_doc("part_02")],
+            utilities,
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash of `bytes`, which is the same from one build to the next
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x100_0000_01b3)
+    })
+}
+
+/// The workspace this crate is a member of, along with `advent-core` and every year crate
+fn workspace_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("advent-macros should be inside the workspace")
+        .to_owned()
+}
+
+/// Where the sources of `advent_core::util` live, so days are versioned along with what they use
+fn util_dir() -> PathBuf {
+    workspace_dir().join("advent-core").join("src").join("util")
+}
+
+/// The identifier at the start of `text` once whitespace is skipped
+fn leading_ident(text: &str) -> String {
+    text.trim_start()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
+/// A top-level convenience macro for avoiding year crate boilerplate. The macro expects to be
+/// called with two integer literals such as `generate_year!(2015 19);` with the literals
+/// representing the crate's year and highest solved day (inclusive) respectively. It generates:
+///
+/// - `run_solution(day: i32, part: i32)`, which matches the given day to `day_##::Puzzle` and
+///   prints the answer to the requested part if such a solution exists
+/// - `days_solved() -> i32` to see how many days have solutions, and `parts_solved(day) -> i32`
+///   to see how many parts of each day are solved
+/// - `SOLUTIONS`, listing `(day, part, fn(&mut Context) -> Result<Answer>, Option<fn(...)>,
+///   &[Param])` for every part, where the fourth entry streams the input if the day supports it
+///   and the last lists the parameters it reads
+/// - `DAYS`, listing `(day, fn(&mut Context) -> Result<[Answer; 2]>)` to solve both parts of a
+///   day from a single parse
+/// - `LINTERS`, listing `(day, advent_core::lint::lint)` for every day to check an input without
+///   solving it
+/// - `advent_entry_YYYY`, with the `extern "C"` signature from `advent_core::abi`, which solves
+///   any part for a caller that loaded the year as a dynamic library
+/// - `ThisYear`, a unit struct implementing `advent_core::year::Year` that everything above is
+///   reachable through
+///
+/// The day modules are private unless the literals are preceded by a visibility, so
+/// `generate_year!(pub 2022 8);` lets integration tests and other crates reach into each day.
+/// `run_solution` reads inputs from where `advent_core::input::resolve_input` expects them unless
+/// a path template follows the literals, as in
+/// `generate_year!(2022 8, "inputs/{year}/day{day}.txt");`, where `{day:02}` pads the day. A
+/// template in the `ADVENT_INPUT_TEMPLATE` environment variable takes precedence over both.
+///
+/// Ending with `; last_day_parts = 1` marks the highest day as having only part 1 solved, which
+/// leaves its part 2 out of `SOLUTIONS` and the day out of `DAYS`. The `alternatives`,
+/// `explorable`, `parse_cached`, and `visualizers` lists of `ThisYear` are empty unless named after
+/// the `;`, as in `; last_day_parts = 1, alternatives = ALTERNATIVES`.
 /// # Panics
-/// Panics if input cannot be interpreted as year: usize, day: i32
+/// Panics if input cannot be interpreted as year: usize, day: i32, or `last_day_parts` is not 1
+/// or 2
 #[proc_macro]
 pub fn generate_year(input: TokenStream) -> TokenStream {
-    let YearInput { year, max_day } = parse_macro_input!(input as YearInput);
+    let YearInput {
+        visibility,
+        year,
+        max_day,
+        input_template,
+        last_day_parts,
+        lists,
+    } = parse_macro_input!(input as YearInput);
 
     let year: usize = year.base10_parse().expect("Year should be a usize literal");
 
@@ -53,35 +338,450 @@ pub fn generate_year(input: TokenStream) -> TokenStream {
         .base10_parse()
         .expect("Max day should be a usize literal");
 
+    let last_day_parts: i32 = last_day_parts.map_or(2, |p| {
+        p.base10_parse()
+            .expect("Last day parts should be an i32 literal")
+    });
+
+    assert!(
+        (1..=2).contains(&last_day_parts),
+        "Last day parts should be 1 or 2"
+    );
+
+    // Days with both parts solved, which is every day unless the last has only its first part
+    let full_days = if last_day_parts == 2 {
+        max_day
+    } else {
+        max_day - 1
+    };
+
     let range = 1..=max_day;
 
     let day_idx = range.clone().map(syn::Index::from);
     let day_mod = range.map(|d| format_ident!("day_{:02}", d));
     let day_mod2 = day_mod.clone();
+    let day_idx3 = (1..=full_days).map(syn::Index::from);
+    let day_mod4 = (1..=full_days).map(|d| format_ident!("day_{:02}", d));
+    let day_idx5 = (1..=max_day).map(syn::Index::from);
+    let day_mod5 = (1..=max_day).map(|d| format_ident!("day_{:02}", d));
+
+    let solutions = (1..=max_day).flat_map(|day| {
+        let parts = if day > full_days { 1 } else { 2 };
+        let day_mod = format_ident!("day_{:02}", day);
+        let day_idx = syn::Index::from(day);
+
+        (1..=parts).map(move |part: i32| {
+            quote! {
+                (
+                    #day_idx,
+                    #part,
+                    |ctx| ::advent_core::day::solve::<#day_mod::Puzzle>(ctx, #part),
+                    if <#day_mod::Puzzle as ::advent_core::day::Day>::STREAMING {
+                        Some(|ctx| ::advent_core::day::solve_streaming::<#day_mod::Puzzle>(ctx, #part))
+                    } else {
+                        None
+                    },
+                    <#day_mod::Puzzle as ::advent_core::day::Day>::PARAMS,
+                )
+            }
+        })
+    });
+
+    let root = std::env::var("CARGO_MANIFEST_DIR").map(PathBuf::from);
+
+    let descriptions = (1..=max_day).map(|day| {
+        let day_mod = format_ident!("day_{:02}", day);
+        let day_idx = syn::Index::from(day);
+
+        let source = root
+            .as_ref()
+            .ok()
+            .and_then(|root| {
+                let path = root.join("src").join(format!("day_{day:02}.rs"));
+
+                fs::read_to_string(path).ok()
+            })
+            .unwrap_or_default();
+
+        let DaySource {
+            doc,
+            parts: [part_01, part_02],
+            utilities,
+        } = DaySource::read(&source);
+
+        // The day's own source followed by that of each utility it uses, so editing any of them
+        // changes the version
+        let mut versioned = source.clone().into_bytes();
+
+        for utility in &utilities {
+            let path = util_dir().join(format!("{utility}.rs"));
+
+            versioned.extend(fs::read(path).unwrap_or_default());
+        }
+
+        let version = fnv1a(&versioned);
+
+        quote! {
+            ::advent_core::describe::Description {
+                year: #year as i32,
+                day: #day_idx,
+                info: #day_mod::INFO,
+                doc: #doc,
+                parts: [#part_01, #part_02],
+                utilities: &[#(#utilities),*],
+                version: #version,
+            }
+        }
+    });
 
     let max_day = i32::try_from(max_day).expect("maximum day should fit in i32");
 
+    // A template given here still gives way to one set in the environment when run
+    let input_path = match input_template {
+        Some(template) => quote! {
+            ::advent_core::input::fill_input_template(
+                &::advent_core::input::input_template().unwrap_or_else(|| #template.to_owned()),
+                #year as i32,
+                day,
+            )
+        },
+        None => quote! {
+            ::advent_core::input::resolve_input(#year as i32, day, Path::new("data"), None)
+        },
+    };
+
+    let unsolved_part = if last_day_parts == 1 {
+        quote! {
+            if (day, part) == (#max_day, 2) {
+                eprintln!("Only part 1 of day {} of {} is solved", day, #year);
+
+                return;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let list_methods = lists.iter().map(|(name, list)| {
+        let ty = match name.to_string().as_str() {
+            "alternatives" => quote! { [(i32, &'static str, ::advent_core::year::AlternativeFn)] },
+            "explorable" => quote! { [(i32, ::advent_core::explore::ExplorerFn)] },
+            "parse_cached" => quote! { [(i32, ::advent_core::parsed_cache::CachedDayFn)] },
+            _ => quote! { [(i32, ::advent_core::visualize::VisualizeFn)] },
+        };
+
+        quote! {
+            fn #name(&self) -> &'static #ty {
+                #list
+            }
+        }
+    });
+
+    let entry = format_ident!("advent_entry_{}", year);
+
     let expanded = quote! {
-        use std::{fs::File, io::BufReader};
+        use std::{fs::File, io::BufReader, path::Path};
 
-        #(mod #day_mod;
+        #(#visibility mod #day_mod;
             )*
 
         pub fn run_solution(day: i32, part: i32) {
-            let reader = File::open(format!("data/{}-{:02}.txt", #year, day))
-                .map(BufReader::new)
-                .ok();
+            let path = #input_path;
+            let mut reader = File::open(path).map(BufReader::new).ok();
 
-            match (day, part) {
-                #((#day_idx, 1) => #day_mod2::part_01(reader),
-                  (#day_idx, 2) => #day_mod2::part_02(reader),)*
-                _ => eprintln!("No solution exists for day {} of {}", day, #year),
+            #unsolved_part
+            let mut ctx = ::advent_core::context::Context::new(
+                reader.as_mut().map(|r| r as &mut dyn std::io::BufRead),
+            )
+            .with_output(std::io::stderr());
+
+            let result = match day {
+                #(#day_idx => ::advent_core::day::solve::<#day_mod2::Puzzle>(&mut ctx, part),)*
+                _ => {
+                    eprintln!("No solution exists for day {} of {}", day, #year);
+
+                    return;
+                }
+            };
+
+            match result {
+                Ok(answer) => println!("{answer}"),
+                Err(e) => eprintln!("Could not solve day {} part {} of {}: {e}", day, part, #year),
             }
         }
 
         pub fn days_solved() -> i32 {
             #max_day
         }
+
+        /// How many parts of `day` are solved, counting from part 1
+        pub fn parts_solved(day: i32) -> i32 {
+            if day == #max_day {
+                #last_day_parts
+            } else if (1..#max_day).contains(&day) {
+                2
+            } else {
+                0
+            }
+        }
+
+        /// Every solved part of this year as `(day, part, solution, streaming, params)` with the
+        /// solutions taking a context holding a type-erased reader so all parts share a single
+        /// signature. The streaming solution is only present for days that can be solved while
+        /// reading their input.
+        pub const SOLUTIONS: ::advent_core::year::YearSolutions = &[
+            #(#solutions,)*
+        ];
+
+        /// Every solved day of this year as `(day, solution)` with the solution parsing the input
+        /// once and answering both parts from it
+        pub const DAYS: ::advent_core::year::YearDays = &[
+            #((#day_idx3, ::advent_core::day::solve_both::<#day_mod4::Puzzle>),)*
+        ];
+
+        /// What each day of this year is about, from the `INFO` every day module exports along
+        /// with the docs and utilities found in its source
+        pub const DESCRIPTIONS: &[::advent_core::describe::Description] = &[
+            #(#descriptions,)*
+        ];
+
+        /// How each day of this year checks an input without solving it
+        pub const LINTERS: &[(i32, ::advent_core::lint::LintFn)] = &[
+            #((#day_idx5, ::advent_core::lint::lint::<#day_mod5::Puzzle>),)*
+        ];
+
+        /// This year as the `advent-solutions` registry sees it
+        pub struct ThisYear;
+
+        impl ::advent_core::year::Year for ThisYear {
+            fn year(&self) -> i32 {
+                #year as i32
+            }
+
+            fn solutions(&self) -> ::advent_core::year::YearSolutions {
+                SOLUTIONS
+            }
+
+            fn days(&self) -> ::advent_core::year::YearDays {
+                DAYS
+            }
+
+            fn descriptions(&self) -> &'static [::advent_core::describe::Description] {
+                DESCRIPTIONS
+            }
+
+            fn linters(&self) -> &'static [(i32, ::advent_core::lint::LintFn)] {
+                LINTERS
+            }
+
+            fn days_solved(&self) -> i32 {
+                days_solved()
+            }
+
+            fn parts_solved(&self, day: i32) -> i32 {
+                parts_solved(day)
+            }
+
+            fn run_solution(&self, day: i32, part: i32) {
+                run_solution(day, part)
+            }
+
+            #(#list_methods)*
+        }
+
+        /// Solves a part of this year for a caller that loaded the crate as a dynamic library, as
+        /// described in `advent_core::abi`
+        /// # Safety
+        /// The same as for `advent_core::abi::solve`
+        #[no_mangle]
+        pub unsafe extern "C" fn #entry(
+            version: u32,
+            day: i32,
+            part: i32,
+            input: *const u8,
+            len: usize,
+            sink: ::advent_core::abi::Sink,
+        ) -> i32 {
+            unsafe { ::advent_core::abi::solve(&ThisYear, version, day, part, input, len, sink) }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Registers a function as the solution to one part of a puzzle without listing it in a year's
+/// `generate_year!`, as in `#[advent(year = 2023, day = 3)]`. The part is read from a name ending
+/// in `1` or `2` such as `part1` or `part_02`, or given with `part = N`. The function takes the
+/// whole input as `&str` and returns anything that converts into `advent_core::answer::Answer`, or
+/// a `Result` of one. It is collected at link time by `advent_core::annotated`, so days can
+/// be skipped or spread across files and helper modules freely.
+/// # Panics
+/// Panics if the arguments are not integer `year`, `day`, and optional `part` values, or the part
+/// cannot be worked out
+#[proc_macro_attribute]
+pub fn advent(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as AttributeArgs);
+    let function = parse_macro_input!(item as ItemFn);
+
+    let mut year = None;
+    let mut day = None;
+    let mut part = None;
+
+    for arg in args {
+        let NestedMeta::Meta(Meta::NameValue(arg)) = arg else {
+            panic!("advent arguments should look like `year = 2023`");
+        };
+
+        let Lit::Int(value) = &arg.lit else {
+            panic!("advent arguments should be integers");
+        };
+
+        let value = value
+            .base10_parse::<i32>()
+            .expect("advent arguments should fit in i32");
+
+        match arg.path.get_ident().map(ToString::to_string).as_deref() {
+            Some("year") => year = Some(value),
+            Some("day") => day = Some(value),
+            Some("part") => part = Some(value),
+            _ => panic!("advent only accepts year, day, and part"),
+        }
+    }
+
+    let name = &function.sig.ident;
+
+    let part = part.unwrap_or_else(|| match name.to_string().chars().last() {
+        Some('1') => 1,
+        Some('2') => 2,
+        _ => panic!("`{name}` should end in 1 or 2, or the part should be given with `part = N`"),
+    });
+
+    let year = year.expect("advent needs the year, such as `year = 2023`");
+    let day = day.expect("advent needs the day, such as `day = 3`");
+
+    let expanded = quote! {
+        #function
+
+        const _: () = {
+            fn run(
+                ctx: &mut ::advent_core::context::Context<'_>,
+            ) -> anyhow::Result<::advent_core::answer::Answer> {
+                ::advent_core::annotated::solve_text(ctx, #name)
+            }
+
+            ::advent_core::__private::inventory::submit! {
+                ::advent_core::annotated::AnnotatedPart {
+                    year: #year,
+                    day: #day,
+                    part: #part,
+                    run,
+                }
+            }
+        };
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Adds a test for every day of a year whose `day_##.rs` file has no `#[cfg(test)]` module,
+/// called as `generate_missing_test_stubs!(2015);` from the root of the year's crate after its
+/// `generate_year!`. Each test solves every part the year lists in `SOLUTIONS` for the day from its
+/// committed input in `advent-solutions/data/YYYY-DD.txt` and fails on an
+/// error. Days without a committed input get the same test marked `#[ignore]` and run without an
+/// input, which suits days that embed theirs but is often too slow to run by default. Every day
+/// file is included in the expansion so adding a test module to one regenerates the stubs.
+/// # Panics
+/// Panics if the argument is not an integer year or the year's `src` directory cannot be read
+#[proc_macro]
+pub fn generate_missing_test_stubs(input: TokenStream) -> TokenStream {
+    let year = parse_macro_input!(input as LitInt);
+    let year: i32 = year.base10_parse().expect("Year should be an i32 literal");
+
+    let root = PathBuf::from(
+        std::env::var("CARGO_MANIFEST_DIR").expect("Cargo should set CARGO_MANIFEST_DIR"),
+    );
+
+    let year_dir = root.join("src");
+    let data_dir = workspace_dir().join("advent-solutions").join("data");
+
+    let mut days = fs::read_dir(&year_dir)
+        .unwrap_or_else(|e| panic!("could not read {}: {e}", year_dir.display()))
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let day = path
+                .file_stem()?
+                .to_str()?
+                .strip_prefix("day_")?
+                .parse::<u32>()
+                .ok()?;
+
+            Some((day, path))
+        })
+        .collect::<Vec<_>>();
+
+    days.sort();
+
+    let tracked = days.iter().map(|(_, path)| path.display().to_string());
+
+    let stubs = days.iter().filter_map(|(day, path)| {
+        let source = fs::read_to_string(path).ok()?;
+
+        if source.contains("#[cfg(test)]") {
+            return None;
+        }
+
+        let name = format_ident!("day_{:02}", day);
+        let day = i32::try_from(*day).expect("days should fit in i32");
+        let data = data_dir.join(format!("{year}-{day:02}.txt"));
+
+        let (ignore, input) = if data.exists() {
+            let data = data.display().to_string();
+
+            (
+                quote! {},
+                quote! { Some(&mut include_bytes!(#data).as_slice()) },
+            )
+        } else {
+            let reason = format!("{year} day {day:02} has no tests or committed input");
+
+            (quote! { #[ignore = #reason] }, quote! { None })
+        };
+
+        Some(quote! {
+            #[test]
+            #ignore
+            fn #name() {
+                let parts = super::SOLUTIONS
+                    .iter()
+                    .filter(|&&(day, ..)| day == #day)
+                    .collect::<Vec<_>>();
+
+                assert!(!parts.is_empty(), "day {} of {} is not registered", #day, #year);
+
+                for &(_, part, run, ..) in parts {
+                    let answer = run(&mut ::advent_core::context::Context::new(#input));
+
+                    assert!(
+                        answer.is_ok(),
+                        "part {} of day {} of {} failed: {:?}",
+                        part,
+                        #day,
+                        #year,
+                        answer
+                    );
+                }
+            }
+        })
+    });
+
+    let expanded = quote! {
+        /// Tests standing in for days of this year that do not have their own yet
+        #[cfg(test)]
+        mod missing_tests {
+            #(const _: &str = include_str!(#tracked);)*
+
+            #(#stubs)*
+        }
     };
 
     TokenStream::from(expanded)
@@ -0,0 +1,141 @@
+//! Compares evaluating a 2015 day 07 circuit in topological order against the stack of wires that
+//! were retried until every input was known, as the circuit was evaluated before, on a synthetic
+//! circuit of 100k gates. Run with `cargo bench --bench circuit`.
+
+use std::collections::{BTreeMap, HashSet};
+
+use advent_solutions::{util::synth, year_2015::LogicWires};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+/// Gates in the synthetic circuit, each depending on the one before it
+const GATES: usize = 100_000;
+
+enum Operand {
+    Signal(u16),
+    Wire(String),
+}
+
+/// The operator (empty for a plain connection) and operands of a wire's source, read
+/// independently of day 07's own parsing
+struct Gate {
+    op: String,
+    operands: Vec<Operand>,
+}
+
+fn parse(line: &str) -> (String, Gate) {
+    let tokens = line.split_ascii_whitespace().collect::<Vec<_>>();
+    let (source, wire) = (&tokens[..tokens.len() - 2], tokens[tokens.len() - 1]);
+    let operand = |t: &str| match t.parse() {
+        Ok(v) => Operand::Signal(v),
+        Err(_) => Operand::Wire(t.to_owned()),
+    };
+
+    let (op, operands) = match source {
+        [value] => ("", vec![operand(value)]),
+        [not, value] => (*not, vec![operand(value)]),
+        [lhs, op, rhs] => (*op, vec![operand(lhs), operand(rhs)]),
+        _ => panic!("unexpected connection '{line}'"),
+    };
+
+    let gate = Gate {
+        op: op.to_owned(),
+        operands,
+    };
+
+    (wire.to_owned(), gate)
+}
+
+/// Evaluates `wire` by retrying the wire on top of a stack until its inputs are known, pushing
+/// whichever input is missing, as `LogicWires` did before it ordered wires topologically. The
+/// maps and sets are the ones it kept them in. Returns the signal of every wire evaluated.
+fn eval_ask_stack(gates: &BTreeMap<String, Gate>, wire: &str) -> BTreeMap<String, u16> {
+    let mut state = BTreeMap::<String, u16>::new();
+    let mut ask_stack = vec![wire.to_owned()];
+    let mut waiting = HashSet::new();
+
+    while let Some(wire) = ask_stack.last() {
+        let gate = &gates[wire];
+        let signals = gate
+            .operands
+            .iter()
+            .map(|o| match o {
+                Operand::Signal(v) => Ok(*v),
+                Operand::Wire(w) => state.get(w).copied().ok_or_else(|| w.clone()),
+            })
+            .collect::<Result<Vec<_>, _>>();
+
+        match signals {
+            Ok(s) => {
+                let v = match (gate.op.as_str(), &s[..]) {
+                    ("", &[v]) => v,
+                    ("NOT", &[v]) => !v,
+                    ("AND", &[l, r]) => l & r,
+                    ("OR", &[l, r]) => l | r,
+                    ("LSHIFT", &[l, r]) => l << r,
+                    ("RSHIFT", &[l, r]) => l >> r,
+                    _ => panic!("unexpected gate {}", gate.op),
+                };
+
+                let wire = ask_stack.pop().unwrap();
+
+                waiting.remove(&wire);
+                state.insert(wire, v);
+            }
+            Err(needed) => {
+                waiting.insert(wire.clone());
+
+                assert!(!waiting.contains(&needed), "the circuit has a cycle");
+
+                ask_stack.push(needed);
+            }
+        }
+    }
+
+    state
+}
+
+fn bench_circuit(c: &mut Criterion) {
+    let mut out = Vec::new();
+
+    synth::circuit(&mut out, GATES, &mut synth::Rng::new(synth::DEFAULT_SEED)).unwrap();
+
+    let input = String::from_utf8(out).unwrap();
+    let gates = input.lines().map(parse).collect::<BTreeMap<_, _>>();
+    let mut wires = LogicWires::default();
+
+    for line in input.lines() {
+        wires.add_connection(line).unwrap();
+    }
+
+    assert_eq!(
+        wires.clone().val("a"),
+        Ok(eval_ask_stack(&gates, "a")["a"]),
+        "both approaches should agree"
+    );
+
+    let mut group = c.benchmark_group("2015/07 circuit");
+
+    group.sample_size(10);
+
+    group.bench_function("ask stack", |b| {
+        b.iter_with_large_drop(|| eval_ask_stack(&gates, "a"));
+    });
+
+    group.bench_function("topological", |b| {
+        b.iter_batched(
+            || wires.clone(),
+            |mut wires| {
+                wires.val("a").unwrap();
+
+                // Returned so dropping the evaluated circuit is not timed, as with the ask stack
+                wires
+            },
+            BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_circuit);
+criterion_main!(benches);
@@ -0,0 +1,166 @@
+//! Compares the standard library's SipHash against the Fx hasher behind `util::collections` on the
+//! keys the hottest maps hash, sized well beyond the real inputs: houses visited by following a
+//! million random directions (2015 day 03), every way of splitting 100 teaspoons between four
+//! ingredients (2015 day 15), and the wire names of a 100k gate circuit (2015 day 07). Both days
+//! with synthetic inputs are also parsed and solved whole. Run with `cargo bench --bench hashers`.
+
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::BuildHasher,
+};
+
+use advent_solutions::{
+    context::Context,
+    registry,
+    util::{
+        collections::FxBuildHasher,
+        geom::{Dir4, Point2},
+        synth,
+    },
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Directions followed around the houses
+const DIRECTIONS: usize = 1_000_000;
+
+/// Gates in the synthetic circuit
+const GATES: usize = 100_000;
+
+fn directions() -> String {
+    let mut rng = synth::Rng::new(synth::DEFAULT_SEED);
+
+    (0..DIRECTIONS)
+        .map(|_| ['^', 'v', '<', '>'][rng.below(4) as usize])
+        .collect()
+}
+
+/// Every recipe of four ingredients adding up to 100 teaspoons
+fn recipes() -> Vec<Vec<i64>> {
+    let mut recipes = Vec::new();
+
+    for a in 0..=100 {
+        for b in 0..=100 - a {
+            for c in 0..=100 - a - b {
+                recipes.push(vec![a, b, c, 100 - a - b - c]);
+            }
+        }
+    }
+
+    recipes
+}
+
+/// Houses visited at least once, counting presents per house as day 03 does
+fn houses<S: BuildHasher + Default>(directions: &[Dir4]) -> usize {
+    let mut grid = HashMap::<Point2<i64>, u32, S>::default();
+    let mut pos = Point2::ORIGIN;
+
+    grid.insert(pos, 1);
+
+    for &dir in directions {
+        pos = pos.step(dir);
+        *grid.entry(pos).or_default() += 1;
+    }
+
+    grid.len()
+}
+
+/// Tests each recipe twice, skipping those already scored, as day 15's gradient ascent does
+fn candidates<S: BuildHasher + Default>(recipes: &[Vec<i64>]) -> usize {
+    let mut tested = HashMap::<Vec<i64>, i64, S>::default();
+
+    for recipe in recipes.iter().chain(recipes) {
+        if !tested.contains_key(recipe) {
+            tested.insert(recipe.clone(), recipe.iter().product());
+        }
+    }
+
+    tested.len()
+}
+
+/// Numbers each name read from the circuit in the order first seen, as wires are interned
+fn names<S: BuildHasher + Default>(circuit: &str) -> usize {
+    let mut symbols = HashMap::<&str, usize, S>::default();
+
+    for name in circuit.split_ascii_whitespace() {
+        if name.starts_with(|c: char| c.is_ascii_lowercase()) {
+            let next = symbols.len();
+
+            symbols.entry(name).or_insert(next);
+        }
+    }
+
+    symbols.len()
+}
+
+fn compare<T: ?Sized>(
+    c: &mut Criterion,
+    name: &str,
+    input: &T,
+    sip: fn(&T) -> usize,
+    fx: fn(&T) -> usize,
+) {
+    assert_eq!(sip(input), fx(input), "both hashers should agree");
+
+    let mut group = c.benchmark_group(name);
+
+    group.sample_size(10);
+    group.bench_function("SipHash", |b| b.iter(|| sip(input)));
+    group.bench_function("Fx", |b| b.iter(|| fx(input)));
+    group.finish();
+}
+
+fn solve(c: &mut Criterion, day: i32, input: &[u8]) {
+    let solve = registry::find_day(2015, day).expect("the day should be solved");
+
+    c.benchmark_group(format!("2015/{day:02} synthetic"))
+        .sample_size(10)
+        .bench_function("parse and solve", |b| {
+            b.iter(|| solve(&mut Context::new(Some(&mut &input[..]))).unwrap())
+        });
+}
+
+fn bench_hashers(c: &mut Criterion) {
+    let arrows = directions();
+    let dirs = arrows
+        .chars()
+        .map(|c| Dir4::try_from(c).unwrap())
+        .collect::<Vec<_>>();
+    let mut circuit = Vec::new();
+
+    synth::circuit(
+        &mut circuit,
+        GATES,
+        &mut synth::Rng::new(synth::DEFAULT_SEED),
+    )
+    .unwrap();
+
+    let circuit = String::from_utf8(circuit).unwrap();
+
+    compare(
+        c,
+        "2015/03 houses",
+        &dirs[..],
+        houses::<RandomState>,
+        houses::<FxBuildHasher>,
+    );
+    compare(
+        c,
+        "2015/15 candidates",
+        &recipes()[..],
+        candidates::<RandomState>,
+        candidates::<FxBuildHasher>,
+    );
+    compare(
+        c,
+        "2015/07 names",
+        circuit.as_str(),
+        names::<RandomState>,
+        names::<FxBuildHasher>,
+    );
+
+    solve(c, 3, arrows.as_bytes());
+    solve(c, 7, circuit.as_bytes());
+}
+
+criterion_group!(benches, bench_hashers);
+criterion_main!(benches);
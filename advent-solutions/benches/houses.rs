@@ -0,0 +1,75 @@
+//! Stress test of tracking the houses visited in 2015 day 03, comparing a hash map of houses
+//! against the dense quadrant grid on random walks from the size of a real input up to millions of
+//! directions, along with a staircase that outgrows the dense grid's budget and moves to a hash
+//! map part way. Run with `cargo bench --bench houses`.
+
+use advent_solutions::{
+    util::{geom::Dir4, synth},
+    year_2015::HouseGrid,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Directions in each random walk, starting around the length of the real input
+const WALKS: [usize; 4] = [8_192, 65_536, 1_048_576, 4_194_304];
+
+fn random_walk(len: usize) -> Vec<Dir4> {
+    let mut rng = synth::Rng::new(synth::DEFAULT_SEED);
+
+    (0..len)
+        .map(|_| [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right][rng.below(4) as usize])
+        .collect()
+}
+
+fn bench_houses(c: &mut Criterion) {
+    let mut group = c.benchmark_group("2015/03 houses");
+
+    group.sample_size(10);
+
+    for len in WALKS {
+        let walk = random_walk(len);
+
+        assert_eq!(
+            HouseGrid::sparse().visit(&walk, 2),
+            HouseGrid::dense(usize::MAX).visit(&walk, 2),
+            "both grids should agree"
+        );
+
+        group.bench_with_input(BenchmarkId::new("sparse", len), &walk, |b, walk| {
+            b.iter(|| HouseGrid::sparse().visit(walk, 2).houses_visited())
+        });
+        group.bench_with_input(BenchmarkId::new("dense", len), &walk, |b, walk| {
+            b.iter(|| HouseGrid::dense(usize::MAX).visit(walk, 2).houses_visited())
+        });
+        group.bench_with_input(BenchmarkId::new("chosen", len), &walk, |b, walk| {
+            b.iter(|| {
+                HouseGrid::for_directions(walk.len())
+                    .visit(walk, 2)
+                    .houses_visited()
+            })
+        });
+    }
+
+    let staircase = [Dir4::Up, Dir4::Right].repeat(WALKS[2] / 2);
+
+    group.bench_with_input(
+        BenchmarkId::new("sparse staircase", staircase.len()),
+        &staircase,
+        |b, walk| b.iter(|| HouseGrid::sparse().visit(walk, 1).houses_visited()),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("chosen staircase", staircase.len()),
+        &staircase,
+        |b, walk| {
+            b.iter(|| {
+                HouseGrid::for_directions(walk.len())
+                    .visit(walk, 1)
+                    .houses_visited()
+            })
+        },
+    );
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_houses);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+//! Measures the 2015 days that key their puzzles by interned names, parsing and solving both
+//! parts from their real input, and counts what each run allocates through a counting global
+//! allocator so the effect of interning shows up beyond time alone. Allocation counts are printed
+//! before criterion starts. Run with `cargo bench --bench interned_days`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fs,
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use advent_solutions::{context::Context, registry};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Days whose names are interned
+const DAYS: [i32; 4] = [7, 9, 13, 16];
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting every allocation made through it
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn bench_interned_days(c: &mut Criterion) {
+    for day in DAYS {
+        let input = fs::read(Path::new("data").join(format!("2015-{day:02}.txt")))
+            .expect("the day's input should be committed");
+        let solve = registry::find_day(2015, day).expect("the day should be solved");
+        let run = || solve(&mut Context::new(Some(&mut input.as_slice()))).unwrap();
+
+        let (allocations, bytes) = (
+            ALLOCATIONS.load(Ordering::Relaxed),
+            BYTES.load(Ordering::Relaxed),
+        );
+
+        run();
+
+        println!(
+            "2015/{day:02}: {} allocations, {} bytes allocated to parse and solve both parts",
+            ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+            BYTES.load(Ordering::Relaxed) - bytes
+        );
+
+        let mut group = c.benchmark_group(format!("2015/{day:02}"));
+
+        group.sample_size(10);
+        group.bench_function("parse and solve", |b| b.iter(run));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_interned_days);
+criterion_main!(benches);
@@ -0,0 +1,93 @@
+//! Compares applying 2015 day 06 instructions a row at a time against the original approach of
+//! visiting each light individually. Run with `cargo bench --bench lights`.
+
+use std::fs;
+
+use advent_solutions::{
+    util::grid::{LightChanger, Region, SimpleLights, VariableLights},
+    year_2015::{parse_instruction, process_instructions, RequestedAction},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Inclusive upper left and lower right corners of an instruction's area
+type Area = ((usize, usize), (usize, usize));
+
+/// Corners of the area an instruction covers, read independently of day 06's own parsing
+fn corners(line: &str) -> Area {
+    let mut corners = line.split_ascii_whitespace().filter_map(|w| {
+        let (x, y) = w.split_once(',')?;
+
+        Some((x.parse().unwrap(), y.parse().unwrap()))
+    });
+
+    (corners.next().unwrap(), corners.next().unwrap())
+}
+
+/// Applies each instruction one light at a time through a bounds-checked index, as day 06 did
+/// before its lights were changed a row at a time
+fn process_per_cell(
+    instructions: &[(RequestedAction, Region)],
+    areas: &[Area],
+    lights: &mut [usize],
+) {
+    for ((action, _), &((x0, y0), (x1, y1))) in instructions.iter().zip(areas) {
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                let light = &mut lights[y * 1000 + x];
+
+                *light = match action {
+                    RequestedAction::TurnOn => 1,
+                    RequestedAction::TurnOff => 0,
+                    RequestedAction::Toggle => *light ^ 1,
+                };
+            }
+        }
+    }
+}
+
+fn bench_lights(c: &mut Criterion) {
+    let input = fs::read_to_string("data/2015-06.txt")
+        .unwrap_or_else(|_| "turn on 0,0 through 999,999\ntoggle 0,0 through 999,0\n".repeat(150));
+
+    let instructions = input.lines().map(parse_instruction).collect::<Vec<_>>();
+    let areas = input.lines().map(corners).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("2015/06 lights");
+
+    group.sample_size(10);
+
+    group.bench_function("per cell", |b| {
+        b.iter(|| {
+            let mut lights = vec![0; 1000 * 1000];
+
+            process_per_cell(&instructions, &areas, &mut lights);
+
+            lights.iter().sum::<usize>()
+        });
+    });
+
+    group.bench_function("simple rows", |b| {
+        b.iter(|| {
+            let mut lights = SimpleLights::<1000, 1000>::default();
+
+            process_instructions(&instructions, &mut lights);
+
+            lights.count_on()
+        });
+    });
+
+    group.bench_function("variable rows", |b| {
+        b.iter(|| {
+            let mut lights = VariableLights::<1000, 1000>::default();
+
+            process_instructions(&instructions, &mut lights);
+
+            lights.count_on()
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_lights);
+criterion_main!(benches);
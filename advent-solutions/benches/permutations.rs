@@ -0,0 +1,58 @@
+//! Compares visiting every ordering of a route through `itertools`, which allocates each one, with
+//! rearranging a single order in place as 2015 day 09 does. Run with
+//! `cargo bench --bench permutations`.
+
+use std::hint::black_box;
+
+use advent_solutions::util::perm::Permutations;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use itertools::Itertools;
+
+/// Distances between every pair of `n` places, arbitrary but fixed
+fn distances(n: usize) -> Vec<Vec<usize>> {
+    (0..n)
+        .map(|a| (0..n).map(|b| (a * 31 + b * 17) % 97 + 1).collect())
+        .collect()
+}
+
+fn route_length(distances: &[Vec<usize>], route: &[usize]) -> usize {
+    route.windows(2).map(|w| distances[w[0]][w[1]]).sum()
+}
+
+fn bench_permutations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shortest route");
+
+    group.sample_size(10);
+
+    // Day 09 visits eight places
+    for n in [8, 10] {
+        let distances = distances(n);
+
+        group.bench_with_input(BenchmarkId::new("itertools", n), &distances, |b, d| {
+            b.iter(|| {
+                (0..n)
+                    .permutations(n)
+                    .map(|route| route_length(d, &route))
+                    .min()
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("in place", n), &distances, |b, d| {
+            b.iter(|| {
+                let mut routes = Permutations::of_indices(n);
+                let mut shortest = usize::MAX;
+
+                while let Some(route) = routes.next_permutation() {
+                    shortest = shortest.min(route_length(d, black_box(route)));
+                }
+
+                shortest
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_permutations);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+//! Benchmarks every part registered through `generate_year!` against its real puzzle input so
+//! performance regressions across refactors show up in criterion's reports. A subset can be
+//! selected with criterion's filter, e.g. `cargo bench --bench solutions -- 2022/08`. Parts that
+//! can stream their input are also measured that way for comparison, and days that cache their
+//! parsed input have both parts measured together once the cache is warm. Setting `ADVENT_THREADS`
+//! measures parallel solutions on that many threads instead of one per core.
+
+use std::{fs, path::Path};
+
+use advent_solutions::{
+    context::Context,
+    parsed_cache::{self, ParsedCache},
+    registry, threads,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_solutions(c: &mut Criterion) {
+    let cache = ParsedCache::in_data_dir(Path::new("data"));
+
+    if let Some(count) = threads::requested(None).expect("thread count should be valid") {
+        threads::configure(count).expect("thread pool should start");
+    }
+
+    for solution in registry::solutions() {
+        // Days without a data file carry their input in the source and expect `None`
+        let input = fs::read(solution.data_path()).ok();
+        let name = format!("{}/{:02}/{:02}", solution.year, solution.day, solution.part);
+
+        let mut group = c.benchmark_group(name);
+
+        // Some parts take seconds per run, so keep the minimum number of samples
+        group.sample_size(10);
+
+        group.bench_function("real input", |b| {
+            b.iter(|| {
+                threads::install(|| match &input {
+                    Some(data) => (solution.run)(&mut Context::new(Some(&mut data.as_slice()))),
+                    None => (solution.run)(&mut Context::new(None)),
+                })
+            });
+        });
+
+        if let (Some(stream), Some(data)) = (solution.stream, &input) {
+            group.bench_function("streaming", |b| {
+                b.iter(|| {
+                    threads::install(|| stream(&mut Context::new(Some(&mut data.as_slice()))))
+                });
+            });
+        }
+
+        let cached = parsed_cache::find(solution.year, solution.day);
+
+        if let (1, Some(cached), Some(data)) = (solution.part, cached, &input) {
+            let (year, day) = (solution.year, solution.day);
+
+            group.bench_function("both parts, cached parse", |b| {
+                b.iter(|| {
+                    threads::install(|| cached(&cache, year, day, data, &mut Context::new(None)))
+                });
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_solutions);
+criterion_main!(benches);
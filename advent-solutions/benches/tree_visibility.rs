@@ -0,0 +1,63 @@
+//! Compares the two ways 2022 day 08 finds visible trees on synthetic maps much larger than the
+//! puzzle input, along with each way of marching the columns when scanning rows. Run with
+//! `cargo bench --bench tree_visibility`.
+
+use advent_solutions::{
+    util::{grid::ColumnStrategy, synth},
+    year_2022::{TreeMap, TreeVisibility},
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// A square map from the day's stress input generator so every run measures the same map
+fn synthetic_map(size: usize) -> TreeMap {
+    let mut out = Vec::new();
+
+    synth::tree_map(&mut out, size, &mut synth::Rng::new(synth::DEFAULT_SEED)).unwrap();
+
+    String::from_utf8(out).unwrap().lines().collect()
+}
+
+fn bench_visibility(c: &mut Criterion) {
+    let mut group = c.benchmark_group("2022/08 visibility");
+
+    // The largest maps take around a second per run
+    group.sample_size(10);
+
+    for size in [1_000, 10_000] {
+        let map = synthetic_map(size);
+
+        group.bench_with_input(BenchmarkId::new("rows", size), &map, |b, map| {
+            b.iter(|| map.visibility_by_rows());
+        });
+
+        group.bench_with_input(BenchmarkId::new("lanes", size), &map, |b, map| {
+            b.iter(|| map.visibility_by_lanes());
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_column_strategies(c: &mut Criterion) {
+    let mut group = c.benchmark_group("2022/08 column strategies");
+    let map = synthetic_map(8_000);
+
+    group.sample_size(10);
+
+    for columns in [
+        ColumnStrategy::Sequential,
+        ColumnStrategy::Transposed,
+        ColumnStrategy::Strided,
+    ] {
+        group.bench_with_input(
+            BenchmarkId::new(format!("{columns:?}").to_lowercase(), 8_000),
+            &columns,
+            |b, &columns| b.iter(|| map.visibility_with(columns)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_visibility, bench_column_strategies);
+criterion_main!(benches);
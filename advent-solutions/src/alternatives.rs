@@ -0,0 +1,229 @@
+//! Other implementations of a day kept alongside its main one, such as a readable version of an
+//! optimized solution or the other way around. Years list them by name in their `ALTERNATIVES`
+//! list, `--impl NAME` runs one in place of the main implementation, and [`compare`] runs every
+//! implementation of a day to check that they agree and see how their times differ.
+
+use std::{
+    fs,
+    io::BufRead,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{answer::Answer, context::Context, day::Strategy, registry, threads};
+
+pub use crate::year::AlternativeFn;
+
+/// Every year's `(day, name, solver)` list of alternatives, paired with the year
+fn all() -> impl Iterator<Item = (i32, &'static (i32, &'static str, AlternativeFn))> {
+    registry::YEARS.iter().flat_map(|y| {
+        let year = y.year();
+
+        y.alternatives()
+            .iter()
+            .map(move |alternative| (year, alternative))
+    })
+}
+
+/// Looks up the alternative called `name` for the given day if it has one
+pub fn find(year: i32, day: i32, name: &str) -> Option<AlternativeFn> {
+    all()
+        .find(|&(y, &(d, n, _))| (y, d) == (year, day) && n == name)
+        .map(|(_, &(_, _, run))| run)
+}
+
+/// The alternative `strategy` asks for, if it names one that `year`/`day` has
+pub fn chosen(year: i32, day: i32, strategy: Strategy) -> Option<AlternativeFn> {
+    match strategy {
+        Strategy::Alternative(name) => find(year, day, name),
+        _ => None,
+    }
+}
+
+/// Every name used by an alternative of any day, sorted and without repeats
+pub fn names() -> Vec<&'static str> {
+    let mut names = all().map(|(_, &(_, name, _))| name).collect::<Vec<_>>();
+
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+/// The registered name equal to `name`, so it can be held without borrowing `name`
+pub fn named(name: &str) -> Option<&'static str> {
+    names().into_iter().find(|&n| n == name)
+}
+
+/// Reads the strategy `--impl` names: `collect`, `streaming`, or the name of any alternative
+pub fn strategy(name: &str) -> anyhow::Result<Strategy> {
+    Strategy::parse(name, &names())
+}
+
+/// One implementation's answer to a part and how long it took, including parsing
+#[derive(Debug)]
+pub struct ImplRun {
+    pub part: i32,
+    /// `collect` or `streaming` for the main implementation, otherwise the alternative's name
+    pub name: &'static str,
+    pub answer: anyhow::Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// Solves both parts of `year`/`day` with the input at `path` (if one exists) using every
+/// implementation: collecting the input, streaming it when the day supports that, and then
+/// each alternative in the order listed
+pub fn compare(year: i32, day: i32, path: &Path) -> Vec<ImplRun> {
+    threads::install(|| {
+        let input = fs::read(path).ok();
+
+        let time = |part, name, solve: &dyn Fn(&mut Context<'_>) -> anyhow::Result<Answer>| {
+            let mut reader = input.as_deref();
+            let mut ctx = Context::new(reader.as_mut().map(|r| r as &mut dyn BufRead));
+            let start = Instant::now();
+            let answer = solve(&mut ctx);
+
+            ImplRun {
+                part,
+                name,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        };
+
+        let alternatives = all()
+            .filter(|&(y, &(d, _, _))| (y, d) == (year, day))
+            .map(|(_, alternative)| alternative)
+            .collect::<Vec<_>>();
+
+        let mut runs = Vec::new();
+
+        for part in [1, 2] {
+            let Some(solution) = registry::find(year, day, part) else {
+                continue;
+            };
+
+            runs.push(time(part, "collect", &solution.run));
+
+            if let Some(stream) = solution.stream {
+                runs.push(time(part, "streaming", &stream));
+            }
+
+            for &&(_, name, run) in &alternatives {
+                runs.push(time(part, name, &|ctx| run(ctx, part)));
+            }
+        }
+
+        runs
+    })
+}
+
+/// The first part on which the `runs` that succeeded do not all give the same answer
+pub fn disagreement(runs: &[ImplRun]) -> Option<i32> {
+    let answered = runs
+        .iter()
+        .filter_map(|r| Some((r.part, r.answer.as_ref().ok()?)))
+        .collect::<Vec<_>>();
+
+    answered
+        .iter()
+        .find(|&&(part, answer)| {
+            answered
+                .iter()
+                .find(|&&(p, _)| p == part)
+                .is_some_and(|&(_, first)| first != answer)
+        })
+        .map(|&(part, _)| part)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "y2022", feature = "y2023"))]
+    fn registered() {
+        assert!(find(2022, 6, "readable").is_some());
+        assert!(find(2022, 6, "fast").is_none());
+        assert!(find(2023, 10, "scan").is_some());
+        assert!(find(2015, 6, "readable").is_none());
+        assert_eq!(named("fast"), Some("fast"));
+        assert_eq!(named("slow"), None);
+        assert!(chosen(2022, 1, Strategy::Alternative("fast")).is_some());
+        assert!(chosen(2022, 6, Strategy::Alternative("fast")).is_none());
+        assert!(chosen(2022, 1, Strategy::Streaming).is_none());
+        assert_eq!(strategy("streaming").unwrap(), Strategy::Streaming);
+        assert_eq!(strategy("scan").unwrap(), Strategy::Alternative("scan"));
+        assert!(strategy("lazy").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "y2022")]
+    fn implementations_agree() {
+        let dir = std::env::temp_dir().join(format!("alternatives-{}", std::process::id()));
+        let path = dir.join("input.txt");
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            &path,
+            "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000",
+        )
+        .unwrap();
+
+        let runs = compare(2022, 1, &path);
+        let names = runs.iter().map(|r| (r.part, r.name)).collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            [
+                (1, "collect"),
+                (1, "streaming"),
+                (1, "fast"),
+                (2, "collect"),
+                (2, "streaming"),
+                (2, "fast"),
+            ]
+        );
+        assert_eq!(disagreement(&runs), None);
+        assert_eq!(runs[5].answer.as_ref().unwrap(), &Answer::Int(45000));
+
+        // Only answers are compared, so a missing input that fails everything has nothing to
+        // disagree about
+        let runs = compare(2022, 6, &dir.join("missing.txt"));
+
+        assert_eq!(runs.len(), 4);
+        assert!(runs.iter().all(|r| r.answer.is_err()));
+        assert_eq!(disagreement(&runs), None);
+
+        let mut runs = compare(2022, 1, &path);
+
+        runs[4].answer = Ok(Answer::Int(0));
+        runs[2].answer = Err(anyhow::anyhow!("failed"));
+
+        assert_eq!(disagreement(&runs), Some(2));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn alternatives_match_main() {
+        for (year, &(day, name, run)) in all() {
+            let Some(solution) = registry::find(year, day, 1) else {
+                continue;
+            };
+
+            let Ok(data) = fs::read(solution.data_path()) else {
+                continue;
+            };
+
+            for part in [1, 2] {
+                let main = registry::find(year, day, part).unwrap().run;
+
+                assert_eq!(
+                    run(&mut Context::new(Some(&mut data.as_slice())), part).unwrap(),
+                    main(&mut Context::new(Some(&mut data.as_slice()))).unwrap(),
+                    "{name} for day {day} part {part} of {year}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Answers kept on disk so running a part again with the same code and input skips solving it.
+//! Entries live in `.advent-cache` alongside the puzzle inputs, one bincode file per run named by
+//! a hash of everything the answers depend on: the puzzle, the version of the day's code from its
+//! [`Description`](crate::describe::Description), the input, the parameters, and the
+//! implementation chosen. Editing the day or a utility it uses changes the version, so answers
+//! from older code are never returned. Only the most recently written entries are kept.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use md5::{Digest, Md5};
+
+use crate::{answer::Answer, day::Strategy};
+
+/// Entries kept once older ones are evicted
+pub const MAX_ENTRIES: usize = 256;
+
+/// Name of the cache directory within the data directory
+pub const DIR_NAME: &str = ".advent-cache";
+
+/// What a cached run's answers depend on, hashed into the name of its entry
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    /// The key for running `part` of `year`/`day`, or both parts when `None`, at code `version`
+    /// on `input` with `params` and `strategy`. The input is read a piece at a time, so hashing a
+    /// large one takes no more memory than streaming it. A missing input file is told apart from
+    /// an empty one.
+    pub fn new(
+        year: i32,
+        day: i32,
+        part: Option<i32>,
+        version: u64,
+        input: Option<impl Read>,
+        params: &BTreeMap<String, String>,
+        strategy: Strategy,
+    ) -> io::Result<Self> {
+        let mut hasher = Md5::new();
+
+        hasher.update(format!("{year}-{day}-{part:?}-{version:x}-{strategy:?}\n"));
+
+        for (name, value) in params {
+            hasher.update(format!("{}:{name}={value}\n", name.len()));
+        }
+
+        match input {
+            Some(mut input) => {
+                hasher.update(b"input\n");
+                io::copy(&mut input, &mut hasher)?;
+            }
+            None => hasher.update(b"no input\n"),
+        }
+
+        let hash = hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>();
+
+        Ok(Self(format!("{year}-{day:02}-{hash}")))
+    }
+}
+
+/// Directory of cached answers
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnswerCache {
+    dir: PathBuf,
+    max_entries: usize,
+}
+
+impl AnswerCache {
+    pub fn new(dir: impl Into<PathBuf>, max_entries: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            max_entries,
+        }
+    }
+
+    /// The cache kept alongside the puzzle inputs in `data_dir`
+    pub fn in_data_dir(data_dir: &Path) -> Self {
+        Self::new(data_dir.join(DIR_NAME), MAX_ENTRIES)
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{}.bin", key.0))
+    }
+
+    /// The answers stored under `key`, if any could be read
+    pub fn get(&self, key: &CacheKey) -> Option<Vec<Answer>> {
+        fs::read(self.path(key))
+            .ok()
+            .and_then(|bytes| bincode::deserialize(&bytes).ok())
+    }
+
+    /// Stores `answers` under `key`, then evicts the oldest entries beyond the limit. A cache
+    /// that cannot be written only costs solving again next time, so failures are ignored.
+    pub fn put(&self, key: &CacheKey, answers: &[Answer]) {
+        let Ok(bytes) = bincode::serialize(answers) else {
+            return;
+        };
+
+        if fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(self.path(key), bytes))
+            .is_ok()
+        {
+            self.evict();
+        }
+    }
+
+    /// Removes the least recently written entries until at most the limit remain
+    fn evict(&self) {
+        let Ok(dir) = fs::read_dir(&self.dir) else {
+            return;
+        };
+
+        let mut entries = dir
+            .filter_map(Result::ok)
+            .filter(|e| e.path().extension().is_some_and(|x| x == "bin"))
+            .map(|e| {
+                let written = e
+                    .metadata()
+                    .and_then(|m| m.modified())
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+
+                (written, e.path())
+            })
+            .collect::<Vec<_>>();
+
+        if entries.len() <= self.max_entries {
+            return;
+        }
+
+        entries.sort();
+
+        for (_, path) in &entries[..entries.len() - self.max_entries] {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{thread, time::Duration};
+
+    use super::*;
+
+    fn key(part: Option<i32>, version: u64, input: Option<&[u8]>) -> CacheKey {
+        CacheKey::new(
+            2015,
+            1,
+            part,
+            version,
+            input,
+            &BTreeMap::new(),
+            Strategy::Collect,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn keys() {
+        let base = key(Some(1), 7, Some(b"(()"));
+
+        assert_eq!(base, key(Some(1), 7, Some(b"(()")));
+        assert_ne!(base, key(Some(2), 7, Some(b"(()")));
+        assert_ne!(base, key(None, 7, Some(b"(()")));
+        assert_ne!(base, key(Some(1), 8, Some(b"(()")));
+        assert_ne!(base, key(Some(1), 7, Some(b"())")));
+        assert_ne!(key(Some(1), 7, Some(b"")), key(Some(1), 7, None));
+
+        let params = BTreeMap::from([("seconds".to_owned(), "1000".to_owned())]);
+        let with_params = CacheKey::new(
+            2015,
+            1,
+            Some(1),
+            7,
+            Some(&b"(()"[..]),
+            &params,
+            Strategy::Collect,
+        );
+        let streamed = CacheKey::new(
+            2015,
+            1,
+            Some(1),
+            7,
+            Some(&b"(()"[..]),
+            &BTreeMap::new(),
+            Strategy::Streaming,
+        );
+
+        assert_ne!(base, with_params.unwrap());
+        assert_ne!(base, streamed.unwrap());
+    }
+
+    #[test]
+    fn stores_and_evicts() {
+        let dir = std::env::temp_dir().join(format!("answer-cache-{}", std::process::id()));
+        let cache = AnswerCache::new(&dir, 2);
+        let answers = [Answer::Int(-3), Answer::Text("abc".to_owned())];
+        let keys = [b"1", b"2", b"3"].map(|input| key(None, 1, Some(input)));
+
+        assert_eq!(cache.get(&keys[0]), None);
+
+        for key in &keys {
+            cache.put(key, &answers);
+
+            // Modification times are only so fine on some file systems
+            thread::sleep(Duration::from_millis(20));
+        }
+
+        assert_eq!(cache.get(&keys[0]), None);
+        assert_eq!(cache.get(&keys[1]), Some(answers.to_vec()));
+        assert_eq!(cache.get(&keys[2]), Some(answers.to_vec()));
+
+        fs::write(cache.path(&keys[2]), b"junk").unwrap();
+
+        assert_eq!(cache.get(&keys[2]), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
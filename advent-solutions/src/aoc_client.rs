@@ -0,0 +1,330 @@
+//! The one HTTP layer for everything that talks to adventofcode.com, so downloading inputs,
+//! submitting answers, and reading leaderboards all identify themselves, authenticate, and pace
+//! their requests the same way. Responses can be cached on disk: inputs never change once
+//! unlocked, and leaderboards are only refetched once their cached copy is older than the
+//! [`LEADERBOARD_INTERVAL`] the site asks automated tools to respect.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime},
+};
+
+use thiserror::Error;
+
+use crate::config::Config;
+
+/// Identifies this tool to the Advent of Code servers as their automation guidelines request
+pub const USER_AGENT: &str = concat!(
+    "github.com/FracturedShader/advent-of-code ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Shortest time allowed between requests for the same leaderboard
+pub const LEADERBOARD_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Errors that can occur while talking to Advent of Code
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("no session token configured; run `config set session <cookie>` first")]
+    MissingSession,
+    #[error("request for {url} failed: {source}")]
+    Http {
+        url: String,
+        source: Box<ureq::Error>,
+    },
+    #[error("could not read response: {0}")]
+    Body(#[from] std::io::Error),
+    #[error("{url} was requested too recently; try again in {} minutes", wait.as_secs().div_ceil(60))]
+    RateLimited { url: String, wait: Duration },
+}
+
+/// How long a cached response may be used in place of a new request
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// Always make the request
+    Never,
+    /// Use the cached response while it is younger than the given age
+    For(Duration),
+    /// The response never changes, so any cached copy will do
+    Forever,
+}
+
+/// Responses saved as one file per request path
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResponseCache {
+    dir: PathBuf,
+}
+
+impl ResponseCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// File holding the response for `path`, such as `/2022/day/1/input`
+    fn file(&self, path: &str) -> PathBuf {
+        let name = path
+            .trim_matches('/')
+            .replace(|c: char| !c.is_ascii_alphanumeric() && c != '.', "_");
+
+        self.dir.join(name)
+    }
+
+    /// The cached response for `path` if `policy` allows it to be used
+    pub fn get(&self, path: &str, policy: CachePolicy) -> Option<String> {
+        let file = self.file(path);
+
+        let fresh = match policy {
+            CachePolicy::Never => false,
+            CachePolicy::Forever => true,
+            CachePolicy::For(max_age) => fs::metadata(&file)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                .is_some_and(|age| age < max_age),
+        };
+
+        fresh.then(|| fs::read_to_string(file).ok()).flatten()
+    }
+
+    /// Saves `body` as the response for `path`. A cache that cannot be written only costs a
+    /// request later, so failures are ignored.
+    pub fn put(&self, path: &str, body: &str) {
+        let _ = fs::create_dir_all(&self.dir).and_then(|_| fs::write(self.file(path), body));
+    }
+}
+
+/// Authenticated client for the Advent of Code site
+#[derive(Debug)]
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+    cache: Option<ResponseCache>,
+    /// When each throttled path was last requested by this client, so even without a cache the
+    /// limit holds within a run
+    last_requests: Mutex<HashMap<String, Instant>>,
+}
+
+impl AocClient {
+    /// A client authenticating with `session`, which only requests that need it will insist on
+    pub fn new(session: Option<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: BASE_URL.to_owned(),
+            session,
+            cache: None,
+            last_requests: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// A client using the configured session with its cache kept in the data directory
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.session.clone()).with_cache(Self::cache_dir(&config.data_dir()))
+    }
+
+    /// Where [`AocClient::from_config`] caches responses for inputs kept in `data_dir`
+    pub fn cache_dir(data_dir: &Path) -> PathBuf {
+        data_dir.join("cache")
+    }
+
+    pub fn with_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache = Some(ResponseCache::new(dir));
+        self
+    }
+
+    /// Sends requests somewhere other than [`BASE_URL`], such as a mirror or a test server
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    fn session(&self) -> Result<&str, ClientError> {
+        self.session.as_deref().ok_or(ClientError::MissingSession)
+    }
+
+    fn http_error(url: &str) -> impl FnOnce(ureq::Error) -> ClientError + '_ {
+        move |source| ClientError::Http {
+            url: url.to_owned(),
+            source: Box::new(source),
+        }
+    }
+
+    /// Refuses a request for `path` made within `interval` of the last one by this client
+    fn throttle(&self, path: &str, interval: Duration) -> Result<(), ClientError> {
+        let mut last = self.last_requests.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+
+        if let Some(wait) = last
+            .get(path)
+            .map(|&at| interval.saturating_sub(now - at))
+            .filter(|wait| !wait.is_zero())
+        {
+            return Err(ClientError::RateLimited {
+                url: format!("{}{path}", self.base_url),
+                wait,
+            });
+        }
+
+        last.insert(path.to_owned(), now);
+
+        Ok(())
+    }
+
+    /// Fetches `path` with the session cookie, answering from the cache when `policy` allows and
+    /// caching whatever is fetched
+    fn get(&self, path: &str, policy: CachePolicy) -> Result<String, ClientError> {
+        if let Some(body) = self.cache.as_ref().and_then(|c| c.get(path, policy)) {
+            return Ok(body);
+        }
+
+        if let CachePolicy::For(interval) = policy {
+            self.throttle(path, interval)?;
+        }
+
+        let url = format!("{}{path}", self.base_url);
+
+        let body = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .call()
+            .map_err(Self::http_error(&url))?
+            .into_string()?;
+
+        if let Some(cache) = self.cache.as_ref().filter(|_| policy != CachePolicy::Never) {
+            cache.put(path, &body);
+        }
+
+        Ok(body)
+    }
+
+    /// The personal puzzle input for `year`/`day`
+    pub fn input(&self, year: i32, day: i32) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}/input"), CachePolicy::Forever)
+    }
+
+    /// The JSON for private leaderboard `id` in `year`, from the cache when it was fetched within
+    /// the last [`LEADERBOARD_INTERVAL`]
+    pub fn leaderboard(&self, year: i32, id: u64) -> Result<String, ClientError> {
+        self.get(
+            &format!("/{year}/leaderboard/private/view/{id}.json"),
+            CachePolicy::For(LEADERBOARD_INTERVAL),
+        )
+    }
+
+    /// The calendar page for `year`, which marks the stars earned on each day. Stars change
+    /// slowly, so it is cached for as long as a leaderboard.
+    pub fn calendar(&self, year: i32) -> Result<String, ClientError> {
+        self.get(&format!("/{year}"), CachePolicy::For(LEADERBOARD_INTERVAL))
+    }
+
+    /// Submits `answer` for one part of a puzzle, returning the page describing the outcome
+    pub fn submit(
+        &self,
+        year: i32,
+        day: i32,
+        part: i32,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+
+        Ok(self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(Self::http_error(&url))?
+            .into_string()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()))
+    }
+
+    #[test]
+    fn cache_policies() {
+        let dir = temp_dir("cache");
+        let cache = ResponseCache::new(&dir);
+        let path = "/2022/leaderboard/private/view/1.json";
+
+        assert_eq!(cache.get(path, CachePolicy::Forever), None);
+
+        cache.put(path, "{}");
+
+        assert!(dir.join("2022_leaderboard_private_view_1.json").exists());
+        assert_eq!(cache.get(path, CachePolicy::Forever).as_deref(), Some("{}"));
+        assert_eq!(
+            cache
+                .get(path, CachePolicy::For(Duration::from_secs(60)))
+                .as_deref(),
+            Some("{}")
+        );
+        assert_eq!(cache.get(path, CachePolicy::For(Duration::ZERO)), None);
+        assert_eq!(cache.get(path, CachePolicy::Never), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn cached_responses_need_no_session() {
+        let dir = temp_dir("offline");
+        // Nothing listens here, so any request that is actually made fails
+        let client = AocClient::new(None)
+            .with_cache(&dir)
+            .with_base_url("http://127.0.0.1:9");
+
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::MissingSession)
+        ));
+
+        ResponseCache::new(&dir).put("/2022/day/1/input", "1\n2\n");
+        ResponseCache::new(&dir).put("/2022/leaderboard/private/view/7.json", "{}");
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n");
+        assert_eq!(client.leaderboard(2022, 7).unwrap(), "{}");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn leaderboards_are_throttled() {
+        let client = AocClient::new(Some("token".to_owned())).with_base_url("http://127.0.0.1:9");
+
+        // The first request goes out (and fails, with nothing listening), the next is refused
+        assert!(matches!(
+            client.leaderboard(2022, 7),
+            Err(ClientError::Http { .. })
+        ));
+        assert!(matches!(
+            client.leaderboard(2022, 7),
+            Err(ClientError::RateLimited { wait, .. }) if wait <= LEADERBOARD_INTERVAL
+        ));
+
+        // Other leaderboards and inputs are not held back
+        assert!(matches!(
+            client.leaderboard(2022, 8),
+            Err(ClientError::Http { .. })
+        ));
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Http { .. })
+        ));
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Http { .. })
+        ));
+    }
+}
@@ -0,0 +1,425 @@
+//! Timing every solved part of one or more years for the `bench-all` command. Each part is run a
+//! number of times against its input held in memory, the median of those runs is what the
+//! report ranks and totals by, and the result can be written as CSV for further processing or as
+//! markdown for reading.
+
+use std::{fmt::Write as _, fs, path::Path, str::FromStr, time::Duration, time::Instant};
+
+use thiserror::Error;
+
+use crate::{
+    context::Context,
+    registry::{self, Solution},
+    threads,
+};
+
+/// Runs per part when no count is given
+pub const DEFAULT_RUNS: usize = 5;
+
+/// Number of parts called out as the slowest in a report
+pub const SLOWEST_SHOWN: usize = 5;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BenchError {
+    #[error("unknown report format '{0}', expected csv or markdown")]
+    UnknownFormat(String),
+}
+
+/// How a [`Report`] is written out
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    #[default]
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = BenchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(BenchError::UnknownFormat(s.to_owned())),
+        }
+    }
+}
+
+/// The answer and run times of one part
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartTiming {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    pub answer: Result<String, String>,
+    /// Time of each run in order, ending early at the first failure
+    pub times: Vec<Duration>,
+}
+
+impl PartTiming {
+    /// Runs `solution` `runs` times against input already loaded into memory so disk access is
+    /// not included in the timings. Stops at the first failure.
+    pub fn measure(solution: Solution, input: Option<&[u8]>, runs: usize) -> Self {
+        let mut times = Vec::with_capacity(runs);
+        let mut answer = Err("not run".to_owned());
+
+        for _ in 0..runs {
+            let start = Instant::now();
+            let result = match input {
+                Some(mut data) => (solution.run)(&mut Context::new(Some(&mut data))),
+                None => (solution.run)(&mut Context::new(None)),
+            };
+
+            times.push(start.elapsed());
+
+            answer = result.map(|a| a.to_string()).map_err(|e| e.to_string());
+
+            if answer.is_err() {
+                break;
+            }
+        }
+
+        Self {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            answer,
+            times,
+        }
+    }
+
+    pub fn failed(&self) -> bool {
+        self.answer.is_err()
+    }
+
+    pub fn min(&self) -> Duration {
+        self.times.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn mean(&self) -> Duration {
+        match u32::try_from(self.times.len()) {
+            Ok(0) => Duration::ZERO,
+            Ok(runs) => self.times.iter().sum::<Duration>() / runs,
+            Err(_) => self.min(),
+        }
+    }
+
+    /// The middle run, or the faster of the two middle runs when there is an even number
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.times.clone();
+
+        sorted.sort_unstable();
+
+        match sorted.len() {
+            0 => Duration::ZERO,
+            n => sorted[(n - 1) / 2],
+        }
+    }
+
+    /// The puzzle as written on the command line, such as `2015-04-1`
+    pub fn puzzle(&self) -> String {
+        format!("{}-{:02}-{}", self.year, self.day, self.part)
+    }
+
+    fn status(&self) -> String {
+        match &self.answer {
+            Ok(_) => "ok".to_owned(),
+            Err(e) => format!("failed: {e}"),
+        }
+    }
+}
+
+/// Totals for every part of one year in a [`Report`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct YearSummary {
+    pub year: i32,
+    pub parts: usize,
+    pub failures: usize,
+    /// Sum of the median time of each part that succeeded
+    pub total: Duration,
+}
+
+/// Timings of every part benchmarked, ordered by year, day, and part
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub runs: usize,
+    pub parts: Vec<PartTiming>,
+}
+
+impl Report {
+    /// Times every solved part of `years` `runs` times each on its default input in `data_dir`,
+    /// calling `on_part` as each one finishes
+    pub fn measure(
+        years: &[i32],
+        data_dir: &Path,
+        runs: usize,
+        mut on_part: impl FnMut(&PartTiming),
+    ) -> Self {
+        let mut parts = Vec::new();
+        let mut loaded = None;
+
+        for solution in registry::solutions().filter(|s| years.contains(&s.year)) {
+            let (year, day) = (solution.year, solution.day);
+
+            // Both parts share an input, so it is only read once per day
+            if loaded.as_ref().is_none_or(|(key, _)| *key != (year, day)) {
+                let input = fs::read(registry::resolve_input(year, day, data_dir, None)).ok();
+
+                loaded = Some(((year, day), input));
+            }
+
+            let input = loaded.as_ref().and_then(|(_, input)| input.as_deref());
+            let timing = threads::install(|| PartTiming::measure(solution, input, runs));
+
+            on_part(&timing);
+            parts.push(timing);
+        }
+
+        Self { runs, parts }
+    }
+
+    /// Totals for each year with at least one part, in order
+    pub fn years(&self) -> Vec<YearSummary> {
+        let mut years = Vec::<YearSummary>::new();
+
+        for timing in &self.parts {
+            let summary = match years.last_mut() {
+                Some(s) if s.year == timing.year => s,
+                _ => {
+                    years.push(YearSummary {
+                        year: timing.year,
+                        parts: 0,
+                        failures: 0,
+                        total: Duration::ZERO,
+                    });
+
+                    years.last_mut().expect("a summary was just added")
+                }
+            };
+
+            summary.parts += 1;
+
+            if timing.failed() {
+                summary.failures += 1;
+            } else {
+                summary.total += timing.median();
+            }
+        }
+
+        years
+    }
+
+    /// The `n` parts that succeeded with the longest median time, slowest first
+    pub fn slowest(&self, n: usize) -> Vec<&PartTiming> {
+        let mut ranked = self
+            .parts
+            .iter()
+            .filter(|t| !t.failed())
+            .collect::<Vec<_>>();
+
+        ranked.sort_by_key(|t| std::cmp::Reverse(t.median()));
+        ranked.truncate(n);
+
+        ranked
+    }
+
+    /// Position of `timing` among the [`SLOWEST_SHOWN`] slowest parts, counting from 1
+    fn slow_rank(&self, timing: &PartTiming) -> Option<usize> {
+        self.slowest(SLOWEST_SHOWN)
+            .iter()
+            .position(|t| std::ptr::eq(*t, timing))
+            .map(|i| i + 1)
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Csv => self.to_csv(),
+            Format::Markdown => self.to_markdown(),
+        }
+    }
+
+    /// One row per part with times in microseconds and the rank of the slowest parts
+    pub fn to_csv(&self) -> String {
+        let mut csv = "year,day,part,runs,min_us,median_us,mean_us,slowest,status\n".to_owned();
+
+        for timing in &self.parts {
+            let rank = self
+                .slow_rank(timing)
+                .map_or(String::new(), |r| r.to_string());
+
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{rank},{}",
+                timing.year,
+                timing.day,
+                timing.part,
+                timing.times.len(),
+                timing.min().as_micros(),
+                timing.median().as_micros(),
+                timing.mean().as_micros(),
+                csv_field(&timing.status()),
+            );
+        }
+
+        csv
+    }
+
+    /// Tables of the totals per year, the slowest parts, and every part
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# Benchmarks\n\nMedian of {} runs per part.\n\n", self.runs);
+
+        md.push_str("## Years\n\n| Year | Parts | Failed | Total |\n|---|---:|---:|---:|\n");
+
+        for year in self.years() {
+            let _ = writeln!(
+                md,
+                "| {} | {} | {} | {:.2?} |",
+                year.year, year.parts, year.failures, year.total
+            );
+        }
+
+        md.push_str("\n## Slowest parts\n\n| Rank | Puzzle | Median |\n|---:|---|---:|\n");
+
+        for (rank, timing) in (1..).zip(self.slowest(SLOWEST_SHOWN)) {
+            let _ = writeln!(
+                md,
+                "| {rank} | {} | {:.2?} |",
+                timing.puzzle(),
+                timing.median()
+            );
+        }
+
+        md.push_str(
+            "\n## Parts\n\n| Puzzle | Min | Median | Mean | Status |\n|---|---:|---:|---:|---|\n",
+        );
+
+        for timing in &self.parts {
+            let _ = writeln!(
+                md,
+                "| {} | {:.2?} | {:.2?} | {:.2?} | {} |",
+                timing.puzzle(),
+                timing.min(),
+                timing.median(),
+                timing.mean(),
+                timing.status().replace('|', "\\|").replace('\n', "<br>"),
+            );
+        }
+
+        md
+    }
+}
+
+/// Quotes a CSV field when it holds anything that would break the row apart
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn timing(year: i32, day: i32, part: i32, millis: &[u64]) -> PartTiming {
+        PartTiming {
+            year,
+            day,
+            part,
+            answer: Ok("1".to_owned()),
+            times: millis.iter().copied().map(Duration::from_millis).collect(),
+        }
+    }
+
+    fn report() -> Report {
+        let mut failed = timing(2022, 2, 1, &[1]);
+
+        failed.answer = Err("bad input, \"line 1\"".to_owned());
+
+        Report {
+            runs: 3,
+            parts: vec![
+                timing(2015, 1, 1, &[3, 1, 2]),
+                timing(2015, 1, 2, &[40, 50, 60]),
+                timing(2022, 1, 1, &[7, 9, 8]),
+                failed,
+                timing(2022, 2, 2, &[4, 4, 4]),
+            ],
+        }
+    }
+
+    #[test]
+    fn statistics() {
+        let part = timing(2015, 1, 1, &[3, 1, 2, 10]);
+
+        assert_eq!(part.min(), Duration::from_millis(1));
+        assert_eq!(part.median(), Duration::from_millis(2));
+        assert_eq!(part.mean(), Duration::from_millis(4));
+        assert_eq!(timing(2015, 1, 1, &[]).median(), Duration::ZERO);
+
+        let report = report();
+
+        assert_eq!(
+            report.years(),
+            [
+                YearSummary {
+                    year: 2015,
+                    parts: 2,
+                    failures: 0,
+                    total: Duration::from_millis(52),
+                },
+                YearSummary {
+                    year: 2022,
+                    parts: 3,
+                    failures: 1,
+                    total: Duration::from_millis(12),
+                },
+            ]
+        );
+
+        let slowest = report.slowest(3);
+
+        assert_eq!(
+            slowest.iter().map(|t| t.puzzle()).collect::<Vec<_>>(),
+            ["2015-01-2", "2022-01-1", "2022-02-2"]
+        );
+    }
+
+    #[test]
+    fn rendered() {
+        let report = report();
+        let csv = report.to_csv();
+        let mut rows = csv.lines().skip(1);
+
+        assert_eq!(rows.next(), Some("2015,1,1,3,1000,2000,2000,4,ok"));
+        assert_eq!(rows.next(), Some("2015,1,2,3,40000,50000,50000,1,ok"));
+        assert_eq!(
+            rows.nth(1),
+            Some("2022,2,1,1,1000,1000,1000,,\"failed: bad input, \"\"line 1\"\"\"")
+        );
+
+        let md = report.to_markdown();
+
+        assert!(md.contains("| 2022 | 3 | 1 | 12.00ms |"));
+        assert!(md.contains("| 1 | 2015-01-2 | 50.00ms |"));
+        assert!(md.contains("| 2022-02-1 | 1.00ms | 1.00ms | 1.00ms | failed: bad input"));
+        assert_eq!("md".parse(), Ok(Format::Markdown));
+        assert!("json".parse::<Format>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn measured() {
+        let solution = registry::find(2015, 1, 1).unwrap();
+        let part = PartTiming::measure(solution, Some(b"(()(()(".as_slice()), 3);
+
+        assert_eq!(part.answer, Ok("3".to_owned()));
+        assert_eq!(part.times.len(), 3);
+
+        let missing = PartTiming::measure(solution, None, 3);
+
+        assert!(missing.failed());
+        assert_eq!(missing.times.len(), 1);
+    }
+}
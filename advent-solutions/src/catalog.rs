@@ -0,0 +1,151 @@
+//! The table of solved days shown by the help: each day's title from its `INFO`, which parts are
+//! solved, whether its default input is on disk, and how long its last run on that input took
+//! according to the history in `runs.jsonl`.
+
+use std::{collections::HashMap, fmt::Write, path::Path, time::Duration};
+
+use crate::{
+    describe::{self, Description},
+    history::{History, RunRecord},
+    registry,
+};
+
+/// One solved day as listed in the table
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub year: i32,
+    pub day: i32,
+    pub title: &'static str,
+    /// Parts with a registered solution
+    pub parts_solved: i32,
+    /// Whether the day's default input is present
+    pub has_input: bool,
+    /// Time taken by the latest recorded run on the default input, of one part or both
+    pub last_run: Option<Duration>,
+}
+
+/// Time taken by the latest run of each `(year, day)` on its default input
+fn last_runs(runs: &[RunRecord]) -> HashMap<(i32, i32), Duration> {
+    // Later runs replace earlier ones as the history is oldest first
+    runs.iter()
+        .filter(|r| r.input.is_none())
+        .map(|r| ((r.year, r.day), Duration::from_micros(r.micros)))
+        .collect()
+}
+
+/// An entry for every day in `descriptions`, with inputs looked up in `data_dir` and timings
+/// taken from `runs`
+pub fn entries<'a>(
+    descriptions: impl IntoIterator<Item = &'a Description>,
+    runs: &[RunRecord],
+    data_dir: &Path,
+) -> Vec<Entry> {
+    let last_runs = last_runs(runs);
+
+    descriptions
+        .into_iter()
+        .map(|d| Entry {
+            year: d.year,
+            day: d.day,
+            title: d.info.title,
+            parts_solved: registry::parts_solved(d.year, d.day),
+            has_input: registry::resolve_input(d.year, d.day, data_dir, None).exists(),
+            last_run: last_runs.get(&(d.year, d.day)).copied(),
+        })
+        .collect()
+}
+
+/// An entry for every solved day, with inputs and run history read from `data_dir`. A history that
+/// cannot be read leaves every day without a timing.
+pub fn gather(data_dir: &Path) -> Vec<Entry> {
+    let runs = History::in_data_dir(data_dir).records().unwrap_or_default();
+
+    entries(describe::descriptions(), &runs, data_dir)
+}
+
+/// `entries` laid out in aligned columns, one day per line under a header
+pub fn table(entries: &[Entry]) -> String {
+    let width = entries
+        .iter()
+        .map(|e| e.title.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+
+    let mut table = format!(
+        "{:<7}  {:<width$}  {:<5}  {:<5}  {}\n",
+        "Puzzle", "Title", "Parts", "Input", "Last run"
+    );
+
+    for entry in entries {
+        let parts = match entry.parts_solved {
+            1 => "1",
+            _ => "1, 2",
+        };
+        let input = if entry.has_input { "yes" } else { "no" };
+        let last_run = entry
+            .last_run
+            .map_or_else(|| "-".to_owned(), |d| format!("{d:.2?}"));
+
+        let _ = writeln!(
+            table,
+            "{}-{:02}  {:<width$}  {parts:<5}  {input:<5}  {last_run}",
+            entry.year, entry.day, entry.title
+        );
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn run(day: i32, input: Option<&str>, millis: u64) -> RunRecord {
+        RunRecord::new(
+            2015,
+            day,
+            None,
+            input,
+            &[Answer::Int(1), Answer::Int(2)],
+            Duration::from_millis(millis),
+        )
+    }
+
+    #[test]
+    fn latest_default_runs() {
+        let runs = [
+            run(1, None, 5),
+            run(1, None, 3),
+            run(1, Some("example"), 1),
+            run(2, Some("example"), 1),
+        ];
+
+        let last = last_runs(&runs);
+
+        assert_eq!(last.get(&(2015, 1)), Some(&Duration::from_millis(3)));
+        assert_eq!(last.get(&(2015, 2)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn lists_solved_days() {
+        let dir = std::env::temp_dir().join(format!("catalog-{}", std::process::id()));
+        let descriptions = describe::descriptions().filter(|d| d.year == 2015 && d.day <= 2);
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("2015-01.txt"), "(").unwrap();
+
+        let entries = entries(descriptions, &[run(1, None, 3)], &dir);
+
+        assert_eq!(
+            table(&entries),
+            "Puzzle   Title                              Parts  Input  Last run\n\
+             2015-01  Not Quite Lisp                     1, 2   yes    3.00ms\n\
+             2015-02  I Was Told There Would Be No Math  1, 2   no     -\n"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,314 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Environment variable that, when set, overrides where the config file is read from and written to
+pub const CONFIG_PATH_VAR: &str = "ADVENT_CONFIG";
+
+/// Errors that can occur while loading, saving, or editing a `Config`
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("unknown config key '{0}' (expected one of: session, data_dir, default_year)")]
+    UnknownKey(String),
+    #[error("invalid value '{value}' for '{key}': {reason}")]
+    InvalidValue {
+        key: String,
+        value: String,
+        reason: &'static str,
+    },
+    #[error("no config location available; set HOME, XDG_CONFIG_HOME, or {CONFIG_PATH_VAR}")]
+    NoLocation,
+    #[error("could not access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("could not parse {}: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("could not serialize config: {0}")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+/// User settings shared by everything that talks to Advent of Code or locates puzzle input. Stored
+/// as TOML in `~/.config/advent/config.toml` unless overridden by [`CONFIG_PATH_VAR`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Value of the `session` cookie from a logged in adventofcode.com browser session
+    pub session: Option<String>,
+    /// Directory containing puzzle inputs named `YYYY-DD.txt`
+    pub data_dir: Option<PathBuf>,
+    /// Year used when a puzzle is requested without one
+    pub default_year: Option<i32>,
+}
+
+impl Config {
+    /// Keys understood by [`Config::get`], [`Config::set`], and [`Config::unset`]
+    pub const KEYS: [&'static str; 3] = ["session", "data_dir", "default_year"];
+
+    /// Where the config file lives: [`CONFIG_PATH_VAR`] if set, otherwise `advent/config.toml`
+    /// inside `$XDG_CONFIG_HOME` or `~/.config`.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(p) = env::var_os(CONFIG_PATH_VAR) {
+            return Some(PathBuf::from(p));
+        }
+
+        env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+            .map(|base| base.join("advent").join("config.toml"))
+    }
+
+    /// Loads the config from its default location. A missing file is not an error and results in
+    /// an empty config.
+    pub fn load() -> Result<Self, ConfigError> {
+        match Self::path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Loads the config stored at `path`, treating a missing file as an empty config
+    pub fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|source| ConfigError::Parse {
+                path: path.to_owned(),
+                source,
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(ConfigError::Io {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    /// Writes the config to its default location, creating parent directories as needed
+    pub fn save(&self) -> Result<PathBuf, ConfigError> {
+        let path = Self::path().ok_or(ConfigError::NoLocation)?;
+
+        self.save_to(&path)?;
+
+        Ok(path)
+    }
+
+    /// Writes the config to `path`, creating parent directories as needed
+    pub fn save_to(&self, path: &Path) -> Result<(), ConfigError> {
+        let io_err = |source| ConfigError::Io {
+            path: path.to_owned(),
+            source,
+        };
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_err)?;
+        }
+
+        fs::write(path, toml::to_string(self)?).map_err(io_err)
+    }
+
+    /// Gets the string form of a setting, or `None` if it has not been set
+    pub fn get(&self, key: &str) -> Result<Option<String>, ConfigError> {
+        Ok(match key {
+            "session" => self.session.clone(),
+            "data_dir" => self.data_dir.as_ref().map(|d| d.display().to_string()),
+            "default_year" => self.default_year.map(|y| y.to_string()),
+            _ => return Err(ConfigError::UnknownKey(key.to_owned())),
+        })
+    }
+
+    /// Validates and stores a setting from its string form
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), ConfigError> {
+        let invalid = |reason| ConfigError::InvalidValue {
+            key: key.to_owned(),
+            value: value.to_owned(),
+            reason,
+        };
+
+        match key {
+            "session" => {
+                let session = value.trim();
+
+                if session.is_empty() || !session.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(invalid(
+                        "session cookies are a non-empty hexadecimal string",
+                    ));
+                }
+
+                self.session = Some(session.to_owned());
+            }
+            "data_dir" => {
+                if value.is_empty() {
+                    return Err(invalid("data directory cannot be empty"));
+                }
+
+                self.data_dir = Some(PathBuf::from(value));
+            }
+            "default_year" => {
+                let year = value
+                    .parse::<i32>()
+                    .ok()
+                    .filter(|y| *y >= 2015)
+                    .ok_or_else(|| invalid("Advent of Code started in 2015"))?;
+
+                self.default_year = Some(year);
+            }
+            _ => return Err(ConfigError::UnknownKey(key.to_owned())),
+        }
+
+        Ok(())
+    }
+
+    /// Clears a setting so its default behavior applies again
+    pub fn unset(&mut self, key: &str) -> Result<(), ConfigError> {
+        match key {
+            "session" => self.session = None,
+            "data_dir" => self.data_dir = None,
+            "default_year" => self.default_year = None,
+            _ => return Err(ConfigError::UnknownKey(key.to_owned())),
+        }
+
+        Ok(())
+    }
+
+    /// Directory puzzle inputs are read from, defaulting to `data` relative to the working
+    /// directory
+    pub fn data_dir(&self) -> PathBuf {
+        self.data_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("data"))
+    }
+}
+
+/// Handles `config <action> [key] [value]` from the command line
+pub fn command(args: &[String]) -> anyhow::Result<()> {
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match args.as_slice() {
+        ["path"] => match Config::path() {
+            Some(path) => println!("{}", path.display()),
+            None => return Err(ConfigError::NoLocation.into()),
+        },
+        ["show"] | [] => {
+            let config = Config::load()?;
+
+            for key in Config::KEYS {
+                let value = match (key, config.get(key)?) {
+                    (_, None) => "(unset)".to_owned(),
+                    // Never echo the full session token where it could end up in logs
+                    ("session", Some(s)) => format!("{}…", &s[..s.len().min(8)]),
+                    (_, Some(v)) => v,
+                };
+
+                println!("{key} = {value}");
+            }
+        }
+        ["get", key] => {
+            if let Some(value) = Config::load()?.get(key)? {
+                println!("{value}");
+            }
+        }
+        ["set", key, value] => {
+            let mut config = Config::load()?;
+
+            config.set(key, value)?;
+
+            println!("Saved {key} to {}", config.save()?.display());
+        }
+        ["unset", key] => {
+            let mut config = Config::load()?;
+
+            config.unset(key)?;
+
+            println!("Removed {key} from {}", config.save()?.display());
+        }
+        _ => anyhow::bail!(
+            "usage: config [show | path | get <key> | set <key> <value> | unset <key>]"
+        ),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn set_and_get() {
+        let mut config = Config::default();
+
+        config.set("session", "53616c7465645f5f").unwrap();
+        config.set("data_dir", "inputs").unwrap();
+        config.set("default_year", "2022").unwrap();
+
+        assert_eq!(config.get("session").unwrap().unwrap(), "53616c7465645f5f");
+        assert_eq!(config.data_dir(), PathBuf::from("inputs"));
+        assert_eq!(config.default_year, Some(2022));
+
+        config.unset("data_dir").unwrap();
+
+        assert_eq!(config.data_dir(), PathBuf::from("data"));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        let mut config = Config::default();
+
+        assert!(matches!(
+            config.set("token", "abc"),
+            Err(ConfigError::UnknownKey(_))
+        ));
+
+        assert!(config.set("session", "not a cookie").is_err());
+        assert!(config.set("default_year", "2014").is_err());
+        assert!(config.set("default_year", "next").is_err());
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let config = Config {
+            session: Some("abc123".into()),
+            data_dir: Some(PathBuf::from("/tmp/advent")),
+            default_year: Some(2023),
+        };
+
+        let text = toml::to_string(&config).unwrap();
+
+        assert_eq!(toml::from_str::<Config>(&text).unwrap(), config);
+
+        // Missing keys fall back to being unset
+        assert_eq!(
+            toml::from_str::<Config>("default_year = 2015").unwrap(),
+            Config {
+                default_year: Some(2015),
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = env::temp_dir()
+            .join(format!("advent-config-test-{}", std::process::id()))
+            .join("config.toml");
+
+        assert_eq!(Config::load_from(&path).unwrap(), Config::default());
+
+        let mut config = Config::default();
+
+        config.set("default_year", "2016").unwrap();
+        config.save_to(&path).unwrap();
+
+        assert_eq!(Config::load_from(&path).unwrap(), config);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,57 @@
+//! What each day is about, for the `describe` command, gathered from the `DESCRIPTIONS` every
+//! year crate generates. The descriptions themselves are built as [`advent_core::describe`]
+//! explains.
+
+use crate::registry;
+
+pub use advent_core::describe::*;
+
+/// Every described day ordered by year and then day
+pub fn descriptions() -> impl Iterator<Item = &'static Description> {
+    registry::YEARS.iter().flat_map(|y| y.descriptions())
+}
+
+/// Looks up the description of the given day
+pub fn find(year: i32, day: i32) -> Option<&'static Description> {
+    descriptions().find(|d| (d.year, d.day) == (year, day))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_solved_day_is_described() {
+        // Parts registered with `#[advent]` are not part of a generated year
+        let generated =
+            registry::solutions().filter(|s| registry::find_day(s.year, s.day).is_some());
+
+        for solution in generated {
+            assert!(
+                find(solution.year, solution.day).is_some(),
+                "day {} of {}",
+                solution.day,
+                solution.year
+            );
+        }
+
+        assert!(descriptions().all(|d| !d.info.title.is_empty()));
+    }
+
+    #[test]
+    #[cfg(all(feature = "y2022", feature = "y2023"))]
+    fn described_from_source() {
+        let treetops = find(2022, 8).unwrap();
+
+        assert_eq!(treetops.info.title, "Treetop Tree House");
+        assert_eq!(treetops.url(), "https://adventofcode.com/2022/day/8");
+        assert!(treetops.utilities.contains(&"grid"));
+        assert!(treetops.parts.iter().all(|p| !p.is_empty()));
+
+        let listing = find(2023, 1).unwrap().to_string();
+
+        assert!(listing.starts_with("Day 1 of 2023: Trebuchet?!\n"));
+        assert!(listing.contains("Uses util::matcher, util::par_lines"));
+        assert!(find(2015, 26).is_none());
+    }
+}
@@ -0,0 +1,43 @@
+use std::{fs, path::PathBuf};
+
+use thiserror::Error;
+
+use crate::{
+    aoc_client::{AocClient, ClientError},
+    config::Config,
+};
+
+/// Errors that can occur while fetching or storing puzzle input
+#[derive(Error, Debug)]
+pub enum DownloadError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("could not write {}: {source}", path.display())]
+    Save {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+/// Downloads the input for `year`/`day` into the configured data directory unless it already
+/// exists there, returning where the input can be found.
+pub fn download_input(config: &Config, year: i32, day: i32) -> Result<PathBuf, DownloadError> {
+    let data_dir = config.data_dir();
+    let path = data_dir.join(format!("{year}-{day:02}.txt"));
+
+    if path.exists() {
+        return Ok(path);
+    }
+
+    // The saved input is its own cache, so there is no need to keep a second copy
+    let input = AocClient::new(config.session.clone()).input(year, day)?;
+
+    fs::create_dir_all(&data_dir)
+        .and_then(|()| fs::write(&path, input))
+        .map_err(|source| DownloadError::Save {
+            path: path.clone(),
+            source,
+        })?;
+
+    Ok(path)
+}
@@ -0,0 +1,144 @@
+//! The binary's exit codes, so scripts and CI can tell why a run failed without reading its
+//! output:
+//!
+//! | Code | Meaning                                                                |
+//! |------|------------------------------------------------------------------------|
+//! | 0    | Success                                                                |
+//! | 1    | Any other error, such as malformed arguments or a failed download      |
+//! | 2    | No solution exists for the requested year, day, or part                |
+//! | 3    | The input is missing, either a named input or a default the part needs |
+//! | 4    | The solution failed or ran past its `--timeout`                        |
+//! | 5    | A wrong answer while verifying, from `check` or `--compare`            |
+//!
+//! `check` verifies the examples from a puzzle's description, while `--compare` treats any answer
+//! that changed since the last run on the same input as wrong. `lint-input` exits with 4 for a
+//! malformed input, as a run parsing it would. With `--format json` the error is written to stderr
+//! as an [`ErrorReport`] instead of as text.
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::registry::RunError;
+
+/// Why the binary exited, as the code it exits with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Exit {
+    Success = 0,
+    Other = 1,
+    Unknown = 2,
+    MissingInput = 3,
+    SolutionFailed = 4,
+    WrongAnswer = 5,
+}
+
+impl Exit {
+    /// How the binary exits after returning `error`
+    pub fn of(error: &anyhow::Error) -> Self {
+        if let Some(e) = error.downcast_ref::<RunError>() {
+            e.exit()
+        } else if error.is::<WrongAnswer>() {
+            Self::WrongAnswer
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl From<Exit> for ExitCode {
+    fn from(exit: Exit) -> Self {
+        Self::from(exit as u8)
+    }
+}
+
+/// Answers found to be wrong while verifying them, described by the message
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{0}")]
+pub struct WrongAnswer(pub String);
+
+/// An error as written with `--format json`, for tools that react to the kind of failure
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ErrorReport {
+    /// Such as `missing_input` or `parse_failed`, from [`RunError::kind`], or `wrong_answer` and
+    /// `other` for everything else
+    pub kind: &'static str,
+    pub message: String,
+    /// The input the error is about
+    pub file: Option<PathBuf>,
+    /// The line of `file` the error is about, counting from 1
+    pub line: Option<usize>,
+}
+
+impl ErrorReport {
+    pub fn of(error: &anyhow::Error) -> Self {
+        match error.downcast_ref::<RunError>() {
+            Some(e) => Self {
+                kind: e.kind(),
+                message: e.to_string(),
+                file: e.file().map(Path::to_owned),
+                line: e.line(),
+            },
+            None => Self {
+                kind: if error.is::<WrongAnswer>() {
+                    "wrong_answer"
+                } else {
+                    "other"
+                },
+                message: format!("{error:#}"),
+                file: None,
+                line: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn codes_by_error() {
+        assert_eq!(Exit::of(&anyhow::anyhow!("bad argument")), Exit::Other);
+        assert_eq!(
+            Exit::of(&WrongAnswer("1 of 2 examples failed".to_owned()).into()),
+            Exit::WrongAnswer
+        );
+        assert_eq!(Exit::of(&RunError::UnknownYear(1999).into()), Exit::Unknown);
+    }
+
+    #[test]
+    fn reports_as_json() {
+        let unparsable = RunError::Unparsable {
+            puzzle: "day 2 of 2015".to_owned(),
+            path: PathBuf::from("data/2015-02.txt"),
+            line: Some(3),
+            message: "line 3 is not a valid present".to_owned(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&ErrorReport::of(&unparsable.into())).unwrap(),
+            r#"{"kind":"parse_failed","message":"Could not solve day 2 of 2015: line 3 is not a valid present","file":"data/2015-02.txt","line":3}"#
+        );
+
+        let panicked = RunError::Failed {
+            puzzle: "day 1 of 2022".to_owned(),
+            message: "the solution panicked: on purpose".to_owned(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&ErrorReport::of(&panicked.into())).unwrap(),
+            r#"{"kind":"solution_failed","message":"Could not solve day 1 of 2022: the solution panicked: on purpose","file":null,"line":null}"#
+        );
+
+        let other = ErrorReport::of(&anyhow::anyhow!("bad argument").context("while reading"));
+
+        assert_eq!(other.kind, "other");
+        assert_eq!(other.message, "while reading: bad argument");
+        assert_eq!((other.file, other.line), (None, None));
+    }
+}
@@ -0,0 +1,37 @@
+//! Finding the days that can be explored with the `explore` command, from the `EXPLORABLE` list
+//! each year crate may name in its `generate_year!`. How exploring works is up to
+//! [`advent_core::explore`].
+
+use crate::registry;
+
+pub use advent_core::explore::*;
+
+/// Looks up the explorer for the given day if it has one
+pub fn find(year: i32, day: i32) -> Option<ExplorerFn> {
+    registry::year(year)?
+        .explorable()
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, explore)| explore)
+}
+
+/// Every explorable day as `(year, day)`, in order
+pub fn days() -> impl Iterator<Item = (i32, i32)> {
+    registry::YEARS.iter().flat_map(|y| {
+        let year = y.year();
+
+        y.explorable().iter().map(move |&(day, _)| (year, day))
+    })
+}
+
+#[cfg(all(test, feature = "y2015", feature = "y2022"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn explorable_days() {
+        assert!(find(2022, 7).is_some());
+        assert!(find(2022, 6).is_none());
+        assert!(days().any(|d| d == (2015, 7)));
+    }
+}
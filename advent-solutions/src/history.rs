@@ -0,0 +1,278 @@
+//! A log of every run's answers and timing, kept as one JSON object per line in `runs.jsonl` so
+//! it can be appended to without reading it back. A new run can be compared against the last one
+//! of the same puzzle and input to catch answers that changed or solutions that slowed down.
+
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+
+/// Fraction a run may be slower than the last one before it is flagged
+pub const SLOWDOWN_THRESHOLD: f64 = 0.2;
+
+/// One run of a puzzle as stored in the history
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub year: i32,
+    pub day: i32,
+    /// The part that was run, or `None` when both were
+    pub part: Option<i32>,
+    /// Name of the alternate input used, if any
+    pub input: Option<String>,
+    pub answers: Vec<String>,
+    /// Time spent solving in microseconds
+    pub micros: u64,
+    /// When the run finished, in RFC 3339
+    pub recorded_at: String,
+}
+
+impl RunRecord {
+    /// A record of a run that just finished
+    pub fn new(
+        year: i32,
+        day: i32,
+        part: Option<i32>,
+        input: Option<&str>,
+        answers: &[Answer],
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            input: input.map(str::to_owned),
+            answers: answers.iter().map(Answer::to_string).collect(),
+            micros: elapsed.as_micros().try_into().unwrap_or(u64::MAX),
+            recorded_at: Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Whether both records are runs of the same puzzle parts on the same input
+    pub fn same_run(&self, other: &Self) -> bool {
+        (self.year, self.day, self.part, &self.input)
+            == (other.year, other.day, other.part, &other.input)
+    }
+}
+
+/// The `runs.jsonl` file runs are appended to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// The history kept alongside the puzzle inputs in `data_dir`
+    pub fn in_data_dir(data_dir: &Path) -> Self {
+        Self::new(data_dir.join("runs.jsonl"))
+    }
+
+    pub fn append(&self, record: &RunRecord) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut line = serde_json::to_string(record)?;
+
+        line.push('\n');
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(line.as_bytes())
+    }
+
+    /// Every recorded run, oldest first. Lines that cannot be read, such as one cut short by an
+    /// interrupted write, are skipped rather than losing the rest of the history.
+    pub fn records(&self) -> io::Result<Vec<RunRecord>> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        Ok(text
+            .lines()
+            .filter_map(|l| serde_json::from_str(l).ok())
+            .collect())
+    }
+
+    /// The most recent run of the same puzzle parts and input as `record`
+    pub fn last_run(&self, record: &RunRecord) -> io::Result<Option<RunRecord>> {
+        Ok(self
+            .records()?
+            .into_iter()
+            .rev()
+            .find(|r| r.same_run(record)))
+    }
+}
+
+/// An answer that differs from the one given by the previous run
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChangedAnswer {
+    /// Part the answer is for, counting from 1
+    pub part: i32,
+    pub previous: Option<String>,
+    pub current: Option<String>,
+}
+
+/// How a run differs from the previous run of the same puzzle
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub changed: Vec<ChangedAnswer>,
+    pub previous_micros: u64,
+    pub current_micros: u64,
+}
+
+impl Comparison {
+    pub fn new(previous: &RunRecord, current: &RunRecord) -> Self {
+        // A single part run is numbered by that part rather than its position in the list
+        let first_part = current.part.unwrap_or(1);
+        let parts = previous.answers.len().max(current.answers.len());
+
+        let changed = (0..parts)
+            .map(|i| ChangedAnswer {
+                part: first_part + i as i32,
+                previous: previous.answers.get(i).cloned(),
+                current: current.answers.get(i).cloned(),
+            })
+            .filter(|c| c.previous != c.current)
+            .collect();
+
+        Self {
+            changed,
+            previous_micros: previous.micros,
+            current_micros: current.micros,
+        }
+    }
+
+    /// How much longer this run took as a fraction of the previous run's time, which is negative
+    /// when it was faster
+    pub fn slowdown(&self) -> f64 {
+        if self.previous_micros == 0 {
+            return 0.0;
+        }
+
+        self.current_micros as f64 / self.previous_micros as f64 - 1.0
+    }
+
+    /// Whether the run took more than [`SLOWDOWN_THRESHOLD`] longer than the previous one
+    pub fn is_slower(&self) -> bool {
+        self.slowdown() > SLOWDOWN_THRESHOLD
+    }
+
+    /// Whether anything is worth flagging
+    pub fn is_regression(&self) -> bool {
+        !self.changed.is_empty() || self.is_slower()
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in &self.changed {
+            let show = |a: &Option<String>| a.clone().unwrap_or_else(|| "nothing".to_owned());
+
+            writeln!(
+                f,
+                "CHANGED part {}: {} -> {}",
+                c.part,
+                show(&c.previous),
+                show(&c.current)
+            )?;
+        }
+
+        let label = if self.is_slower() { "SLOWER" } else { "Time" };
+
+        write!(
+            f,
+            "{label}: {:?} -> {:?} ({:+.0}%)",
+            Duration::from_micros(self.previous_micros),
+            Duration::from_micros(self.current_micros),
+            self.slowdown() * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(part: Option<i32>, answers: &[i64], millis: u64) -> RunRecord {
+        let answers = answers.iter().map(|&a| a.into()).collect::<Vec<Answer>>();
+
+        RunRecord::new(2022, 1, part, None, &answers, Duration::from_millis(millis))
+    }
+
+    #[test]
+    fn appends_and_finds_last() {
+        let dir = std::env::temp_dir().join(format!("history-{}", std::process::id()));
+        let history = History::in_data_dir(&dir);
+        let both = record(None, &[1, 2], 10);
+
+        assert_eq!(history.last_run(&both).unwrap(), None);
+
+        history.append(&record(None, &[1, 2], 10)).unwrap();
+        history.append(&record(None, &[1, 3], 12)).unwrap();
+        history.append(&record(Some(1), &[1], 5)).unwrap();
+
+        // A partial line from an interrupted write does not hide the runs around it
+        OpenOptions::new()
+            .append(true)
+            .open(dir.join("runs.jsonl"))
+            .unwrap()
+            .write_all(b"{\"year\":20\n")
+            .unwrap();
+
+        let last = history.last_run(&both).unwrap().unwrap();
+
+        assert_eq!(last.answers, vec!["1", "3"]);
+        assert_eq!(last.micros, 12_000);
+        assert_eq!(history.records().unwrap().len(), 3);
+
+        let mut example = both.clone();
+
+        example.input = Some("example".to_owned());
+
+        assert_eq!(history.last_run(&example).unwrap(), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn comparison() {
+        let same = Comparison::new(&record(None, &[1, 2], 10), &record(None, &[1, 2], 11));
+
+        assert!(!same.is_regression());
+
+        let slower = Comparison::new(&record(None, &[1, 2], 10), &record(None, &[1, 2], 13));
+
+        assert!(slower.is_slower());
+        assert!(slower.changed.is_empty());
+        assert!(slower.to_string().starts_with("SLOWER"));
+
+        let changed = Comparison::new(&record(Some(2), &[4], 10), &record(Some(2), &[5], 1));
+
+        assert!(!changed.is_slower());
+        assert!(changed.is_regression());
+        assert_eq!(
+            changed.changed,
+            vec![ChangedAnswer {
+                part: 2,
+                previous: Some("4".to_owned()),
+                current: Some("5".to_owned()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! Hot reloading for the `watch` command, built only with the `dylib` feature as it is meant for
+//! working on a day rather than for running finished ones. The crate of the day's year is rebuilt
+//! as a dynamic library whenever one of its sources changes, which only compiles that crate, and
+//! the day is solved through the entry described in [`advent_core::abi`] instead of relinking this
+//! whole binary.
+
+use std::{
+    env::{
+        self,
+        consts::{DLL_PREFIX, DLL_SUFFIX},
+    },
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    ptr, thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use advent_core::abi::{self, EntryFn, Sink, Status, ABI_VERSION};
+use libloading::Library;
+use thiserror::Error;
+
+/// How often the sources of the watched year are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Error, Debug)]
+pub enum HotError {
+    #[error("could not run cargo to build advent-{year}: {source}")]
+    Cargo { year: i32, source: std::io::Error },
+    #[error("building advent-{year} as a dynamic library failed")]
+    Build { year: i32 },
+    #[error("could not copy {} to load it: {source}", path.display())]
+    Copy {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("could not load {}: {source}", path.display())]
+    Load {
+        path: PathBuf,
+        source: libloading::Error,
+    },
+    #[error("no solution exists for day {day} part {part} of {year}")]
+    Unsolved { year: i32, day: i32, part: i32 },
+    #[error("{0}")]
+    Failed(String),
+    #[error("{0}")]
+    VersionMismatch(String),
+    #[error("the library returned the unknown status {0}")]
+    UnknownStatus(i32),
+}
+
+/// The crate for one year, loaded from a dynamic library
+pub struct HotYear {
+    year: i32,
+    entry: EntryFn,
+    // Keeps `entry` loaded
+    _library: Library,
+}
+
+impl HotYear {
+    /// Loads the library at `path` built from the crate for `year`
+    pub fn load(year: i32, path: &Path) -> Result<Self, HotError> {
+        let load_error = |source| HotError::Load {
+            path: path.to_owned(),
+            source,
+        };
+
+        // SAFETY: the library is a year crate, whose initializers only register annotated parts
+        let library = unsafe { Library::new(path) }.map_err(load_error)?;
+        // SAFETY: `generate_year!` exports the entry with the signature from `advent_core::abi`
+        let entry = unsafe { library.get::<EntryFn>(abi::entry_symbol(year).as_bytes()) }
+            .map(|entry| *entry)
+            .map_err(load_error)?;
+
+        Ok(Self {
+            year,
+            entry,
+            _library: library,
+        })
+    }
+
+    /// Solves `part` of `day` from `input`, or without any input when it is `None`
+    pub fn solve(&self, day: i32, part: i32, input: Option<&[u8]>) -> Result<String, HotError> {
+        let mut out = String::new();
+        let (input, len) = input.map_or((ptr::null(), 0), |i| (i.as_ptr(), i.len()));
+
+        // SAFETY: the input is null or a live slice, and the sink appends to `out` which outlives
+        // the call
+        let status = unsafe {
+            (self.entry)(
+                ABI_VERSION,
+                day,
+                part,
+                input,
+                len,
+                Sink::appending(&mut out),
+            )
+        };
+
+        match Status::from_raw(status) {
+            Some(Status::Solved) => Ok(out),
+            Some(Status::Failed) => Err(HotError::Failed(out)),
+            Some(Status::Unsolved) => Err(HotError::Unsolved {
+                year: self.year,
+                day,
+                part,
+            }),
+            Some(Status::VersionMismatch) => Err(HotError::VersionMismatch(out)),
+            None => Err(HotError::UnknownStatus(status)),
+        }
+    }
+}
+
+/// Directory the dynamic libraries are built in, apart from `target` so building them never
+/// invalidates the build of this binary
+fn target_dir(workspace: &Path) -> PathBuf {
+    workspace.join("target").join("hot")
+}
+
+/// Builds the crate for `year` in `workspace` as a dynamic library with the same profile as this
+/// binary, returning the library's path
+pub fn build(workspace: &Path, year: i32) -> Result<PathBuf, HotError> {
+    let target = target_dir(workspace);
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+
+    cargo
+        .current_dir(workspace)
+        .args(["rustc", "--quiet", "--lib", "--crate-type", "cdylib"])
+        .arg("--package")
+        .arg(format!("advent-{year}"))
+        .arg("--target-dir")
+        .arg(&target);
+
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+
+    let status = cargo
+        .status()
+        .map_err(|source| HotError::Cargo { year, source })?;
+
+    if !status.success() {
+        return Err(HotError::Build { year });
+    }
+
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    Ok(target
+        .join(profile)
+        .join(format!("{DLL_PREFIX}advent_{year}{DLL_SUFFIX}")))
+}
+
+/// Copies the library at `built` to a path of its own before loading it, since loading the same
+/// path again can hand back the library that was already loaded
+fn load_copy(
+    workspace: &Path,
+    year: i32,
+    built: &Path,
+    generation: u32,
+) -> Result<HotYear, HotError> {
+    let dir = target_dir(workspace).join("loaded");
+    let path = dir.join(format!(
+        "{DLL_PREFIX}advent_{year}_{generation}{DLL_SUFFIX}"
+    ));
+
+    fs::create_dir_all(&dir)
+        .and_then(|_| fs::copy(built, &path))
+        .map_err(|source| HotError::Copy {
+            path: built.to_owned(),
+            source,
+        })?;
+
+    HotYear::load(year, &path)
+}
+
+/// When a file directly within `path`, or `path` itself if it is a file, was last changed
+fn last_change(path: &Path) -> Option<SystemTime> {
+    let modified = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+
+    match fs::read_dir(path) {
+        Ok(entries) => entries.flatten().filter_map(|e| modified(&e.path())).max(),
+        Err(_) => modified(path),
+    }
+}
+
+/// Rebuilds the crate for `year` and solves `parts` of `day` from `input` whenever one of the
+/// crate's sources or the input changes, until the process is stopped. Failed builds are reported
+/// and wait for the next change.
+pub fn watch(
+    workspace: &Path,
+    year: i32,
+    day: i32,
+    parts: &[i32],
+    input: &Path,
+) -> anyhow::Result<()> {
+    let sources = workspace.join(format!("advent-{year}")).join("src");
+    let mut seen = None;
+    let mut generation = 0;
+
+    // Copies from an earlier watch are no longer loaded by anything
+    let _ = fs::remove_dir_all(target_dir(workspace).join("loaded"));
+
+    loop {
+        let changed = (last_change(&sources), last_change(input));
+
+        if seen == Some(changed) {
+            thread::sleep(POLL_INTERVAL);
+
+            continue;
+        }
+
+        seen = Some(changed);
+        generation += 1;
+
+        let started = Instant::now();
+
+        match build(workspace, year).and_then(|p| load_copy(workspace, year, &p, generation)) {
+            Ok(hot) => {
+                println!("Rebuilt advent-{year} in {:.2?}", started.elapsed());
+
+                let input = fs::read(input).ok();
+
+                for &part in parts {
+                    let started = Instant::now();
+
+                    match hot.solve(day, part, input.as_deref()) {
+                        Ok(answer) => println!("Part {part}: {answer} ({:.2?})", started.elapsed()),
+                        Err(e) => eprintln!("Part {part}: {e}"),
+                    }
+                }
+            }
+            Err(e) => eprintln!("{e}"),
+        }
+
+        println!("Watching {} for changes", sources.display());
+    }
+}
@@ -0,0 +1,125 @@
+//! Private leaderboards as returned by the Advent of Code API, and a plain text table of their
+//! members for the terminal.
+
+use std::{collections::HashMap, fmt::Write};
+
+use serde::Deserialize;
+
+/// A private leaderboard for one event
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    /// Members keyed by the string form of their id
+    pub members: HashMap<String, Member>,
+}
+
+/// Someone on a private leaderboard
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for anonymous users
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub global_score: u64,
+}
+
+impl Member {
+    /// The name shown on the site, which is the user number for anonymous users
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Members in leaderboard order: highest local score first, then most stars, then by id so the
+    /// order is stable
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+
+        members
+    }
+
+    /// A table of every member's rank, name, stars, and local score
+    pub fn table(&self) -> String {
+        let ranked = self.ranked();
+        let names = ranked.iter().map(|m| m.display_name()).collect::<Vec<_>>();
+        let width = names
+            .iter()
+            .map(|n| n.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        let mut table = format!(
+            "{:>4}  {:<width$}  {:>5}  {:>5}\n",
+            "#", "Name", "Stars", "Score"
+        );
+
+        for (rank, (member, name)) in ranked.iter().zip(&names).enumerate() {
+            let _ = writeln!(
+                table,
+                "{:>4}  {name:<width$}  {:>5}  {:>5}",
+                rank + 1,
+                member.stars,
+                member.local_score
+            );
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "event": "2022",
+        "owner_id": 1,
+        "members": {
+            "1": {"id": 1, "name": "Owner", "stars": 10, "local_score": 40, "global_score": 0,
+                  "last_star_ts": 1670000000, "completion_day_level": {}},
+            "2": {"id": 2, "name": null, "stars": 12, "local_score": 55, "global_score": 0,
+                  "last_star_ts": 1670000500, "completion_day_level": {}},
+            "3": {"id": 3, "name": "Tied", "stars": 11, "local_score": 40, "global_score": 0,
+                  "last_star_ts": 1670000900, "completion_day_level": {}}
+        }
+    }"#;
+
+    #[test]
+    fn ranking() {
+        let board = Leaderboard::parse(JSON).unwrap();
+        let ids = board.ranked().iter().map(|m| m.id).collect::<Vec<_>>();
+
+        assert_eq!(board.event, "2022");
+        assert_eq!(ids, vec![2, 3, 1]);
+        assert!(Leaderboard::parse("{}").is_err());
+    }
+
+    #[test]
+    fn render_table() {
+        let table = Leaderboard::parse(JSON).unwrap().table();
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "   #  Name                 Stars  Score");
+        assert_eq!(lines[1], "   1  (anonymous user #2)     12     55");
+        assert_eq!(lines[3], "   3  Owner                   10     40");
+    }
+}
@@ -0,0 +1,88 @@
+//! # Summary
+//! Solutions to Advent of Code puzzles organized as one crate per year, along with the tooling
+//! that runs them. Every day implements [`day::Day`], parsing its input once and answering both
+//! parts from it. Each year crate is generated by [`advent_macros::generate_year`] and exposes
+//! `run_solution`, `days_solved`, and a `SOLUTIONS` list, along with re-exports of the reusable
+//! structures built for individual days, and is re-exported here as `year_YYYY`. Years generated
+//! with a visibility, such as `generate_year!(pub 2022 25)`, also make each `day_##` module and its
+//! `Puzzle` reachable, so a single day can be parsed and solved directly. The [`registry`] module
+//! ties all years together through the [`advent_core::year::Year`] each one implements, for
+//! tooling such as the CLI and benchmarks. Each year is only compiled with its `yYYYY` feature, all
+//! of which are enabled by default, so `--no-default-features --features y2023` builds just the
+//! year being solved.
+//!
+//! # Example
+//! Rebuilding the file system from a terminal session in 2022 day 7 and measuring it:
+//!
+//! ```
+//! # #[cfg(feature = "y2022")] {
+//! use advent_solutions::year_2022::{Commands, DirectorySizer, FileSystem, TerminalLine};
+//!
+//! let session = "$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt";
+//!
+//! let fs = session
+//!     .lines()
+//!     .map(|l| l.parse::<TerminalLine>().unwrap())
+//!     .commands()
+//!     .collect::<FileSystem>();
+//!
+//! let sizer = DirectorySizer::for_file_system(&fs);
+//!
+//! assert_eq!(sizer.sum_under(1_000), 200);
+//! # }
+//! ```
+//!
+//! Solving a public day directly:
+//!
+//! ```
+//! # #[cfg(feature = "y2022")] {
+//! use advent_solutions::{answer::Answer, day::Day, year_2022::day_01::Puzzle};
+//!
+//! let elves = Puzzle::parse("1000\n2000\n\n4000\n\n5000\n6000".as_bytes()).unwrap();
+//!
+//! assert_eq!(Puzzle::part_01(&elves), Answer::Int(11000));
+//! # }
+//! ```
+
+// Everything the years are built from, reachable from here so tooling only needs this crate
+pub use advent_core::{
+    abi, annotated, answer, cancel, context, day, examples, input, normalize, progress, threads,
+    trace, util, year,
+};
+
+pub mod alternatives;
+pub mod answer_cache;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod aoc_client;
+pub mod bench;
+pub mod catalog;
+pub mod config;
+pub mod describe;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod download;
+pub mod exit;
+pub mod explore;
+pub mod history;
+#[cfg(feature = "dylib")]
+pub mod hot;
+pub mod leaderboard;
+pub mod lint;
+pub mod parsed_cache;
+pub mod registry;
+pub mod scaffold;
+pub mod schedule;
+pub mod site;
+pub mod stats;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod visualize;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "y2015")]
+pub use advent_2015 as year_2015;
+#[cfg(feature = "y2016")]
+pub use advent_2016 as year_2016;
+#[cfg(feature = "y2022")]
+pub use advent_2022 as year_2022;
+#[cfg(feature = "y2023")]
+pub use advent_2023 as year_2023;
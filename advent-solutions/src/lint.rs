@@ -0,0 +1,66 @@
+//! Checking inputs without solving them for the `lint-input` command, through the `LINTERS` every
+//! year crate generates. What is checked is up to [`advent_core::lint`] and each day's
+//! `Day::validate`.
+
+use crate::registry;
+
+pub use advent_core::lint::*;
+
+/// Looks up the check of the given day's input
+pub fn find(year: i32, day: i32) -> Option<LintFn> {
+    registry::year(year)?
+        .linters()
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, lint)| lint)
+}
+
+/// Days that check the structure of their input beyond it not being an HTML page or failing to
+/// parse, found by linting an empty input, which every such day rejects or accepts as valid
+pub fn validated_days() -> impl Iterator<Item = (i32, i32)> {
+    registry::YEARS.iter().flat_map(|y| {
+        y.linters()
+            .iter()
+            .filter(|(_, lint)| matches!(lint(b""), Lint::Valid | Lint::Malformed(_)))
+            .map(|&(day, _)| (y.year(), day))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn every_solved_day_is_linted() {
+        for year in registry::YEARS {
+            for day in 1..=year.days_solved() {
+                assert!(find(year.year(), day).is_some(), "day {day}");
+            }
+        }
+
+        assert!(find(1999, 1).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "y2022")]
+    fn crates_are_validated() {
+        let crates = find(2022, 5).unwrap();
+
+        assert_eq!(crates(b"[A]\n 1 \n\nmove 1 from 1 to 2\n"), Lint::Valid);
+        assert!(matches!(
+            crates(b"<!DOCTYPE html>\n<html>"),
+            Lint::Malformed(Malformed { line: 1, .. })
+        ));
+        assert!(validated_days().any(|d| d == (2022, 5)));
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn unchecked_days_are_parsed() {
+        let json = find(2015, 12).unwrap();
+
+        assert_eq!(json(br#"{"a":[1,2]}"#), Lint::Unchecked);
+        assert!(matches!(json(b"{\"a\":"), Lint::Unparsable { .. }));
+        assert!(!validated_days().any(|d| d == (2015, 12)));
+    }
+}
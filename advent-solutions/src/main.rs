@@ -1,52 +1,1139 @@
-mod year_2015;
-mod year_2022;
-mod year_2023;
+#[cfg(feature = "dylib")]
+use advent_solutions::hot;
+#[cfg(feature = "y2015")]
+use advent_solutions::year_2015::{RuleSet, StringRule};
+use advent_solutions::{
+    alternatives,
+    answer::Answer,
+    answer_cache::{self, AnswerCache, CacheKey},
+    aoc_client::AocClient,
+    bench, cancel, catalog, config,
+    config::Config,
+    context,
+    day::Strategy,
+    describe, download, examples,
+    exit::{ErrorReport, Exit, WrongAnswer},
+    explore,
+    history::{self, Comparison, History, RunRecord},
+    leaderboard::Leaderboard,
+    lint, progress, registry, scaffold, schedule, site,
+    stats::{self, YearStats},
+    threads, trace,
+    util::{memo, synth},
+    visualize,
+};
+use anyhow::Context;
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufRead, BufReader, IsTerminal},
+    path::Path,
+    process::ExitCode,
+};
 
-fn main() {
-    let mut args = std::env::args();
+fn print_help(config: &Config) {
+    println!("This application expects one argument in the form YYYY-DD-PP (year-day-part) and any needed inputs to exist in {}/YYYY-DD.txt", config.data_dir().display());
+    println!("Leaving off the part (YYYY-DD) or passing --both solves both parts from a single parse of the input");
+    println!(
+        "Some days keep that parse in {}/parsed so later runs on the same input skip parsing",
+        config.data_dir().display()
+    );
+    println!("--input-name NAME uses {0}/YYYY-DD/NAME.txt instead, such as an example or someone else's input. Without it {0}/YYYY-DD/input.txt is used when {0}/YYYY-DD.txt does not exist", config.data_dir().display());
+    println!("Setting {} to a path template such as inputs/{{year}}/day{{day:02}}.txt reads default inputs from there instead", registry::INPUT_TEMPLATE_VAR);
 
-    if args.len() == 1 {
-        println!("This application expects one argument in the form YYYY-DD-PP (year-day-part) and any needed inputs to exist in data/YYYY-DD.txt");
-        println!("The following solutions are implemented:");
+    if let Some(year) = config.default_year {
+        println!("The year may be omitted (DD-PP or DD) to use the configured default of {year}");
+    }
+
+    println!("Settings such as the session token are managed with the `config` command");
+    println!("--impl streaming solves days that support it while reading the input instead of collecting it first (--impl collect, the default)");
+    println!(
+        "--impl NAME runs an alternative implementation where a day has one, such as {} (--strategy is the same)",
+        alternatives::names().join(" or ")
+    );
+    println!(
+        "Every run's answers and time are appended to {}/runs.jsonl, and --compare reports changed answers or a slowdown of over {:.0}% since the last run",
+        config.data_dir().display(),
+        history::SLOWDOWN_THRESHOLD * 100.0
+    );
+    println!(
+        "Answers are cached in {}/{} by the day's code, input, and parameters, so solving again with none of them changed prints the earlier answers; --force (or --compare, which needs a timed run) solves it regardless",
+        config.data_dir().display(),
+        answer_cache::DIR_NAME
+    );
+    println!("--timeout 30s gives up on a puzzle that is still running after that long (ms, s, m, and h are understood) instead of waiting on it forever");
+    println!("--param NAME=VALUE changes a puzzle parameter, such as --param seconds=1000 for the race in 2015-14 or --param steps=4 for the lights in 2015-18, and can be repeated");
+    println!("--format json prints the answers, and any error along with its kind and the input file and line it is about, as one JSON object per line with everything else on stderr, while other subcommands besides bench-all only write their errors this way");
+    println!("--verbose reports how often cached subproblems were reused by days that cache them");
+    println!(
+        "--threads N (or setting {}) runs parallel solutions on a pool of N threads instead of one per core, for any command",
+        threads::THREADS_VAR
+    );
+
+    if trace::ENABLED {
+        println!(
+            "It also logs each parse and solve, along with milestones within some days, to stderr"
+        );
+    }
+
+    if progress::AVAILABLE {
+        println!("--progress draws bars on stderr while slow days such as 2015-04 work, as long as stdout is a terminal and answers are written as text");
+    }
+
+    println!("Days that support it can be animated in the terminal with --visualize (and --fps N, default {DEFAULT_FPS})");
+    println!("`tui` opens an interactive browser to run, time, and benchmark solutions");
+    #[cfg(feature = "dylib")]
+    println!("`watch YYYY DD [PART] [--input-name NAME]` rebuilds the day's year as a dynamic library and solves the day again whenever its sources or input change, without relinking this binary");
+    #[cfg(feature = "y2015")]
+    println!("`nice RULES [FILE]` counts the lines of FILE (default the 2015 day 05 input) passing comma separated rules such as vowels>=3,double,!forbid=ab|cd");
+    println!("`leaderboard ID [YYYY]` shows a private leaderboard (fetched at most every 15 minutes), or its raw JSON with --json");
+    println!("`stats [YYYY]` shows the stars earned in each event and days with an input but no solution, using the site's calendar when a session is configured (--offline counts solved parts instead)");
+    println!(
+        "`bench-all [--years 2015,2022] [--runs K] [--format csv|markdown] [--output FILE]` times every solved part K times (default {}) and reports the total per year and the {} slowest parts",
+        bench::DEFAULT_RUNS,
+        bench::SLOWEST_SHOWN
+    );
+    println!(
+        "`generate-docs [--years 2015,2022] [--runs K] [--answers] [--source-url URL] [--output DIR]` writes an HTML index of every solved day with its timings and links to the puzzle and source to DIR/{} (default site), showing answers only with --answers",
+        site::INDEX
+    );
+    println!("`impls YYYY DD` times every implementation of a day on its input and checks that they agree");
+    println!(
+        "`gen-input YYYY DD [SIZE] [--seed N]` writes a large input for stress testing to the day's {STRESS_INPUT_NAME} input, for days {}",
+        synth::GENERATORS
+            .iter()
+            .map(|g| format!("{}-{:02}", g.year, g.day))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    println!("`describe YYYY DD` shows a day's title, puzzle link, what each part asks for, and the utilities it is built on");
+    println!(
+        "`explore YYYY DD [--input-name NAME]` parses a day's input and takes commands such as `ls /a` or `wire a` to look around it (type help for a day's commands), for days {}",
+        explore::days()
+            .map(|(year, day)| format!("{year}-{day:02}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    println!(
+        "`lint-input YYYY DD [--input-name NAME]` checks that an input is not an HTML page saved by a failed download, and for days {} that it has the structure the day expects, reporting the first malformed line, while other days check that it parses",
+        lint::validated_days()
+            .map(|(year, day)| format!("{year}-{day:02}"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    println!("`check YYYY DD` solves the examples from a day's puzzle description without its real input");
+    println!("`download YYYY DD` fetches a puzzle input and `wait YYYY DD` does so (and scaffolds the day) as soon as it unlocks");
+    println!("Runs exit with 2 when no solution exists, 3 when the input is missing, 4 when solving fails or times out or `lint-input` finds a malformed input, and 5 when `check` fails an example or --compare finds a changed answer, so CI can tell them apart");
+    println!("The following solutions are implemented, with the time taken by their last run on the default input:");
+    print!("{}", catalog::table(&catalog::gather(&config.data_dir())));
+}
+
+/// Parses the `YYYY DD` arguments shared by subcommands that operate on a single puzzle
+fn puzzle_args(args: &[String], usage: &str) -> anyhow::Result<(i32, i32)> {
+    match args {
+        [year, day] => Ok((year.parse()?, day.parse()?)),
+        _ => anyhow::bail!("usage: {usage} YYYY DD"),
+    }
+}
+
+/// Checks that a name given with `--input-name` names one of a day's inputs rather than a path
+fn checked_input_name(name: &str) -> anyhow::Result<&str> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        anyhow::bail!("Input names cannot be empty or contain path separators");
+    }
+
+    Ok(name)
+}
+
+/// Parses the `YYYY DD [...] [--input-name NAME]` arguments of subcommands that read a single
+/// puzzle's input, returning any arguments between the day and the flag for the subcommand itself
+fn puzzle_with_input_name<'a>(
+    args: &'a [String],
+    usage: &str,
+) -> anyhow::Result<(i32, i32, &'a [String], Option<&'a str>)> {
+    let (puzzle, input_name) = match args {
+        [puzzle @ .., flag, name] if flag == "--input-name" => {
+            (puzzle, Some(checked_input_name(name)?))
+        }
+        _ => (args, None),
+    };
+
+    match puzzle {
+        [year, day, rest @ ..] => Ok((year.parse()?, day.parse()?, rest, input_name)),
+        _ => anyhow::bail!("{usage}"),
+    }
+}
+
+fn download_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    let (year, day) = puzzle_args(args, "download")?;
+
+    schedule::unlock_time(year, day)?;
+
+    let path = download::download_input(config, year, day)?;
+
+    println!("Input available at {}", path.display());
+
+    Ok(())
+}
+
+fn wait_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    let (year, day) = puzzle_args(args, "wait")?;
+    let unlock = schedule::unlock_time(year, day)?;
+
+    schedule::wait_until(unlock);
+
+    match download::download_input(config, year, day) {
+        Ok(path) => println!("Input saved to {}", path.display()),
+        // Still worth scaffolding so the puzzle can be started while the input is sorted out
+        Err(e) => eprintln!("Could not download input: {e}"),
+    }
+
+    for path in scaffold::scaffold_day(&scaffold::workspace_dir(), year, day)? {
+        println!("Scaffolded {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Shows a private leaderboard as a table, or as the raw JSON with `--json`
+fn leaderboard_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    let json = args.iter().any(|a| a == "--json");
+    let args = args.iter().filter(|a| *a != "--json").collect::<Vec<_>>();
 
-        let opts: Vec<(i32, &dyn Fn() -> i32)> = vec![
-            (2015, &year_2015::days_solved),
-            (2022, &year_2022::days_solved),
-            (2023, &year_2023::days_solved),
-        ];
+    let (id, year) = match args[..] {
+        [id] => (id, None),
+        [id, year] => (id, Some(year.parse()?)),
+        _ => anyhow::bail!("usage: leaderboard ID [YYYY] [--json]"),
+    };
 
-        for (year, solved) in opts {
-            let avail = solved();
+    let year = year
+        .or(config.default_year)
+        .unwrap_or_else(|| schedule::latest_event(chrono::Utc::now()));
 
-            print!(" - {year}: The first ");
+    let body = AocClient::from_config(config).leaderboard(year, id.parse()?)?;
 
-            if avail == 1 {
-                print!("day is");
-            } else {
-                print!("{avail} days are");
+    if json {
+        println!("{body}");
+    } else {
+        print!("{}", Leaderboard::parse(&body)?.table());
+    }
+
+    Ok(())
+}
+
+/// Shows the stars earned in each event, read from the site when a session is configured unless
+/// `--offline` is given
+fn stats_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    let offline = args.iter().any(|a| a == "--offline");
+    let args = args
+        .iter()
+        .filter(|a| *a != "--offline")
+        .collect::<Vec<_>>();
+    let latest = schedule::latest_event(chrono::Utc::now());
+
+    let years = match args[..] {
+        [] => 2015..=latest,
+        [year] => {
+            let year = year.parse()?;
+
+            year..=year
+        }
+        _ => anyhow::bail!("usage: stats [YYYY] [--offline]"),
+    };
+
+    let client = (!offline && config.session.is_some()).then(|| AocClient::from_config(config));
+    let data_dir = config.data_dir();
+
+    for year in years {
+        let stars = client.as_ref().and_then(|c| match c.calendar(year) {
+            Ok(html) => Some(stats::parse_calendar_stars(&html)),
+            Err(e) => {
+                eprintln!("Could not read the {year} calendar, counting solved parts instead: {e}");
+
+                None
             }
+        });
+
+        println!("{}", YearStats::gather(year, &data_dir, stars.as_ref()));
+    }
+
+    println!("* both stars, + one star, ! input without a solution");
+
+    Ok(())
+}
+
+/// Solves the examples declared for a day, failing if any of them give the wrong answer
+fn check_command(args: &[String]) -> anyhow::Result<()> {
+    let (year, day) = puzzle_args(args, "check")?;
+
+    let set = examples::find(year, day)
+        .with_context(|| format!("no examples are declared for day {day} of {year}"))?;
+
+    let checked = set.check();
+
+    for (i, c) in checked.iter().enumerate() {
+        // Numbered within each part, matching the order they appear in the puzzle
+        let number = 1 + checked[..i]
+            .iter()
+            .filter(|earlier| earlier.example.part == c.example.part)
+            .count();
+
+        println!("Part {} example {number}: {c}", c.example.part);
+    }
+
+    let failed = checked.iter().filter(|c| !c.passed()).count();
+
+    if failed > 0 {
+        return Err(WrongAnswer(format!("{failed} of {} examples failed", checked.len())).into());
+    }
+
+    Ok(())
+}
+
+fn describe_command(args: &[String]) -> anyhow::Result<()> {
+    let (year, day) = puzzle_args(args, "describe")?;
+
+    let description = describe::find(year, day)
+        .with_context(|| format!("no solution exists for day {day} of {year}"))?;
+
+    print!("{description}");
+
+    Ok(())
+}
+
+/// Parses a day's input and reads commands exploring it from stdin until it ends or `quit` is given
+fn explore_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    const USAGE: &str = "usage: explore YYYY DD [--input-name NAME]";
+
+    let (year, day, [], input_name) = puzzle_with_input_name(args, USAGE)? else {
+        anyhow::bail!(USAGE);
+    };
+
+    let explore = explore::find(year, day).with_context(|| {
+        format!(
+            "day {day} of {year} cannot be explored, only {}",
+            explore::days()
+                .map(|(y, d)| format!("{y}-{d:02}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    })?;
 
-            println!(" complete.");
+    let path = registry::resolve_input(year, day, &config.data_dir(), input_name);
+    let mut reader = File::open(&path).map(BufReader::new).ok();
+    let mut explorable = explore(&mut context::Context::new(
+        reader.as_mut().map(|r| r as &mut dyn BufRead),
+    ))
+    .with_context(|| format!("could not parse {}", path.display()))?;
+
+    println!("Exploring day {day} of {year}, type help for commands");
+
+    explore::command_loop(
+        explorable.as_mut(),
+        std::io::stdin().lock(),
+        std::io::stdout(),
+    )?;
+
+    Ok(())
+}
+
+/// Rebuilds a day's year as a dynamic library and solves the day again whenever its sources or
+/// input change
+#[cfg(feature = "dylib")]
+fn watch_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    const USAGE: &str = "usage: watch YYYY DD [PART] [--input-name NAME]";
+
+    let (year, day, part, input_name) = puzzle_with_input_name(args, USAGE)?;
+    let parts = match part {
+        [] => vec![1, 2],
+        [part] => vec![part.parse()?],
+        _ => anyhow::bail!(USAGE),
+    };
+
+    let path = registry::resolve_input(year, day, &config.data_dir(), input_name);
+
+    hot::watch(&scaffold::workspace_dir(), year, day, &parts, &path)
+}
+
+/// Checks that a day's input has the structure the day expects without solving it, failing at the
+/// first malformed line
+fn lint_input_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    const USAGE: &str = "usage: lint-input YYYY DD [--input-name NAME]";
+
+    let (year, day, [], input_name) = puzzle_with_input_name(args, USAGE)? else {
+        anyhow::bail!(USAGE);
+    };
+
+    let lint = lint::find(year, day)
+        .with_context(|| format!("no solution exists for day {day} of {year}"))?;
+
+    let path = registry::resolve_input(year, day, &config.data_dir(), input_name);
+    let input =
+        std::fs::read(&path).with_context(|| format!("could not read {}", path.display()))?;
+
+    let (line, message) = match lint(&input) {
+        lint::Lint::Valid => {
+            println!(
+                "{} is a valid input for day {day} of {year}",
+                path.display()
+            );
+
+            return Ok(());
+        }
+        lint::Lint::Unchecked => {
+            println!(
+                "{} is not an HTML page and parses, but day {day} of {year} has no check of its own",
+                path.display()
+            );
+
+            return Ok(());
         }
+        lint::Lint::Malformed(malformed) => (Some(malformed.line), malformed.to_string()),
+        lint::Lint::Unparsable { line, message } => (line, message),
+    };
 
-        return;
+    Err(registry::RunError::Malformed {
+        path,
+        line,
+        message,
     }
+    .into())
+}
+
+/// Runs every implementation of a day on its default input, failing if any of them fail or their
+/// answers differ
+fn impls_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    let (year, day) = puzzle_args(args, "impls")?;
+    let path = registry::resolve_input(year, day, &config.data_dir(), None);
+    let runs = alternatives::compare(year, day, &path);
+
+    if runs.is_empty() {
+        anyhow::bail!("no solution exists for day {day} of {year}");
+    }
+
+    for run in &runs {
+        match &run.answer {
+            Ok(answer) => println!(
+                "Part {} {:<10} {answer} in {:.2?}",
+                run.part, run.name, run.elapsed
+            ),
+            Err(e) => println!("Part {} {:<10} failed: {e}", run.part, run.name),
+        }
+    }
+
+    let failed = runs.iter().filter(|r| r.answer.is_err()).count();
+
+    if failed > 0 {
+        anyhow::bail!("{failed} of {} runs failed", runs.len());
+    }
+
+    if let Some(part) = alternatives::disagreement(&runs) {
+        anyhow::bail!("implementations of part {part} do not agree");
+    }
+
+    Ok(())
+}
+
+/// Years given as a comma separated list, such as `2015,2022`, each of which has solutions
+fn parse_years(value: &str) -> anyhow::Result<Vec<i32>> {
+    let years = value
+        .split(',')
+        .map(|y| y.trim().parse())
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("could not read the years '{value}'"))?;
+
+    if let Some(year) = years.iter().find(|&&y| registry::days_solved(y) == 0) {
+        anyhow::bail!("no solutions exist for {year}");
+    }
+
+    Ok(years)
+}
+
+/// Times every solved part of the chosen years and prints or writes a report of the totals per
+/// year and the slowest parts
+fn bench_all_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    const USAGE: &str =
+        "usage: bench-all [--years YYYY,YYYY] [--runs K] [--format csv|markdown] [--output FILE]";
+
+    let mut years = registry::years().collect::<Vec<_>>();
+    let mut runs = bench::DEFAULT_RUNS;
+    let mut format = bench::Format::default();
+    let mut output = None;
+
+    for pair in args.chunks(2) {
+        let [flag, value] = pair else {
+            anyhow::bail!(USAGE);
+        };
+
+        match flag.as_str() {
+            "--years" => years = parse_years(value)?,
+            "--runs" => runs = value.parse().context("--runs takes a number of runs")?,
+            "--format" => format = value.parse()?,
+            "--output" => output = Some(value),
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+
+    if runs == 0 {
+        anyhow::bail!("--runs must be at least 1");
+    }
+
+    let report = bench::Report::measure(&years, &config.data_dir(), runs, |timing| {
+        match &timing.answer {
+            Ok(_) => eprintln!("{} took {:.2?}", timing.puzzle(), timing.median()),
+            Err(e) => eprintln!("{} failed: {e}", timing.puzzle()),
+        }
+    });
+    let rendered = report.render(format);
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, rendered).with_context(|| format!("could not write {path}"))?;
+
+            println!("Report written to {path}");
+        }
+        None => print!("{rendered}"),
+    }
+
+    Ok(())
+}
+
+/// Times every solved part and writes the page listing them to a directory, `site` by default
+fn generate_docs_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    const USAGE: &str = "usage: generate-docs [--years YYYY,YYYY] [--runs K] [--answers] [--source-url URL] [--output DIR]";
+
+    let mut years = registry::years().collect::<Vec<_>>();
+    let mut runs = site::DEFAULT_RUNS;
+    let mut options = site::SiteOptions::default();
+    let mut output = "site".to_owned();
+    let mut args = args.iter();
+
+    while let Some(flag) = args.next() {
+        if flag == "--answers" {
+            options.answers = true;
+            continue;
+        }
+
+        let value = args.next().context(USAGE)?;
+
+        match flag.as_str() {
+            "--years" => years = parse_years(value)?,
+            "--runs" => runs = value.parse().context("--runs takes a number of runs")?,
+            "--source-url" => options.source_url = value.to_owned(),
+            "--output" => output = value.to_owned(),
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+
+    if runs == 0 {
+        anyhow::bail!("--runs must be at least 1");
+    }
+
+    let report = bench::Report::measure(&years, &config.data_dir(), runs, |timing| {
+        if let Err(e) = &timing.answer {
+            eprintln!("{} failed: {e}", timing.puzzle());
+        }
+    });
+    let path = site::Site::from_report(&report)
+        .write(Path::new(&output), &options)
+        .with_context(|| format!("could not write the site to {output}"))?;
+
+    println!(
+        "Index of {} parts written to {}",
+        report.parts.len(),
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Writes a large synthetic input for a day to its `stress` input, reproducible from the size and
+/// `--seed`
+fn gen_input_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    const USAGE: &str = "usage: gen-input YYYY DD [SIZE] [--seed N]";
+
+    let (seed, args) = match args.iter().position(|a| a == "--seed") {
+        Some(i) => (
+            args.get(i + 1).context(USAGE)?.parse()?,
+            [&args[..i], args.get(i + 2..).unwrap_or_default()].concat(),
+        ),
+        None => (synth::DEFAULT_SEED, args.to_vec()),
+    };
 
-    let parts = args
-        .nth(1)
-        .unwrap()
+    let (puzzle, size) = match &args[..] {
+        [_, _] => (&args[..], None),
+        [_, _, size] => (&args[..2], Some(size.parse()?)),
+        _ => anyhow::bail!(USAGE),
+    };
+
+    let (year, day) = puzzle_args(puzzle, "gen-input")?;
+    let generator = synth::find(year, day)
+        .with_context(|| format!("no input generator exists for day {day} of {year}"))?;
+
+    let size = size.unwrap_or(generator.default_size);
+    let path = registry::resolve_input(year, day, &config.data_dir(), Some(STRESS_INPUT_NAME));
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut out = std::io::BufWriter::new(std::fs::File::create(&path)?);
+
+    (generator.generate)(&mut out, size, &mut synth::Rng::new(seed))?;
+    std::io::Write::flush(&mut out)?;
+
+    println!(
+        "Wrote {size} {} to {}; solve it with {year}-{day:02} --input-name {STRESS_INPUT_NAME}",
+        generator.unit,
+        path.display()
+    );
+
+    Ok(())
+}
+
+/// Counts the lines that pass a custom set of 2015 day 05 string rules
+#[cfg(feature = "y2015")]
+fn nice_command(config: &Config, args: &[String]) -> anyhow::Result<()> {
+    let (rules, path) = match args {
+        [rules] => (
+            rules,
+            registry::resolve_input(2015, 5, &config.data_dir(), None),
+        ),
+        [rules, path] => (rules, path.into()),
+        _ => anyhow::bail!("usage: nice RULES [FILE]"),
+    };
+
+    let rules = rules.parse::<RuleSet>()?;
+    let text = std::fs::read_to_string(&path)
+        .with_context(|| format!("could not read {}", path.display()))?;
+
+    let passing = text.lines().filter(|l| rules.matches(l)).count();
+
+    println!("{passing} of {} lines pass", text.lines().count());
+
+    Ok(())
+}
+
+/// Takes `--threads N` out of the arguments and builds the pool it, or [`threads::THREADS_VAR`],
+/// asks for before any command runs
+fn configure_threads(args: &mut Vec<String>) -> anyhow::Result<()> {
+    let flag = match args.iter().position(|a| a == "--threads") {
+        Some(i) => {
+            let count = args
+                .get(i + 1)
+                .context("--threads expects a number of threads")?
+                .clone();
+
+            args.drain(i..=i + 1);
+
+            Some(count)
+        }
+        None => None,
+    };
+
+    if let Some(count) = threads::requested(flag.as_deref())? {
+        threads::configure(count)?;
+    }
+
+    Ok(())
+}
+
+/// Subcommands that take `--format` only for how [`main`] reports an error, unlike a run and
+/// `bench-all`, which also write their results in it
+const ERROR_FORMAT_COMMANDS: &[&str] = &[
+    "download",
+    "wait",
+    "nice",
+    "leaderboard",
+    "stats",
+    "check",
+    "describe",
+    "explore",
+    "impls",
+    "lint-input",
+    "gen-input",
+    "generate-docs",
+    "tui",
+    "watch",
+];
+
+/// Takes `--format FMT` out of the arguments of subcommands in [`ERROR_FORMAT_COMMANDS`], checking
+/// it names a format even though [`OutputFormat::requested`] has already read it
+fn strip_error_format(args: &mut Vec<String>) -> anyhow::Result<()> {
+    if !args
+        .first()
+        .is_some_and(|c| ERROR_FORMAT_COMMANDS.contains(&c.as_str()))
+    {
+        return Ok(());
+    }
+
+    while let Some(i) = args.iter().position(|a| a == "--format") {
+        OutputFormat::parse(args.get(i + 1).context("--format expects text or json")?)?;
+
+        args.drain(i..=i + 1);
+    }
+
+    Ok(())
+}
+
+/// Frame rate used by `--visualize` unless `--fps` is given
+const DEFAULT_FPS: u32 = 10;
+
+/// Input name `gen-input` writes to, so the stress case never replaces a real input
+const STRESS_INPUT_NAME: &str = "stress";
+
+/// How a run writes its answers and errors, chosen with `--format`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum OutputFormat {
+    #[default]
+    Text,
+    /// One JSON object per line, for tools wrapping the binary
+    Json,
+}
+
+impl OutputFormat {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => anyhow::bail!("--format expects text or json but got '{s}'"),
+        }
+    }
+
+    /// The format asked for anywhere in `args`, so an error reading the rest of them is written in
+    /// it too
+    fn requested(args: &[String]) -> Self {
+        args.windows(2)
+            .rev()
+            .find(|w| w[0] == "--format")
+            .and_then(|w| Self::parse(&w[1]).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes a line reported alongside the answers, such as a comparison with the last run. JSON
+    /// sends it to stderr so stdout only holds the JSON objects.
+    fn note(self, line: impl std::fmt::Display) {
+        match self {
+            Self::Text => println!("{line}"),
+            Self::Json => eprintln!("{line}"),
+        }
+    }
+}
+
+/// Answers to a run as written with `--format json`
+#[derive(Serialize)]
+struct AnswerReport {
+    year: i32,
+    day: i32,
+    /// The part that was run, or `None` when both were
+    part: Option<i32>,
+    answers: Vec<String>,
+    /// Time spent solving, or `None` when the answers came from the cache
+    micros: Option<u64>,
+}
+
+/// Prints the answers to a run of `part`, or both parts when `None`, in `format`
+fn print_answers(
+    format: OutputFormat,
+    year: i32,
+    day: i32,
+    part: Option<i32>,
+    answers: &[Answer],
+    elapsed: Option<std::time::Duration>,
+) {
+    match format {
+        OutputFormat::Text => registry::print_answers(part, answers),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::json!(AnswerReport {
+                year,
+                day,
+                part,
+                answers: answers.iter().map(Answer::to_string).collect(),
+                micros: elapsed.map(|e| e.as_micros().try_into().unwrap_or(u64::MAX)),
+            })
+        ),
+    }
+}
+
+/// The puzzle to run and the flags that change how it is run
+struct RunOptions {
+    puzzle: Option<String>,
+    both: bool,
+    input_name: Option<String>,
+    visualize: bool,
+    verbose: bool,
+    compare: bool,
+    force: bool,
+    progress: bool,
+    timeout: Option<std::time::Duration>,
+    params: Vec<(String, String)>,
+    fps: u32,
+    strategy: Strategy,
+    format: OutputFormat,
+}
+
+impl RunOptions {
+    fn parse(args: &[String]) -> anyhow::Result<Self> {
+        let mut options = Self {
+            puzzle: None,
+            both: false,
+            input_name: None,
+            visualize: false,
+            verbose: false,
+            compare: false,
+            force: false,
+            progress: false,
+            timeout: None,
+            params: Vec::new(),
+            fps: DEFAULT_FPS,
+            strategy: Strategy::default(),
+            format: OutputFormat::default(),
+        };
+
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--both" => options.both = true,
+                "--visualize" => options.visualize = true,
+                "--verbose" => options.verbose = true,
+                "--compare" => options.compare = true,
+                "--force" => options.force = true,
+                "--progress" => options.progress = true,
+                "--input-name" => {
+                    let name = args
+                        .next()
+                        .context("--input-name expects the name of an input")?;
+
+                    options.input_name = Some(checked_input_name(name)?.to_owned());
+                }
+                "--timeout" => {
+                    let limit = args
+                        .next()
+                        .context("--timeout expects a duration such as 30s")?;
+
+                    options.timeout = Some(cancel::parse_duration(limit)?);
+                }
+                "--param" => {
+                    let param = args
+                        .next()
+                        .context("--param expects a parameter such as seconds=1000")?;
+
+                    options.params.push(context::parse_param(param)?);
+                }
+                "--fps" => {
+                    options.fps = args
+                        .next()
+                        .context("--fps expects a number of frames per second")?
+                        .parse()?;
+                }
+                "--format" => {
+                    options.format =
+                        OutputFormat::parse(args.next().context("--format expects text or json")?)?;
+                }
+                "--impl" | "--strategy" => {
+                    options.strategy = alternatives::strategy(args.next().with_context(|| {
+                        format!("{arg} expects collect, streaming, or the name of an alternative")
+                    })?)?;
+                }
+                flag if flag.starts_with("--") => anyhow::bail!("Unknown flag '{flag}'"),
+                puzzle => {
+                    if options.puzzle.replace(puzzle.to_owned()).is_some() {
+                        anyhow::bail!("Only one puzzle can be run at a time");
+                    }
+                }
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Whether `--progress` draws its bars, which would otherwise be mixed into answers that are
+    /// piped, redirected, or read as JSON
+    fn shows_progress(&self, stdout_is_terminal: bool) -> bool {
+        self.progress && stdout_is_terminal && self.format != OutputFormat::Json
+    }
+}
+
+/// Appends a successful run to the history, first comparing it against the last matching run when
+/// `--compare` was given
+fn record_run(
+    data_dir: &Path,
+    year: i32,
+    day: i32,
+    part: Option<i32>,
+    options: &RunOptions,
+    outcome: &registry::Outcome,
+) -> anyhow::Result<()> {
+    let history = History::in_data_dir(data_dir);
+    let record = RunRecord::new(
+        year,
+        day,
+        part,
+        options.input_name.as_deref(),
+        &outcome.answers,
+        outcome.elapsed,
+    );
+
+    let mut changed = 0;
+
+    if options.compare {
+        match history.last_run(&record)? {
+            Some(previous) => {
+                let comparison = Comparison::new(&previous, &record);
+
+                options.format.note(&comparison);
+                changed = comparison.changed.len();
+            }
+            None => options.format.note("No earlier run to compare against"),
+        }
+    }
+
+    history
+        .append(&record)
+        .with_context(|| format!("could not record the run in {}", data_dir.display()))?;
+
+    if changed > 0 {
+        return Err(WrongAnswer(format!(
+            "{changed} of the answers changed since the last run"
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+/// The key the answers to this run are cached under, or `None` when they cannot be cached: the
+/// day has no [`describe::Description`] to version its code, or a named input is missing and is
+/// left for the run to report
+fn cache_key(
+    data_dir: &Path,
+    year: i32,
+    day: i32,
+    part: Option<i32>,
+    options: &RunOptions,
+) -> Option<CacheKey> {
+    let version = describe::find(year, day)?.version;
+    let input = registry::resolve_input(year, day, data_dir, options.input_name.as_deref());
+    let input = File::open(input).ok();
+
+    if options.input_name.is_some() && input.is_none() {
+        return None;
+    }
+
+    let params = options.params.iter().cloned().collect::<BTreeMap<_, _>>();
+
+    CacheKey::new(year, day, part, version, input, &params, options.strategy).ok()
+}
+
+/// Exits with the code [`Exit`] gives for how the run went, documented in `exit` for CI
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let format = OutputFormat::requested(&args);
+
+    // Panics are reported as errors in `format` below rather than by the default hook, which
+    // would write plain text among the JSON objects
+    if format == OutputFormat::Json {
+        std::panic::set_hook(Box::new(|_| {}));
+    }
+
+    let result = std::panic::catch_unwind(|| run(args)).unwrap_or_else(|payload| {
+        anyhow::bail!("panicked: {}", registry::panic_message(payload.as_ref()))
+    });
+
+    match result {
+        Ok(()) => Exit::Success.into(),
+        Err(e) => {
+            let exit = Exit::of(&e);
+
+            match format {
+                OutputFormat::Json => eprintln!("{}", serde_json::json!(ErrorReport::of(&e))),
+                // Errors with a code of their own are already worded for the user
+                OutputFormat::Text if exit == Exit::Other => eprintln!("Error: {e:?}"),
+                OutputFormat::Text => eprintln!("{e}"),
+            }
+
+            exit.into()
+        }
+    }
+}
+
+fn run(mut args: Vec<String>) -> anyhow::Result<()> {
+    if args.first().map(String::as_str) == Some("config") {
+        return config::command(&args[1..]);
+    }
+
+    let config = Config::load()?;
+
+    configure_threads(&mut args)?;
+    strip_error_format(&mut args)?;
+
+    match args.first().map(String::as_str) {
+        Some("download") => return download_command(&config, &args[1..]),
+        Some("wait") => return wait_command(&config, &args[1..]),
+        #[cfg(feature = "y2015")]
+        Some("nice") => return nice_command(&config, &args[1..]),
+        Some("leaderboard") => return leaderboard_command(&config, &args[1..]),
+        Some("stats") => return stats_command(&config, &args[1..]),
+        Some("check") => return check_command(&args[1..]),
+        Some("describe") => return describe_command(&args[1..]),
+        Some("explore") => return explore_command(&config, &args[1..]),
+        Some("impls") => return impls_command(&config, &args[1..]),
+        Some("lint-input") => return lint_input_command(&config, &args[1..]),
+        Some("gen-input") => return gen_input_command(&config, &args[1..]),
+        Some("bench-all") => return bench_all_command(&config, &args[1..]),
+        Some("generate-docs") => return generate_docs_command(&config, &args[1..]),
+        #[cfg(feature = "tui")]
+        Some("tui") => return Ok(advent_solutions::tui::run(&config.data_dir())?),
+        #[cfg(not(feature = "tui"))]
+        Some("tui") => anyhow::bail!("this build does not include the `tui` feature"),
+        #[cfg(feature = "dylib")]
+        Some("watch") => return watch_command(&config, &args[1..]),
+        #[cfg(not(feature = "dylib"))]
+        Some("watch") => anyhow::bail!("this build does not include the `dylib` feature"),
+        _ => {}
+    }
+
+    let options = RunOptions::parse(&args)?;
+
+    let Some(puzzle) = &options.puzzle else {
+        print_help(&config);
+
+        return Ok(());
+    };
+
+    let mut parts = puzzle
         .split('-')
         .filter_map(|p| p.parse().ok())
         .collect::<Vec<i32>>();
 
-    let year = parts[0];
-    let day = parts[1];
-    let part = parts[2];
+    // Every event is from 2015 onwards, so anything smaller must be a day missing its year
+    if parts.first().is_some_and(|&p| p < 2015) {
+        if let Some(year) = config.default_year {
+            parts.insert(0, year);
+        }
+    }
+
+    let data_dir = config.data_dir();
+
+    let (year, day, part) = match parts[..] {
+        [year, day] => (year, day, None),
+        [year, day, _] if options.both => (year, day, None),
+        [year, day, part] => (year, day, Some(part)),
+        _ => anyhow::bail!("Expected a puzzle in the form YYYY-DD[-PP] but got '{puzzle}'"),
+    };
+
+    for (name, value) in &options.params {
+        context::set_param(name, value);
+    }
+
+    if options.visualize {
+        let mut player = visualize::TerminalPlayer::new(options.fps);
+
+        let parts = part.map_or(vec![1, 2], |p| vec![p]);
+
+        visualize::run(
+            year,
+            day,
+            &parts,
+            &data_dir,
+            options.input_name.as_deref(),
+            &mut player,
+        )?;
+    }
+
+    memo::record_stats(options.verbose);
+
+    if options.progress && !progress::AVAILABLE {
+        eprintln!("This build does not include the `progress` feature, so no bars are shown");
+    }
+
+    progress::enable(options.shows_progress(std::io::stdout().is_terminal()));
+
+    if options.verbose {
+        trace::install_subscriber();
+    }
+
+    let cache = AnswerCache::in_data_dir(&data_dir);
+    let key = cache_key(&data_dir, year, day, part, &options);
+
+    if let Some(answers) = key
+        .as_ref()
+        .filter(|_| !options.force && !options.compare)
+        .and_then(|k| cache.get(k))
+    {
+        print_answers(options.format, year, day, part, &answers, None);
+        eprintln!(
+            "Cached from an earlier run on the same code and input, pass --force to solve it again"
+        );
+
+        return Ok(());
+    }
+
+    let outcome = match part {
+        Some(part) => registry::run(
+            year,
+            day,
+            part,
+            &data_dir,
+            options.input_name.as_deref(),
+            options.strategy,
+            options.timeout,
+        ),
+        None => registry::run_both(
+            year,
+            day,
+            &data_dir,
+            options.input_name.as_deref(),
+            options.strategy,
+            options.timeout,
+        ),
+    };
+
+    if let Ok(outcome) = &outcome {
+        print_answers(
+            options.format,
+            year,
+            day,
+            part,
+            &outcome.answers,
+            Some(outcome.elapsed),
+        );
+
+        if let Some(key) = &key {
+            cache.put(key, &outcome.answers);
+        }
+
+        record_run(&data_dir, year, day, part, &options, outcome)?;
+    }
+
+    for stats in memo::take_stats() {
+        options.format.note(format_args!(
+            "{}: {} hits, {} misses, {} entries",
+            stats.label, stats.hits, stats.misses, stats.entries
+        ));
+    }
+
+    outcome?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn options(args: &str) -> RunOptions {
+        RunOptions::parse(&args.split(' ').map(str::to_owned).collect::<Vec<_>>()).unwrap()
+    }
 
-    match year {
-        2015 => year_2015::run_solution(day, part),
-        2022 => year_2022::run_solution(day, part),
-        2023 => year_2023::run_solution(day, part),
-        _ => eprintln!("No solutions found for the year {year}"),
+    #[test]
+    fn progress_only_on_a_terminal_with_text() {
+        assert!(options("2015-04 --progress").shows_progress(true));
+        assert!(!options("2015-04 --progress").shows_progress(false));
+        assert!(!options("2015-04 --progress --format json").shows_progress(true));
+        assert!(!options("2015-04").shows_progress(true));
     }
 }
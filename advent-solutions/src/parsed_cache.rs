@@ -0,0 +1,27 @@
+//! Finding the days whose parsed input is kept on disk between runs, from the `PARSE_CACHED` list
+//! each year crate may name in its `generate_year!`. The cache itself is
+//! [`advent_core::parsed_cache`].
+
+use crate::registry;
+
+pub use advent_core::parsed_cache::*;
+
+/// Looks up the cached solver for the given day if it has one
+pub fn find(year: i32, day: i32) -> Option<CachedDayFn> {
+    registry::year(year)?
+        .parse_cached()
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, run)| run)
+}
+
+#[cfg(all(test, feature = "y2022"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registered() {
+        assert!(find(2022, 8).is_some());
+        assert!(find(2022, 1).is_none());
+    }
+}
@@ -0,0 +1,863 @@
+use std::{
+    any::Any,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::alternatives;
+use crate::annotated;
+use crate::answer::Answer;
+use crate::cancel;
+use crate::context;
+use crate::day::{InputRequired, ParseError, Strategy};
+use crate::exit::Exit;
+use crate::parsed_cache::{self, ParsedCache};
+use crate::year::Year;
+#[cfg(feature = "y2015")]
+use crate::year_2015;
+#[cfg(feature = "y2016")]
+use crate::year_2016;
+#[cfg(feature = "y2022")]
+use crate::year_2022;
+#[cfg(feature = "y2023")]
+use crate::year_2023;
+
+pub use crate::input::{
+    fill_input_template, input_dir, input_path, input_template, resolve_input, DEFAULT_INPUT_NAME,
+    INPUT_TEMPLATE_VAR,
+};
+pub use crate::year::{DayFn, PartFn};
+
+/// Solves whichever part it is given, as each way of running both parts separately does
+type EitherPart<'a> = dyn Fn(&mut context::Context<'_>, i32) -> anyhow::Result<Answer> + 'a;
+
+/// A single solved part of a puzzle, locatable by its year, day, and part
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    pub run: PartFn,
+    /// Solves the part while reading its input, if the day supports that
+    pub stream: Option<PartFn>,
+    /// Parameters the part can be given with `--param`
+    pub params: &'static [context::Param],
+}
+
+impl Solution {
+    /// Location of the puzzle input for this solution relative to the crate root
+    pub fn data_path(&self) -> PathBuf {
+        self.data_path_in(Path::new("data"))
+    }
+
+    /// Location of the default puzzle input for this solution within `data_dir`
+    pub fn data_path_in(&self, data_dir: &Path) -> PathBuf {
+        resolve_input(self.year, self.day, data_dir, None)
+    }
+
+    /// The solver to use for `strategy`, falling back to collecting the input when the day cannot
+    /// be streamed
+    pub fn solver(&self, strategy: Strategy) -> PartFn {
+        match (strategy, self.stream) {
+            (Strategy::Streaming, Some(stream)) => stream,
+            _ => self.run,
+        }
+    }
+}
+
+/// Every year crate that is compiled in, as the [`Year`] its `generate_year!` implements
+pub const YEARS: &[&dyn Year] = &[
+    #[cfg(feature = "y2015")]
+    &year_2015::ThisYear,
+    #[cfg(feature = "y2016")]
+    &year_2016::ThisYear,
+    #[cfg(feature = "y2022")]
+    &year_2022::ThisYear,
+    #[cfg(feature = "y2023")]
+    &year_2023::ThisYear,
+];
+
+/// Looks up the crate of `year` if it is compiled in
+pub fn year(year: i32) -> Option<&'static dyn Year> {
+    YEARS.iter().copied().find(|y| y.year() == year)
+}
+
+/// Iterates all registered solutions ordered by year, day, and then part, including those
+/// registered with the `#[advent]` attribute. A part registered both ways keeps the one from
+/// `generate_year!`.
+pub fn solutions() -> impl Iterator<Item = Solution> {
+    let generated = YEARS.iter().flat_map(|y| {
+        let year = y.year();
+
+        y.solutions()
+            .iter()
+            .map(move |&(day, part, run, stream, params)| Solution {
+                year,
+                day,
+                part,
+                run,
+                stream,
+                params,
+            })
+    });
+
+    let annotated = annotated::parts().into_iter().map(|p| Solution {
+        year: p.year,
+        day: p.day,
+        part: p.part,
+        run: p.run,
+        stream: None,
+        params: &[],
+    });
+
+    let mut all = generated.chain(annotated).collect::<Vec<_>>();
+
+    // Stable, so a generated part stays ahead of an annotated one for the same puzzle
+    all.sort_by_key(|s| (s.year, s.day, s.part));
+    all.into_iter()
+}
+
+/// Iterates every year that has at least one registered solution
+pub fn years() -> impl Iterator<Item = i32> {
+    solutions().map(|s| s.year).dedup()
+}
+
+/// Gets the highest day with a registered solution for `year`, or 0 if there are none
+pub fn days_solved(year: i32) -> i32 {
+    solutions()
+        .filter(|s| s.year == year)
+        .map(|s| s.day)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Gets how many parts of `year`/`day` have a registered solution, such as 1 while only the first
+/// part of the latest day is done
+pub fn parts_solved(year: i32, day: i32) -> i32 {
+    let parts = solutions()
+        .filter(|s| (s.year, s.day) == (year, day))
+        .count();
+
+    i32::try_from(parts).expect("days have at most two parts")
+}
+
+/// Why [`run`] or [`run_both`] gave no answers, with each message as it is shown to the user
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    #[error("No solutions found for the year {0}")]
+    UnknownYear(i32),
+    #[error("No solution exists for day {day} of {year}")]
+    Unsolved { year: i32, day: i32 },
+    #[error("Only part 1 of day {day} of {year} is solved")]
+    OnlyPartOne { year: i32, day: i32 },
+    #[error("No input named '{name}' exists at {}", path.display())]
+    MissingNamedInput { name: String, path: PathBuf },
+    #[error("Could not solve {puzzle} as {} does not exist: {message}", path.display())]
+    MissingInput {
+        puzzle: String,
+        path: PathBuf,
+        message: String,
+    },
+    #[error("Could not solve {puzzle}: {message}")]
+    Unparsable {
+        puzzle: String,
+        path: PathBuf,
+        /// Line of the input that was rejected, when the day named one
+        line: Option<usize>,
+        message: String,
+    },
+    #[error("Could not solve {puzzle}: {message}")]
+    Failed { puzzle: String, message: String },
+    /// An input `lint-input` rejected without solving it
+    #[error("{} is malformed: {message}", path.display())]
+    Malformed {
+        path: PathBuf,
+        /// Line of the input that was rejected, when it is known
+        line: Option<usize>,
+        message: String,
+    },
+}
+
+impl RunError {
+    /// How the binary exits after this error
+    pub fn exit(&self) -> Exit {
+        match self {
+            Self::UnknownYear(_) | Self::Unsolved { .. } | Self::OnlyPartOne { .. } => {
+                Exit::Unknown
+            }
+            Self::MissingNamedInput { .. } | Self::MissingInput { .. } => Exit::MissingInput,
+            Self::Unparsable { .. } | Self::Failed { .. } | Self::Malformed { .. } => {
+                Exit::SolutionFailed
+            }
+        }
+    }
+
+    /// A name for the kind of error, for tools reading errors as JSON
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::UnknownYear(_) => "unknown_year",
+            Self::Unsolved { .. } => "unsolved",
+            Self::OnlyPartOne { .. } => "only_part_one",
+            Self::MissingNamedInput { .. } | Self::MissingInput { .. } => "missing_input",
+            Self::Unparsable { .. } | Self::Malformed { .. } => "parse_failed",
+            Self::Failed { .. } => "solution_failed",
+        }
+    }
+
+    /// The input the error is about, if any
+    pub fn file(&self) -> Option<&Path> {
+        match self {
+            Self::MissingNamedInput { path, .. }
+            | Self::MissingInput { path, .. }
+            | Self::Unparsable { path, .. }
+            | Self::Malformed { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// The line of [`RunError::file`] the error is about, if any
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Self::Unparsable { line, .. } | Self::Malformed { line, .. } => *line,
+            _ => None,
+        }
+    }
+
+    /// That nothing is registered for a part or day, mentioning when the day only has some of its
+    /// parts solved
+    fn unsolved(year: i32, day: i32) -> Self {
+        match parts_solved(year, day) {
+            1 if find(year, day, 1).is_some() => Self::OnlyPartOne { year, day },
+            _ => Self::Unsolved { year, day },
+        }
+    }
+
+    /// That `puzzle` failed with `error`, blamed on its input at `path` when the day was refused
+    /// for lacking it or could not parse it
+    fn failed(puzzle: String, path: &Path, error: impl Into<anyhow::Error>) -> Self {
+        let error = error.into();
+        let message = error.to_string();
+
+        if error.is::<InputRequired>() {
+            Self::MissingInput {
+                puzzle,
+                path: path.to_owned(),
+                message,
+            }
+        } else if let Some(parse) = error.downcast_ref::<ParseError>() {
+            Self::Unparsable {
+                puzzle,
+                path: path.to_owned(),
+                line: parse.line(),
+                message,
+            }
+        } else {
+            Self::Failed { puzzle, message }
+        }
+    }
+}
+
+/// Looks up the solution registered for the given puzzle part
+pub fn find(year: i32, day: i32, part: i32) -> Option<Solution> {
+    solutions().find(|s| (s.year, s.day, s.part) == (year, day, part))
+}
+
+/// Looks up the solver registered for both parts of the given day
+pub fn find_day(year: i32, day: i32) -> Option<DayFn> {
+    self::year(year)?
+        .days()
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, run)| run)
+}
+
+/// Opens the input at `path`, or `None` if there is no input file
+fn open_input(path: &Path) -> Option<BufReader<File>> {
+    File::open(path).map(BufReader::new).ok()
+}
+
+/// Prints one part's answer, starting a grid on its own line so its rows line up
+fn print_part(part: i32, answer: &Answer) {
+    match answer {
+        Answer::Grid(grid) => println!("Part {part}:\n{grid}"),
+        _ => println!("Part {part}: {answer}"),
+    }
+}
+
+/// Prints the answers to a run of `part`, or of both parts when `None`, each part's on its own
+/// line
+pub fn print_answers(part: Option<i32>, answers: &[Answer]) {
+    match part {
+        Some(_) => answers.iter().for_each(|answer| println!("{answer}")),
+        None => (1..)
+            .zip(answers)
+            .for_each(|(part, answer)| print_part(part, answer)),
+    }
+}
+
+/// Resolves the input for `year`/`day`, failing when a named input was requested but does not
+/// exist. A missing default input is left for the solution to reject, since some days carry their
+/// input in the source.
+fn requested_input(
+    year: i32,
+    day: i32,
+    data_dir: &Path,
+    name: Option<&str>,
+) -> Result<PathBuf, RunError> {
+    let path = resolve_input(year, day, data_dir, name);
+
+    match name {
+        Some(name) if !path.exists() => Err(RunError::MissingNamedInput {
+            name: name.to_owned(),
+            path,
+        }),
+        _ => Ok(path),
+    }
+}
+
+/// Solves the requested part from input already held in memory, for callers without file system
+/// access. An empty input is treated the same as a missing input file.
+pub fn solve(year: i32, day: i32, part: i32, input: &str) -> anyhow::Result<Answer> {
+    let solution = find(year, day, part)
+        .with_context(|| format!("no solution exists for day {day} part {part} of {year}"))?;
+
+    let mut reader = input.as_bytes();
+
+    if input.is_empty() {
+        (solution.run)(&mut context::Context::new(None))
+    } else {
+        (solution.run)(&mut context::Context::new(Some(&mut reader)))
+    }
+}
+
+/// Tells the user when `strategy` asks for streaming or an alternative implementation that the day
+/// does not have, so its input is collected for the main implementation instead
+fn warn_unsupported(year: i32, day: i32, strategy: Strategy) {
+    let streams = solutions().any(|s| (s.year, s.day) == (year, day) && s.stream.is_some());
+
+    match strategy {
+        Strategy::Streaming if !streams => {
+            eprintln!("Day {day} of {year} cannot be streamed, collecting its input instead")
+        }
+        Strategy::Alternative(name) if alternatives::find(year, day, name).is_none() => {
+            eprintln!("Day {day} of {year} has no '{name}' implementation, using the main one")
+        }
+        _ => {}
+    }
+}
+
+/// The context parts run from the command line are solved with, reporting anything they write
+/// to stderr so it stays apart from the answers on stdout
+fn cli_context(reader: Option<&mut dyn BufRead>) -> context::Context<'_> {
+    context::Context::new(reader).with_output(io::stderr())
+}
+
+/// Tells the user about parameters given with `--param` that no part of `year`/`day` reads, along
+/// with the ones it does
+fn warn_unknown_params(year: i32, day: i32) {
+    let known = solutions()
+        .filter(|s| (s.year, s.day) == (year, day))
+        .flat_map(|s| s.params)
+        .map(|p| p.name)
+        .sorted()
+        .dedup()
+        .collect::<Vec<_>>();
+
+    for name in context::param_names() {
+        if known.contains(&name.as_str()) {
+            continue;
+        }
+
+        if known.is_empty() {
+            eprintln!("Day {day} of {year} takes no parameters, ignoring '{name}'");
+        } else {
+            eprintln!(
+                "Day {day} of {year} has no parameter '{name}', only {}",
+                known.join(", ")
+            );
+        }
+    }
+}
+
+/// The message a panic was raised with, from the payload [`panic::catch_unwind`] caught
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|m| m.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "without a message".to_owned())
+}
+
+/// Runs `solve`, turning a panic into an error with its message so a day that panics fails the
+/// same way as one that returns an error
+fn catch_panics<T>(solve: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(solve)).unwrap_or_else(|payload| {
+        anyhow::bail!("the solution panicked: {}", panic_message(payload.as_ref()))
+    })
+}
+
+/// Answers given by a successful run and how long solving took
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    pub answers: Vec<Answer>,
+    pub elapsed: Duration,
+}
+
+/// Runs the requested part with its input loaded from `data_dir` (if one exists) using `strategy`,
+/// failing when nothing is registered for it or solving fails. `input_name` selects one of the
+/// day's alternate inputs instead of the default. The answer is returned along with its timing,
+/// ready for [`print_answers`]. With a `timeout` the part is given up on, and reported as such,
+/// once it has run for that long.
+pub fn run(
+    year: i32,
+    day: i32,
+    part: i32,
+    data_dir: &Path,
+    input_name: Option<&str>,
+    strategy: Strategy,
+    timeout: Option<Duration>,
+) -> Result<Outcome, RunError> {
+    if !years().any(|y| y == year) {
+        return Err(RunError::UnknownYear(year));
+    }
+
+    let Some(solution) = find(year, day, part) else {
+        return Err(RunError::unsolved(year, day));
+    };
+
+    warn_unsupported(year, day, strategy);
+    warn_unknown_params(year, day);
+
+    let path = requested_input(year, day, data_dir, input_name)?;
+    let input = path.clone();
+    let puzzle = || format!("day {day} part {part} of {year}");
+
+    let solved = cancel::run_with_timeout(timeout, move || {
+        let mut reader = open_input(&path);
+        let mut ctx = cli_context(reader.as_mut().map(|r| r as &mut dyn BufRead));
+        let start = Instant::now();
+
+        let answer = catch_panics(|| match alternatives::chosen(year, day, strategy) {
+            Some(alternative) => alternative(&mut ctx, part),
+            None => (solution.solver(strategy))(&mut ctx),
+        });
+
+        (answer, start.elapsed())
+    });
+
+    let (answer, elapsed) = solved.map_err(|e| RunError::failed(puzzle(), &input, e))?;
+    let answer = answer.map_err(|e| RunError::failed(puzzle(), &input, e))?;
+
+    Ok(Outcome {
+        answers: vec![answer],
+        elapsed,
+    })
+}
+
+/// Runs both parts of the requested day in the same manner as [`run`]. Collecting parses the
+/// input once for both parts, while streaming reads it once per part so nothing has to be kept
+/// between them, as do days registered a part at a time with the `#[advent]` attribute and
+/// alternatives chosen with [`Strategy::Alternative`]. Days in a year's `PARSE_CACHED` list reuse
+/// the parse from an earlier run on the same input. Both answers are returned with their combined
+/// timing when solving succeeds. A `timeout` covers both parts together.
+pub fn run_both(
+    year: i32,
+    day: i32,
+    data_dir: &Path,
+    input_name: Option<&str>,
+    strategy: Strategy,
+    timeout: Option<Duration>,
+) -> Result<Outcome, RunError> {
+    let runs = [1, 2].map(|part| find(year, day, part).map(|s| s.run));
+
+    if !years().any(|y| y == year) {
+        return Err(RunError::UnknownYear(year));
+    }
+
+    if find_day(year, day).is_none() && runs.iter().any(Option::is_none) {
+        return Err(RunError::unsolved(year, day));
+    }
+
+    warn_unsupported(year, day, strategy);
+    warn_unknown_params(year, day);
+
+    let path = requested_input(year, day, data_dir, input_name)?;
+    let input = path.clone();
+    let data_dir = data_dir.to_owned();
+    let puzzle = || format!("day {day} of {year}");
+
+    let solved = cancel::run_with_timeout(timeout, move || {
+        let start = Instant::now();
+        let streams = [1, 2].map(|part| find(year, day, part).and_then(|s| s.stream));
+
+        let separately = |solve: &EitherPart| {
+            let solve = |part| {
+                let mut reader = open_input(&path);
+                let mut ctx = cli_context(reader.as_mut().map(|r| r as &mut dyn BufRead));
+
+                solve(&mut ctx, part)
+            };
+
+            solve(1).and_then(|part_01| Ok([part_01, solve(2)?]))
+        };
+
+        let by_part = |solvers: [Option<PartFn>; 2]| {
+            move |ctx: &mut context::Context<'_>, part: i32| {
+                solvers[usize::try_from(part - 1).expect("parts start at 1")]
+                    .expect("both parts were found above")(ctx)
+            }
+        };
+
+        let alternative = alternatives::chosen(year, day, strategy);
+
+        let answers =
+            catch_panics(
+                || match (strategy, streams, find_day(year, day), alternative) {
+                    (_, _, _, Some(alternative)) => separately(&alternative),
+                    (Strategy::Streaming, [Some(_), Some(_)], _, _) => {
+                        separately(&by_part(streams))
+                    }
+                    // Days registered a part at a time with `#[advent]` have no parse to share
+                    (_, _, None, _) => separately(&by_part(runs)),
+                    (_, _, Some(solve), _) => {
+                        match (parsed_cache::find(year, day), std::fs::read(&path).ok()) {
+                            (Some(cached), Some(input)) => cached(
+                                &ParsedCache::in_data_dir(&data_dir),
+                                year,
+                                day,
+                                &input,
+                                &mut cli_context(None),
+                            ),
+                            _ => {
+                                let mut reader = open_input(&path);
+                                let mut ctx =
+                                    cli_context(reader.as_mut().map(|r| r as &mut dyn BufRead));
+
+                                solve(&mut ctx)
+                            }
+                        }
+                    }
+                },
+            );
+
+        (answers, start.elapsed())
+    });
+
+    let (answers, elapsed) = solved.map_err(|e| RunError::failed(puzzle(), &input, e))?;
+    let answers = answers.map_err(|e| RunError::failed(puzzle(), &input, e))?;
+
+    Ok(Outcome {
+        answers: answers.to_vec(),
+        elapsed,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use advent_macros::advent;
+
+    use super::*;
+    use crate::util::synth;
+
+    // Registered under a year no puzzles will ever have, with day 1 skipped
+
+    #[advent(year = 2099, day = 2)]
+    fn part1(input: &str) -> usize {
+        input.lines().count()
+    }
+
+    #[advent(year = 2099, day = 2)]
+    fn part_02(input: &str) -> anyhow::Result<i64> {
+        input
+            .lines()
+            .map(|l| Ok(l.parse::<i64>()?))
+            .sum::<anyhow::Result<i64>>()
+    }
+
+    #[advent(year = 2099, day = 4, part = 1)]
+    fn panics(_: &str) -> usize {
+        panic!("on purpose")
+    }
+
+    #[advent(year = 2099, day = 5, part = 1)]
+    fn longest_line(input: &str) -> String {
+        input
+            .lines()
+            .max_by_key(|l| l.len())
+            .unwrap_or("")
+            .to_owned()
+    }
+
+    #[test]
+    fn annotated_parts() {
+        let solve = |day, part, input: &[u8]| {
+            let solution = find(2099, day, part).unwrap();
+
+            (solution.run)(&mut context::Context::new(Some(&mut &input[..])))
+        };
+
+        assert_eq!(solve(2, 1, b"3\n4").unwrap(), Answer::Int(2));
+        assert_eq!(solve(2, 2, b"3\n4").unwrap(), Answer::Int(7));
+        assert_eq!(solve(5, 1, b"ab\nabc").unwrap(), Answer::from("abc"));
+        assert!(find(2099, 1, 1).is_none());
+        assert!(find_day(2099, 2).is_none());
+        assert_eq!(days_solved(2099), 5);
+        assert!(years().any(|y| y == 2099));
+
+        // Both parts are solved from their own read of the input
+        let data_dir = std::env::temp_dir().join(format!("annotated-{}", std::process::id()));
+
+        std::fs::create_dir_all(&data_dir).unwrap();
+        std::fs::write(input_path(2099, 2, &data_dir), "3\n4").unwrap();
+
+        let outcome = run_both(2099, 2, &data_dir, None, Default::default(), None).unwrap();
+
+        assert_eq!(outcome.answers, vec![Answer::Int(2), Answer::Int(7)]);
+        assert_eq!(
+            run_both(2099, 5, &data_dir, None, Default::default(), None),
+            Err(RunError::OnlyPartOne { year: 2099, day: 5 })
+        );
+
+        std::fs::remove_dir_all(&data_dir).unwrap();
+    }
+
+    #[test]
+    fn synthetic_inputs_are_solvable() {
+        // Generators for years that are not compiled in have nothing to solve their inputs
+        for generator in synth::GENERATORS.iter().filter(|g| year(g.year).is_some()) {
+            let mut input = Vec::new();
+
+            (generator.generate)(&mut input, 100, &mut synth::Rng::new(synth::DEFAULT_SEED))
+                .unwrap();
+
+            assert_eq!(input.iter().filter(|&&b| b == b'\n').count(), 100);
+
+            for part in [1, 2] {
+                let solution = find(generator.year, generator.day, part).unwrap();
+
+                assert!(
+                    (solution.run)(&mut context::Context::new(Some(&mut input.as_slice()))).is_ok(),
+                    "{}/{:02} part {part}",
+                    generator.year,
+                    generator.day
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn registry_matches_days_solved() {
+        type PartsOf = fn(i32) -> i32;
+
+        let expected: &[(i32, i32, PartsOf)] = &[
+            #[cfg(feature = "y2015")]
+            (2015, year_2015::days_solved(), year_2015::parts_solved),
+            #[cfg(feature = "y2016")]
+            (2016, year_2016::days_solved(), year_2016::parts_solved),
+            #[cfg(feature = "y2022")]
+            (2022, year_2022::days_solved(), year_2022::parts_solved),
+            #[cfg(feature = "y2023")]
+            (2023, year_2023::days_solved(), year_2023::parts_solved),
+        ];
+
+        for &(year, days, parts_of) in expected {
+            let parts = solutions().filter(|s| s.year == year).count();
+            let expected_parts = (1..=days).map(parts_of).sum::<i32>();
+
+            assert_eq!(parts, usize::try_from(expected_parts).unwrap());
+            assert_eq!(days_solved(year), days);
+
+            for day in 1..=days {
+                assert_eq!(parts_solved(year, day), parts_of(day));
+            }
+        }
+    }
+
+    #[test]
+    fn requested_inputs() {
+        let data_dir =
+            std::env::temp_dir().join(format!("requested-inputs-{}", std::process::id()));
+        let day_dir = input_dir(2015, 1, &data_dir);
+
+        std::fs::create_dir_all(&day_dir).unwrap();
+        std::fs::write(day_dir.join("input.txt"), "(").unwrap();
+
+        assert_eq!(
+            requested_input(2015, 1, &data_dir, Some("input")),
+            Ok(day_dir.join("input.txt"))
+        );
+        assert_eq!(
+            requested_input(2015, 1, &data_dir, Some("friend")).map_err(|e| e.exit()),
+            Err(Exit::MissingInput)
+        );
+
+        // A missing default input is left for the solution to reject
+        assert_eq!(
+            requested_input(2015, 2, &data_dir, None),
+            Ok(data_dir.join("2015-02.txt"))
+        );
+
+        std::fs::remove_dir_all(&data_dir).unwrap();
+    }
+
+    #[test]
+    fn panics_are_failures() {
+        let data_dir = std::env::temp_dir().join(format!("panics-{}", std::process::id()));
+
+        for timeout in [None, Some(Duration::from_secs(60))] {
+            let panicked =
+                run(2099, 4, 1, &data_dir, None, Default::default(), timeout).unwrap_err();
+
+            assert_eq!(panicked.exit(), Exit::SolutionFailed);
+            assert_eq!(
+                panicked.to_string(),
+                "Could not solve day 4 part 1 of 2099: the solution panicked: on purpose"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn timeouts_are_failures() {
+        let data_dir = std::env::temp_dir().join(format!("timeouts-{}", std::process::id()));
+        let limit = Some(Duration::from_millis(1));
+
+        // Day 4 carries its input in the source, so nothing is missing when it runs out of time
+        let timed_out = run(2015, 4, 2, &data_dir, None, Default::default(), limit).unwrap_err();
+
+        assert_eq!(
+            (timed_out.exit(), timed_out.kind()),
+            (Exit::SolutionFailed, "solution_failed")
+        );
+        assert_eq!(timed_out.file(), None);
+
+        let missing = run(2015, 1, 1, &data_dir, None, Default::default(), None).unwrap_err();
+
+        assert_eq!(
+            (missing.exit(), missing.file()),
+            (
+                Exit::MissingInput,
+                Some(input_path(2015, 1, &data_dir).as_path())
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn count_parts() {
+        assert_eq!(parts_solved(2015, 1), 2);
+        assert_eq!(parts_solved(2015, 26), 0);
+        assert_eq!(parts_solved(2023, 0), 0);
+        assert_eq!(year_2015::parts_solved(25), 2);
+        assert_eq!(year_2015::parts_solved(26), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn solve_registered() {
+        let solution = find(2015, 1, 1).unwrap();
+
+        assert_eq!(
+            (solution.run)(&mut context::Context::new(Some(&mut b"(()(()(".as_slice()))).unwrap(),
+            Answer::Int(3)
+        );
+
+        // Days with their input embedded in the source run without a reader
+        assert!(
+            find(2015, 10, 1).is_some_and(|s| (s.run)(&mut context::Context::new(None)).is_ok())
+        );
+        assert!((solution.run)(&mut context::Context::new(None)).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn solve_day_once() {
+        let solve = find_day(2015, 1).unwrap();
+
+        assert_eq!(
+            solve(&mut context::Context::new(Some(&mut b"())".as_slice()))).unwrap(),
+            [Answer::Int(-1), Answer::Int(3)]
+        );
+
+        assert!(find_day(2015, 26).is_none());
+        assert!(find_day(1999, 1).is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "y2015", feature = "y2022"))]
+    fn streaming_matches_collecting() {
+        let streamed = solutions()
+            .filter(|s| s.stream.is_some())
+            .collect::<Vec<_>>();
+
+        assert!(streamed.iter().any(|s| (s.year, s.day) == (2022, 1)));
+        assert!(find(2015, 10, 1).is_some_and(|s| s.stream.is_none()));
+
+        for solution in streamed {
+            let Ok(data) = std::fs::read(solution.data_path()) else {
+                continue;
+            };
+
+            let stream = solution.solver(Strategy::Streaming);
+
+            assert_eq!(
+                stream(&mut context::Context::new(Some(&mut data.as_slice()))).unwrap(),
+                (solution.run)(&mut context::Context::new(Some(&mut data.as_slice()))).unwrap(),
+                "day {} part {} of {}",
+                solution.day,
+                solution.part,
+                solution.year
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn solve_in_memory() {
+        assert_eq!(solve(2015, 1, 2, "())").unwrap(), Answer::Int(3));
+        assert!(solve(2015, 10, 1, "").is_ok());
+        assert!(solve(2015, 1, 1, "").is_err());
+        assert!(solve(1999, 1, 1, "(").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn solve_through_entry() {
+        use crate::abi::{Sink, Status, ABI_VERSION};
+
+        let entry = |day, part, input: &[u8]| {
+            let mut out = String::new();
+            // SAFETY: the input is a live slice and `out` outlives the call
+            let status = unsafe {
+                year_2015::advent_entry_2015(
+                    ABI_VERSION,
+                    day,
+                    part,
+                    input.as_ptr(),
+                    input.len(),
+                    Sink::appending(&mut out),
+                )
+            };
+
+            (Status::from_raw(status), out)
+        };
+
+        assert_eq!(entry(1, 2, b"())"), (Some(Status::Solved), "3".to_owned()));
+        assert_eq!(entry(26, 1, b"").0, Some(Status::Unsolved));
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn find_registered() {
+        assert!(find(2015, 1, 1).is_some());
+        assert!(find(2015, 1, 3).is_none());
+        assert!(find(1999, 1, 1).is_none());
+    }
+}
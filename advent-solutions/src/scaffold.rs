@@ -0,0 +1,442 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day_XX.tpl.rs");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year_lib.tpl.rs");
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/year_manifest.tpl.toml");
+
+/// Errors that can occur while generating source files for a new day
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("could not access {}: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("could not find where to register the new year in {}", path.display())]
+    Unrecognized { path: PathBuf },
+}
+
+/// The workspace holding this crate, which is where each year's crate is scaffolded
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("advent-solutions should be inside the workspace")
+        .to_owned()
+}
+
+/// Directory of the crate holding `year`'s solutions within `workspace`
+fn year_crate_dir(workspace: &Path, year: i32) -> PathBuf {
+    workspace.join(format!("advent-{year}"))
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+/// Rewrites the `generate_year!([VIS] YEAR N[, TEMPLATE][; last_day_parts = P])` invocation in
+/// `source` so that it covers at least `day`, keeping everything else as it is. Returns `None` if
+/// no invocation is present.
+fn bump_max_day(source: &str, day: i32) -> Option<String> {
+    let start = source.find("generate_year!(")? + "generate_year!(".len();
+    // The day comes right before any template or part count, and a visibility like
+    // `pub(crate)` has parentheses of its own
+    let close = start + source[start..].find(");")?;
+    let end = source[start..close]
+        .find([',', ';'])
+        .map_or(close, |c| start + c);
+    let args = &source[start..end];
+    let max_day_at = args.trim_end().rfind(char::is_whitespace)? + 1;
+    let max_day = args[max_day_at..].trim_end().parse::<i32>().ok()?;
+
+    Some(format!(
+        "{}{}{}{}",
+        &source[..start],
+        &args[..max_day_at],
+        max_day.max(day),
+        &source[end..]
+    ))
+}
+
+/// Adds `line` to the block of consecutive lines starting with `prefix` (keeping it sorted) if it is
+/// not already present, preceded by `attribute` at the same indentation when given. Attributes
+/// already in the block stay with the line they come before. Returns `None` if no such block
+/// exists.
+fn insert_sorted_line(
+    source: &str,
+    prefix: &str,
+    line: &str,
+    attribute: Option<&str>,
+) -> Option<String> {
+    let mut lines = source.lines().collect::<Vec<_>>();
+    let is_attribute = |l: &str| l.trim_start().starts_with("#[");
+    let mut end = lines.iter().position(|l| l.starts_with(prefix))?;
+
+    while end > 0 && is_attribute(lines[end - 1]) {
+        end -= 1;
+    }
+
+    let mut at = None;
+
+    // Each entry is a line with the prefix and any attributes right before it
+    loop {
+        let entry = end + lines[end..].iter().take_while(|l| is_attribute(l)).count();
+
+        match lines.get(entry) {
+            Some(&l) if l.starts_with(prefix) => {
+                if l == line {
+                    at = Some(None);
+                } else if at.is_none() && l > line {
+                    at = Some(Some(end));
+                }
+
+                end = entry + 1;
+            }
+            _ => break,
+        }
+    }
+
+    let inserted = match attribute {
+        Some(attribute) => {
+            let indent = &line[..line.len() - line.trim_start().len()];
+
+            format!("{indent}{attribute}\n{line}")
+        }
+        None => line.to_owned(),
+    };
+
+    if let Some(at) = at.unwrap_or(Some(end)) {
+        lines.insert(at, &inserted);
+    }
+
+    let mut out = lines.join("\n");
+
+    out.push('\n');
+
+    Some(out)
+}
+
+/// Name of the cargo feature that compiles `year`
+fn year_feature(year: i32) -> String {
+    format!("y{year}")
+}
+
+/// Adds an optional dependency on `year`'s crate to `manifest` along with a feature enabling it,
+/// which is also added to the `all-years` feature that enables every year. Returns `None` if the
+/// other years' dependencies or features are missing.
+fn add_year_feature(manifest: &str, year: i32) -> Option<String> {
+    let feature = year_feature(year);
+    let manifest = insert_sorted_line(
+        manifest,
+        "advent-20",
+        &format!("advent-{year} = {{ path = \"../advent-{year}\", optional = true }}"),
+        None,
+    )?;
+    let manifest = insert_sorted_line(
+        &manifest,
+        "y20",
+        &format!("{feature} = [\"dep:advent-{year}\"]"),
+        None,
+    )?;
+    let mut lines = manifest.lines().map(str::to_owned).collect::<Vec<_>>();
+    let all_years = lines.iter_mut().find(|l| l.starts_with("all-years = ["))?;
+    let mut years = all_years
+        .strip_prefix("all-years = [")?
+        .strip_suffix(']')?
+        .split(',')
+        .map(|y| y.trim().to_owned())
+        .filter(|y| !y.is_empty())
+        .collect::<Vec<_>>();
+
+    years.push(format!("\"{feature}\""));
+    years.sort();
+    years.dedup();
+
+    *all_years = format!("all-years = [{}]", years.join(", "));
+
+    let mut out = lines.join("\n");
+
+    out.push('\n');
+
+    Some(out)
+}
+
+/// Makes a brand new year crate a member of the workspace and visible to this crate's library and
+/// registry, behind a feature of its own that is enabled by default
+fn register_year(workspace: &Path, year: i32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let solutions_dir = workspace.join("advent-solutions");
+    let workspace_path = workspace.join("Cargo.toml");
+    let manifest_path = solutions_dir.join("Cargo.toml");
+    let lib_path = solutions_dir.join("src").join("lib.rs");
+    let registry_path = solutions_dir.join("src").join("registry.rs");
+    let gate = format!("#[cfg(feature = \"{}\")]", year_feature(year));
+    let gate = Some(gate.as_str());
+    let unrecognized = |path: &Path| ScaffoldError::Unrecognized {
+        path: path.to_owned(),
+    };
+
+    let members = insert_sorted_line(
+        &read(&workspace_path)?,
+        "    \"advent-20",
+        &format!("    \"advent-{year}\","),
+        None,
+    )
+    .ok_or_else(|| unrecognized(&workspace_path))?;
+
+    let manifest = add_year_feature(&read(&manifest_path)?, year)
+        .ok_or_else(|| unrecognized(&manifest_path))?;
+
+    let lib = insert_sorted_line(
+        &read(&lib_path)?,
+        "pub use advent_20",
+        &format!("pub use advent_{year} as year_{year};"),
+        gate,
+    )
+    .ok_or_else(|| unrecognized(&lib_path))?;
+
+    let registry = read(&registry_path)?;
+    let registry = insert_sorted_line(
+        &registry,
+        "    &year_20",
+        &format!("    &year_{year}::ThisYear,"),
+        gate,
+    )
+    .and_then(|r| {
+        insert_sorted_line(
+            &r,
+            "use crate::year_",
+            &format!("use crate::year_{year};"),
+            gate,
+        )
+    })
+    .ok_or_else(|| unrecognized(&registry_path))?;
+
+    write(&workspace_path, &members)?;
+    write(&manifest_path, &manifest)?;
+    write(&lib_path, &lib)?;
+    write(&registry_path, &registry)?;
+
+    Ok(vec![workspace_path, manifest_path, lib_path, registry_path])
+}
+
+/// Creates the module for `year`/`day` from the templates (along with any earlier days that are
+/// missing, since `generate_year!` expects a contiguous range) and extends `generate_year!` to
+/// include it. A year without a crate in `workspace` gets one. Returns every file created or
+/// modified.
+pub fn scaffold_day(workspace: &Path, year: i32, day: i32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let crate_dir = year_crate_dir(workspace, year);
+    let src_dir = crate_dir.join("src");
+    let lib_path = src_dir.join("lib.rs");
+    let mut touched = Vec::new();
+
+    if !lib_path.exists() {
+        fs::create_dir_all(&src_dir).map_err(|source| ScaffoldError::Io {
+            path: src_dir.clone(),
+            source,
+        })?;
+
+        let manifest_path = crate_dir.join("Cargo.toml");
+
+        write(
+            &manifest_path,
+            &MANIFEST_TEMPLATE.replace("YEAR", &year.to_string()),
+        )?;
+        write(
+            &lib_path,
+            &YEAR_TEMPLATE
+                .replace("YEAR", &year.to_string())
+                .replace("MAX_DAY", &day.to_string()),
+        )?;
+
+        touched.push(manifest_path);
+        touched.push(lib_path.clone());
+        touched.extend(register_year(workspace, year)?);
+    } else {
+        let source = read(&lib_path)?;
+        let bumped = bump_max_day(&source, day).ok_or_else(|| ScaffoldError::Unrecognized {
+            path: lib_path.clone(),
+        })?;
+
+        if bumped != source {
+            write(&lib_path, &bumped)?;
+            touched.push(lib_path);
+        }
+    }
+
+    for d in 1..=day {
+        let day_path = src_dir.join(format!("day_{d:02}.rs"));
+
+        if !day_path.exists() {
+            write(&day_path, &DAY_TEMPLATE.replace("DAY", &d.to_string()))?;
+            touched.push(day_path);
+        }
+    }
+
+    Ok(touched)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bump_generate_year() {
+        let src = "use advent_macros::generate_year;\n\ngenerate_year!(2022 8);\n";
+
+        assert_eq!(
+            bump_max_day(src, 9).unwrap(),
+            "use advent_macros::generate_year;\n\ngenerate_year!(2022 9);\n"
+        );
+
+        assert_eq!(bump_max_day(src, 3).unwrap(), src);
+
+        let public = "generate_year!(pub(crate) 2022 8);\n";
+
+        assert_eq!(
+            bump_max_day(public, 12).unwrap(),
+            "generate_year!(pub(crate) 2022 12);\n"
+        );
+
+        let templated = "generate_year!(2022 8, \"inputs/{year}/day{day}.txt\");\n";
+
+        assert_eq!(
+            bump_max_day(templated, 9).unwrap(),
+            "generate_year!(2022 9, \"inputs/{year}/day{day}.txt\");\n"
+        );
+
+        assert_eq!(
+            bump_max_day("generate_year!(2023 5; last_day_parts = 1);", 6).unwrap(),
+            "generate_year!(2023 6; last_day_parts = 1);"
+        );
+        assert_eq!(bump_max_day("mod day_01;", 3), None);
+    }
+
+    #[test]
+    fn insert_lines_sorted() {
+        let src = "pub mod registry;\npub mod year_2015;\npub mod year_2022;\n";
+
+        assert_eq!(
+            insert_sorted_line(src, "pub mod year_", "pub mod year_2016;", None).unwrap(),
+            "pub mod registry;\npub mod year_2015;\npub mod year_2016;\npub mod year_2022;\n"
+        );
+
+        assert_eq!(
+            insert_sorted_line(src, "pub mod year_", "pub mod year_2022;", None).unwrap(),
+            src
+        );
+
+        assert_eq!(insert_sorted_line(src, "use ", "use x;", None), None);
+
+        let gated =
+            "const YEARS = &[\n    #[cfg(a)]\n    (2015, x),\n    #[cfg(b)]\n    (2022, y),\n];\n";
+
+        assert_eq!(
+            insert_sorted_line(gated, "    (20", "    (2016, z),", Some("#[cfg(c)]")).unwrap(),
+            "const YEARS = &[\n    #[cfg(a)]\n    (2015, x),\n    #[cfg(c)]\n    (2016, z),\n    #[cfg(b)]\n    (2022, y),\n];\n"
+        );
+        assert_eq!(
+            insert_sorted_line(gated, "    (20", "    (2023, z),", Some("#[cfg(c)]")).unwrap(),
+            "const YEARS = &[\n    #[cfg(a)]\n    (2015, x),\n    #[cfg(b)]\n    (2022, y),\n    #[cfg(c)]\n    (2023, z),\n];\n"
+        );
+        assert_eq!(
+            insert_sorted_line(gated, "    (20", "    (2015, x),", Some("#[cfg(c)]")).unwrap(),
+            gated
+        );
+    }
+
+    #[test]
+    fn year_features() {
+        let manifest = "[dependencies]\nadvent-2015 = { path = \"../advent-2015\", optional = true }\nadvent-2022 = { path = \"../advent-2022\", optional = true }\n\n[features]\ndefault = [\"all-years\"]\nall-years = [\"y2015\", \"y2022\"]\ny2015 = [\"dep:advent-2015\"]\ny2022 = [\"dep:advent-2022\"]\n";
+
+        assert_eq!(
+            add_year_feature(manifest, 2016).unwrap(),
+            "[dependencies]\nadvent-2015 = { path = \"../advent-2015\", optional = true }\nadvent-2016 = { path = \"../advent-2016\", optional = true }\nadvent-2022 = { path = \"../advent-2022\", optional = true }\n\n[features]\ndefault = [\"all-years\"]\nall-years = [\"y2015\", \"y2016\", \"y2022\"]\ny2015 = [\"dep:advent-2015\"]\ny2016 = [\"dep:advent-2016\"]\ny2022 = [\"dep:advent-2022\"]\n"
+        );
+        assert_eq!(add_year_feature(manifest, 2022).unwrap(), manifest);
+        assert_eq!(add_year_feature("[features]\ny2015 = []\n", 2016), None);
+    }
+
+    #[test]
+    fn scaffold_new_year() {
+        let root = std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        let solutions = root.join("advent-solutions");
+        let src_dir = solutions.join("src");
+
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"advent-solutions\",\n    \"advent-2015\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            solutions.join("Cargo.toml"),
+            "[dependencies]\nadvent-2015 = { path = \"../advent-2015\", optional = true }\n\n[features]\nall-years = [\"y2015\"]\ny2015 = [\"dep:advent-2015\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            src_dir.join("lib.rs"),
+            "#[cfg(feature = \"y2015\")]\npub use advent_2015 as year_2015;\n",
+        )
+        .unwrap();
+        fs::write(
+            src_dir.join("registry.rs"),
+            "#[cfg(feature = \"y2015\")]\nuse crate::year_2015;\n\npub const YEARS: &[&dyn Year] = &[\n    #[cfg(feature = \"y2015\")]\n    &year_2015::ThisYear,\n];\n",
+        )
+        .unwrap();
+
+        let touched = scaffold_day(&root, 2016, 2).unwrap();
+        let year_dir = root.join("advent-2016");
+
+        assert_eq!(touched.len(), 8);
+        assert!(year_dir.join("src/day_01.rs").exists());
+        assert!(year_dir.join("src/day_02.rs").exists());
+
+        assert_eq!(
+            fs::read_to_string(year_dir.join("src/lib.rs")).unwrap(),
+            "//! Solutions to the 2016 puzzles.\n\nuse advent_macros::generate_year;\n\ngenerate_year!(2016 2);\n"
+        );
+        assert!(fs::read_to_string(year_dir.join("Cargo.toml"))
+            .unwrap()
+            .starts_with("[package]\nname = \"advent-2016\"\n"));
+
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\n    \"advent-solutions\",\n    \"advent-2015\",\n    \"advent-2016\",\n]\n"
+        );
+
+        assert!(fs::read_to_string(src_dir.join("lib.rs")).unwrap().ends_with(
+            "pub use advent_2015 as year_2015;\n#[cfg(feature = \"y2016\")]\npub use advent_2016 as year_2016;\n"
+        ));
+
+        assert!(fs::read_to_string(src_dir.join("registry.rs"))
+            .unwrap()
+            .contains("#[cfg(feature = \"y2016\")]\nuse crate::year_2016;\n\npub const YEARS: &[&dyn Year] = &[\n    #[cfg(feature = \"y2015\")]\n    &year_2015::ThisYear,\n    #[cfg(feature = \"y2016\")]\n    &year_2016::ThisYear,\n];"));
+
+        assert_eq!(
+            fs::read_to_string(solutions.join("Cargo.toml")).unwrap(),
+            "[dependencies]\nadvent-2015 = { path = \"../advent-2015\", optional = true }\nadvent-2016 = { path = \"../advent-2016\", optional = true }\n\n[features]\nall-years = [\"y2015\", \"y2016\"]\ny2015 = [\"dep:advent-2015\"]\ny2016 = [\"dep:advent-2016\"]\n"
+        );
+        assert!(fs::read_to_string(year_dir.join("src/day_02.rs"))
+            .unwrap()
+            .contains("title: \"Day 2\""));
+
+        // Further days only touch the new day and the year's crate root
+        assert_eq!(scaffold_day(&root, 2016, 3).unwrap().len(), 2);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+use thiserror::Error;
+
+/// Puzzles unlock at midnight US Eastern time. December never observes daylight saving time, so a
+/// fixed UTC-5 offset is exact for every puzzle.
+const UNLOCK_OFFSET_SECS: i32 = -5 * 60 * 60;
+
+/// Errors for puzzle dates that Advent of Code never published
+#[derive(Error, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScheduleError {
+    #[error("Advent of Code started in 2015, there is no {0} event")]
+    NoSuchYear(i32),
+    #[error("the {year} event has days 1 through {last}, not day {day}")]
+    NoSuchDay { year: i32, day: i32, last: i32 },
+}
+
+/// Number of puzzles in the event for `year`. Starting with 2025 the calendar was shortened from
+/// 25 to 12 days.
+pub fn days_in_event(year: i32) -> i32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// The instant the puzzle for `year`/`day` becomes available
+pub fn unlock_time(year: i32, day: i32) -> Result<DateTime<Utc>, ScheduleError> {
+    if year < 2015 {
+        return Err(ScheduleError::NoSuchYear(year));
+    }
+
+    let last = days_in_event(year);
+
+    let date = u32::try_from(day)
+        .ok()
+        .filter(|_| (1..=last).contains(&day))
+        .and_then(|d| NaiveDate::from_ymd_opt(year, 12, d))
+        .ok_or(ScheduleError::NoSuchDay { year, day, last })?;
+
+    let eastern = FixedOffset::east_opt(UNLOCK_OFFSET_SECS).expect("offset should be in range");
+
+    Ok(eastern
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).expect("midnight should be valid"))
+        .single()
+        .expect("fixed offsets have exactly one mapping")
+        .with_timezone(&Utc))
+}
+
+/// The most recent event whose first puzzle had unlocked at `now`
+pub fn latest_event(now: DateTime<Utc>) -> i32 {
+    let year = now.year();
+
+    match unlock_time(year, 1) {
+        Ok(unlock) if unlock <= now => year,
+        _ => year - 1,
+    }
+}
+
+/// Time remaining from `now` until `unlock`, or `None` if the puzzle is already available
+pub fn time_until(unlock: DateTime<Utc>, now: DateTime<Utc>) -> Option<Duration> {
+    (unlock - now).to_std().ok().filter(|d| !d.is_zero())
+}
+
+/// Formats a countdown as `[Dd ]HH:MM:SS`, rounding partial seconds up so zero is only shown once
+/// the time has actually elapsed.
+pub fn format_countdown(remaining: Duration) -> String {
+    let total = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (days, rem) = (total / 86_400, total % 86_400);
+    let clock = format!("{:02}:{:02}:{:02}", rem / 3600, rem % 3600 / 60, rem % 60);
+
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+/// Blocks until `unlock`, redrawing a single-line countdown on stdout once per second
+pub fn wait_until(unlock: DateTime<Utc>) {
+    let mut stdout = io::stdout();
+
+    while let Some(remaining) = time_until(unlock, Utc::now()) {
+        // Errors writing the countdown are cosmetic and should not abort the wait
+        let _ = write!(stdout, "\rUnlocks in {} ", format_countdown(remaining));
+        let _ = stdout.flush();
+
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\rUnlocked!{:16}", "");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unlock_is_midnight_eastern() {
+        let unlock = unlock_time(2024, 5).unwrap();
+
+        assert_eq!(unlock.to_rfc3339(), "2024-12-05T05:00:00+00:00");
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert_eq!(unlock_time(2014, 1), Err(ScheduleError::NoSuchYear(2014)));
+
+        assert_eq!(
+            unlock_time(2022, 26),
+            Err(ScheduleError::NoSuchDay {
+                year: 2022,
+                day: 26,
+                last: 25
+            })
+        );
+
+        assert!(unlock_time(2022, 0).is_err());
+        assert!(unlock_time(2025, 13).is_err());
+        assert!(unlock_time(2025, 12).is_ok());
+    }
+
+    #[test]
+    fn remaining_time() {
+        let unlock = unlock_time(2023, 1).unwrap();
+        let before = unlock - chrono::Duration::seconds(90);
+
+        assert_eq!(time_until(unlock, before), Some(Duration::from_secs(90)));
+        assert_eq!(time_until(unlock, unlock), None);
+        assert_eq!(time_until(before, unlock), None);
+    }
+
+    #[test]
+    fn latest_events() {
+        let unlock = unlock_time(2023, 1).unwrap();
+
+        assert_eq!(latest_event(unlock), 2023);
+        assert_eq!(latest_event(unlock - chrono::Duration::seconds(1)), 2022);
+        assert_eq!(latest_event(unlock_time(2023, 25).unwrap()), 2023);
+    }
+
+    #[test]
+    fn countdown_format() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(format_countdown(Duration::from_millis(1500)), "00:00:02");
+        assert_eq!(
+            format_countdown(Duration::from_secs(3 * 86_400 + 3661)),
+            "3d 01:01:01"
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! A static HTML index of every solved puzzle for the `generate-docs` command. Each day is listed
+//! by year with its title and what it is about from its [`Description`], how long each part took
+//! from a [`bench::Report`], and links to the puzzle and to the day's source. Answers are left
+//! out unless asked for, since the site asks that they not be published. The page is a single
+//! self-contained file, so it can be opened locally or published as it is.
+
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+
+use crate::{
+    bench::{self, PartTiming},
+    describe::{self, Description},
+};
+
+/// Runs per part when no count is given, as a single run is enough to show roughly how long a
+/// part takes
+pub const DEFAULT_RUNS: usize = 1;
+
+/// Where source links point when no other location is given: the workspace holding every year
+/// crate on the machine it was built on
+pub const LOCAL_SOURCE: &str = concat!("file://", env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Name of the page written to the output directory
+pub const INDEX: &str = "index.html";
+
+const STYLE: &str = "body{font-family:sans-serif;max-width:60em;margin:2em auto;padding:0 1em}\
+table{border-collapse:collapse;width:100%}th,td{border-bottom:1px solid #ccc;padding:.3em .6em;\
+text-align:left;vertical-align:top}td.time{text-align:right;white-space:nowrap}\
+p.about{margin:.2em 0 0;color:#555;font-size:.9em}pre{margin:0}";
+
+/// What is shown beyond each day's title and timings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SiteOptions {
+    /// Whether each part's answer is shown
+    pub answers: bool,
+    /// Prefix of links to each day's source, to which `/advent-YYYY/src/day_DD.rs` is added
+    pub source_url: String,
+}
+
+impl Default for SiteOptions {
+    fn default() -> Self {
+        Self {
+            answers: false,
+            source_url: LOCAL_SOURCE.to_owned(),
+        }
+    }
+}
+
+/// One solved day and the timing of each of its parts
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayEntry {
+    pub year: i32,
+    pub day: i32,
+    /// Missing for days registered a part at a time with `#[advent]`
+    pub description: Option<&'static Description>,
+    pub parts: Vec<PartTiming>,
+}
+
+impl DayEntry {
+    /// The puzzle's page on the Advent of Code site
+    pub fn url(&self) -> String {
+        format!("https://adventofcode.com/{}/day/{}", self.year, self.day)
+    }
+
+    /// Where the day's source is, for days laid out as a `day_##` module of their year's crate
+    pub fn source(&self, source_url: &str) -> Option<String> {
+        self.description.map(|_| {
+            format!(
+                "{}/advent-{}/src/day_{:02}.rs",
+                source_url.trim_end_matches('/'),
+                self.year,
+                self.day
+            )
+        })
+    }
+}
+
+/// Every day timed in a [`bench::Report`], ready to be written out as a page
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Site {
+    pub runs: usize,
+    pub days: Vec<DayEntry>,
+}
+
+impl Site {
+    /// Groups the parts timed in `report` by day, ordered by year and then day as they were timed
+    pub fn from_report(report: &bench::Report) -> Self {
+        let mut days = Vec::<DayEntry>::new();
+
+        for timing in &report.parts {
+            match days.last_mut() {
+                Some(entry) if (entry.year, entry.day) == (timing.year, timing.day) => {
+                    entry.parts.push(timing.clone());
+                }
+                _ => days.push(DayEntry {
+                    year: timing.year,
+                    day: timing.day,
+                    description: describe::find(timing.year, timing.day),
+                    parts: vec![timing.clone()],
+                }),
+            }
+        }
+
+        Self {
+            runs: report.runs,
+            days,
+        }
+    }
+
+    /// The page listing every day, with a table for each year
+    pub fn render(&self, options: &SiteOptions) -> String {
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Advent of Code solutions</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+             <h1>Advent of Code solutions</h1>\n<p>{} days solved. Times are the median of {} \
+             run{} per part.</p>\n",
+            self.days.len(),
+            self.runs,
+            if self.runs == 1 { "" } else { "s" }
+        );
+
+        for (year, days) in &self.days.iter().group_by(|d| d.year) {
+            let _ = write!(
+                html,
+                "<h2 id=\"{year}\">{year}</h2>\n<table>\n<tr><th>Day</th><th>Puzzle</th>\
+                 <th>Part</th><th>Time</th>{}<th>Source</th></tr>\n",
+                if options.answers {
+                    "<th>Answer</th>"
+                } else {
+                    ""
+                }
+            );
+
+            for entry in days {
+                render_day(&mut html, entry, options);
+            }
+
+            html.push_str("</table>\n");
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Writes the page to [`INDEX`] in `dir`, creating the directory if needed, and returns where
+    /// it was written
+    pub fn write(&self, dir: &Path, options: &SiteOptions) -> io::Result<PathBuf> {
+        let path = dir.join(INDEX);
+
+        fs::create_dir_all(dir)?;
+        fs::write(&path, self.render(options))?;
+
+        Ok(path)
+    }
+}
+
+/// Adds a row for each part of `entry`, with the day's details spanning all of them
+fn render_day(html: &mut String, entry: &DayEntry, options: &SiteOptions) {
+    let rows = entry.parts.len();
+
+    for (i, timing) in entry.parts.iter().enumerate() {
+        html.push_str("<tr>");
+
+        if i == 0 {
+            let title = entry
+                .description
+                .map_or_else(|| format!("Day {}", entry.day), |d| d.info.title.to_owned());
+            let about = entry
+                .description
+                .and_then(|d| d.doc.split("\n\n").next())
+                .filter(|about| !about.is_empty())
+                .map_or(String::new(), |about| {
+                    format!("<p class=\"about\">{}</p>", escape(about))
+                });
+            let source = entry
+                .source(&options.source_url)
+                .map_or(String::new(), |s| {
+                    format!("<a href=\"{}\">day_{:02}.rs</a>", escape(&s), entry.day)
+                });
+
+            let _ = write!(
+                html,
+                "<td rowspan=\"{rows}\">{}</td><td rowspan=\"{rows}\"><a href=\"{}\">{}</a>{about}</td>",
+                entry.day,
+                entry.url(),
+                escape(&title),
+            );
+
+            let _ = write!(
+                html,
+                "<td>{}</td>{}<td rowspan=\"{rows}\">{source}</td>",
+                timing.part,
+                part_cells(timing, options)
+            );
+        } else {
+            let _ = write!(
+                html,
+                "<td>{}</td>{}",
+                timing.part,
+                part_cells(timing, options)
+            );
+        }
+
+        html.push_str("</tr>\n");
+    }
+}
+
+/// The time taken by a part and, when shown, its answer
+fn part_cells(timing: &PartTiming, options: &SiteOptions) -> String {
+    let time = match &timing.answer {
+        Ok(_) => format!("{:.2?}", timing.median()),
+        Err(_) => "failed".to_owned(),
+    };
+
+    let mut cells = format!("<td class=\"time\">{time}</td>");
+
+    if options.answers {
+        let answer = match &timing.answer {
+            Ok(answer) if answer.contains('\n') => format!("<pre>{}</pre>", escape(answer)),
+            Ok(answer) => escape(answer),
+            Err(e) => escape(e),
+        };
+
+        let _ = write!(cells, "<td>{answer}</td>");
+    }
+
+    cells
+}
+
+/// `text` with the characters HTML gives meaning to replaced by their entities
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(all(test, feature = "y2022"))]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn timing(year: i32, day: i32, part: i32, answer: Result<&str, &str>) -> PartTiming {
+        PartTiming {
+            year,
+            day,
+            part,
+            answer: answer.map(str::to_owned).map_err(str::to_owned),
+            times: vec![Duration::from_micros(1500)],
+        }
+    }
+
+    fn site() -> Site {
+        Site::from_report(&bench::Report {
+            runs: 1,
+            parts: vec![
+                timing(2015, 1, 1, Ok("232")),
+                timing(2015, 1, 2, Ok("1783")),
+                timing(2022, 8, 1, Err("no <input>")),
+                timing(2022, 8, 2, Ok("8")),
+                timing(2099, 5, 1, Ok("#.\n.#")),
+            ],
+        })
+    }
+
+    #[test]
+    fn groups_parts_by_day() {
+        let site = site();
+
+        assert_eq!(
+            site.days
+                .iter()
+                .map(|d| (d.year, d.day, d.parts.len()))
+                .collect::<Vec<_>>(),
+            [(2015, 1, 2), (2022, 8, 2), (2099, 5, 1)]
+        );
+        assert_eq!(
+            site.days[1]
+                .source("https://example.com/tree/main/")
+                .as_deref(),
+            Some("https://example.com/tree/main/advent-2022/src/day_08.rs")
+        );
+        assert_eq!(site.days[2].source("."), None);
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn answers_only_when_asked() {
+        let site = site();
+        let hidden = site.render(&SiteOptions::default());
+
+        assert!(hidden.contains("<h2 id=\"2015\">2015</h2>"));
+        assert!(hidden.contains("Treetop Tree House"));
+        assert!(hidden.contains("<td class=\"time\">failed</td>"));
+        assert!(hidden.contains("1.50ms"));
+        assert!(!hidden.contains("1783"));
+
+        let shown = site.render(&SiteOptions {
+            answers: true,
+            source_url: ".".to_owned(),
+        });
+
+        assert!(shown.contains("<td>1783</td>"));
+        assert!(shown.contains("no &lt;input&gt;"));
+        assert!(shown.contains("<pre>#.\n.#</pre>"));
+        assert!(shown.contains("<a href=\"./advent-2015/src/day_01.rs\">day_01.rs</a>"));
+    }
+}
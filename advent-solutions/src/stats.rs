@@ -0,0 +1,214 @@
+//! Progress through each event: the stars earned according to the Advent of Code calendar, or
+//! the parts with solutions here when the site is not consulted, along with the days that have
+//! an input waiting but no solution yet.
+
+use std::{collections::HashMap, fmt, path::Path};
+
+use crate::{registry, schedule};
+
+/// Progress on a single day
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: i32,
+    /// Stars shown on the site's calendar, if it was consulted
+    pub stars: Option<u8>,
+    /// Parts with a registered solution
+    pub parts_solved: i32,
+    /// Whether the day's default input is present
+    pub has_input: bool,
+}
+
+impl DayStats {
+    /// Stars from the site when known, otherwise one per solved part
+    pub fn earned(&self) -> u32 {
+        self.stars
+            .map_or(self.parts_solved.unsigned_abs(), u32::from)
+    }
+
+    /// Whether there is an input to work on but nothing solves it yet
+    pub fn needs_solution(&self) -> bool {
+        self.has_input && self.parts_solved == 0
+    }
+}
+
+/// Progress on every day of one event
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YearStats {
+    pub year: i32,
+    pub days: Vec<DayStats>,
+}
+
+impl YearStats {
+    /// Progress on `year` with inputs looked up in `data_dir` and `stars` read from the site's
+    /// calendar, if it was fetched
+    pub fn gather(year: i32, data_dir: &Path, stars: Option<&HashMap<i32, u8>>) -> Self {
+        let days = (1..=schedule::days_in_event(year))
+            .map(|day| DayStats {
+                day,
+                stars: stars.map(|s| s.get(&day).copied().unwrap_or(0)),
+                parts_solved: registry::parts_solved(year, day),
+                has_input: registry::resolve_input(year, day, data_dir, None).exists(),
+            })
+            .collect();
+
+        Self { year, days }
+    }
+
+    pub fn earned(&self) -> u32 {
+        self.days.iter().map(DayStats::earned).sum()
+    }
+
+    /// Stars available in the event, two per day
+    pub fn available(&self) -> u32 {
+        2 * u32::try_from(self.days.len()).expect("events have few days")
+    }
+
+    pub fn percent_complete(&self) -> f64 {
+        100.0 * f64::from(self.earned()) / f64::from(self.available())
+    }
+
+    /// Whether the stars came from the site rather than the solutions here
+    pub fn from_site(&self) -> bool {
+        self.days.iter().any(|d| d.stars.is_some())
+    }
+
+    /// Days with an input but no solution, the natural candidates for backfilling
+    pub fn needing_solutions(&self) -> Vec<i32> {
+        self.days
+            .iter()
+            .filter(|d| d.needs_solution())
+            .map(|d| d.day)
+            .collect()
+    }
+}
+
+impl fmt::Display for YearStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Padded to the longest calendar so the counts line up between years
+        let bar = self
+            .days
+            .iter()
+            .map(|d| match d.earned() {
+                0 if d.needs_solution() => '!',
+                0 => '.',
+                1 => '+',
+                _ => '*',
+            })
+            .collect::<String>();
+
+        write!(
+            f,
+            "{}: {bar:<25} {:>2}/{} stars ({:.0}%)",
+            self.year,
+            self.earned(),
+            self.available(),
+            self.percent_complete()
+        )?;
+
+        if !self.from_site() {
+            write!(f, " from solutions")?;
+        }
+
+        let waiting = self.needing_solutions();
+
+        if !waiting.is_empty() {
+            let days = waiting
+                .iter()
+                .map(i32::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            write!(f, "; unsolved inputs on days {days}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the stars earned on each day from an event's calendar page, where each day is labelled
+/// like `aria-label="Day 3, two stars"`
+pub fn parse_calendar_stars(html: &str) -> HashMap<i32, u8> {
+    html.split("aria-label=\"Day ")
+        .skip(1)
+        .filter_map(|label| {
+            let label = &label[..label.find('"')?];
+            let (day, stars) = label.split_once(',').unwrap_or((label, ""));
+
+            let stars = match stars.trim() {
+                "one star" => 1,
+                "two stars" => 2,
+                _ => 0,
+            };
+
+            Some((day.trim().parse().ok()?, stars))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn calendar_stars() {
+        let html = r#"<pre class="calendar">
+            <a aria-label="Day 1, two stars" href="/2016/day/1" class="calendar-day1 calendar-verycomplete">
+            <a aria-label="Day 2, one star" href="/2016/day/2" class="calendar-day2 calendar-complete">
+            <a aria-label="Day 3" href="/2016/day/3" class="calendar-day3">
+        </pre>"#;
+
+        assert_eq!(
+            parse_calendar_stars(html),
+            HashMap::from([(1, 2), (2, 1), (3, 0)])
+        );
+    }
+
+    #[test]
+    fn year_summary() {
+        let day = |day, stars, parts_solved, has_input| DayStats {
+            day,
+            stars,
+            parts_solved,
+            has_input,
+        };
+
+        let local = YearStats {
+            year: 2016,
+            days: vec![
+                day(1, None, 2, true),
+                day(2, None, 1, true),
+                day(3, None, 0, true),
+                day(4, None, 0, false),
+            ],
+        };
+
+        assert_eq!(local.earned(), 3);
+        assert_eq!(local.available(), 8);
+        assert_eq!(local.needing_solutions(), vec![3]);
+        assert_eq!(
+            local.to_string(),
+            "2016: *+!.                       3/8 stars (38%) from solutions; unsolved inputs on days 3"
+        );
+
+        // The site's stars win, since a day may have been solved somewhere else
+        let site = YearStats {
+            year: 2016,
+            days: vec![day(1, Some(2), 0, false), day(2, Some(0), 0, false)],
+        };
+
+        assert_eq!(
+            site.to_string(),
+            "2016: *.                         2/4 stars (50%)"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "y2015")]
+    fn gather_from_registry() {
+        let stats = YearStats::gather(2015, Path::new("/nonexistent"), None);
+
+        assert_eq!(stats.days.len(), 25);
+        assert_eq!(stats.earned(), 50);
+        assert!(stats.needing_solutions().is_empty());
+    }
+}
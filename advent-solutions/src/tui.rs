@@ -0,0 +1,420 @@
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use ratatui::{
+    crossterm::{
+        event::{self, Event, KeyCode, KeyEventKind},
+        execute,
+        terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    layout::{Constraint, Layout},
+    style::{Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, List, ListItem, ListState, Paragraph},
+    DefaultTerminal, Frame,
+};
+
+use crate::{
+    bench::PartTiming,
+    registry::{self, Solution},
+    threads,
+};
+
+/// Number of runs per part when benchmarking from the browser
+const BENCH_RUNS: usize = 10;
+
+/// How often the browser checks for finished runs while waiting for key presses
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A line in the solution tree
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Row {
+    Year(i32),
+    Day(i32, i32),
+}
+
+/// Progress of running a single part from the browser
+enum PartState {
+    Running,
+    Done {
+        answer: Result<String, String>,
+        times: Vec<Duration>,
+    },
+}
+
+/// Message sent from a worker thread when a part has finished running
+struct PartUpdate {
+    key: (i32, i32, i32),
+    state: PartState,
+}
+
+/// Runs `solution` `runs` times against its input, already loaded into memory
+fn time_solution(solution: Solution, input: Option<&[u8]>, runs: usize) -> PartState {
+    let timing = PartTiming::measure(solution, input, runs);
+
+    PartState::Done {
+        answer: timing.answer,
+        times: timing.times,
+    }
+}
+
+/// Browser state: the solution tree, the current selection, and results of previous runs
+struct App {
+    data_dir: PathBuf,
+    collapsed: HashSet<i32>,
+    list: ListState,
+    parts: HashMap<(i32, i32, i32), PartState>,
+    status: String,
+    updates: Receiver<PartUpdate>,
+    sender: Sender<PartUpdate>,
+}
+
+impl App {
+    fn new(data_dir: &Path) -> Self {
+        let (sender, updates) = mpsc::channel();
+
+        Self {
+            data_dir: data_dir.to_owned(),
+            collapsed: HashSet::new(),
+            list: ListState::default().with_selected(Some(0)),
+            parts: HashMap::new(),
+            status: String::new(),
+            updates,
+            sender,
+        }
+    }
+
+    /// Visible rows of the tree, leaving out the days of collapsed years
+    fn rows(&self) -> Vec<Row> {
+        visible_rows(&self.collapsed)
+    }
+
+    fn selected(&self) -> Option<Row> {
+        self.list
+            .selected()
+            .and_then(|i| self.rows().get(i).copied())
+    }
+
+    fn select_row(&mut self, row: Row) {
+        let idx = self.rows().iter().position(|&r| r == row);
+
+        self.list.select(idx);
+    }
+
+    fn toggle_year(&mut self, year: i32, collapse: bool) {
+        if collapse {
+            self.collapsed.insert(year);
+        } else {
+            self.collapsed.remove(&year);
+        }
+
+        self.select_row(Row::Year(year));
+    }
+
+    /// Runs every part of the selected day on a background thread, `runs` times each
+    fn run_selected(&mut self, runs: usize) {
+        let Some(Row::Day(year, day)) = self.selected() else {
+            self.status = "Select a day to run it".to_owned();
+
+            return;
+        };
+
+        let input = fs::read(registry::resolve_input(year, day, &self.data_dir, None)).ok();
+
+        for solution in registry::solutions().filter(|s| (s.year, s.day) == (year, day)) {
+            let key = (year, day, solution.part);
+
+            if matches!(self.parts.get(&key), Some(PartState::Running)) {
+                continue;
+            }
+
+            let sender = self.sender.clone();
+            let input = input.clone();
+
+            self.parts.insert(key, PartState::Running);
+
+            thread::spawn(move || {
+                let state = threads::install(|| time_solution(solution, input.as_deref(), runs));
+
+                // The browser may have been closed while this was running
+                let _ = sender.send(PartUpdate { key, state });
+            });
+        }
+
+        self.status.clear();
+    }
+
+    /// Shows the input of the selected day in `$PAGER` (or `less`), suspending the browser meanwhile
+    fn open_input(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        let Some(Row::Day(year, day)) = self.selected() else {
+            return Ok(());
+        };
+
+        let path = registry::resolve_input(year, day, &self.data_dir, None);
+
+        if !path.exists() {
+            self.status = format!("{} does not exist", path.display());
+
+            return Ok(());
+        }
+
+        let pager = env::var("PAGER").unwrap_or_else(|_| "less".to_owned());
+
+        terminal::disable_raw_mode()?;
+        execute!(io::stdout(), LeaveAlternateScreen)?;
+
+        let result = Command::new(&pager).arg(&path).status();
+
+        execute!(io::stdout(), EnterAlternateScreen)?;
+        terminal::enable_raw_mode()?;
+        terminal.clear()?;
+
+        if let Err(e) = result {
+            self.status = format!("Could not run {pager}: {e}");
+        }
+
+        Ok(())
+    }
+
+    fn receive_updates(&mut self) {
+        while let Ok(PartUpdate { key, state }) = self.updates.try_recv() {
+            self.parts.insert(key, state);
+        }
+    }
+
+    /// Handles a key press, returning `false` once the browser should close
+    fn handle_key(&mut self, code: KeyCode, terminal: &mut DefaultTerminal) -> io::Result<bool> {
+        let len = self.rows().len();
+
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Down | KeyCode::Char('j') => {
+                let next = self
+                    .list
+                    .selected()
+                    .map_or(0, |i| (i + 1).min(len.saturating_sub(1)));
+
+                self.list.select(Some(next));
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                let prev = self.list.selected().map_or(0, |i| i.saturating_sub(1));
+
+                self.list.select(Some(prev));
+            }
+            KeyCode::Left | KeyCode::Char('h') => match self.selected() {
+                Some(Row::Year(year) | Row::Day(year, _)) => self.toggle_year(year, true),
+                None => {}
+            },
+            KeyCode::Right | KeyCode::Char('l') => {
+                if let Some(Row::Year(year)) = self.selected() {
+                    self.toggle_year(year, false);
+                }
+            }
+            KeyCode::Enter => match self.selected() {
+                Some(Row::Year(year)) => {
+                    let collapse = !self.collapsed.contains(&year);
+
+                    self.toggle_year(year, collapse);
+                }
+                Some(Row::Day(..)) => self.run_selected(1),
+                None => {}
+            },
+            KeyCode::Char('r') => self.run_selected(1),
+            KeyCode::Char('b') => self.run_selected(BENCH_RUNS),
+            KeyCode::Char('o') => self.open_input(terminal)?,
+            _ => {}
+        }
+
+        Ok(true)
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(frame.area());
+        let [tree, details] =
+            Layout::horizontal([Constraint::Length(24), Constraint::Min(1)]).areas(main);
+
+        let items = self
+            .rows()
+            .into_iter()
+            .map(|row| match row {
+                Row::Year(year) => {
+                    let marker = if self.collapsed.contains(&year) {
+                        '+'
+                    } else {
+                        '-'
+                    };
+
+                    ListItem::new(format!("{marker} {year}")).bold()
+                }
+                Row::Day(_, day) => ListItem::new(format!("    Day {day:02}")),
+            })
+            .collect::<Vec<_>>();
+
+        let list = List::new(items)
+            .block(Block::bordered().title("Solutions"))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(list, tree, &mut self.list);
+
+        frame.render_widget(
+            Paragraph::new(self.detail_lines()).block(Block::bordered().title("Details")),
+            details,
+        );
+
+        let help = if self.status.is_empty() {
+            "↑/↓ move  ←/→ fold  enter/r run  b benchmark  o open input  q quit"
+        } else {
+            &self.status
+        };
+
+        frame.render_widget(Line::from(help).dim(), footer);
+    }
+
+    fn detail_lines(&self) -> Vec<Line<'static>> {
+        match self.selected() {
+            Some(Row::Year(year)) => vec![
+                Line::from(format!("{year}")).bold(),
+                Line::from(format!("{} days solved", registry::days_solved(year))),
+            ],
+            Some(Row::Day(year, day)) => {
+                let path = registry::resolve_input(year, day, &self.data_dir, None);
+                let input = if path.exists() {
+                    path.display().to_string()
+                } else {
+                    format!("{} (missing)", path.display())
+                };
+
+                let mut lines = vec![
+                    Line::from(format!("{year} day {day:02}")).bold(),
+                    Line::from(format!("Input: {input}")),
+                    Line::default(),
+                ];
+
+                for part in [1, 2] {
+                    lines.push(Line::from(format!(
+                        "Part {part}: {}",
+                        describe_part(self.parts.get(&(year, day, part)))
+                    )));
+                }
+
+                lines
+            }
+            None => Vec::new(),
+        }
+    }
+}
+
+/// The tree rows for every registered year, with days only listed for expanded years
+fn visible_rows(collapsed: &HashSet<i32>) -> Vec<Row> {
+    registry::years()
+        .flat_map(|year| {
+            let days = if collapsed.contains(&year) {
+                0
+            } else {
+                registry::days_solved(year)
+            };
+
+            std::iter::once(Row::Year(year)).chain((1..=days).map(move |d| Row::Day(year, d)))
+        })
+        .collect()
+}
+
+/// Summarizes the answer and timings of a part for display
+fn describe_part(state: Option<&PartState>) -> String {
+    match state {
+        None => "not run".to_owned(),
+        Some(PartState::Running) => "running…".to_owned(),
+        Some(PartState::Done { answer, times }) => {
+            let answer = match answer {
+                Ok(a) => a.clone(),
+                Err(e) => format!("error: {e}"),
+            };
+
+            match times.as_slice() {
+                [] => answer,
+                [t] => format!("{answer} ({t:.2?})"),
+                _ => {
+                    let total = times.iter().sum::<Duration>();
+                    let runs = u32::try_from(times.len()).unwrap_or(u32::MAX);
+                    let min = times.iter().min().copied().unwrap_or_default();
+
+                    format!(
+                        "{answer} (mean {:.2?}, min {min:.2?} over {runs} runs)",
+                        total / runs
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Opens the interactive solution browser, reading inputs from `data_dir`
+pub fn run(data_dir: &Path) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let mut app = App::new(data_dir);
+
+    let result = (|| loop {
+        app.receive_updates();
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if !event::poll(POLL_INTERVAL)? {
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle_key(key.code, &mut terminal)? {
+                return Ok(());
+            }
+        }
+    })();
+
+    ratatui::restore();
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "y2015", feature = "y2016"))]
+    fn collapse_years() {
+        let all = visible_rows(&HashSet::new());
+
+        assert_eq!(all[0], Row::Year(2015));
+        assert_eq!(all[1], Row::Day(2015, 1));
+
+        let folded = visible_rows(&HashSet::from([2015]));
+
+        assert_eq!(folded[0], Row::Year(2015));
+        assert_eq!(folded[1], Row::Year(2016));
+        assert_eq!(
+            folded.len(),
+            all.len() - usize::try_from(registry::days_solved(2015)).unwrap()
+        );
+    }
+
+    #[test]
+    fn describe_timings() {
+        assert_eq!(describe_part(None), "not run");
+
+        let done = PartState::Done {
+            answer: Ok("42".to_owned()),
+            times: vec![Duration::from_millis(2), Duration::from_millis(4)],
+        };
+
+        assert_eq!(
+            describe_part(Some(&done)),
+            "42 (mean 3.00ms, min 2.00ms over 2 runs)"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{context::Context, registry};
+
+pub use advent_core::visualize::*;
+
+/// Looks up the visualizer for the given day if it has one
+pub fn find(year: i32, day: i32) -> Option<VisualizeFn> {
+    registry::year(year)?
+        .visualizers()
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, run)| run)
+}
+
+/// Plays the visualization of each of `parts` in turn with its input loaded from `data_dir` (if one
+/// exists) and the parameters set for this process. Days without a visualizer are reported to
+/// stderr and otherwise ignored.
+pub fn run(
+    year: i32,
+    day: i32,
+    parts: &[i32],
+    data_dir: &Path,
+    input_name: Option<&str>,
+    player: &mut TerminalPlayer,
+) -> anyhow::Result<()> {
+    let Some(visualizer) = find(year, day) else {
+        eprintln!("No visualization exists for day {day} of {year}");
+
+        return Ok(());
+    };
+
+    let path = registry::resolve_input(year, day, data_dir, input_name);
+
+    for &part in parts {
+        let mut reader = File::open(&path).map(BufReader::new).ok();
+
+        visualizer(
+            &mut Context::new(reader.as_mut().map(|r| r as &mut dyn BufRead)),
+            part,
+            &mut |frame| player.show(frame),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Draws frames to the terminal, replacing the previous frame each time, no faster than `fps`
+/// frames per second.
+pub struct TerminalPlayer {
+    frame_time: Duration,
+    last: Option<Instant>,
+}
+
+impl TerminalPlayer {
+    pub fn new(fps: u32) -> Self {
+        Self {
+            frame_time: Duration::from_secs(1) / fps.max(1),
+            last: None,
+        }
+    }
+
+    pub fn show(&mut self, frame: &str) {
+        if let Some(remaining) = self
+            .last
+            .and_then(|l| self.frame_time.checked_sub(l.elapsed()))
+        {
+            thread::sleep(remaining);
+        }
+
+        let mut stdout = io::stdout().lock();
+
+        // Clear the screen and move home before drawing. Failing to draw is purely cosmetic.
+        let _ = write!(stdout, "\x1b[2J\x1b[H{frame}");
+        let _ = stdout.flush();
+
+        self.last = Some(Instant::now());
+    }
+}
+
+#[cfg(all(test, feature = "y2015"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn visualize_registered() {
+        let run = find(2015, 18).unwrap();
+        let mut frames = Vec::new();
+        let mut input = b".#\n##".as_slice();
+
+        run(&mut Context::new(Some(&mut input)), 1, &mut |f| {
+            frames.push(f.to_owned())
+        })
+        .unwrap();
+
+        assert_eq!(frames.len(), 101);
+        assert!(frames[0].starts_with(".#\n##\n"));
+        assert!(find(2015, 1).is_none());
+
+        // The lights are animated for as many steps as the parts solve them for
+        let mut steps = Vec::new();
+        let mut input = b".#\n##".as_slice();
+
+        run(
+            &mut Context::new(Some(&mut input)).with_param("steps", 4),
+            2,
+            &mut |f| steps.push(f.to_owned()),
+        )
+        .unwrap();
+
+        assert_eq!(steps.len(), 5);
+    }
+}
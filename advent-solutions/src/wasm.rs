@@ -0,0 +1,15 @@
+//! JavaScript bindings for running the solvers in a web page. Inputs are passed in as strings since
+//! there is no file system to read them from.
+
+use wasm_bindgen::prelude::*;
+
+use crate::registry;
+
+/// Solves one part of a puzzle from its input text, returning the answer as a string. Throws when
+/// no solution is registered for the puzzle or the input cannot be solved.
+#[wasm_bindgen]
+pub fn solve(year: i32, day: i32, part: i32, input: &str) -> Result<String, JsError> {
+    registry::solve(year, day, part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|e| JsError::new(&format!("{e:#}")))
+}
@@ -1,3 +0,0 @@
-use advent_macros::generate_year;
-
-generate_year!(2022 8);
@@ -1,11 +1,28 @@
 use std::io::BufRead;
 
-pub fn part_01(_reader: Option<impl BufRead>) {
-    unimplemented!()
-}
+use advent_core::{answer::Answer, day::Day, describe::DayInfo};
+
+pub const INFO: DayInfo = DayInfo {
+    title: "Day DAY",
+    links: &[],
+};
+
+pub struct Puzzle;
+
+impl Day for Puzzle {
+    type Input = Vec<String>;
+
+    fn parse(reader: impl BufRead) -> anyhow::Result<Self::Input> {
+        Ok(reader.lines().collect::<Result<_, _>>()?)
+    }
+
+    fn part_01(_input: &Self::Input) -> Answer {
+        unimplemented!()
+    }
 
-pub fn part_02(_reader: Option<impl BufRead>) {
-    unimplemented!()
+    fn part_02(_input: &Self::Input) -> Answer {
+        unimplemented!()
+    }
 }
 
 #[cfg(test)]
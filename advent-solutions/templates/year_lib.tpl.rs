@@ -0,0 +1,5 @@
+//! Solutions to the YEAR puzzles.
+
+use advent_macros::generate_year;
+
+generate_year!(YEAR MAX_DAY);
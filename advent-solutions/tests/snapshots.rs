@@ -0,0 +1,76 @@
+//! Runs the CLI on every solved part that has an input committed under `data` and compares what
+//! it prints against the snapshots in `tests/snapshots`, so days covered by few or no unit tests
+//! still fail loudly when their answers change. New or changed snapshots are reviewed with
+//! `cargo insta review`, or written directly by running with `INSTA_UPDATE=always`.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use advent_solutions::{config::CONFIG_PATH_VAR, registry};
+
+/// Committed input for `year`/`day`, named the way the downloader saves them
+fn committed_input(year: i32, day: i32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data")
+        .join(format!("{year}-{day:02}.txt"))
+}
+
+/// What the CLI prints to stdout when asked for `puzzle`, with the run recorded under `scratch`
+/// rather than anywhere the user would notice
+fn printed(puzzle: &str, scratch: &Path) -> String {
+    let template = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data")
+        .join("{year}-{day:02}.txt");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_advent-solutions"))
+        .arg(puzzle)
+        .env(CONFIG_PATH_VAR, scratch.join("config.toml"))
+        .env(registry::INPUT_TEMPLATE_VAR, template)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .expect("the CLI should start");
+
+    assert!(
+        output.status.success(),
+        "{puzzle} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).expect("answers should be UTF-8")
+}
+
+#[test]
+fn printed_answers() {
+    let scratch = env::temp_dir().join(format!("advent-snapshots-{}", std::process::id()));
+    let data_dir = scratch.join("data");
+
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(
+        scratch.join("config.toml"),
+        format!("data_dir = {:?}\n", data_dir.display().to_string()),
+    )
+    .unwrap();
+
+    let parts = registry::solutions()
+        .filter(|s| committed_input(s.year, s.day).exists())
+        .collect::<Vec<_>>();
+
+    // Inputs are committed for 2015, while building only another year may leave nothing to run
+    assert!(
+        !parts.is_empty() || !cfg!(feature = "y2015"),
+        "no committed inputs were found"
+    );
+
+    for solution in parts {
+        let puzzle = format!("{}-{:02}-{}", solution.year, solution.day, solution.part);
+
+        insta::assert_snapshot!(puzzle.clone(), printed(&puzzle, &scratch));
+    }
+
+    fs::remove_dir_all(&scratch).unwrap();
+}
@@ -0,0 +1,61 @@
+//! Solves every part with an input committed under `data` from a copy of the input saved the way
+//! Windows tools tend to save it, with a byte order mark, `\r\n` line endings, and blank lines at
+//! the end, and checks the answers match those from the input as committed.
+
+use std::{fs, path::Path};
+
+use advent_solutions::{context::Context, registry};
+
+/// `input` as saved with a byte order mark, `\r\n` line endings, and trailing blank lines
+fn windows_copy(input: &[u8]) -> Vec<u8> {
+    let mut copy = b"\xEF\xBB\xBF".to_vec();
+
+    for &b in input {
+        if b == b'\n' {
+            copy.push(b'\r');
+        }
+
+        copy.push(b);
+    }
+
+    copy.extend_from_slice(b"\r\n\r\n  \r\n");
+    copy
+}
+
+#[test]
+fn same_answers_from_windows_inputs() {
+    let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+    let mut checked = 0;
+
+    for entry in fs::read_dir(&data).expect("inputs should be committed") {
+        let path = entry.unwrap().path();
+        let Some((year, day)) = path
+            .file_stem()
+            .and_then(|s| s.to_str()?.split_once('-'))
+            .and_then(|(y, d)| Some((y.parse::<i32>().ok()?, d.parse::<i32>().ok()?)))
+        else {
+            continue;
+        };
+        let input = fs::read(&path).unwrap();
+        let Some(solve) = registry::find_day(year, day) else {
+            continue;
+        };
+        let expected = solve(&mut Context::new(Some(&mut input.as_slice())));
+        let windows = windows_copy(&input);
+        let answers = solve(&mut Context::new(Some(&mut windows.as_slice())));
+
+        assert_eq!(
+            answers.map_err(|e| e.to_string()),
+            expected.map_err(|e| e.to_string()),
+            "{year}/{day:02}"
+        );
+
+        checked += 1;
+    }
+
+    // Only 2015 and later years have inputs committed, so a build of 2016 alone checks nothing
+    assert!(
+        checked > 0 || !cfg!(feature = "y2015"),
+        "some inputs should be committed"
+    );
+}